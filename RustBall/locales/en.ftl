@@ -0,0 +1,100 @@
+# Game texts in English (see src/i18n.rs)
+
+## Formation menu
+formacion-elige = Choose your formation
+formacion-esperando = ⏳ Waiting for your opponent…
+formacion-elige-jugador = { $nombre }: choose your formation
+formacion-a-jugar = Let's play!
+formacion-cantidad = A formation needs { $esperadas } disks (it has { $tiene })
+ficha-coordenadas = Disk { $n }: invalid coordinates
+ficha-campo = Disk { $n }: must be fully inside your half
+ficha-fuera = Disk { $n }: is outside the pitch
+ficha-area = Disk { $n }: cannot be inside the box
+fichas-superpuestas = Disks { $a } and { $b }: overlap
+
+## HUD
+hud-turno-inicial = Turn: Player 1
+hud-marcador-inicial = Player 1: 0 | Player 2: 0
+turno-de = Turn: { $nombre }
+turno-tuyo = Your turn
+turno-rival = Opponent's turn (UID { $uid })
+rival-desconectado = ⚠️ Your opponent disconnected
+gol = GOOOOAL!
+
+## Players
+jugador-izquierdo = Left Player
+jugador-derecho = Right Player
+jugador-tu = You
+jugador-bot = RustBot ({ $dificultad })
+dificultad-facil = easy
+dificultad-medio = medium
+dificultad-dificil = hard
+
+## Events and power-ups
+efecto-resbalosa = Current effect: Slippery zone
+efecto-lenta = Current effect: Slow zone
+efecto-trampolin = Current effect: Bounce pad
+powerup-velocidad = Speed
+powerup-doble-rebote = Double Bounce
+powerup-doble-turno = Double Turn
+
+## End of match
+fin-gana = { $nombre } wins!
+fin-empate = It's a draw!
+fin-ganaste = You won!
+fin-perdiste = You lost
+fin-tu = (you)
+fin-tiros = Shots
+fin-goles = Goals
+fin-powerups = Power-ups collected
+fin-dominio = Ball in rival half
+fin-elo = Elo { $elo } ({ $cambio })
+fin-elo-cargando = Elo: calculating…
+fin-elo-no-disponible = Elo not available
+fin-sin-ranking = Unranked match
+fin-revancha = Rematch
+fin-repeticion = Replay
+fin-volver-lobby = Back to lobby
+fin-ver-perfil = Opponent profile
+fin-cerrar-repeticion = Back to results
+fin-repeticion-vacia = Nothing to replay
+fin-revancha-creando = Creating the rematch…
+fin-revancha-error = Could not create the rematch: { $error }
+fin-revancha-relanzar = Rematch ready: relaunch the game with --partida { $partida }
+
+## Settings and pause
+ajustes = Settings
+ajustes-volumen = Master volume
+ajustes-musica = Music
+ajustes-efectos = Effects
+ajustes-silencio = Mute
+ajustes-mira = Aim sensitivity
+ajustes-carga = Charge speed
+ajustes-trayectoria = Show trajectory
+ajustes-daltonicos = Colorblind colors
+ajustes-idioma = Language
+ajustes-restablecer = Reset
+ajustes-volver = Back
+ajustes-si = Yes
+ajustes-no = No
+ajustes-pulsa-tecla = Press a key…
+pausa = Paused
+pausa-reanudar = Resume
+pausa-en-linea = Online matches keep running
+
+## Keys
+tecla-apuntar-izquierda = Aim left
+tecla-apuntar-derecha = Aim right
+tecla-apuntar-arriba = Aim up
+tecla-apuntar-abajo = Aim down
+tecla-disparar = Charge / shoot
+tecla-cambiar-ficha = Switch disk
+
+## Achievements
+logro-desbloqueado = Achievement unlocked!
+logro-primera-victoria = First win
+logro-hat-trick = Hat-trick
+logro-porteria-a-cero = Clean sheet
+logro-gol-de-poste = Off the post
+logro-ganar-con = Win with the { $formacion }
+logro-coleccionista = Power-up collector
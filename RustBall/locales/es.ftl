@@ -0,0 +1,100 @@
+# Textos del juego en español (ver src/i18n.rs)
+
+## Menú de formaciones
+formacion-elige = Elige tu formación
+formacion-esperando = ⏳ Esperando a tu rival…
+formacion-elige-jugador = { $nombre }: elige tu formación
+formacion-a-jugar = ¡A jugar!
+formacion-cantidad = La formación debe tener { $esperadas } fichas (tiene { $tiene })
+ficha-coordenadas = Ficha { $n }: coordenadas inválidas
+ficha-campo = Ficha { $n }: debe quedar entera en tu campo
+ficha-fuera = Ficha { $n }: queda fuera de la cancha
+ficha-area = Ficha { $n }: no puede estar dentro del área
+fichas-superpuestas = Fichas { $a } y { $b }: se superponen
+
+## HUD
+hud-turno-inicial = Turno: Jugador 1
+hud-marcador-inicial = Jugador 1: 0 | Jugador 2: 0
+turno-de = Turno: { $nombre }
+turno-tuyo = Tu turno
+turno-rival = Turno del rival (UID { $uid })
+rival-desconectado = ⚠️ Tu rival se desconectó
+gol = ¡GOOOOL!
+
+## Jugadores
+jugador-izquierdo = Jugador Izquierdo
+jugador-derecho = Jugador Derecho
+jugador-tu = Tú
+jugador-bot = RustBot ({ $dificultad })
+dificultad-facil = fácil
+dificultad-medio = medio
+dificultad-dificil = difícil
+
+## Eventos y power-ups
+efecto-resbalosa = Efecto actual: Zona resbalosa
+efecto-lenta = Efecto actual: Zona lenta
+efecto-trampolin = Efecto actual: Trampolín
+powerup-velocidad = Velocidad
+powerup-doble-rebote = Doble Rebote
+powerup-doble-turno = Doble Turno
+
+## Fin del partido
+fin-gana = ¡Ganó { $nombre }!
+fin-empate = ¡Empate!
+fin-ganaste = ¡Ganaste!
+fin-perdiste = Perdiste
+fin-tu = (tú)
+fin-tiros = Tiros
+fin-goles = Goles
+fin-powerups = Power-ups recogidos
+fin-dominio = Pelota en campo rival
+fin-elo = Elo { $elo } ({ $cambio })
+fin-elo-cargando = Elo: calculando…
+fin-elo-no-disponible = Elo no disponible
+fin-sin-ranking = Partida sin ranking
+fin-revancha = Revancha
+fin-repeticion = Repetición
+fin-volver-lobby = Volver al lobby
+fin-ver-perfil = Perfil del rival
+fin-cerrar-repeticion = Volver a resultados
+fin-repeticion-vacia = No hay jugadas para repetir
+fin-revancha-creando = Creando la revancha…
+fin-revancha-error = No se pudo crear la revancha: { $error }
+fin-revancha-relanzar = Revancha lista: relanza el juego con --partida { $partida }
+
+## Ajustes y pausa
+ajustes = Ajustes
+ajustes-volumen = Volumen general
+ajustes-musica = Música
+ajustes-efectos = Efectos
+ajustes-silencio = Silencio
+ajustes-mira = Sensibilidad de mira
+ajustes-carga = Velocidad de carga
+ajustes-trayectoria = Mostrar trayectoria
+ajustes-daltonicos = Colores daltónicos
+ajustes-idioma = Idioma
+ajustes-restablecer = Restablecer
+ajustes-volver = Volver
+ajustes-si = Sí
+ajustes-no = No
+ajustes-pulsa-tecla = Pulsa una tecla…
+pausa = Pausa
+pausa-reanudar = Reanudar
+pausa-en-linea = En línea la partida no se detiene
+
+## Teclas
+tecla-apuntar-izquierda = Apuntar izquierda
+tecla-apuntar-derecha = Apuntar derecha
+tecla-apuntar-arriba = Apuntar arriba
+tecla-apuntar-abajo = Apuntar abajo
+tecla-disparar = Cargar / disparar
+tecla-cambiar-ficha = Cambiar ficha
+
+## Logros
+logro-desbloqueado = ¡Logro desbloqueado!
+logro-primera-victoria = Primera victoria
+logro-hat-trick = Hat-trick
+logro-porteria-a-cero = Portería a cero
+logro-gol-de-poste = Gol de poste
+logro-ganar-con = Ganar con la { $formacion }
+logro-coleccionista = Coleccionista de power-ups
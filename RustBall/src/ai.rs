@@ -0,0 +1,118 @@
+//! src/ai.rs
+//! --------------------------------------------------------------
+//! Rival controlado por la máquina (modo `GameMode::VsBot`).
+//!
+//! En su turno la IA prueba, para cada una de sus fichas, una
+//! rejilla de direcciones × potencias con el modelo simplificado de
+//! `rustball_shared` (el mismo que los bots del servidor), puntúa
+//! cada tiro según cuánto acerca la pelota al arco rival y cuánto
+//! riesgo deja frente al propio, y dispara el mejor.  La dificultad
+//! cambia la densidad de la rejilla, el error de puntería y cuántos
+//! de los mejores tiros se consideran.
+//! --------------------------------------------------------------
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::{Sleeping, Velocity};
+use rand::seq::SliceRandom;
+use rustball_shared::bot::plan_shot;
+
+use crate::components::{Ball, OwnedBy, PlayerDisk};
+use crate::i18n::t;
+use crate::resources::{Formation, GameMode, PlayerFormations, TurnState};
+use crate::systems::turn_systems::SHOT_MAX_SPEED;
+use crate::trajectory::{to_vec2, SimBody};
+
+/// Dificultad del bot: las tablas son las mismas que las de los bots del
+/// servidor (`rustball_shared::bot`).
+pub use rustball_shared::bot::Difficulty as BotDifficulty;
+
+/// Nombre traducido de la dificultad.
+pub fn difficulty_label(difficulty: BotDifficulty) -> String {
+    match difficulty {
+        BotDifficulty::Easy => t!("dificultad-facil"),
+        BotDifficulty::Medium => t!("dificultad-medio"),
+        BotDifficulty::Hard => t!("dificultad-dificil"),
+    }
+}
+
+/* ───────── Sistemas ───────── */
+
+/// Pausa antes de que la IA dispare, para que el jugador vea la jugada.
+#[derive(Resource)]
+pub struct BotThinkTimer(pub Timer);
+
+impl Default for BotThinkTimer {
+    fn default() -> Self {
+        Self(Timer::from_seconds(0.8, TimerMode::Once))
+    }
+}
+
+/// Elige al azar la formación del bot cuando el jugador ya eligió la suya.
+pub fn bot_choose_formation(mode: Res<GameMode>, mut formations: ResMut<PlayerFormations>) {
+    if mode.bot_uid().is_none() || formations.player1.is_none() || formations.player2.is_some() {
+        return;
+    }
+
+    formations.player2 = Formation::PRESETS.choose(&mut rand::thread_rng()).copied();
+    info!("🤖 El bot eligió la formación {:?}", formations.player2);
+}
+
+/// Dispara el tiro de la IA cuando es su turno y todo está quieto.
+#[allow(clippy::too_many_arguments)]
+pub fn bot_take_turn(
+    mode: Res<GameMode>,
+    time: Res<Time>,
+    mut timer: ResMut<BotThinkTimer>,
+    mut turn_state: ResMut<TurnState>,
+    disks: Query<(Entity, &Transform, &OwnedBy), With<PlayerDisk>>,
+    balls: Query<(Entity, &Transform), With<Ball>>,
+    mut velocities: Query<&mut Velocity>,
+    mut commands: Commands,
+) {
+    let (GameMode::VsBot(difficulty), Some(bot_uid)) = (*mode, mode.bot_uid()) else {
+        return;
+    };
+    if turn_state.in_motion || turn_state.current_turn_id != bot_uid {
+        timer.0.reset();
+        return;
+    }
+    if !timer.0.tick(time.delta()).finished() {
+        return;
+    }
+    timer.0.reset();
+
+    let Some((ball_entity, ball_tf)) = balls.iter().next() else { return };
+    let ball = SimBody::ball(Some(ball_entity), ball_tf.translation.truncate());
+
+    let mut shooters = Vec::new();
+    let mut bodies = vec![ball];
+    for (entity, tf, owner) in &disks {
+        if owner.0 == bot_uid {
+            shooters.push(bodies.len());
+        }
+        bodies.push(SimBody::disk(Some(entity), tf.translation.truncate()));
+    }
+
+    // El bot juega a la derecha ⇒ ataca el arco izquierdo
+    let shared: Vec<_> = bodies.iter().map(SimBody::shared).collect();
+    let Some(plan) = plan_shot(&shooters, &shared, true, difficulty, &mut rand::thread_rng()) else {
+        warn!("🤖 El bot no encontró ningún tiro");
+        return;
+    };
+    let Some(disk) = bodies[plan.shooter].entity else { return };
+    let direction = to_vec2(plan.direction);
+
+    if let Ok(mut vel) = velocities.get_mut(disk) {
+        vel.linvel = direction * plan.power * SHOT_MAX_SPEED;
+        commands.entity(disk).remove::<Sleeping>();
+        turn_state.in_motion = true;
+        turn_state.power = 0.0;
+        info!(
+            "🤖 Bot ({}) dispara {:?} con potencia {:.2} (puntuación {:.1})",
+            difficulty_label(difficulty),
+            direction,
+            plan.power,
+            plan.score
+        );
+    }
+}
@@ -0,0 +1,276 @@
+//! src/audio.rs
+//! --------------------------------------------------------------
+//! Sonido del juego.
+//!
+//!   • Música de fondo según `AppState` (menú, partido, fin).
+//!   • Cántico de gol con `GoalEvent`; si el gol lo detectó el rival
+//!     (llega por snapshot) suena al entrar en `GoalScored`.
+//!   • Choques: un tono corto por cada `CollisionEvent` de rapier,
+//!     más fuerte cuanto mayor la velocidad relativa del impacto.
+//!   • Recoger un power-up (`PowerUpPickedEvent`) suena con un acorde.
+//!   • Volumen (general, música, efectos) y silencio en `AudioSettings`,
+//!     guardados con `storage` (localStorage en el navegador).
+//!     Atajos: `M` silencia, `-` / `+` bajan / suben el volumen general.
+//!
+//! No hay archivos para choques ni power-ups: se sintetizan con `Pitch`.
+//! --------------------------------------------------------------
+
+use std::time::Duration;
+
+use bevy::audio::{PlaybackMode, Volume};
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use bevy_rapier2d::rapier::geometry::CollisionEventFlags;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    components::{Ball, PlayerDisk},
+    events::{GoalEvent, PowerUpPickedEvent},
+    resources::AppState,
+    storage,
+    systems::turn_systems::SHOT_MAX_SPEED,
+};
+
+const STORAGE_KEY: &str = "rb_audio";
+
+const GOAL_CHANT: &str = "audio/mariano-closs-ahi-estaaaaa-gooool.ogg";
+const MENU_MUSIC: &str = "audio/uefa-champions-league-theme.mp3";
+const MATCH_MUSIC: &str = "audio/Avicii_-_The_Nights_CeeNaija.com_.ogg";
+const GAME_OVER_MUSIC: &str = "audio/love_me_again.ogg";
+
+/// Por debajo de esta velocidad relativa (px/s) un choque no suena.
+const MIN_IMPACT_SPEED: f32 = 40.0;
+/// Tope de choques que suenan en un mismo frame.
+const MAX_IMPACTS_PER_FRAME: usize = 4;
+const IMPACT_DURATION: Duration = Duration::from_millis(60);
+
+/* ───────── Preferencias ───────── */
+
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AudioSettings {
+    /// Volumen general 0‥1.
+    pub master: f32,
+    pub music: f32,
+    pub effects: f32,
+    pub muted: bool,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self { master: 0.5, music: 0.6, effects: 1.0, muted: false }
+    }
+}
+
+impl AudioSettings {
+    /// Lee las preferencias guardadas (o las de por defecto).
+    pub fn load() -> Self {
+        storage::get(STORAGE_KEY)
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) {
+        if let Ok(json) = serde_json::to_string(self) {
+            storage::set(STORAGE_KEY, &json);
+        }
+    }
+
+    pub fn music_volume(&self) -> f32 {
+        if self.muted { 0.0 } else { self.master * self.music }
+    }
+
+    pub fn effects_volume(&self) -> f32 {
+        if self.muted { 0.0 } else { self.master * self.effects }
+    }
+}
+
+/* ───────── Componentes ───────── */
+
+/// Pista de música sonando (una a la vez).
+#[derive(Component)]
+pub struct MusicTrack(&'static str);
+
+/// Cántico de gol en curso (evita que suene dos veces el mismo gol).
+#[derive(Component)]
+pub struct GoalChant;
+
+fn music_for(state: &AppState) -> &'static str {
+    match state {
+        AppState::FormationSelection => MENU_MUSIC,
+        AppState::InGame | AppState::GoalScored | AppState::FormationChange => MATCH_MUSIC,
+        AppState::GameOver => GAME_OVER_MUSIC,
+    }
+}
+
+/* ───────── Sistemas ───────── */
+
+/// Startup: carga las preferencias guardadas.
+pub fn load_audio_settings(mut commands: Commands) {
+    let settings = AudioSettings::load();
+    info!("🔊 Audio: {:?}", settings);
+    commands.insert_resource(settings);
+}
+
+/// Cambia la música cuando cambia el estado (si la pista es otra).
+pub fn switch_music_on_state(
+    mut commands: Commands,
+    state: Res<State<AppState>>,
+    settings: Res<AudioSettings>,
+    asset_server: Res<AssetServer>,
+    current: Query<(Entity, &MusicTrack)>,
+) {
+    if !state.is_changed() {
+        return;
+    }
+
+    let wanted = music_for(state.get());
+    if current.iter().any(|(_, t)| t.0 == wanted) {
+        return;
+    }
+    for (entity, _) in &current {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    commands.spawn((
+        AudioBundle {
+            source: asset_server.load(wanted),
+            settings: PlaybackSettings::LOOP.with_volume(Volume::new_relative(settings.music_volume())),
+        },
+        MusicTrack(wanted),
+    ));
+}
+
+/// Aplica a la música en curso los cambios de volumen.
+pub fn apply_music_volume(settings: Res<AudioSettings>, sinks: Query<&AudioSink, With<MusicTrack>>) {
+    if !settings.is_changed() {
+        return;
+    }
+    for sink in &sinks {
+        sink.set_volume(settings.music_volume());
+    }
+}
+
+/// `M` silencia; `-` / `+` ajustan el volumen general.  Se guarda al cambiar.
+pub fn audio_hotkeys(keys: Res<Input<KeyCode>>, mut settings: ResMut<AudioSettings>) {
+    let before = *settings;
+
+    if keys.just_pressed(KeyCode::M) {
+        settings.muted = !settings.muted;
+    }
+    if keys.any_just_pressed([KeyCode::Minus, KeyCode::NumpadSubtract]) {
+        settings.master = (settings.master - 0.1).max(0.0);
+    }
+    if keys.any_just_pressed([KeyCode::Equals, KeyCode::NumpadAdd]) {
+        settings.master = (settings.master + 0.1).min(1.0);
+    }
+
+    if *settings != before {
+        info!("🔊 Volumen {:.0}%{}", settings.master * 100.0, if settings.muted { " (silenciado)" } else { "" });
+        settings.save();
+    }
+}
+
+fn spawn_goal_chant(commands: &mut Commands, asset_server: &AssetServer, settings: &AudioSettings) {
+    commands.spawn((
+        AudioBundle {
+            source: asset_server.load(GOAL_CHANT),
+            settings: PlaybackSettings {
+                mode: PlaybackMode::Despawn,
+                volume: Volume::new_relative(settings.effects_volume()),
+                ..default()
+            },
+        },
+        GoalChant,
+    ));
+}
+
+/// ¡Ahí estaaaá! con cada `GoalEvent`.
+pub fn play_goal_chant(
+    mut commands: Commands,
+    mut goals: EventReader<GoalEvent>,
+    settings: Res<AudioSettings>,
+    asset_server: Res<AssetServer>,
+) {
+    if goals.read().count() > 0 {
+        spawn_goal_chant(&mut commands, &asset_server, &settings);
+    }
+}
+
+/// OnEnter(GoalScored): si el gol llegó por snapshot no hubo `GoalEvent`.
+pub fn play_goal_chant_if_silent(
+    mut commands: Commands,
+    chants: Query<(), With<GoalChant>>,
+    settings: Res<AudioSettings>,
+    asset_server: Res<AssetServer>,
+) {
+    if chants.is_empty() {
+        spawn_goal_chant(&mut commands, &asset_server, &settings);
+    }
+}
+
+/// Un tono corto por choque; volumen según la velocidad relativa y tono
+/// según lo que choca (pelota aguda, paredes graves).
+pub fn play_collision_sounds(
+    mut commands: Commands,
+    mut collisions: EventReader<CollisionEvent>,
+    bodies: Query<(&Velocity, Option<&Ball>), Or<(With<PlayerDisk>, With<Ball>)>>,
+    settings: Res<AudioSettings>,
+    mut pitches: ResMut<Assets<Pitch>>,
+) {
+    let base = settings.effects_volume();
+    let mut played = 0;
+
+    for ev in collisions.read() {
+        let CollisionEvent::Started(a, b, flags) = ev else { continue };
+        // Arcos, power-ups y zonas son sensores: no suenan como choque
+        if flags.contains(CollisionEventFlags::SENSOR) || base <= 0.0 || played >= MAX_IMPACTS_PER_FRAME {
+            continue;
+        }
+
+        // Lo que no es ficha ni pelota es pared: velocidad cero
+        let body = |e: Entity| bodies.get(e).map(|(v, ball)| (v.linvel, ball.is_some())).ok();
+        let (ra, rb) = (body(*a), body(*b));
+        let (va, ball_a) = ra.unwrap_or_default();
+        let (vb, ball_b) = rb.unwrap_or_default();
+        let speed = (va - vb).length();
+        if speed < MIN_IMPACT_SPEED {
+            continue;
+        }
+
+        let wall = ra.is_none() || rb.is_none();
+        let frequency = if ball_a || ball_b {
+            660.0
+        } else if wall {
+            160.0
+        } else {
+            330.0
+        };
+        let strength = (speed / SHOT_MAX_SPEED).clamp(0.05, 1.0);
+
+        commands.spawn(PitchBundle {
+            source: pitches.add(Pitch::new(frequency, IMPACT_DURATION)),
+            settings: PlaybackSettings::DESPAWN.with_volume(Volume::new_relative(base * strength)),
+        });
+        played += 1;
+    }
+}
+
+/// Acorde ascendente al recoger un power-up.
+pub fn play_powerup_pickup(
+    mut commands: Commands,
+    mut picked: EventReader<PowerUpPickedEvent>,
+    settings: Res<AudioSettings>,
+    mut pitches: ResMut<Assets<Pitch>>,
+) {
+    if picked.read().count() == 0 || settings.effects_volume() <= 0.0 {
+        return;
+    }
+
+    for (i, frequency) in [523.25, 659.25, 783.99].into_iter().enumerate() {
+        commands.spawn(PitchBundle {
+            source: pitches.add(Pitch::new(frequency, Duration::from_millis(90 + 60 * i as u64))),
+            settings: PlaybackSettings::DESPAWN.with_volume(Volume::new_relative(settings.effects_volume() * 0.6)),
+        });
+    }
+}
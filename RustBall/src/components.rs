@@ -9,10 +9,35 @@ pub struct PlayerDisk {
 }
 
 
+/// Identificador estable de la ficha dentro de la partida (no cambia
+/// entre snapshots, a diferencia de `Entity::index()`).
+/// Izquierda `0..5`, derecha `5..10`.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DiskId(pub u32);
+
+impl DiskId {
+    pub const PER_SIDE: u32 = 5;
+
+    pub fn for_slot(is_left: bool, idx: usize) -> Self {
+        Self(if is_left { 0 } else { Self::PER_SIDE } + idx as u32)
+    }
+}
+
 /// Pelota
 #[derive(Component)]
 pub struct Ball;
 
+/// Movimiento interpolado hacia la posición que trae un snapshot.
+/// Mientras dura, el cuerpo es cinemático y no se aceptan tiros.
+#[derive(Component, Debug)]
+pub struct BoardTween {
+    pub from: Vec2,
+    pub to: Vec2,
+    pub timer: Timer,
+    /// Velocidad con la que se suelta el cuerpo al llegar.
+    pub velocity: Vec2,
+}
+
 /// Componente marcador de turno
 #[derive(Component)]
 pub struct TurnControlled;
@@ -23,7 +48,14 @@ pub struct GoalZone {
  pub is_left: bool,
 }
 
-/// Texto animado del power-up (solo si lo dejás con parpadeo)
+/// Postes y travesaños de los arcos (estructura fija, no sensor)
+#[derive(Component)]
+pub struct GoalFrame;
+
+/// Poste del fondo del arco (el que rebota fuerte): cuenta para el logro
+/// de gol de poste
+#[derive(Component)]
+pub struct GoalPost;
 
 /// Componente que identifica al texto de turno
 #[derive(Component)]
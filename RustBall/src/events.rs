@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Event)]
+pub struct GoalEvent {
+    pub scored_by_left: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RandomEvent {
+    SlipperyZone,
+    SlowZone,
+    BouncePad,
+}
+
+#[derive(Event)]
+pub struct FormationChosenEvent {
+    pub formacion: String,   // "1-2-1-1", etc.
+    pub turno_inicio: i32,
+    /// `id_formacion` si es una formación personalizada guardada.
+    pub id_formacion: Option<i32>,
+}
+
+#[derive(Event)]
+pub struct TurnFinishedEvent;
+
+/// Una ficha recogió el power-up del campo.
+#[derive(Event)]
+pub struct PowerUpPickedEvent {
+    pub disk: Entity,
+    pub tipo: usize,
+}
+
+
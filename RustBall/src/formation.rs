@@ -1,54 +1,149 @@
 //! src/formation.rs
 //! --------------------------------------------------------------
-//! Spawnea los discos de una formación inicial o reconstruye desde snapshot
+//! Spawnea los discos de una formación inicial o reconstruye desde snapshot.
+//!
+//! Las posiciones predefinidas y las reglas de las formaciones
+//! personalizadas (5 fichas dentro de la cancha, en el campo propio,
+//! fuera del área y sin solaparse) viven en `rustball_shared::formation`,
+//! las mismas que valida el backend.
 //! --------------------------------------------------------------
 
 use bevy::prelude::*;
-use bevy_rapier2d::prelude::*;
+use rustball_shared::formation::{self as shared, FormationError};
 
-use crate::components::{PlayerDisk, OwnedBy};
-use crate::resources::{BackendInfo, Formation};
-use crate::snapshot::FormacionData;
+use crate::components::{DiskId, PlayerDisk, OwnedBy};
+use crate::i18n::t;
+use crate::physics::disk_body;
+use crate::resources::{BackendInfo, CustomFormation, Formation};
+use crate::snapshot::{BallPos, BoardSnapshot, FormacionData, FormationPos, PiezaPos};
+use crate::trajectory::{to_v2, to_vec2};
+
+pub use rustball_shared::formation::KICKOFF_RADIUS;
 
 /// Devuelve las posiciones de una formación; se reflejan si `is_left = true`
 pub fn get_formation_positions(formation: Formation, is_left: bool) -> Vec<Vec2> {
-    let flip = if is_left { -1.0 } else { 1.0 };
-
-    match formation {
-        Formation::Rombo1211 => vec![
-            Vec2::new(400.0, 0.0),
-            Vec2::new(300.0, 100.0),
-            Vec2::new(300.0, -100.0),
-            Vec2::new(200.0, 0.0),
-            Vec2::new(100.0, 0.0),
-        ],
-        Formation::Muro221 => vec![
-            Vec2::new(400.0, 100.0),
-            Vec2::new(400.0, -100.0),
-            Vec2::new(250.0, 100.0),
-            Vec2::new(250.0, -100.0),
-            Vec2::new(100.0, 0.0),
-        ],
-        Formation::Ofensiva113 => vec![
-            Vec2::new(300.0, 150.0),
-            Vec2::new(300.0, 0.0),
-            Vec2::new(300.0, -150.0),
-            Vec2::new(200.0, 0.0),
-            Vec2::new(400.0, 0.0),
-        ],
-        Formation::Diamante2111 => vec![
-            Vec2::new(400.0, 100.0),
-            Vec2::new(400.0, -100.0),
-            Vec2::new(300.0, 0.0),
-            Vec2::new(200.0, 0.0),
-            Vec2::new(100.0, 0.0),
-        ],
+    let canonical = match formation {
+        Formation::Custom(custom) => custom.positions.to_vec(),
+        preset => shared::preset(preset.as_str())
+            .expect("todas las predefinidas están en rustball_shared")
+            .map(to_vec2)
+            .to_vec(),
+    };
+
+    canonical
+        .into_iter()
+        .map(|v| to_vec2(shared::mirror(to_v2(v), is_left)))
+        .collect()
+}
+
+/// Posiciones de `data` para el saque.  Si su dueño no saca
+/// (`turno_inicio == 2`) las fichas que invaden el círculo central se
+/// empujan hasta su borde, siempre en el campo propio.
+pub fn kickoff_positions(data: &FormacionData, is_left: bool) -> Vec<Vec2> {
+    let positions = get_formation_positions(formation_from_data(data), is_left);
+    if data.turno_inicio != 2 {
+        return positions;
     }
+
+    positions
         .into_iter()
-        .map(|v| Vec2::new(v.x * flip, v.y))
+        .map(|pos| to_vec2(shared::kickoff_position(to_v2(pos), is_left)))
         .collect()
 }
 
+/// Convierte el texto que guarda el backend (`"1-2-1-1"`, …) en `Formation`.
+pub fn parse_formation(s: &str) -> Option<Formation> {
+    Formation::PRESETS.into_iter().find(|f| f.as_str() == s)
+}
+
+/// Formación de una `FormacionData`: las posiciones si es personalizada,
+/// si no la predefinida por nombre.
+pub fn formation_from_data(data: &FormacionData) -> Formation {
+    if let Some(posiciones) = &data.posiciones {
+        let positions: Vec<Vec2> = posiciones.iter().map(|p| Vec2::new(p.x, p.y)).collect();
+        match validate_custom_positions(&positions) {
+            Ok(()) => {
+                return Formation::Custom(CustomFormation {
+                    id: 0,
+                    positions: positions.try_into().expect("validada: 5 posiciones"),
+                })
+            }
+            Err(e) => warn!(
+                "⚠️ Formación personalizada de {} inválida ({}); se usa la predefinida",
+                data.id_usuario,
+                formation_error_text(e)
+            ),
+        }
+    }
+
+    parse_formation(&data.formacion).unwrap_or_else(|| {
+        warn!("⚠️ Formación desconocida '{}' de {}; se usa 1-2-1-1", data.formacion, data.id_usuario);
+        Formation::Rombo1211
+    })
+}
+
+/// `FormacionData` equivalente a elegir `formation` (modo local).
+pub fn formation_data(id_usuario: i32, formation: Formation) -> FormacionData {
+    let posiciones = match formation {
+        Formation::Custom(c) => Some(c.positions.iter().map(|p| FormationPos { x: p.x, y: p.y }).collect()),
+        _ => None,
+    };
+
+    FormacionData {
+        id_usuario,
+        formacion: formation.as_str().to_string(),
+        turno_inicio: 0,
+        posiciones,
+    }
+}
+
+/// Reglas de una formación personalizada (coordenadas del lado derecho).
+pub fn validate_custom_positions(positions: &[Vec2]) -> Result<(), FormationError> {
+    let positions: Vec<_> = positions.iter().copied().map(to_v2).collect();
+    shared::validate_positions(&positions)
+}
+
+/// Texto traducido de un error de `validate_custom_positions`.
+pub fn formation_error_text(error: FormationError) -> String {
+    let key = error.key();
+    match error {
+        FormationError::Count(tiene) => t!(key, esperadas = shared::DISKS_PER_FORMATION, tiene = tiene),
+        FormationError::Overlap(a, b) => t!(key, a = a, b = b),
+        FormationError::Coordinates(n)
+        | FormationError::OwnHalf(n)
+        | FormationError::OutOfField(n)
+        | FormationError::GoalArea(n) => t!(key, n = n),
+    }
+}
+
+/// Tablero de saque (sin jugadas) a partir de las formaciones elegidas,
+/// con los mismos `DiskId` que asigna `spawn_formation_for`.
+pub fn board_from_formations(formaciones: &[FormacionData], backend_info: &BackendInfo) -> BoardSnapshot {
+    let piezas = formaciones
+        .iter()
+        .flat_map(|f| {
+            let is_left = f.id_usuario == backend_info.id_left;
+            kickoff_positions(f, is_left)
+                .into_iter()
+                .enumerate()
+                .map(move |(idx, pos)| PiezaPos {
+                    id: DiskId::for_slot(is_left, idx).0,
+                    x: pos.x,
+                    y: pos.y,
+                    id_usuario_real: f.id_usuario,
+                    vx: 0.0,
+                    vy: 0.0,
+                    powerup: None,
+                })
+        })
+        .collect();
+
+    // La pelota arranca en el centro
+    let pelota = Some(BallPos { x: 0.0, y: 0.0, vx: 0.0, vy: 0.0 });
+
+    BoardSnapshot { piezas, pelota, ..Default::default() }
+}
+
 /// Spawnea los discos de la formación `data`.
 ///
 /// Se usa:
@@ -62,26 +157,13 @@ pub fn spawn_formation_for(
 ) {
     let is_left = data.id_usuario == backend_info.id_left;
 
-    let formation = match data.formacion.as_str() {
-        "1-2-1-1"   => Formation::Rombo1211,
-        "2-2-1"     => Formation::Muro221,
-        "1-1-3"     => Formation::Ofensiva113,
-        "2-1-1-1"   => Formation::Diamante2111,
-        _           => Formation::Rombo1211, // fallback
-    };
-
     let texture = if is_left {
         asset_server.load("circulobarca.png")
     } else {
         asset_server.load("circuloparis.png")
     };
 
-    let damping = Damping {
-        linear_damping:  2.0,
-        angular_damping: 2.0,
-    };
-
-    for (idx, pos) in get_formation_positions(formation, is_left)
+    for (idx, pos) in kickoff_positions(data, is_left)
         .into_iter()
         .enumerate()
     {
@@ -98,23 +180,14 @@ pub fn spawn_formation_for(
                 ..default()
             },
             // ───── Física Rapier ─────
-            RigidBody::Dynamic,
-            Collider::ball(35.0),
-            Restitution::coefficient(0.5),
-            ActiveEvents::COLLISION_EVENTS,
-            ExternalImpulse::default(),
-            ExternalForce::default(),
-            AdditionalMassProperties::Mass(1.0),
-            Velocity::zero(),
-            damping.clone(),
-            LockedAxes::ROTATION_LOCKED,
-            Sleeping::disabled(),
+            disk_body(Vec2::ZERO),
             // ───── Componentes de juego ─────
             PlayerDisk {
                 player_id:      data.id_usuario, // ya no se usa para lógica de turno
                 id_usuario_real: data.id_usuario,
             },
             OwnedBy(data.id_usuario),            // 👈 necesario para selección de turno
+            DiskId::for_slot(is_left, idx),
             Name::new(format!("disk_{}_{}", data.id_usuario, idx)),
         ));
     }
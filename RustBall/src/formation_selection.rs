@@ -1,11 +1,29 @@
+use std::sync::Mutex;
+
 use bevy::prelude::*;
+use serde::Deserialize;
 
 use crate::{
     components::FormationMenu,
     events::FormationChosenEvent,
-    resources::{Formation, PlayerFormations},
+    formation::{formation_error_text, validate_custom_positions},
+    i18n::{localized_text, Localized},
+    net,
+    resources::{AppState, BackendInfo, CustomFormation, Formation, GameMode, PlayerFormations, SavedFormation, SavedFormations},
+    settings_menu::{spawn_settings_button, SettingsAction},
+    snapshot::FormationPos,
 };
 
+/// Respuesta de `GET /formaciones/:u` pendiente de pasar a `SavedFormations`.
+static SAVED_INBOX: Mutex<Option<Vec<FormacionGuardada>>> = Mutex::new(None);
+
+#[derive(Deserialize, Debug)]
+struct FormacionGuardada {
+    id_formacion: i32,
+    nombre: String,
+    posiciones: Vec<FormationPos>,
+}
+
 /* ──────────────── UI ──────────────── */
 
 #[derive(Component)]
@@ -13,14 +31,30 @@ pub struct SelectionButton {
     pub formation: Formation,
 }
 
+/// Título del menú ("Elige tu formación", "Esperando…", etc.)
+#[derive(Component)]
+pub struct FormationTitle;
+
+/// Fila donde se agregan los botones de las formaciones personalizadas.
+#[derive(Component)]
+pub struct CustomFormationList;
+
+/// Sistema de arranque: muestra el menú al entrar en `FormationSelection`.
+pub fn setup_formation_menu(mut commands: Commands, asset_server: Res<AssetServer>) {
+    show_formation_ui(&mut commands, &asset_server);
+}
+
 pub fn show_formation_ui(commands: &mut Commands, asset_server: &Res<AssetServer>) {
     let font = asset_server.load("fonts/Linebeam.ttf");
 
-    // cámara 2D
-    commands.spawn(Camera2dBundle {
-        transform: Transform::from_xyz(0.0, 0.0, 1000.0),
-        ..default()
-    });
+    // cámara 2D (se limpia junto con el menú)
+    commands.spawn((
+        Camera2dBundle {
+            transform: Transform::from_xyz(0.0, 0.0, 1000.0),
+            ..default()
+        },
+        FormationMenu,
+    ));
 
     // contenedor vertical centrado
     commands
@@ -41,54 +75,142 @@ pub fn show_formation_ui(commands: &mut Commands, asset_server: &Res<AssetServer
         ))
         .with_children(|parent| {
             // Título que cambia a “esperando…”
-            parent.spawn(TextBundle::from_section(
-                "Elige tu formación",
+            parent.spawn((
+                localized_text(
+                    "formacion-elige",
+                    TextStyle {
+                        font: font.clone(),
+                        font_size: 30.0,
+                        color: Color::WHITE,
+                    },
+                ),
+                FormationTitle,
+            ));
+
+            // Botones de formación
+            for formation in Formation::PRESETS {
+                spawn_selection_button(parent, &font, formation, format!("{formation:?}"));
+            }
+
+            // Personalizadas (se llenan al llegar `SavedFormations`)
+            parent.spawn((
+                NodeBundle {
+                    style: Style {
+                        flex_direction: FlexDirection::Row,
+                        flex_wrap: FlexWrap::Wrap,
+                        justify_content: JustifyContent::Center,
+                        max_width: Val::Px(720.0),
+                        margin: UiRect::top(Val::Px(12.0)),
+                        ..default()
+                    },
+                    ..default()
+                },
+                CustomFormationList,
+            ));
+
+            // ⚙️ Ajustes (sonido, teclas, idioma…)
+            spawn_settings_button(parent, asset_server, "ajustes", SettingsAction::Open, 220.0);
+        });
+}
+
+fn spawn_selection_button(parent: &mut ChildBuilder, font: &Handle<Font>, formation: Formation, label: String) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(220.0),
+                    height: Val::Px(42.0),
+                    margin: UiRect::all(Val::Px(6.0)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: Color::DARK_GRAY.into(),
+                ..default()
+            },
+            SelectionButton { formation },
+        ))
+        .with_children(|b| {
+            b.spawn(TextBundle::from_section(
+                label,
                 TextStyle {
                     font: font.clone(),
-                    font_size: 30.0,
+                    font_size: 20.0,
                     color: Color::WHITE,
                 },
             ));
+        });
+}
 
-            // Botones de formación
-            for &formation in &[
-                Formation::Rombo1211,
-                Formation::Muro221,
-                Formation::Ofensiva113,
-                Formation::Diamante2111,
-            ] {
-                parent
-                    .spawn((
-                        ButtonBundle {
-                            style: Style {
-                                width: Val::Px(220.0),
-                                height: Val::Px(42.0),
-                                margin: UiRect::all(Val::Px(6.0)),
-                                justify_content: JustifyContent::Center,
-                                align_items: AlignItems::Center,
-                                ..default()
-                            },
-                            background_color: Color::DARK_GRAY.into(),
-                            ..default()
-                        },
-                        SelectionButton { formation },
-                    ))
-                    .with_children(|b| {
-                        b.spawn(TextBundle::from_section(
-                            format!("{formation:?}"),
-                            TextStyle {
-                                font: font.clone(),
-                                font_size: 20.0,
-                                color: Color::WHITE,
-                            },
-                        ));
-                    });
+/* ──────────────── formaciones personalizadas ──────────────── */
+
+/// Al conocer al usuario pide al backend sus formaciones guardadas
+/// (en hot-seat no hay usuario logueado: sólo las predefinidas).
+pub fn fetch_saved_formations(mode: Res<GameMode>, backend: Res<BackendInfo>) {
+    if !mode.is_online() {
+        return;
+    }
+
+    net::get(&format!("/formaciones/{}", backend.my_uid), |res| match res {
+        Ok(r) if r.is_success() => match serde_json::from_str::<Vec<FormacionGuardada>>(&r.body) {
+            Ok(lista) => *SAVED_INBOX.lock().unwrap() = Some(lista),
+            Err(e) => error!("❌ /formaciones: respuesta inválida: {e}"),
+        },
+        Ok(r) => error!("❌ Error /api/formaciones: status={} body={}", r.status, r.body),
+        Err(e) => error!("❌ Fallo de red /api/formaciones: {e}"),
+    });
+}
+
+/// Pasa la respuesta de `fetch_saved_formations` a `SavedFormations`,
+/// descartando las que no cumplen las reglas.
+pub fn apply_saved_formations(mut saved: ResMut<SavedFormations>) {
+    let Some(lista) = SAVED_INBOX.lock().unwrap().take() else { return };
+
+    saved.0 = lista
+        .into_iter()
+        .filter_map(|f| {
+            let positions: Vec<Vec2> = f.posiciones.iter().map(|p| Vec2::new(p.x, p.y)).collect();
+            if let Err(e) = validate_custom_positions(&positions) {
+                warn!("⚠️ Formación '{}' ignorada: {}", f.nombre, formation_error_text(e));
+                return None;
+            }
+            Some(SavedFormation {
+                nombre: f.nombre,
+                formation: CustomFormation { id: f.id_formacion, positions: positions.try_into().ok()? },
+            })
+        })
+        .collect();
+    info!("📋 {} formaciones personalizadas", saved.0.len());
+}
+
+/// Agrega un botón por formación guardada (también al volver al menú tras un gol).
+pub fn populate_custom_formation_buttons(
+    mut commands: Commands,
+    saved: Res<SavedFormations>,
+    lists: Query<(Entity, Ref<CustomFormationList>)>,
+    asset_server: Res<AssetServer>,
+) {
+    let font = asset_server.load("fonts/Linebeam.ttf");
+
+    for (list, marker) in &lists {
+        if !saved.is_changed() && !marker.is_added() {
+            continue;
+        }
+        commands.entity(list).despawn_descendants().with_children(|parent| {
+            for f in &saved.0 {
+                spawn_selection_button(parent, &font, Formation::Custom(f.formation), f.nombre.clone());
             }
         });
+    }
 }
 
 /* ──────────────── lógica ──────────────── */
 
+/// Condición: el menú de formaciones está visible (inicio o tras un gol).
+pub fn in_formation_menu(state: Res<State<AppState>>) -> bool {
+    matches!(state.get(), AppState::FormationSelection | AppState::FormationChange)
+}
+
 pub fn handle_formation_click(
     mut interaction_q: Query<
         (&Interaction, &SelectionButton, &mut BackgroundColor),
@@ -96,24 +218,44 @@ pub fn handle_formation_click(
     >,
     mut formations: ResMut<PlayerFormations>,
     mut ev_form_send: EventWriter<FormationChosenEvent>,
-    mut menu_text_q: Query<&mut Text, (With<FormationMenu>, Without<Button>)>,
+    mut menu_text_q: Query<(&mut Text, &mut Localized), With<FormationTitle>>,
+    mode: Res<GameMode>,
 ) {
     for (interaction, button, mut bg) in &mut interaction_q {
-        if *interaction == Interaction::Pressed {
-            // 1) Guardamos la formación local
-            formations.player1 = Some(button.formation);
-
-            // 2) Disparamos evento para que otro sistema (send_formacion_to_backend) lo envíe
-            ev_form_send.send(FormationChosenEvent {
-                formacion: button.formation.as_str().into(),
-                turno_inicio: 0,
-            });
-
-            // 3) Feedback visual
-            *bg = Color::GRAY.into();
-            for mut txt in &mut menu_text_q {
-                txt.sections[0].value = "⏳ Esperando a tu rival…".into();
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        // 🪑 Hot-seat: primero elige la izquierda y luego la derecha;
+        //    `start_local_match` arranca cuando ambas están listas
+        if !mode.is_online() {
+            if formations.player1.is_none() {
+                formations.player1 = Some(button.formation);
+            } else if formations.player2.is_none() {
+                formations.player2 = Some(button.formation);
             }
+            continue;
+        }
+
+        // 1) Guardamos la formación local
+        formations.player1 = Some(button.formation);
+
+        // 2) Disparamos evento para que otro sistema (send_formacion_to_backend) lo envíe
+        let id_formacion = match button.formation {
+            Formation::Custom(c) => Some(c.id),
+            _ => None,
+        };
+        ev_form_send.send(FormationChosenEvent {
+            formacion: button.formation.as_str().into(),
+            turno_inicio: 0,
+            id_formacion,
+        });
+
+        // 3) Feedback visual
+        *bg = Color::GRAY.into();
+        for (mut txt, mut localized) in &mut menu_text_q {
+            localized.0 = "formacion-esperando";
+            txt.sections[0].value = crate::i18n::t!(localized.0);
         }
     }
 
@@ -1,24 +1,163 @@
+//! src/game_over.rs
+//! --------------------------------------------------------------
+//! Pantalla final del partido.
+//!
+//!   • Los dos jugadores con su escudo, nombre, goles y cuál soy yo
+//!     (online por `my_uid`, contra la IA el izquierdo; en hot-seat
+//!     no se marca ninguno).  Con marcador igualado se anuncia empate.
+//!   • Estadísticas de `MatchStats`: tiros, goles, power-ups y tiempo
+//!     con la pelota en campo rival.
+//!   • Cambio de Elo con `GET /partida/:p/resultado`; se reintenta
+//!     mientras el backend no haya cerrado la partida (el último gol
+//!     puede llegar después que nosotros a esta pantalla).
+//!   • Botones: revancha (local: se reinicia aquí mismo; online: nueva
+//!     partida con `POST /partida`), repetición de `MatchTape` y
+//!     volver al lobby.
+//! --------------------------------------------------------------
+
+use std::sync::Mutex;
+
+use bevy::app::AppExit;
 use bevy::prelude::*;
-use crate::resources::Scores;
-use crate::resources::GameOverBackground;
+use serde::Deserialize;
+
+use crate::formation_selection::show_formation_ui;
+use crate::i18n::{localized_text, t};
+use crate::match_stats::{side_of, MatchStats, MatchTape, TAPE_HZ};
+use crate::net;
+use crate::physics::{BALL_RADIUS, DISK_RADIUS};
+use crate::powerup::PowerUpControl;
+use crate::resources::*;
+use crate::setup::field::FIELD_BOUNDS;
+use crate::systems::Kickoff;
+
+const FONT: &str = "fonts/FiraSans-Bold.ttf";
+/// Intentos de `GET /partida/:p/resultado` antes de rendirse.
+const RESULT_MAX_ATTEMPTS: u32 = 10;
+
+const LEFT_COLOR: Color = Color::rgb(0.0, 0.447, 0.698);
+const RIGHT_COLOR: Color = Color::rgb(0.902, 0.624, 0.0);
+
+/// Respuesta de `GET /partida/:p/resultado` pendiente de mostrar.
+static RESULT_INBOX: Mutex<Option<ResultadoPartida>> = Mutex::new(None);
+/// Id de la partida de revancha (o el error) pendiente de aplicar.
+static REMATCH_INBOX: Mutex<Option<Result<i32, String>>> = Mutex::new(None);
+
+#[derive(Deserialize, Debug)]
+struct JugadorResultado {
+    id_usuario: i32,
+    nombre_usuario: String,
+    elo: i32,
+    elo_cambio: Option<i32>,
+}
+
+#[derive(Deserialize, Debug)]
+struct ResultadoPartida {
+    estado: String,
+    jugadores: [JugadorResultado; 2],
+}
+
+#[derive(Deserialize, Debug)]
+struct PartidaCreada {
+    id_partida: i32,
+}
+
+/* ───────── Componentes y recursos ───────── */
 
 #[derive(Component)]
 pub struct GameOverUI;
 
+/// Nombre del jugador de cada lado (0 izquierda, 1 derecha).
+#[derive(Component)]
+pub struct PlayerNameText(pub usize);
+
+/// Elo del jugador de cada lado.
+#[derive(Component)]
+pub struct EloText(pub usize);
+
+/// Línea de avisos bajo los botones (revancha creada, errores…).
+#[derive(Component)]
+pub struct ResultNote;
+
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GameOverButton {
+    Rematch,
+    Replay,
+    Lobby,
+    /// Perfil del rival en la webapp (sólo navegador y online).
+    Profile,
+    CloseReplay,
+}
+
+/// Capa de la repetición (los tableros se dibujan con gizmos).
+#[derive(Component)]
+pub struct ReplayUI;
+
+#[derive(Component)]
+pub struct ReplayScoreText;
+
+/// Repetición en curso: cuadro actual de `MatchTape` (fraccionario).
+#[derive(Resource, Default)]
+pub struct ReplayPlayback {
+    pub frame: f32,
+}
+
+/// Reintentos pendientes de `GET /partida/:p/resultado`.
+#[derive(Resource)]
+pub struct ResultFetch {
+    timer: Timer,
+    attempts: u32,
+}
+
+/// Carga al inicio el fondo de la pantalla final.
+pub fn load_game_over_background(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(GameOverBackground(asset_server.load("championsfondo.png")));
+}
+
+/// Lado que juega este cliente, si es uno solo.
+fn my_side(mode: &GameMode, backend: &BackendInfo) -> Option<usize> {
+    match mode {
+        GameMode::Online if backend.i_am_left() => Some(0),
+        GameMode::Online if backend.i_am_right() => Some(1),
+        GameMode::VsBot(_) => Some(0),
+        _ => None,
+    }
+}
+
+fn text_style(asset_server: &AssetServer, size: f32, color: Color) -> TextStyle {
+    TextStyle { font: asset_server.load(FONT), font_size: size, color }
+}
+
+/* ───────── Pantalla ───────── */
+
+#[allow(clippy::too_many_arguments)]
 pub fn show_game_over_screen(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     scores: Res<Scores>,
-    game_over_bg: Res<GameOverBackground>, // ✅ recurso correcto
+    names: Res<PlayerNames>,
+    stats: Res<MatchStats>,
+    mode: Res<GameMode>,
+    backend: Res<BackendInfo>,
+    game_over_bg: Res<GameOverBackground>,
 ) {
-    // Cámara 2D sin limpiar el fondo
-    commands.spawn(Camera2dBundle::default());
+    // Cámara 2D sin limpiar el fondo (se va con la pantalla)
+    commands.spawn((Camera2dBundle::default(), GameOverUI));
 
-    let (winner_text, final_score) = if scores.left > scores.right {
-        ("¡Ganador: Jugador Izquierdo!", format!("{} - {}", scores.left, scores.right))
-    } else {
-        ("¡Ganador: Jugador Derecho!", format!("{} - {}", scores.right, scores.left))
+    let me = my_side(&mode, &backend);
+    let goals = [scores.left, scores.right];
+    let player_names = [names.left_name.as_str(), names.right_name.as_str()];
+
+    let title = match scores.left.cmp(&scores.right) {
+        std::cmp::Ordering::Greater => t!("fin-gana", nombre = player_names[0]),
+        std::cmp::Ordering::Less => t!("fin-gana", nombre = player_names[1]),
+        std::cmp::Ordering::Equal => t!("fin-empate"),
     };
+    let subtitle = me.and_then(|side| match goals[side].cmp(&goals[1 - side]) {
+        std::cmp::Ordering::Greater => Some(t!("fin-ganaste")),
+        std::cmp::Ordering::Less => Some(t!("fin-perdiste")),
+        std::cmp::Ordering::Equal => None,
+    });
 
     // Nodo raíz
     commands.spawn((
@@ -46,48 +185,514 @@ pub fn show_game_over_screen(
                 bottom: Val::Px(0.0),
                 ..default()
             },
-            image: UiImage::new(game_over_bg.0.clone()), // ✅ uso correcto
+            image: UiImage::new(game_over_bg.0.clone()),
             ..default()
         });
 
-        // Texto ganador
-        parent.spawn(TextBundle::from_section(
-            winner_text,
-            TextStyle {
-                font: asset_server.load("fonts/FiraSans-Bold.ttf"),
-                font_size: 80.0,
-                color: Color::WHITE,
+        // Ganador (o empate)
+        parent.spawn(TextBundle::from_section(title, text_style(&asset_server, 64.0, Color::WHITE))
+            .with_style(Style { margin: UiRect::bottom(Val::Px(4.0)), ..default() }));
+        if let Some(subtitle) = subtitle {
+            parent.spawn(TextBundle::from_section(subtitle, text_style(&asset_server, 30.0, Color::GOLD)));
+        }
+
+        // Jugadores y marcador
+        parent.spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                margin: UiRect::vertical(Val::Px(16.0)),
+                ..default()
+            },
+            ..default()
+        }).with_children(|row| {
+            spawn_player_card(row, &asset_server, 0, player_names[0], me == Some(0), mode.is_online());
+            row.spawn(TextBundle::from_section(
+                format!("{} - {}", scores.left, scores.right),
+                text_style(&asset_server, 72.0, Color::GOLD),
+            ).with_style(Style { margin: UiRect::horizontal(Val::Px(40.0)), ..default() }));
+            spawn_player_card(row, &asset_server, 1, player_names[1], me == Some(1), mode.is_online());
+        });
+
+        // Estadísticas
+        parent.spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            background_color: Color::rgba(0.0, 0.0, 0.0, 0.55).into(),
+            ..default()
+        }).with_children(|table| {
+            let rows = [
+                ("fin-tiros", stats.shots.map(|n| n.to_string())),
+                ("fin-goles", goals.map(|n| n.to_string())),
+                ("fin-powerups", stats.powerups.map(|n| n.to_string())),
+                ("fin-dominio", [0, 1].map(|side| format!("{}%", stats.territory(side)))),
+            ];
+            for (label, [left, right]) in rows {
+                spawn_stat_row(table, &asset_server, label, left, right);
             }
-        ).with_style(Style {
-            margin: UiRect::bottom(Val::Px(20.0)),
+        });
+
+        // Botones
+        parent.spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                margin: UiRect::top(Val::Px(16.0)),
+                ..default()
+            },
             ..default()
-        }));
-
-        // Texto marcador
-        parent.spawn(TextBundle::from_section(
-            format!("Marcador final: {}", final_score),
-            TextStyle {
-                font: asset_server.load("fonts/FiraSans-Bold.ttf"),
-                font_size: 50.0,
-                color: Color::GOLD,
+        }).with_children(|row| {
+            spawn_button(row, &asset_server, "fin-revancha", GameOverButton::Rematch);
+            spawn_button(row, &asset_server, "fin-repeticion", GameOverButton::Replay);
+            if cfg!(target_arch = "wasm32") && mode.is_online() {
+                spawn_button(row, &asset_server, "fin-ver-perfil", GameOverButton::Profile);
+            }
+            spawn_button(row, &asset_server, "fin-volver-lobby", GameOverButton::Lobby);
+        });
+
+        parent.spawn((
+            TextBundle::from_section("", text_style(&asset_server, 20.0, Color::WHITE))
+                .with_style(Style { margin: UiRect::top(Val::Px(8.0)), ..default() }),
+            ResultNote,
+        ));
+    });
+
+    if mode.is_online() {
+        commands.insert_resource(ResultFetch {
+            // El primer intento sale enseguida
+            timer: Timer::from_seconds(1.5, TimerMode::Repeating),
+            attempts: 0,
+        });
+    }
+    info!("🏁 Fin del partido {}-{} (tiros {:?}, power-ups {:?})", scores.left, scores.right, stats.shots, stats.powerups);
+}
+
+/// Escudo del equipo con la inicial encima, nombre, "(tú)" y Elo.
+fn spawn_player_card(
+    parent: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    side: usize,
+    name: &str,
+    is_me: bool,
+    online: bool,
+) {
+    let (crest, color) = if side == 0 {
+        ("circulobarca.png", LEFT_COLOR)
+    } else {
+        ("circuloparis.png", RIGHT_COLOR)
+    };
+    let initial = name.chars().next().map(|c| c.to_uppercase().to_string()).unwrap_or_default();
+
+    parent.spawn(NodeBundle {
+        style: Style {
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            width: Val::Px(260.0),
+            ..default()
+        },
+        ..default()
+    }).with_children(|card| {
+        card.spawn(ImageBundle {
+            style: Style {
+                width: Val::Px(96.0),
+                height: Val::Px(96.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(if is_me { 4.0 } else { 0.0 })),
+                ..default()
             },
+            image: UiImage::new(asset_server.load(crest)),
+            ..default()
+        }).insert(BorderColor(Color::GOLD)).with_children(|avatar| {
+            avatar.spawn(TextBundle::from_section(initial, text_style(asset_server, 44.0, Color::WHITE)));
+        });
+
+        card.spawn((
+            TextBundle::from_section(name.to_string(), text_style(asset_server, 30.0, color)),
+            PlayerNameText(side),
+        ));
+        if is_me {
+            card.spawn(localized_text("fin-tu", text_style(asset_server, 20.0, Color::GOLD)));
+        }
+
+        let elo = if online { t!("fin-elo-cargando") } else { t!("fin-sin-ranking") };
+        card.spawn((
+            TextBundle::from_section(elo, text_style(asset_server, 20.0, Color::WHITE)),
+            EloText(side),
         ));
     });
 }
 
+/// Fila "izquierda · etiqueta · derecha" de la tabla de estadísticas.
+fn spawn_stat_row(parent: &mut ChildBuilder, asset_server: &AssetServer, label: &'static str, left: String, right: String) {
+    parent.spawn(NodeBundle {
+        style: Style {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            margin: UiRect::vertical(Val::Px(2.0)),
+            ..default()
+        },
+        ..default()
+    }).with_children(|row| {
+        let cell = |width| Style { width: Val::Px(width), justify_content: JustifyContent::Center, ..default() };
+
+        row.spawn(NodeBundle { style: cell(80.0), ..default() }).with_children(|c| {
+            c.spawn(TextBundle::from_section(left, text_style(asset_server, 24.0, LEFT_COLOR)));
+        });
+        row.spawn(NodeBundle { style: cell(280.0), ..default() }).with_children(|c| {
+            c.spawn(localized_text(label, text_style(asset_server, 22.0, Color::WHITE)));
+        });
+        row.spawn(NodeBundle { style: cell(80.0), ..default() }).with_children(|c| {
+            c.spawn(TextBundle::from_section(right, text_style(asset_server, 24.0, RIGHT_COLOR)));
+        });
+    });
+}
+
+fn spawn_button(parent: &mut ChildBuilder, asset_server: &AssetServer, label: &'static str, action: GameOverButton) {
+    parent.spawn((
+        ButtonBundle {
+            style: Style {
+                width: Val::Px(200.0),
+                height: Val::Px(44.0),
+                margin: UiRect::all(Val::Px(6.0)),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            background_color: Color::DARK_GRAY.into(),
+            ..default()
+        },
+        action,
+    )).with_children(|b| {
+        b.spawn(localized_text(label, text_style(asset_server, 22.0, Color::WHITE)));
+    });
+}
+
+pub fn animate_game_over_buttons(
+    mut buttons: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<GameOverButton>)>,
+) {
+    for (interaction, mut color) in &mut buttons {
+        *color = match *interaction {
+            Interaction::Pressed => Color::rgb(0.20, 0.70, 0.20),
+            Interaction::Hovered => Color::rgb(0.50, 0.50, 0.90),
+            Interaction::None => Color::DARK_GRAY,
+        }
+        .into();
+    }
+}
+
+/* ───────── Resultado del backend (Elo) ───────── */
+
+pub fn poll_match_result(
+    mut commands: Commands,
+    time: Res<Time>,
+    fetch: Option<ResMut<ResultFetch>>,
+    backend: Res<BackendInfo>,
+    mut elo_texts: Query<(&EloText, &mut Text)>,
+) {
+    let Some(mut fetch) = fetch else { return };
+
+    fetch.timer.tick(time.delta());
+    if fetch.attempts == 0 || fetch.timer.just_finished() {
+        if fetch.attempts >= RESULT_MAX_ATTEMPTS {
+            warn!("⚠️ El backend no cerró la partida {}: sin cambio de Elo", backend.partida_id);
+            for (_, mut text) in &mut elo_texts {
+                text.sections[0].value = t!("fin-elo-no-disponible");
+            }
+            commands.remove_resource::<ResultFetch>();
+            return;
+        }
+        fetch.attempts += 1;
+
+        net::get(&format!("/partida/{}/resultado", backend.partida_id), |res| match res {
+            Ok(r) if r.is_success() => match serde_json::from_str::<ResultadoPartida>(&r.body) {
+                Ok(resultado) => *RESULT_INBOX.lock().unwrap() = Some(resultado),
+                Err(e) => error!("❌ /resultado: respuesta inválida: {e}"),
+            },
+            Ok(r) => error!("❌ Error /api/resultado: status={} body={}", r.status, r.body),
+            Err(e) => error!("❌ Fallo de red /api/resultado: {e}"),
+        });
+    }
+}
+
+/// Pone nombres y Elo del backend en las tarjetas cuando la partida
+/// ya figura como terminada.
+pub fn apply_match_result(
+    mut commands: Commands,
+    backend: Res<BackendInfo>,
+    mut names: Query<(&PlayerNameText, &mut Text), Without<EloText>>,
+    mut elo_texts: Query<(&EloText, &mut Text), Without<PlayerNameText>>,
+) {
+    let Some(resultado) = RESULT_INBOX.lock().unwrap().take() else { return };
+    if resultado.estado != "finished" {
+        // Aún no llegó el último gol al backend: `poll_match_result` reintenta
+        return;
+    }
+
+    let by_side = |side: usize| {
+        let uid = if side == 0 { backend.id_left } else { backend.id_right };
+        resultado.jugadores.iter().find(|j| j.id_usuario == uid)
+    };
+
+    for (PlayerNameText(side), mut text) in &mut names {
+        if let Some(j) = by_side(*side) {
+            text.sections[0].value = j.nombre_usuario.clone();
+        }
+    }
+    for (EloText(side), mut text) in &mut elo_texts {
+        if let Some(j) = by_side(*side) {
+            text.sections[0].value = match j.elo_cambio {
+                Some(cambio) => t!("fin-elo", elo = j.elo, cambio = format!("{cambio:+}")),
+                None => t!("fin-elo-no-disponible"),
+            };
+        }
+    }
+
+    info!("📈 Resultado de la partida {} recibido", backend.partida_id);
+    commands.remove_resource::<ResultFetch>();
+}
+
+/* ───────── Botones ───────── */
+
+#[allow(clippy::too_many_arguments)]
+pub fn handle_game_over_buttons(
+    mut commands: Commands,
+    buttons: Query<(&Interaction, &GameOverButton), Changed<Interaction>>,
+    mode: Res<GameMode>,
+    backend: Res<BackendInfo>,
+    tape: Res<MatchTape>,
+    asset_server: Res<AssetServer>,
+    mut roots: Query<&mut Visibility, (With<GameOverUI>, Without<Camera>)>,
+    replay_ui: Query<Entity, With<ReplayUI>>,
+    mut notes: Query<&mut Text, With<ResultNote>>,
+    mut next_state: ResMut<NextState<AppState>>,
+    mut exit: EventWriter<AppExit>,
+) {
+    for (interaction, button) in &buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        match button {
+            GameOverButton::Rematch if mode.is_online() => {
+                set_note(&mut notes, t!("fin-revancha-creando"));
+                request_online_rematch(&backend);
+            }
+            GameOverButton::Rematch => {
+                info!("🔁 Revancha local");
+                next_state.set(AppState::FormationSelection);
+            }
+            GameOverButton::Replay => {
+                if tape.frames.is_empty() {
+                    set_note(&mut notes, t!("fin-repeticion-vacia"));
+                    continue;
+                }
+                for mut visibility in &mut roots {
+                    *visibility = Visibility::Hidden;
+                }
+                spawn_replay_ui(&mut commands, &asset_server);
+                commands.insert_resource(ReplayPlayback::default());
+            }
+            GameOverButton::CloseReplay => {
+                close_replay(&mut commands, &replay_ui, &mut roots);
+            }
+            GameOverButton::Lobby => go_to_lobby(&mut exit),
+            GameOverButton::Profile => open_rival_profile(&backend),
+        }
+    }
+}
+
+fn set_note(notes: &mut Query<&mut Text, With<ResultNote>>, msg: String) {
+    for mut text in notes.iter_mut() {
+        text.sections[0].value = msg.clone();
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn go_to_lobby(_exit: &mut EventWriter<AppExit>) {
+    if let Some(w) = web_sys::window() {
+        let _ = w.location().set_href("/lobby.html");
+    }
+}
+
+// En escritorio no hay lobby: se cierra el juego
+#[cfg(not(target_arch = "wasm32"))]
+fn go_to_lobby(exit: &mut EventWriter<AppExit>) {
+    exit.send(AppExit);
+}
+
+/// `perfil.html` del rival, con el cara a cara contra mí.
+#[cfg(target_arch = "wasm32")]
+fn open_rival_profile(backend: &BackendInfo) {
+    let rival = if backend.i_am_left() { backend.id_right } else { backend.id_left };
+    if let Some(w) = web_sys::window() {
+        let _ = w.location().set_href(&format!("/perfil.html?u={rival}&rival={}", backend.my_uid));
+    }
+}
+
+// El botón sólo existe en el navegador
+#[cfg(not(target_arch = "wasm32"))]
+fn open_rival_profile(_backend: &BackendInfo) {}
+
+/* ───────── Revancha ───────── */
+
+/// Online: pide (o reutiliza, si el rival ya la pidió) una partida nueva
+/// entre los mismos jugadores.
+fn request_online_rematch(backend: &BackendInfo) {
+    let rival = if backend.i_am_left() { backend.id_right } else { backend.id_left };
+    let body = serde_json::json!({ "id_usuario_1": backend.my_uid, "id_usuario_2": rival }).to_string();
+
+    net::post_json("/partida", body, |res| {
+        let outcome = match res {
+            Ok(r) if r.is_success() => serde_json::from_str::<PartidaCreada>(&r.body)
+                .map(|p| p.id_partida)
+                .map_err(|e| e.to_string()),
+            Ok(r) => Err(r.body),
+            Err(e) => Err(e),
+        };
+        *REMATCH_INBOX.lock().unwrap() = Some(outcome);
+    });
+}
+
+pub fn apply_online_rematch(mut notes: Query<&mut Text, With<ResultNote>>) {
+    let Some(outcome) = REMATCH_INBOX.lock().unwrap().take() else { return };
+
+    match outcome {
+        Ok(id_partida) => {
+            info!("🔁 Revancha: partida {id_partida}");
+            enter_rematch(id_partida, &mut notes);
+        }
+        Err(e) => {
+            error!("❌ No se pudo crear la revancha: {e}");
+            set_note(&mut notes, t!("fin-revancha-error", error = e));
+        }
+    }
+}
+
+/// En el navegador se recarga el juego con la nueva partida (mismos
+/// jugadores y lados, así que el resto de claves `rb_*` sigue valiendo).
+#[cfg(target_arch = "wasm32")]
+fn enter_rematch(id_partida: i32, _notes: &mut Query<&mut Text, With<ResultNote>>) {
+    crate::storage::set("rb_pid", &id_partida.to_string());
+    if let Some(w) = web_sys::window() {
+        let _ = w.location().reload();
+    }
+}
+
+// En escritorio la sesión WS es una por proceso: hay que relanzar
+#[cfg(not(target_arch = "wasm32"))]
+fn enter_rematch(id_partida: i32, notes: &mut Query<&mut Text, With<ResultNote>>) {
+    set_note(notes, t!("fin-revancha-relanzar", partida = id_partida));
+}
+
+/// Revancha local (`GameOver` → `FormationSelection`): todo a cero y de
+/// vuelta al menú de formaciones.
+pub fn restart_local_match(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut scores: ResMut<Scores>,
+    mut turn_state: ResMut<TurnState>,
+    mut formations: ResMut<PlayerFormations>,
+    mut event_control: ResMut<EventControl>,
+    mut powerup_control: ResMut<PowerUpControl>,
+) {
+    *scores = Scores::default();
+    *turn_state = TurnState::default();
+    *formations = PlayerFormations::default();
+    *event_control = EventControl::default();
+    *powerup_control = PowerUpControl::default();
+    commands.remove_resource::<Kickoff>();
+
+    show_formation_ui(&mut commands, &asset_server);
+}
+
+/* ───────── Repetición ───────── */
+
+fn spawn_replay_ui(commands: &mut Commands, asset_server: &AssetServer) {
+    commands.spawn((
+        NodeBundle {
+            style: Style {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::SpaceBetween,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(12.0)),
+                ..default()
+            },
+            ..default()
+        },
+        ReplayUI,
+    )).with_children(|parent| {
+        parent.spawn((
+            TextBundle::from_section("", text_style(asset_server, 32.0, Color::GOLD)),
+            ReplayScoreText,
+        ));
+        spawn_button(parent, asset_server, "fin-cerrar-repeticion", GameOverButton::CloseReplay);
+    });
+}
+
+fn close_replay(
+    commands: &mut Commands,
+    replay_ui: &Query<Entity, With<ReplayUI>>,
+    roots: &mut Query<&mut Visibility, (With<GameOverUI>, Without<Camera>)>,
+) {
+    for entity in replay_ui.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+    for mut visibility in roots.iter_mut() {
+        *visibility = Visibility::Inherited;
+    }
+    commands.remove_resource::<ReplayPlayback>();
+}
+
+/// Avanza la repetición y dibuja el cuadro actual con gizmos.
+#[allow(clippy::too_many_arguments)]
+pub fn play_replay(
+    mut commands: Commands,
+    time: Res<Time>,
+    tape: Res<MatchTape>,
+    mut playback: ResMut<ReplayPlayback>,
+    mut gizmos: Gizmos,
+    replay_ui: Query<Entity, With<ReplayUI>>,
+    mut roots: Query<&mut Visibility, (With<GameOverUI>, Without<Camera>)>,
+    mut score_texts: Query<&mut Text, With<ReplayScoreText>>,
+) {
+    let Some(frame) = tape.frames.get(playback.frame as usize) else {
+        close_replay(&mut commands, &replay_ui, &mut roots);
+        return;
+    };
+    playback.frame += time.delta_seconds() * TAPE_HZ;
+
+    // Cancha
+    gizmos.rect_2d(Vec2::ZERO, 0.0, FIELD_BOUNDS, Color::WHITE);
+    gizmos.line_2d(Vec2::new(0.0, -FIELD_BOUNDS.y / 2.0), Vec2::new(0.0, FIELD_BOUNDS.y / 2.0), Color::WHITE);
+    gizmos.circle_2d(Vec2::ZERO, 90.0, Color::WHITE);
+
+    for (id, pos) in &frame.disks {
+        let color = if side_of(*id) == 0 { LEFT_COLOR } else { RIGHT_COLOR };
+        gizmos.circle_2d(*pos, DISK_RADIUS, color);
+        gizmos.circle_2d(*pos, DISK_RADIUS * 0.5, color);
+    }
+    gizmos.circle_2d(frame.ball, BALL_RADIUS, Color::GOLD);
+
+    for mut text in &mut score_texts {
+        text.sections[0].value = format!("{} - {}", frame.score.0, frame.score.1);
+    }
+}
+
 pub fn cleanup_game_over_ui(
     mut commands: Commands,
-    ui_elements: Query<Entity, With<GameOverUI>>,
-    cameras: Query<Entity, With<Camera>>,
+    ui_elements: Query<Entity, Or<(With<GameOverUI>, With<ReplayUI>)>>,
 ) {
     for entity in &ui_elements {
         if commands.get_entity(entity).is_some() {
             commands.entity(entity).despawn_recursive();
         }
     }
-    for cam in &cameras {
-        if commands.get_entity(cam).is_some() {
-            commands.entity(cam).despawn_recursive();
-        }
-    }
+    commands.remove_resource::<ReplayPlayback>();
+    commands.remove_resource::<ResultFetch>();
 }
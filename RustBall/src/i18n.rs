@@ -0,0 +1,119 @@
+//! src/i18n.rs
+//! --------------------------------------------------------------
+//! Textos de la interfaz traducidos con Fluent.
+//!
+//!   • Los textos viven en `locales/<idioma>.ftl` y se compilan dentro
+//!     del binario (también en WASM, sin cargar assets).
+//!   • El idioma activo es global (lo usan sistemas y helpers que no
+//!     tienen acceso al `World`); `apply_language` lo sincroniza con
+//!     `GameSettings` y reescribe los textos marcados con `Localized`.
+//!   • `t!("clave")` o `t!("clave", nombre = valor, …)` arma el texto.
+//!   • Los textos dinámicos (turno, efectos, power-ups…) se recalculan
+//!     con `t!` en sus propios sistemas.
+//! --------------------------------------------------------------
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use bevy::prelude::*;
+use fluent_bundle::{concurrent::FluentBundle, FluentArgs, FluentResource};
+use once_cell::sync::Lazy;
+use unic_langid::LanguageIdentifier;
+
+use crate::settings::{GameSettings, Language};
+
+/// Índice en `Language::ALL` del idioma activo.
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+static BUNDLES: Lazy<Vec<(Language, FluentBundle<FluentResource>)>> = Lazy::new(|| {
+    Language::ALL
+        .into_iter()
+        .map(|language| {
+            let langid: LanguageIdentifier = language.code().parse().expect("código de idioma válido");
+            let resource = FluentResource::try_new(ftl(language).to_string())
+                .unwrap_or_else(|(_, errors)| panic!("❌ locales/{}.ftl inválido: {errors:?}", language.code()));
+
+            let mut bundle = FluentBundle::new_concurrent(vec![langid]);
+            // Sin marcas de aislamiento Unicode: la fuente las dibujaría
+            bundle.set_use_isolating(false);
+            bundle
+                .add_resource(resource)
+                .unwrap_or_else(|errors| panic!("❌ claves repetidas en {}.ftl: {errors:?}", language.code()));
+            (language, bundle)
+        })
+        .collect()
+});
+
+fn ftl(language: Language) -> &'static str {
+    match language {
+        Language::Es => include_str!("../locales/es.ftl"),
+        Language::En => include_str!("../locales/en.ftl"),
+    }
+}
+
+pub fn set_language(language: Language) {
+    let idx = Language::ALL.iter().position(|l| *l == language).unwrap_or(0);
+    CURRENT.store(idx as u8, Ordering::Relaxed);
+}
+
+pub fn language() -> Language {
+    Language::ALL
+        .get(CURRENT.load(Ordering::Relaxed) as usize)
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Texto `id` en el idioma activo; si falta, cae al español y, en
+/// último caso, devuelve la clave tal cual.
+pub fn translate(id: &str, args: Option<&FluentArgs>) -> String {
+    for candidate in [language(), Language::Es] {
+        let Some((_, bundle)) = BUNDLES.iter().find(|(l, _)| *l == candidate) else { continue };
+        let Some(pattern) = bundle.get_message(id).and_then(|m| m.value()) else { continue };
+
+        let mut errors = vec![];
+        let text = bundle.format_pattern(pattern, args, &mut errors);
+        if !errors.is_empty() {
+            warn!("⚠️ Traducción '{id}' ({}): {errors:?}", candidate.code());
+        }
+        return text.into_owned();
+    }
+
+    warn!("⚠️ Falta la traducción '{id}'");
+    id.to_string()
+}
+
+/// `t!("clave")` o `t!("clave", nombre = valor, …)`.
+macro_rules! t {
+    ($id:expr) => {
+        $crate::i18n::translate($id, None)
+    };
+    ($id:expr, $($arg:ident = $value:expr),+ $(,)?) => {{
+        let mut args = fluent_bundle::FluentArgs::new();
+        $( args.set(stringify!($arg), $value); )+
+        $crate::i18n::translate($id, Some(&args))
+    }};
+}
+pub(crate) use t;
+
+/* ───────── Textos fijos ───────── */
+
+/// Texto de UI que sólo depende del idioma: se reescribe al cambiarlo.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Localized(pub &'static str);
+
+/// `TextBundle` con el texto ya traducido y la marca para retraducirlo.
+pub fn localized_text(id: &'static str, style: TextStyle) -> (TextBundle, Localized) {
+    (TextBundle::from_section(t!(id), style), Localized(id))
+}
+
+/// Aplica el idioma elegido en los ajustes y retraduce los textos fijos.
+pub fn apply_language(settings: Res<GameSettings>, mut texts: Query<(&Localized, &mut Text)>) {
+    if !settings.is_changed() || settings.language == language() {
+        return;
+    }
+
+    set_language(settings.language);
+    info!("🌐 Idioma: {}", settings.language.code());
+    for (localized, mut text) in &mut texts {
+        text.sections[0].value = t!(localized.0);
+    }
+}
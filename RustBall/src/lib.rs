@@ -4,6 +4,9 @@
 //  registra todos los sistemas / recursos.
 //  -------------------------------------------------------------------
 
+// Los sistemas de Bevy piden muchos parámetros y queries con filtros largos
+#![allow(clippy::too_many_arguments, clippy::type_complexity)]
+
 // ──────────────── MÓDULOS DEL JUEGO ────────────────────────────────
 pub mod components;
 pub mod resources;
@@ -13,9 +16,21 @@ pub mod setup;
 pub mod formation;
 pub mod formation_selection;
 pub mod game_over;
+pub mod match_stats;
 mod powerup;
 pub mod zone;
-mod snapshot;
+pub mod trajectory;
+pub mod ai;
+pub mod net;
+pub mod physics;
+pub mod audio;
+pub mod storage;
+pub mod settings;
+pub mod settings_menu;
+pub mod i18n;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod native;
+pub mod snapshot;
 
 // ──────────────── USE GENÉRICOS ────────────────────────────────────
 use bevy::asset::AssetMetaCheck;
@@ -24,20 +39,16 @@ use powerup::*;
 
 use crate::resources::WsInbox;
 
-// 🔐 Caja estática para pasar mensajes JS → Bevy (sólo WASM)
-#[cfg(target_arch = "wasm32")]
+// 🔐 Caja estática para pasar mensajes del WebSocket (JS o tokio) → Bevy
 static WS_INBOX: OnceCell<Mutex<WsInbox>> = OnceCell::new();
 
-// WASM bootstrap
-#[cfg(target_arch = "wasm32")]
+// WASM bootstrap (en escritorio las funciones exportadas son Rust normal)
 use wasm_bindgen::prelude::wasm_bindgen;
 
 // ─────────────── USE ESPECÍFICOS DEL JUEGO ─────────────────────────
-use crate::components::{PlayerDisk, PowerUpLabel};
-use crate::events::{FormationChosenEvent, TurnFinishedEvent};
+use crate::events::{FormationChosenEvent, PowerUpPickedEvent, TurnFinishedEvent};
 use crate::zone::apply_zone_effects;
 
-#[cfg(target_arch = "wasm32")]
 use std::sync::Mutex;
 
 #[cfg(target_arch = "wasm32")]
@@ -53,9 +64,10 @@ pub fn start() {
     main_internal();
 }
 
+// ─────────────────────────── Escritorio ────────────────────────────
 #[cfg(not(target_arch = "wasm32"))]
 pub fn main() {
-    main_internal();
+    native::run();
 }
 
 // Función JS para enviar texto por WebSocket
@@ -68,9 +80,8 @@ extern "C" {
 // Entrada JS → cola Bevy
 #[wasm_bindgen]
 pub fn receive_ws_message(msg: String) {
-    web_sys::console::log_1(&format!("📥 Mensaje desde JS: {msg}").into());
+    bevy::log::info!("📥 Mensaje desde JS: {msg}");
 
-    #[cfg(target_arch = "wasm32")]
     if let Some(lock) = WS_INBOX.get() {
         if let Ok(mut inbox) = lock.lock() {
             inbox.0.push(msg);
@@ -81,16 +92,277 @@ pub fn receive_ws_message(msg: String) {
 // 🎮 Juego real
 pub fn main_internal() {
     use bevy::prelude::*;
-    use bevy::audio::{PlaybackSettings, GlobalVolume};
     use bevy_rapier2d::prelude::*;
     use crate::resources::*;
     use crate::events::GoalEvent;
-    use crate::setup::{setup, cleanup_cameras};
+    use crate::setup::{setup, respawn_ball_and_goals};
     use crate::systems::*;
-    use crate::formation_selection::{handle_formation_click, cleanup_formation_ui};
+    use crate::systems::poll_turn::PollState;
+    use crate::ai::{bot_choose_formation, bot_take_turn, BotThinkTimer};
+    use crate::formation_selection::{
+        handle_formation_click, cleanup_formation_ui, setup_formation_menu, in_formation_menu,
+        fetch_saved_formations, apply_saved_formations, populate_custom_formation_buttons,
+    };
     use crate::setup::ui::cleanup_power_bar;
-    use crate::game_over::{show_game_over_screen, cleanup_game_over_ui};
+    use crate::game_over::{
+        show_game_over_screen, cleanup_game_over_ui, load_game_over_background,
+        handle_game_over_buttons, animate_game_over_buttons, poll_match_result, apply_match_result,
+        apply_online_rematch, play_replay, restart_local_match, ReplayPlayback,
+    };
+    use crate::snapshot::{snapshot_apply_system, ApplySnapshotSet, MyTurn, NextTurn};
     use crate::zone::{update_zone_lifetime, update_active_effect_text, hide_effect_text_if_none};
 
-    // ... (resto del código como ya proporcionado) ...
+    // 📨 Bandeja WS → Bevy lista antes de que llegue el primer mensaje
+    WS_INBOX.get_or_init(|| Mutex::new(WsInbox::default()));
+
+    let physics_mode = physics::PhysicsMode::current();
+
+    // 🌐 El idioma se fija antes de crear recursos con textos (`PlayerNames`…)
+    let game_settings = settings::GameSettings::load();
+    i18n::set_language(game_settings.language);
+
+    let mut app = App::new();
+
+    app.insert_resource(AssetMetaCheck::Never)
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "RustBall".into(),
+                resolution: (1280.0, 800.0).into(),
+                fit_canvas_to_parent: true,
+                ..default()
+            }),
+            ..default()
+        }))
+        .add_plugins(RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(physics::PIXELS_PER_METER))
+        .insert_resource(physics_mode)
+        .insert_resource(physics_mode.rapier_configuration())
+        .insert_resource(game_settings)
+        .add_state::<AppState>()
+
+        /* ─── Recursos ─── */
+        .init_resource::<TurnState>()
+        .init_resource::<Scores>()
+        .init_resource::<PlayerFormations>()
+        .init_resource::<SavedFormations>()
+        .init_resource::<EventControl>()
+        .init_resource::<PowerUpControl>()
+        .init_resource::<LatestSnapshot>()
+        .init_resource::<WsInbox>()
+        .init_resource::<UltimoTurnoAplicado>()
+        .init_resource::<RondaActual>()
+        .init_resource::<CurrentPlayerId>()
+        .init_resource::<PlayerNames>()
+        .init_resource::<NextTurn>()
+        .init_resource::<MyTurn>()
+        .init_resource::<PendingTurn>()
+        .init_resource::<LastShot>()
+        .init_resource::<TurnEventLog>()
+        .init_resource::<OpponentPresence>()
+        .init_resource::<AchievementToasts>()
+        .init_resource::<PollState>()
+        .init_resource::<MatchRules>()
+        .init_resource::<GameMode>()
+        .init_resource::<BotThinkTimer>()
+        .init_resource::<settings_menu::Paused>()
+        .init_resource::<settings_menu::RebindingKey>()
+        .init_resource::<match_stats::MatchStats>()
+        .init_resource::<match_stats::MatchTape>()
+
+        /* ─── Eventos ─── */
+        .add_event::<GoalEvent>()
+        .add_event::<FormationChosenEvent>()
+        .add_event::<TurnFinishedEvent>()
+        .add_event::<PowerUpPickedEvent>()
+
+        /* ─── Arranque ─── */
+        .add_systems(Startup, (
+            insert_backend_info,
+            physics::configure_integration,
+            audio::load_audio_settings,
+            setup_fonts,
+            load_game_over_background,
+            setup_formation_menu,
+        ))
+
+        /* ─── Selección de formación (inicio y tras cada gol) ─── */
+        .add_systems(Update, (handle_formation_click, animate_selection_buttons)
+            .run_if(in_formation_menu))
+        .add_systems(Update, fetch_saved_formations.run_if(resource_added::<BackendInfo>()))
+        .add_systems(Update, (apply_saved_formations, populate_custom_formation_buttons)
+            .chain()
+            .run_if(in_formation_menu))
+        .add_systems(Update, (bot_choose_formation, update_local_formation_title, start_local_match)
+            .chain()
+            .after(handle_formation_click)
+            .run_if(in_local_mode)
+            .run_if(in_formation_menu))
+        .add_systems(Update, send_formacion_to_backend.run_if(in_online_mode))
+        .add_systems(OnExit(AppState::FormationSelection), cleanup_formation_ui)
+        .add_systems(OnEnter(AppState::FormationChange), reset_for_formation)
+        .add_systems(OnExit(AppState::FormationChange), cleanup_formation_ui)
+
+        /* ─── Entrada al partido ─── */
+        .add_systems(OnTransition { from: AppState::FormationSelection, to: AppState::InGame }, (
+            setup,
+            match_stats::reset_match_stats,
+        ))
+        .add_systems(OnTransition { from: AppState::FormationChange, to: AppState::InGame }, respawn_ball_and_goals)
+
+        /* ─── Snapshots y red (sólo online) ─── */
+        .add_systems(Update, (
+            snapshot_apply_system.in_set(ApplySnapshotSet),
+            process_ws_messages,
+            handle_turn_finished_event,
+            show_opponent_presence
+                .after(process_ws_messages)
+                .run_if(not(in_state(AppState::GameOver))),
+            show_achievement_toasts.after(process_ws_messages),
+        )
+            .run_if(in_online_mode)
+            .run_if(resource_exists::<BackendInfo>()))
+
+        /* ─── Turno: selección, apuntado, disparo ─── */
+        .add_systems(Update, (
+            auto_select_first_disk,
+            cycle_disk_selection,
+            aim_with_keyboard,
+            charge_shot_power,
+            record_shot_input,
+            fire_selected_disk,
+        )
+            .chain()
+            .after(ApplySnapshotSet)
+            .run_if(in_state(AppState::InGame))
+            .run_if(resource_exists::<BackendInfo>())
+            .run_if(settings_menu::not_paused)
+            .run_if(board_settled))
+        .add_systems(Update, animate_board_tweens.after(ApplySnapshotSet))
+        .add_systems(Update, run_shot_replay
+            .after(animate_board_tweens)
+            .run_if(in_online_mode)
+            .run_if(resource_exists::<BackendInfo>()))
+        .add_systems(Update, (start_turn_event_log.after(record_shot_input), record_turn_events)
+            .chain()
+            .run_if(in_online_mode)
+            .run_if(in_state(AppState::InGame)))
+        .add_systems(Update, (end_kickoff_on_shot, draw_kickoff_circle)
+            .after(fire_selected_disk)
+            .run_if(in_state(AppState::InGame)))
+        .add_systems(Update, check_turn_end
+            .in_set(CheckTurnEndSet)
+            .after(fire_selected_disk)
+            .run_if(in_state(AppState::InGame)))
+        .add_systems(Update, (send_turn_to_backend, maybe_send_pending_turn)
+            .chain()
+            .after(CheckTurnEndSet)
+            .run_if(in_online_mode))
+        .add_systems(Update, (advance_local_turn, sync_local_controller, bot_take_turn)
+            .chain()
+            .after(CheckTurnEndSet)
+            .run_if(in_local_mode)
+            .run_if(in_state(AppState::InGame))
+            .run_if(settings_menu::not_paused))
+
+        /* ─── Goles ─── */
+        .add_systems(Update, (detect_goal.run_if(replay_idle), handle_goal)
+            .chain()
+            .run_if(in_state(AppState::InGame)))
+        .add_systems(Update, send_goal_to_backend
+            .after(detect_goal)
+            .run_if(in_online_mode))
+        .add_systems(OnEnter(AppState::GoalScored), setup_goal_timer)
+        .add_systems(Update, (goal_banner_fadeout, wait_and_change_state)
+            .run_if(in_state(AppState::GoalScored)))
+
+        /* ─── Audio ─── */
+        .add_systems(Update, (
+            audio::switch_music_on_state,
+            audio::apply_music_volume,
+            audio::audio_hotkeys,
+            audio::play_goal_chant.after(detect_goal),
+            audio::play_collision_sounds,
+            audio::play_powerup_pickup,
+        ).run_if(resource_exists::<audio::AudioSettings>()))
+        .add_systems(OnEnter(AppState::GoalScored), audio::play_goal_chant_if_silent)
+
+        /* ─── Ajustes y pausa ─── */
+        .add_systems(Update, (
+            settings_menu::toggle_pause_menu.run_if(in_state(AppState::InGame)),
+            settings_menu::capture_rebind_key,
+            settings_menu::handle_settings_buttons,
+            i18n::apply_language,
+            settings_menu::refresh_settings_values,
+            settings_menu::animate_settings_buttons,
+            settings_menu::apply_pause_to_physics,
+        ).chain())
+        .add_systems(OnExit(AppState::InGame), settings_menu::close_pause_menu)
+
+        /* ─── Power-ups y eventos aleatorios ─── */
+        .add_systems(Update, (
+            spawn_power_up_if_needed,
+            detect_powerup_collision,
+            attach_powerup_label_once,
+            update_powerup_labels,
+            remove_powerup_label,
+        ).run_if(in_state(AppState::InGame)))
+        .add_systems(Update, (
+            trigger_random_event_system,
+            update_zone_lifetime,
+            apply_zone_effects,
+            update_active_effect_text,
+            hide_effect_text_if_none,
+        ).run_if(in_state(AppState::InGame)))
+
+        /* ─── Estadísticas y grabación para la pantalla final ─── */
+        .add_systems(Update, (
+            match_stats::count_shots,
+            match_stats::count_powerups.after(detect_powerup_collision),
+            match_stats::track_ball_half.run_if(settings_menu::not_paused),
+            match_stats::record_tape,
+        ).run_if(in_state(AppState::InGame)))
+
+        /* ─── HUD y visuales ─── */
+        .add_systems(Update, (
+            update_turn_text,
+            update_score_text,
+            update_power_bar,
+            draw_aim_direction_gizmo,
+            draw_colorblind_team_markers,
+            animate_selected_disk,
+        )
+            .after(i18n::apply_language)
+            .run_if(in_state(AppState::InGame))
+            .run_if(resource_exists::<BackendInfo>()))
+
+        /* ─── Fin del partido ─── */
+        .add_systems(OnEnter(AppState::GameOver), (
+            despawn_game_entities,
+            cleanup_power_bar,
+            show_game_over_screen,
+        ).chain())
+        .add_systems(Update, (
+            handle_game_over_buttons,
+            animate_game_over_buttons,
+            poll_match_result,
+            apply_match_result,
+            apply_online_rematch,
+            play_replay.run_if(resource_exists::<ReplayPlayback>()),
+        ).run_if(in_state(AppState::GameOver)))
+        .add_systems(OnExit(AppState::GameOver), cleanup_game_over_ui)
+        .add_systems(OnTransition { from: AppState::GameOver, to: AppState::FormationSelection }, restart_local_match);
+
+    // ─── Polling HTTP (complementa al WebSocket en ambas plataformas) ───
+    app.init_resource::<SnapshotPollTimer>()
+        .add_systems(Update, poll_turn_tick_system
+            .run_if(in_online_mode)
+            .run_if(in_state(AppState::InGame)))
+        .add_systems(Update, crate::snapshot::poll_snapshot_when_forming
+            .run_if(in_online_mode)
+            .run_if(in_formation_menu));
+
+    // ─── Sólo WASM: BackendInfo desde JS ───
+    #[cfg(target_arch = "wasm32")]
+    app.add_systems(Startup, load_backend_info_if_available);
+
+    app.run();
 }
@@ -0,0 +1,11 @@
+//! src/main.rs
+//! Binario de escritorio: ver `native.rs` para los argumentos.
+//! En WASM el juego arranca desde `lib.rs` (`#[wasm_bindgen(start)]`).
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() {
+    rustball::main();
+}
+
+#[cfg(target_arch = "wasm32")]
+fn main() {}
@@ -0,0 +1,156 @@
+//! src/match_stats.rs
+//! --------------------------------------------------------------
+//! Estadísticas del partido para la pantalla final.
+//!
+//!   • Tiros: un tiro empieza cuando todo estaba quieto y alguna ficha
+//!     se pone en marcha (propio, del rival repetido o del bot); se
+//!     cuenta para el lado de la ficha más rápida.
+//!   • Power-ups recogidos por lado (`PowerUpPickedEvent`).
+//!   • Tiempo con la pelota en cada mitad: el izquierdo ataca hacia
+//!     x > 0, así que su "dominio" es el tiempo en la mitad derecha.
+//!   • `MatchTape` guarda el tablero a `TAPE_HZ` mientras hay algo en
+//!     movimiento, para la repetición de la pantalla final.
+//!
+//! Todo se reinicia al entrar al partido desde la selección inicial
+//! (también en la revancha local).
+//! --------------------------------------------------------------
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::Velocity;
+
+use crate::components::{Ball, BoardTween, DiskId, PlayerDisk};
+use crate::events::PowerUpPickedEvent;
+use crate::physics::STOP_THRESHOLD;
+use crate::resources::Scores;
+
+/// Cuadros por segundo de la repetición.
+pub const TAPE_HZ: f32 = 15.0;
+/// Tope de cuadros (10 minutos de juego en movimiento).
+const TAPE_MAX_FRAMES: usize = 15 * 60 * 10;
+
+/* ───────── Recursos ───────── */
+
+/// Índice 0 = lado izquierdo, 1 = lado derecho.
+#[derive(Resource, Default, Debug, Clone)]
+pub struct MatchStats {
+    pub shots: [u32; 2],
+    pub powerups: [u32; 2],
+    /// Segundos con la pelota en la mitad izquierda / derecha.
+    pub ball_time: [f32; 2],
+}
+
+impl MatchStats {
+    /// Porcentaje del tiempo con la pelota en el campo rival de `side`.
+    pub fn territory(&self, side: usize) -> u32 {
+        let total = self.ball_time[0] + self.ball_time[1];
+        if total <= 0.0 {
+            return 50;
+        }
+        // El izquierdo ataca la mitad derecha y viceversa
+        (self.ball_time[1 - side] / total * 100.0).round() as u32
+    }
+}
+
+/// Lado (0 izquierda, 1 derecha) de una ficha.
+pub fn side_of(id: DiskId) -> usize {
+    if id.0 < DiskId::PER_SIDE { 0 } else { 1 }
+}
+
+#[derive(Clone, Debug)]
+pub struct TapeFrame {
+    pub ball: Vec2,
+    pub disks: Vec<(DiskId, Vec2)>,
+    /// Marcador al grabar el cuadro (se muestra durante la repetición).
+    pub score: (u32, u32),
+}
+
+#[derive(Resource, Default, Debug)]
+pub struct MatchTape {
+    pub frames: Vec<TapeFrame>,
+    since_last: f32,
+}
+
+/* ───────── Sistemas ───────── */
+
+/// Al entrar al partido desde la selección inicial.
+pub fn reset_match_stats(mut stats: ResMut<MatchStats>, mut tape: ResMut<MatchTape>) {
+    *stats = MatchStats::default();
+    *tape = MatchTape::default();
+}
+
+pub fn count_shots(
+    mut stats: ResMut<MatchStats>,
+    mut was_moving: Local<bool>,
+    tweens: Query<(), With<BoardTween>>,
+    disks: Query<(&DiskId, &Velocity), With<PlayerDisk>>,
+    balls: Query<&Velocity, With<Ball>>,
+) {
+    // Los snapshots mueven el tablero con tweens: eso no es un tiro
+    if !tweens.is_empty() {
+        return;
+    }
+
+    let fastest = disks
+        .iter()
+        .filter(|(_, v)| v.linvel.length_squared() >= STOP_THRESHOLD)
+        .max_by(|(_, a), (_, b)| a.linvel.length_squared().total_cmp(&b.linvel.length_squared()));
+    let moving = fastest.is_some() || balls.iter().any(|v| v.linvel.length_squared() >= STOP_THRESHOLD);
+
+    if moving && !*was_moving {
+        if let Some((id, _)) = fastest {
+            stats.shots[side_of(*id)] += 1;
+        }
+    }
+    *was_moving = moving;
+}
+
+pub fn count_powerups(
+    mut stats: ResMut<MatchStats>,
+    mut events: EventReader<PowerUpPickedEvent>,
+    disks: Query<&DiskId>,
+) {
+    for ev in events.read() {
+        if let Ok(id) = disks.get(ev.disk) {
+            stats.powerups[side_of(*id)] += 1;
+        }
+    }
+}
+
+pub fn track_ball_half(
+    time: Res<Time>,
+    mut stats: ResMut<MatchStats>,
+    balls: Query<&Transform, With<Ball>>,
+) {
+    let Ok(tf) = balls.get_single() else { return };
+    let half = if tf.translation.x < 0.0 { 0 } else { 1 };
+    stats.ball_time[half] += time.delta_seconds();
+}
+
+pub fn record_tape(
+    time: Res<Time>,
+    scores: Res<Scores>,
+    mut tape: ResMut<MatchTape>,
+    disks: Query<(&DiskId, &Transform, &Velocity), With<PlayerDisk>>,
+    balls: Query<(&Transform, &Velocity), With<Ball>>,
+) {
+    tape.since_last += time.delta_seconds();
+    if tape.since_last < 1.0 / TAPE_HZ || tape.frames.len() >= TAPE_MAX_FRAMES {
+        return;
+    }
+    let Ok((ball_tf, ball_vel)) = balls.get_single() else { return };
+
+    // Sólo se graba el juego en movimiento: los turnos pensando se saltan
+    let moving = ball_vel.linvel.length_squared() >= STOP_THRESHOLD
+        || disks.iter().any(|(_, _, v)| v.linvel.length_squared() >= STOP_THRESHOLD);
+    if !moving {
+        return;
+    }
+
+    tape.since_last = 0.0;
+    let frame = TapeFrame {
+        ball: ball_tf.translation.truncate(),
+        disks: disks.iter().map(|(id, tf, _)| (*id, tf.translation.truncate())).collect(),
+        score: (scores.left, scores.right),
+    };
+    tape.frames.push(frame);
+}
@@ -0,0 +1,179 @@
+//! src/native.rs
+//! --------------------------------------------------------------
+//! Arranque del binario de escritorio (`cargo run --features desktop`).
+//!
+//!   rustball [--server URL] [--partida ID --usuario NOMBRE --contrasena CLAVE]
+//!            [--local | --bot facil|medio|dificil] [--deterministic]
+//!
+//!   • Sin `--partida` se juega en local (hot-seat, o contra la IA
+//!     con `--bot`).
+//!   • Con `--partida` se inicia sesión en el backend (`POST /login`),
+//!     se comprueba que el usuario juegue esa partida y se arranca en
+//!     modo online, igual que el cliente web tras `entrarPartida`.
+//!   • También se leen las variables `RUSTBALL_SERVER`,
+//!     `RUSTBALL_USUARIO` y `RUSTBALL_CONTRASENA`.
+//! --------------------------------------------------------------
+
+use once_cell::sync::OnceCell;
+use serde::Deserialize;
+use serde_json::json;
+
+use crate::ai::BotDifficulty;
+use crate::net;
+use crate::physics::PhysicsMode;
+use crate::resources::{BackendInfo, GameMode};
+
+/// Cómo arranca la partida en escritorio.
+#[derive(Debug, Clone)]
+pub enum NativeLaunch {
+    Online(BackendInfo),
+    Local(GameMode),
+}
+
+static LAUNCH: OnceCell<NativeLaunch> = OnceCell::new();
+
+/// Configuración elegida al arrancar (`None` fuera del binario de escritorio).
+pub fn launch() -> Option<&'static NativeLaunch> {
+    LAUNCH.get()
+}
+
+/// Argumentos de línea de comandos.
+#[derive(Debug, Default, Clone)]
+pub struct CliArgs {
+    pub server: Option<String>,
+    pub partida: Option<i32>,
+    pub usuario: Option<String>,
+    pub contrasena: Option<String>,
+    pub bot: Option<BotDifficulty>,
+    pub deterministic: bool,
+}
+
+const USAGE: &str = "\
+Uso: rustball [opciones]
+
+  --server URL          Backend (por defecto http://127.0.0.1:10000)
+  --partida ID          Partida online a la que entrar
+  --usuario NOMBRE      Usuario para iniciar sesión
+  --contrasena CLAVE    Contraseña del usuario
+  --local               Dos jugadores en la misma pantalla (por defecto)
+  --bot DIFICULTAD      Contra la máquina: facil | medio | dificil
+  --deterministic       Física de paso fijo (resultados reproducibles)
+  -h, --help            Muestra esta ayuda";
+
+impl CliArgs {
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut out = CliArgs {
+            server: std::env::var("RUSTBALL_SERVER").ok(),
+            usuario: std::env::var("RUSTBALL_USUARIO").ok(),
+            contrasena: std::env::var("RUSTBALL_CONTRASENA").ok(),
+            ..Default::default()
+        };
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| args.next().ok_or_else(|| format!("Falta el valor de {name}"));
+
+            match arg.as_str() {
+                "--server" => out.server = Some(value("--server")?),
+                "--partida" => {
+                    let v = value("--partida")?;
+                    out.partida = Some(v.parse().map_err(|_| format!("ID de partida inválido: {v}"))?);
+                }
+                "--usuario" => out.usuario = Some(value("--usuario")?),
+                "--contrasena" => out.contrasena = Some(value("--contrasena")?),
+                "--local" => out.bot = None,
+                "--bot" => {
+                    let v = value("--bot")?;
+                    out.bot = Some(BotDifficulty::parse(&v).ok_or_else(|| format!("Dificultad inválida: {v}"))?);
+                }
+                "--deterministic" => out.deterministic = true,
+                "-h" | "--help" => return Err(USAGE.to_string()),
+                other => return Err(format!("Argumento desconocido: {other}\n\n{USAGE}")),
+            }
+        }
+
+        Ok(out)
+    }
+}
+
+#[derive(Deserialize)]
+struct UsuarioLogin {
+    id_usuario: i32,
+}
+
+#[derive(Deserialize)]
+struct PartidaDetalle {
+    id_usuario_1: i32,
+    id_usuario_2: i32,
+}
+
+/// Inicia sesión y arma el `BackendInfo` de la partida `pid`.
+fn login_and_join(pid: i32, usuario: &str, contrasena: &str) -> Result<BackendInfo, String> {
+    let body = json!({ "nombre_usuario": usuario, "contrasena": contrasena }).to_string();
+    let resp = net::post_json_blocking("/login", body)?;
+    if !resp.is_success() {
+        return Err(format!("Login rechazado ({}): {}", resp.status, resp.body));
+    }
+    let user: UsuarioLogin = serde_json::from_str(&resp.body).map_err(|e| e.to_string())?;
+
+    let resp = net::get_blocking(&format!("/partida_detalle/{pid}"))?;
+    if !resp.is_success() {
+        return Err(format!("No se encontró la partida {pid} ({}): {}", resp.status, resp.body));
+    }
+    let partida: PartidaDetalle = serde_json::from_str(&resp.body).map_err(|e| e.to_string())?;
+
+    if user.id_usuario != partida.id_usuario_1 && user.id_usuario != partida.id_usuario_2 {
+        return Err(format!("El usuario {usuario} no juega la partida {pid}"));
+    }
+
+    // Igual que `entrarPartida` en la web: a la izquierda el UID menor
+    let left = partida.id_usuario_1.min(partida.id_usuario_2);
+    let right = partida.id_usuario_1.max(partida.id_usuario_2);
+    Ok(BackendInfo::new(pid, user.id_usuario, left, right))
+}
+
+/// Punto de entrada del binario de escritorio.
+pub fn run() {
+    let args = match CliArgs::parse(std::env::args().skip(1)) {
+        Ok(a) => a,
+        Err(msg) if msg == USAGE => {
+            println!("{USAGE}");
+            std::process::exit(0);
+        }
+        Err(msg) => {
+            eprintln!("{msg}");
+            std::process::exit(2);
+        }
+    };
+
+    if let Some(url) = &args.server {
+        net::set_server_url(url);
+    }
+    if args.deterministic {
+        PhysicsMode::set(PhysicsMode::Deterministic);
+    }
+
+    let launch = match args.partida {
+        Some(pid) => {
+            let (Some(usuario), Some(contrasena)) = (&args.usuario, &args.contrasena) else {
+                eprintln!("❌ --partida requiere --usuario y --contrasena\n\n{USAGE}");
+                std::process::exit(2);
+            };
+
+            match login_and_join(pid, usuario, contrasena) {
+                Ok(info) => NativeLaunch::Online(info),
+                Err(e) => {
+                    eprintln!("❌ No se pudo entrar a la partida {pid} en {}: {e}", net::server_url());
+                    std::process::exit(1);
+                }
+            }
+        }
+        None => NativeLaunch::Local(match args.bot {
+            Some(d) => GameMode::VsBot(d),
+            None => GameMode::HotSeat,
+        }),
+    };
+
+    let _ = LAUNCH.set(launch);
+    crate::main_internal();
+}
@@ -0,0 +1,206 @@
+//! src/net.rs
+//! --------------------------------------------------------------
+//! Capa de red común a las dos plataformas.
+//!
+//!   • WASM: `gloo-net` + `spawn_local`, rutas relativas (`/api/…`)
+//!     servidas por el mismo backend que entrega la página.
+//!   • Escritorio: `reqwest` + `tokio-tungstenite` sobre un runtime
+//!     tokio propio (Bevy no corre dentro de tokio) y la URL del
+//!     servidor recibida por línea de comandos (`--server`).
+//!
+//! Todas mandan `Accept-Language` con el idioma de la interfaz, así
+//! los errores del backend llegan traducidos.
+//!
+//! Las peticiones no bloquean: el resultado llega a un callback que
+//! se ejecuta fuera del mundo de Bevy, así que los sistemas sólo
+//! pueden comunicarse con él mediante estado compartido
+//! (`set_game_state`, `WS_INBOX`, flags atómicos…).
+//! --------------------------------------------------------------
+
+/// Respuesta HTTP ya leída.
+#[derive(Debug, Clone)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+impl HttpResponse {
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+}
+
+pub type HttpResult = Result<HttpResponse, String>;
+
+fn accept_language() -> &'static str {
+    crate::i18n::language().code()
+}
+
+/* ───────── WASM ───────── */
+
+#[cfg(target_arch = "wasm32")]
+mod imp {
+    use super::{accept_language, HttpResponse, HttpResult};
+    use gloo_net::http::Request;
+    use wasm_bindgen_futures::spawn_local;
+
+    pub fn api_url(path: &str) -> String {
+        format!("/api{path}")
+    }
+
+    async fn read(req: Request) -> HttpResult {
+        let resp = req.send().await.map_err(|e| format!("{e:?}"))?;
+        let status = resp.status();
+        let body = resp.text().await.unwrap_or_default();
+        Ok(HttpResponse { status, body })
+    }
+
+    pub fn get(path: &str, on_done: impl FnOnce(HttpResult) + Send + 'static) {
+        let url = api_url(path);
+        spawn_local(async move {
+            let req = match Request::get(&url).header("Accept-Language", accept_language()).build() {
+                Ok(r) => r,
+                Err(e) => return on_done(Err(format!("{e:?}"))),
+            };
+            on_done(read(req).await);
+        });
+    }
+
+    pub fn post_json(path: &str, body: String, on_done: impl FnOnce(HttpResult) + Send + 'static) {
+        let url = api_url(path);
+        spawn_local(async move {
+            let req = match Request::post(&url)
+                .header("Content-Type", "application/json")
+                .header("Accept-Language", accept_language())
+                .body(body)
+            {
+                Ok(r) => r,
+                Err(e) => return on_done(Err(format!("{e:?}"))),
+            };
+            on_done(read(req).await);
+        });
+    }
+}
+
+/* ───────── Escritorio ───────── */
+
+#[cfg(not(target_arch = "wasm32"))]
+mod imp {
+    use super::{accept_language, HttpResponse, HttpResult};
+    use futures_util::StreamExt;
+    use once_cell::sync::OnceCell;
+    use tokio::runtime::Runtime;
+    use tokio_tungstenite::tungstenite::Message;
+
+    /// URL base del backend por defecto (backend local de desarrollo).
+    pub const DEFAULT_SERVER_URL: &str = "http://127.0.0.1:10000";
+
+    static SERVER_URL: OnceCell<String> = OnceCell::new();
+    static RUNTIME: OnceCell<Runtime> = OnceCell::new();
+    static CLIENT: OnceCell<reqwest::Client> = OnceCell::new();
+
+    /// Fija la URL del backend; sólo tiene efecto la primera vez.
+    pub fn set_server_url(url: &str) {
+        let _ = SERVER_URL.set(url.trim_end_matches('/').to_string());
+    }
+
+    pub fn server_url() -> &'static str {
+        SERVER_URL.get().map(String::as_str).unwrap_or(DEFAULT_SERVER_URL)
+    }
+
+    pub fn api_url(path: &str) -> String {
+        format!("{}/api{path}", server_url())
+    }
+
+    pub fn runtime() -> &'static Runtime {
+        RUNTIME.get_or_init(|| {
+            tokio::runtime::Builder::new_multi_thread()
+                .worker_threads(2)
+                .enable_all()
+                .build()
+                .expect("No se pudo crear el runtime de tokio")
+        })
+    }
+
+    fn client() -> &'static reqwest::Client {
+        CLIENT.get_or_init(reqwest::Client::new)
+    }
+
+    fn get_request(path: &str) -> reqwest::RequestBuilder {
+        client().get(api_url(path)).header("Accept-Language", accept_language())
+    }
+
+    fn post_request(path: &str, body: String) -> reqwest::RequestBuilder {
+        client()
+            .post(api_url(path))
+            .header("Content-Type", "application/json")
+            .header("Accept-Language", accept_language())
+            .body(body)
+    }
+
+    async fn read(req: reqwest::RequestBuilder) -> HttpResult {
+        let resp = req.send().await.map_err(|e| e.to_string())?;
+        let status = resp.status().as_u16();
+        let body = resp.text().await.unwrap_or_default();
+        Ok(HttpResponse { status, body })
+    }
+
+    pub fn get(path: &str, on_done: impl FnOnce(HttpResult) + Send + 'static) {
+        let req = get_request(path);
+        runtime().spawn(async move { on_done(read(req).await) });
+    }
+
+    pub fn post_json(path: &str, body: String, on_done: impl FnOnce(HttpResult) + Send + 'static) {
+        let req = post_request(path, body);
+        runtime().spawn(async move { on_done(read(req).await) });
+    }
+
+    /// Versión bloqueante de `post_json`, para el arranque desde la CLI.
+    pub fn post_json_blocking(path: &str, body: String) -> HttpResult {
+        runtime().block_on(read(post_request(path, body)))
+    }
+
+    /// Versión bloqueante de `get`, para el arranque desde la CLI.
+    pub fn get_blocking(path: &str) -> HttpResult {
+        runtime().block_on(read(get_request(path)))
+    }
+
+    /// Abre el WebSocket de la partida y entrega cada mensaje de texto a
+    /// `on_message` hasta que el servidor cierre la conexión.
+    pub fn connect_ws(partida: i32, uid: i32, mut on_message: impl FnMut(String) + Send + 'static) {
+        let base = server_url();
+        let ws_base = base
+            .strip_prefix("https://")
+            .map(|rest| format!("wss://{rest}"))
+            .or_else(|| base.strip_prefix("http://").map(|rest| format!("ws://{rest}")))
+            .unwrap_or_else(|| base.to_string());
+        let url = format!("{ws_base}/api/ws/{partida}/{uid}");
+
+        runtime().spawn(async move {
+            let (stream, _) = match tokio_tungstenite::connect_async(&url).await {
+                Ok(ok) => ok,
+                Err(e) => {
+                    bevy::log::error!("❌ No se pudo abrir WebSocket {url}: {e}");
+                    return;
+                }
+            };
+            bevy::log::info!("🔗 WS conectado a {url}");
+
+            let (_write, mut read) = stream.split();
+            while let Some(msg) = read.next().await {
+                match msg {
+                    Ok(Message::Text(txt)) => on_message(txt),
+                    Ok(Message::Close(_)) => break,
+                    Ok(_) => {}
+                    Err(e) => {
+                        bevy::log::warn!("⚠️ WS {url} cerrado con error: {e}");
+                        break;
+                    }
+                }
+            }
+            bevy::log::info!("🔌 WS desconectado de {url}");
+        });
+    }
+}
+
+pub use imp::*;
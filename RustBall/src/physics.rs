@@ -0,0 +1,234 @@
+//! src/physics.rs
+//! --------------------------------------------------------------
+//! Física compartida por el juego, la repetición de tiros y la IA.
+//!
+//!   • `disk_body` / `ball_body`: el cuerpo rapier de fichas y pelota
+//!     en un solo sitio, para que todo el que simule use lo mismo.
+//!   • `PhysicsMode::Deterministic`: paso fijo (`FIXED_DT`, un paso
+//!     por frame sin importar cuánto tarde el frame) e iteraciones del
+//!     solver fijas.  Con el feature `deterministic` además se activa
+//!     `enhanced-determinism` de rapier, que da el mismo resultado bit
+//!     a bit en cualquier plataforma (WASM incluido).
+//!   • `simulate_shot`: arma un mundo rapier sin ventana, dispara un
+//!     `ShotInput` sobre un `BoardSnapshot` y devuelve el tablero
+//!     final.  Lo usan los tests de `tests/deterministic_shots.rs`.
+//!
+//! Zonas y power-ups no se simulan en `simulate_shot`: sólo paredes,
+//! arcos, fichas y pelota.
+//! --------------------------------------------------------------
+
+use std::num::NonZeroUsize;
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use once_cell::sync::OnceCell;
+
+use crate::{
+    components::{Ball, DiskId, PlayerDisk},
+    setup::{field::spawn_walls, spawn_goals},
+    snapshot::{BallPos, BoardSnapshot, PiezaPos, ShotInput},
+    systems::turn_systems::SHOT_MAX_SPEED,
+};
+
+/// Paso de simulación en modo determinista.
+pub const FIXED_DT: f32 = 1.0 / 60.0;
+/// Subpasos por paso.
+pub const SUBSTEPS: usize = 1;
+/// Iteraciones del solver (los valores por defecto de rapier, pero fijados aquí).
+pub const SOLVER_ITERATIONS: usize = 4;
+pub const FRICTION_ITERATIONS: usize = 4;
+pub const PGS_ITERATIONS: usize = 1;
+/// Escala píxeles → metros de rapier.
+pub const PIXELS_PER_METER: f32 = 100.0;
+
+/// Igual que `check_turn_end`: por debajo de esto (al cuadrado) se considera quieto.
+pub const STOP_THRESHOLD: f32 = 0.5;
+/// Tope de pasos de `simulate_shot` (20 s a 60 Hz).
+pub const MAX_SIM_STEPS: usize = 1200;
+
+pub use rustball_shared::field::{BALL_RADIUS, DISK_RADIUS};
+use rustball_shared::field::{BALL_RESTITUTION, DISK_RESTITUTION, LINEAR_DAMPING};
+
+/* ───────── Modo ───────── */
+
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhysicsMode {
+    /// Paso según el tiempo real del frame (comportamiento original).
+    Variable,
+    /// Paso fijo e iteraciones fijas.
+    Deterministic,
+}
+
+static MODE: OnceCell<PhysicsMode> = OnceCell::new();
+
+impl PhysicsMode {
+    /// Fija el modo antes de crear la `App` (p. ej. `--deterministic`).
+    pub fn set(mode: PhysicsMode) {
+        let _ = MODE.set(mode);
+    }
+
+    /// Modo elegido; por defecto determinista si se compiló con el feature.
+    pub fn current() -> PhysicsMode {
+        MODE.get().copied().unwrap_or(if cfg!(feature = "deterministic") {
+            PhysicsMode::Deterministic
+        } else {
+            PhysicsMode::Variable
+        })
+    }
+
+    pub fn rapier_configuration(self) -> RapierConfiguration {
+        let timestep_mode = match self {
+            PhysicsMode::Variable => RapierConfiguration::default().timestep_mode,
+            PhysicsMode::Deterministic => TimestepMode::Fixed { dt: FIXED_DT, substeps: SUBSTEPS },
+        };
+
+        RapierConfiguration {
+            gravity: Vec2::ZERO,
+            timestep_mode,
+            ..default()
+        }
+    }
+}
+
+/// Startup: fija las iteraciones del solver en modo determinista.
+pub fn configure_integration(mode: Res<PhysicsMode>, mut ctx: ResMut<RapierContext>) {
+    if *mode != PhysicsMode::Deterministic {
+        return;
+    }
+
+    let params = &mut ctx.integration_parameters;
+    params.dt = FIXED_DT;
+    params.num_solver_iterations = NonZeroUsize::new(SOLVER_ITERATIONS).unwrap();
+    params.num_additional_friction_iterations = FRICTION_ITERATIONS;
+    params.num_internal_pgs_iterations = PGS_ITERATIONS;
+    info!("🧮 Física determinista: dt = {FIXED_DT:.5}, {SOLVER_ITERATIONS} iteraciones");
+}
+
+/* ───────── Cuerpos ───────── */
+
+/// Cuerpo rapier de una ficha.
+pub fn disk_body(velocity: Vec2) -> impl Bundle {
+    (
+        RigidBody::Dynamic,
+        Collider::ball(DISK_RADIUS),
+        Restitution::coefficient(DISK_RESTITUTION),
+        ActiveEvents::COLLISION_EVENTS,
+        ExternalImpulse::default(),
+        ExternalForce::default(),
+        AdditionalMassProperties::Mass(1.0),
+        Velocity::linear(velocity),
+        Damping { linear_damping: LINEAR_DAMPING, angular_damping: LINEAR_DAMPING },
+        LockedAxes::ROTATION_LOCKED,
+        Sleeping::disabled(),
+    )
+}
+
+/// Cuerpo rapier de la pelota.
+pub fn ball_body(velocity: Vec2) -> impl Bundle {
+    (
+        RigidBody::Dynamic,
+        Collider::ball(BALL_RADIUS),
+        Restitution::coefficient(BALL_RESTITUTION),
+        ActiveEvents::COLLISION_EVENTS,
+        ExternalImpulse::default(),
+        ExternalForce::default(),
+        AdditionalMassProperties::Mass(1.0),
+        Velocity::linear(velocity),
+        Damping { linear_damping: LINEAR_DAMPING, angular_damping: LINEAR_DAMPING },
+        LockedAxes::ROTATION_LOCKED,
+        Sleeping::disabled(),
+    )
+}
+
+/* ───────── Simulación sin ventana ───────── */
+
+/// Mundo rapier mínimo (sin render ni ventana) en modo determinista.
+pub fn headless_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, TransformPlugin, AssetPlugin::default()))
+        .init_asset::<Image>()
+        .add_plugins(RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(PIXELS_PER_METER))
+        .insert_resource(PhysicsMode::Deterministic)
+        .insert_resource(PhysicsMode::Deterministic.rapier_configuration())
+        .add_systems(Startup, (configure_integration, spawn_arena));
+    app
+}
+
+/// Paredes y arcos, igual que en `setup`.
+fn spawn_arena(mut commands: Commands, asset_server: Res<AssetServer>) {
+    spawn_walls(&mut commands);
+    spawn_goals(&mut commands, &asset_server);
+}
+
+/// Simula `shot` sobre `board` hasta que todo se detiene y devuelve el
+/// tablero final (fichas ordenadas por `DiskId`).
+pub fn simulate_shot(board: &BoardSnapshot, shot: &ShotInput) -> BoardSnapshot {
+    let mut app = headless_app();
+    let shot_velocity = Vec2::new(shot.dir_x, shot.dir_y) * shot.potencia * SHOT_MAX_SPEED;
+
+    let world = &mut app.world;
+    for p in &board.piezas {
+        let velocity = if p.id == shot.id_ficha { shot_velocity } else { Vec2::new(p.vx, p.vy) };
+        world.spawn((
+            TransformBundle::from_transform(Transform::from_xyz(p.x, p.y, 10.0)),
+            disk_body(velocity),
+            PlayerDisk { player_id: 0, id_usuario_real: p.id_usuario_real },
+            DiskId(p.id),
+        ));
+    }
+
+    let pelota = board.pelota.unwrap_or(BallPos { x: 0.0, y: 0.0, vx: 0.0, vy: 0.0 });
+    world.spawn((
+        TransformBundle::from_transform(Transform::from_xyz(pelota.x, pelota.y, 0.0)),
+        ball_body(Vec2::new(pelota.vx, pelota.vy)),
+        Ball,
+    ));
+
+    // Con `TimestepMode::Fixed` cada `update` es exactamente un paso
+    for _ in 0..MAX_SIM_STEPS {
+        app.update();
+        let moving = app
+            .world
+            .query::<&Velocity>()
+            .iter(&app.world)
+            .any(|v| v.linvel.length_squared() >= STOP_THRESHOLD);
+        if !moving {
+            break;
+        }
+    }
+
+    read_board(&mut app.world, board)
+}
+
+fn read_board(world: &mut World, previo: &BoardSnapshot) -> BoardSnapshot {
+    let mut piezas: Vec<PiezaPos> = world
+        .query::<(&DiskId, &Transform, &Velocity, &PlayerDisk)>()
+        .iter(world)
+        .map(|(id, tf, v, disk)| PiezaPos {
+            id: id.0,
+            x: tf.translation.x,
+            y: tf.translation.y,
+            id_usuario_real: disk.id_usuario_real,
+            vx: v.linvel.x,
+            vy: v.linvel.y,
+            powerup: previo.piezas.iter().find(|p| p.id == id.0).and_then(|p| p.powerup),
+        })
+        .collect();
+    piezas.sort_by_key(|p| p.id);
+
+    let pelota = world
+        .query_filtered::<(&Transform, &Velocity), With<Ball>>()
+        .iter(world)
+        .next()
+        .map(|(tf, v)| BallPos { x: tf.translation.x, y: tf.translation.y, vx: v.linvel.x, vy: v.linvel.y });
+
+    BoardSnapshot {
+        piezas,
+        pelota,
+        powerup: previo.powerup,
+        zonas: previo.zonas.clone(),
+        marcador: previo.marcador,
+        tiro: None,
+        eventos: Vec::new(),
+    }
+}
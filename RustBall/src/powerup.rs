@@ -6,9 +6,13 @@ use bevy_rapier2d::prelude::*;
 use rand::{seq::SliceRandom, thread_rng, Rng};
 
 use crate::components::{PlayerDisk, PowerUpLabel};
+use crate::events::PowerUpPickedEvent;
+use crate::i18n::t;
 
 /* ───────── Config ───────── */
 pub const TURN_INTERVAL_FOR_POWERUP: usize = 1;
+/// Textura de cada `PowerUpType`, por índice.
+const POWERUP_TEXTURES: [&str; 3] = ["rayooo.png", "rebote.png", "dobleturno.png"];
 
 /* ───────── Componentes ───── */
 #[derive(Component)] pub struct PowerUp;
@@ -51,11 +55,22 @@ pub fn spawn_power_up_if_needed(
     let mut rng = thread_rng();
     let pos = Vec2::new(rng.gen_range(-400.0..400.0), rng.gen_range(-300.0..300.0));
 
-    let mut options = vec![("rayooo.png", 0), ("rebote.png", 1), ("dobleturno.png", 2)];
+    let mut options: Vec<usize> = (0..POWERUP_TEXTURES.len()).collect();
     if let Some(last) = control.last_type {
-        options.retain(|(_, t)| *t != last);
+        options.retain(|t| *t != last);
     }
-    let (tex, t) = *options.choose(&mut rng).unwrap();
+    let t = *options.choose(&mut rng).unwrap();
+
+    spawn_power_up_at(&mut commands, &asset_server, pos, t);
+
+    control.active = true;
+    control.turns_since_last = 0;
+    control.last_type = Some(t);
+}
+
+/// Coloca en el campo un power-up del tipo `t` (0 velocidad, 1 rebote, 2 doble turno).
+pub fn spawn_power_up_at(commands: &mut Commands, asset_server: &AssetServer, pos: Vec2, t: usize) -> Entity {
+    let tex = POWERUP_TEXTURES.get(t).copied().unwrap_or(POWERUP_TEXTURES[0]);
 
     commands.spawn((
         SpriteBundle {
@@ -72,11 +87,20 @@ pub fn spawn_power_up_if_needed(
         Collider::ball(20.0),
         Sensor,
         ActiveEvents::COLLISION_EVENTS,
-    ));
+    )).id()
+}
 
-    control.active = true;
-    control.turns_since_last = 0;
-    control.last_type = Some(t);
+/// Deja a `disk` con el power-up `t` pendiente (o sin ninguno si es `None`).
+pub fn grant_power_up(commands: &mut Commands, disk: Entity, t: Option<usize>) {
+    let mut e = commands.entity(disk);
+    e.remove::<(PowerUpType, PendingSpeedBoost, PendingDoubleBounce, PendingDoubleTurn)>();
+
+    match t {
+        Some(0) => { e.insert((PendingSpeedBoost, PowerUpType(0))); }
+        Some(1) => { e.insert((PendingDoubleBounce, PowerUpType(1))); }
+        Some(2) => { e.insert((PendingDoubleTurn, PowerUpType(2))); }
+        _ => {}
+    }
 }
 
 /* ───────── Colisiones ────── */
@@ -86,6 +110,7 @@ pub fn detect_powerup_collision(
     disks:    Query<(Entity, &PlayerDisk)>,
     powerups: Query<(Entity, &PowerUpType), With<PowerUp>>,
     mut control: ResMut<PowerUpControl>,
+    mut picked: EventWriter<PowerUpPickedEvent>,
 ) {
     for ev in collisions.read() {
         if let CollisionEvent::Started(a, b, _) = ev {
@@ -99,6 +124,7 @@ pub fn detect_powerup_collision(
 
             commands.entity(pup_entity).despawn_recursive();
             control.active = false;
+            picked.send(PowerUpPickedEvent { disk, tipo: pup_type });
 
             // Retira tipo anterior y aplica nuevo
             commands.entity(disk).remove::<PowerUpType>();
@@ -172,12 +198,15 @@ pub fn update_powerup_labels(
         for &child in children {
             if let Ok((mut txt, mut vis)) = text_q.get_mut(child) {
                 if let Some(kind) = pup {
-                    txt.sections[0].value = match kind {
-                        0 => "Velocidad",
-                        1 => "Doble Rebote",
-                        2 => "Doble Turno",
-                        _ => "",
-                    }.to_string();
+                    let label = match kind {
+                        0 => t!("powerup-velocidad"),
+                        1 => t!("powerup-doble-rebote"),
+                        2 => t!("powerup-doble-turno"),
+                        _ => String::new(),
+                    };
+                    if txt.sections[0].value != label {
+                        txt.sections[0].value = label;
+                    }
                     *vis = Visibility::Visible;
                 } else {
                     *vis = Visibility::Hidden;
@@ -1,7 +1,9 @@
 use bevy::prelude::*;
 use crate::events::RandomEvent;
-use crate::snapshot::SnapshotFromServer;
+use crate::snapshot::{SnapshotFromServer, SnapshotVersion};
 use crate::snapshot::BoardSnapshot;
+use crate::ai::BotDifficulty;
+use crate::i18n::t;
 
 /* ─────────── Turno / Marcador ─────────── */
 
@@ -36,26 +38,55 @@ pub struct Scores {
 
 /* ─────────── Formaciones ─────────── */
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+/// Formación diseñada por el usuario; `positions` en coordenadas del
+/// lado derecho (se reflejan para el izquierdo, como las predefinidas).
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct CustomFormation {
+    /// `id_formacion` en el backend (0 si no está guardada).
+    pub id: i32,
+    pub positions: [Vec2; 5],
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum Formation {
     Rombo1211,
     Muro221,
     Ofensiva113,
     Diamante2111,
+    Custom(CustomFormation),
 }
 
 impl Formation {
+    pub const PRESETS: [Formation; 4] = [
+        Formation::Rombo1211,
+        Formation::Muro221,
+        Formation::Ofensiva113,
+        Formation::Diamante2111,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             Formation::Rombo1211    => "1-2-1-1",
             Formation::Muro221      => "2-2-1",
             Formation::Ofensiva113  => "1-1-3",
             Formation::Diamante2111 => "2-1-1-1",
+            Formation::Custom(_)    => "personalizada",
         }
     }
 }
 
-#[derive(Resource, Debug)]
+/// Formación personalizada guardada por el usuario.
+#[derive(Debug, Clone)]
+pub struct SavedFormation {
+    pub nombre: String,
+    pub formation: CustomFormation,
+}
+
+/// Formaciones personalizadas del usuario (`GET /formaciones/:u`).
+#[derive(Resource, Debug, Default, Clone)]
+pub struct SavedFormations(pub Vec<SavedFormation>);
+
+#[derive(Resource, Debug, Default)]
 pub struct PlayerFormations {
     pub player1: Option<Formation>,
     pub player2: Option<Formation>,
@@ -85,6 +116,37 @@ pub struct EventControl {
     pub event_active:     bool,
 }
 
+/* ─────────── Modo de juego ─────────── */
+
+/// `Online` juega contra el backend; `HotSeat` alterna ambos lados en la
+/// misma pantalla sin red; `VsBot` es local con el lado derecho en manos
+/// de la IA.
+#[derive(Resource, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum GameMode {
+    #[default]
+    Online,
+    HotSeat,
+    VsBot(BotDifficulty),
+}
+
+impl GameMode {
+    pub fn is_online(&self) -> bool {
+        *self == GameMode::Online
+    }
+
+    /// UID del lado que controla la IA (si lo hay).
+    pub fn bot_uid(&self) -> Option<i32> {
+        match self {
+            GameMode::VsBot(_) => Some(LOCAL_RIGHT_UID),
+            _ => None,
+        }
+    }
+}
+
+/// UIDs ficticios que usan los dos lados en modo local.
+pub const LOCAL_LEFT_UID: i32 = 1;
+pub const LOCAL_RIGHT_UID: i32 = 2;
+
 /* ─────────── Info de backend ─────────── */
 #[derive(Resource, Clone, Debug)]
 pub struct BackendInfo {
@@ -124,6 +186,11 @@ impl BackendInfo {
         }
     }
 
+    // Partida local: sin id de partida, empieza controlando el lado izquierdo
+    pub fn local() -> Self {
+        Self::new(0, LOCAL_LEFT_UID, LOCAL_LEFT_UID, LOCAL_RIGHT_UID)
+    }
+
     pub fn i_am_left(&self) -> bool {
         self.my_uid == self.id_left
     }
@@ -132,6 +199,38 @@ impl BackendInfo {
         self.my_uid == self.id_right
     }
 }
+/* ─────────── Reglas de la partida ─────────── */
+
+/// Reglas que pueden variar entre partidas amistosas y clasificatorias.
+#[derive(Resource, Clone, Debug)]
+pub struct MatchRules {
+    pub ranked: bool,
+    pub trajectory_preview: bool,
+}
+
+impl Default for MatchRules {
+    fn default() -> Self {
+        Self {
+            ranked: false,
+            trajectory_preview: true,
+        }
+    }
+}
+
+impl MatchRules {
+    // En partidas clasificatorias no se muestra la predicción de trayectoria
+    pub fn ranked() -> Self {
+        Self {
+            ranked: true,
+            trajectory_preview: false,
+        }
+    }
+
+    pub fn allows_trajectory_preview(&self) -> bool {
+        self.trajectory_preview && !self.ranked
+    }
+}
+
 /* ─────────── Snapshot más reciente (compartido) ─────────── */
 
 #[derive(Resource, Default)]
@@ -145,14 +244,18 @@ pub struct PowerBarBackground;
 #[derive(Resource, Default)]
 pub struct WsInbox(pub Vec<String>);
 
+/// Versión del último snapshot aplicado (ver `SnapshotFromServer::version`).
 #[derive(Resource, Default)]
-pub struct UltimoTurnoAplicado(pub i32);
+pub struct UltimoTurnoAplicado(pub SnapshotVersion);
 
-#[derive(Resource)]
+/// Ronda de saque en juego según el backend (sube con cada gol).
+#[derive(Resource, Default, Debug, Clone, Copy)]
+pub struct RondaActual(pub i32);
+
+/// Quién maneja el input en esta pantalla.  El texto de turno no lo usa:
+/// sale de `TurnState::current_turn_id`.
+#[derive(Resource, Default)]
 pub struct CurrentPlayerId(pub i32);
-impl Default for CurrentPlayerId {
-    fn default() -> Self { Self(0) }
-}
 
 #[derive(Resource, Debug, Clone)]
 pub struct PlayerNames {
@@ -160,13 +263,20 @@ pub struct PlayerNames {
     pub right_name: String,
 }
 
-/* ─────────── Recursos sólo para WASM ─────────── */
+impl Default for PlayerNames {
+    fn default() -> Self {
+        Self {
+            left_name:  t!("jugador-izquierdo"),
+            right_name: t!("jugador-derecho"),
+        }
+    }
+}
+
+/* ─────────── Polling de snapshots ─────────── */
 
-#[cfg(target_arch = "wasm32")]
 #[derive(Resource)]
 pub struct SnapshotPollTimer(pub Timer);
 
-#[cfg(target_arch = "wasm32")]
 impl Default for SnapshotPollTimer {
     fn default() -> Self {
         Self(Timer::from_seconds(1.0, TimerMode::Repeating))
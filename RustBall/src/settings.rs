@@ -0,0 +1,296 @@
+//! src/settings.rs
+//! --------------------------------------------------------------
+//! Preferencias del jugador que no son de audio (ésas viven en
+//! `AudioSettings`):
+//!
+//!   • Teclas de apuntar, cargar/disparar y cambiar de ficha.
+//!   • Sensibilidad: cuánto gira la mira y cuánto carga la barra
+//!     por frame (antes fijos en 0.1 y 0.02).
+//!   • Mostrar la trayectoria predicha (las reglas de la partida
+//!     pueden ocultarla igual, ver `MatchRules`).
+//!   • Colores para daltónicos: marca cada equipo con forma y color
+//!     distinguibles además del escudo.
+//!   • Idioma de la interfaz (ver `i18n`).
+//!
+//! Se guardan con `storage` (localStorage en el navegador) bajo
+//! `rb_ajustes`.  Las teclas se guardan por nombre (`"Space"`, `"A"`…).
+//! El idioma además va en `rb_lang`, la misma clave que usa la webapp
+//! (el login la llena con el idioma del perfil), y ésa manda al cargar.
+//! --------------------------------------------------------------
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::t;
+use crate::storage;
+
+const STORAGE_KEY: &str = "rb_ajustes";
+/// Idioma compartido con la webapp.
+const LANGUAGE_KEY: &str = "rb_lang";
+
+/// Rango de la sensibilidad de apuntado (giro por frame).
+pub const AIM_STEP_RANGE: (f32, f32) = (0.02, 0.3);
+/// Rango de la velocidad de carga (potencia por frame).
+pub const CHARGE_STEP_RANGE: (f32, f32) = (0.005, 0.06);
+
+/// Teclas que se pueden asignar (también sirven para leer las guardadas).
+pub const BINDABLE_KEYS: &[KeyCode] = &[
+    KeyCode::Left, KeyCode::Right, KeyCode::Up, KeyCode::Down,
+    KeyCode::Space, KeyCode::Tab, KeyCode::Return, KeyCode::ShiftLeft, KeyCode::ShiftRight,
+    KeyCode::ControlLeft, KeyCode::ControlRight, KeyCode::AltLeft,
+    KeyCode::A, KeyCode::B, KeyCode::C, KeyCode::D, KeyCode::E, KeyCode::F, KeyCode::G,
+    KeyCode::H, KeyCode::I, KeyCode::J, KeyCode::K, KeyCode::L, KeyCode::N, KeyCode::O,
+    KeyCode::P, KeyCode::Q, KeyCode::R, KeyCode::S, KeyCode::T, KeyCode::U, KeyCode::V,
+    KeyCode::W, KeyCode::X, KeyCode::Y, KeyCode::Z,
+    KeyCode::Numpad2, KeyCode::Numpad4, KeyCode::Numpad5, KeyCode::Numpad6, KeyCode::Numpad8,
+];
+
+/// Nombre con el que se guarda / muestra una tecla.
+pub fn key_name(key: KeyCode) -> String {
+    format!("{key:?}")
+}
+
+/// Inversa de `key_name` (sólo teclas de `BINDABLE_KEYS`).
+pub fn parse_key(name: &str) -> Option<KeyCode> {
+    BINDABLE_KEYS.iter().copied().find(|k| key_name(*k) == name)
+}
+
+/// (De)serializa un `KeyCode` por nombre: bevy sin el feature
+/// `serialize` no implementa serde para las teclas.
+mod key_serde {
+    use super::{key_name, parse_key};
+    use bevy::prelude::KeyCode;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(key: &KeyCode, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&key_name(*key))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<KeyCode, D::Error> {
+        let name = String::deserialize(d)?;
+        parse_key(&name).ok_or_else(|| D::Error::custom(format!("tecla desconocida: {name}")))
+    }
+}
+
+/* ───────── Teclas ───────── */
+
+/// Acción del jugador que se puede reasignar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyAction {
+    AimLeft,
+    AimRight,
+    AimUp,
+    AimDown,
+    Shoot,
+    CycleDisk,
+}
+
+impl KeyAction {
+    pub const ALL: [KeyAction; 6] = [
+        KeyAction::AimLeft,
+        KeyAction::AimRight,
+        KeyAction::AimUp,
+        KeyAction::AimDown,
+        KeyAction::Shoot,
+        KeyAction::CycleDisk,
+    ];
+
+    /// Clave del texto en `locales/*.ftl`.
+    pub fn label_key(self) -> &'static str {
+        match self {
+            KeyAction::AimLeft => "tecla-apuntar-izquierda",
+            KeyAction::AimRight => "tecla-apuntar-derecha",
+            KeyAction::AimUp => "tecla-apuntar-arriba",
+            KeyAction::AimDown => "tecla-apuntar-abajo",
+            KeyAction::Shoot => "tecla-disparar",
+            KeyAction::CycleDisk => "tecla-cambiar-ficha",
+        }
+    }
+
+    pub fn label(self) -> String {
+        t!(self.label_key())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KeyBindings {
+    #[serde(with = "key_serde")]
+    pub aim_left: KeyCode,
+    #[serde(with = "key_serde")]
+    pub aim_right: KeyCode,
+    #[serde(with = "key_serde")]
+    pub aim_up: KeyCode,
+    #[serde(with = "key_serde")]
+    pub aim_down: KeyCode,
+    /// Mantener carga la potencia; soltar dispara.
+    #[serde(with = "key_serde")]
+    pub shoot: KeyCode,
+    #[serde(with = "key_serde")]
+    pub cycle_disk: KeyCode,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            aim_left: KeyCode::Left,
+            aim_right: KeyCode::Right,
+            aim_up: KeyCode::Up,
+            aim_down: KeyCode::Down,
+            shoot: KeyCode::Space,
+            cycle_disk: KeyCode::Tab,
+        }
+    }
+}
+
+impl KeyBindings {
+    pub fn get(&self, action: KeyAction) -> KeyCode {
+        match action {
+            KeyAction::AimLeft => self.aim_left,
+            KeyAction::AimRight => self.aim_right,
+            KeyAction::AimUp => self.aim_up,
+            KeyAction::AimDown => self.aim_down,
+            KeyAction::Shoot => self.shoot,
+            KeyAction::CycleDisk => self.cycle_disk,
+        }
+    }
+
+    /// Asigna `key` a `action`; si otra acción ya la usaba, intercambian
+    /// teclas para que ninguna quede duplicada.
+    pub fn assign(&mut self, action: KeyAction, key: KeyCode) {
+        let previous = self.get(action);
+        if let Some(other) = KeyAction::ALL.into_iter().find(|a| *a != action && self.get(*a) == key) {
+            *self.slot(other) = previous;
+        }
+        *self.slot(action) = key;
+    }
+
+    fn slot(&mut self, action: KeyAction) -> &mut KeyCode {
+        match action {
+            KeyAction::AimLeft => &mut self.aim_left,
+            KeyAction::AimRight => &mut self.aim_right,
+            KeyAction::AimUp => &mut self.aim_up,
+            KeyAction::AimDown => &mut self.aim_down,
+            KeyAction::Shoot => &mut self.shoot,
+            KeyAction::CycleDisk => &mut self.cycle_disk,
+        }
+    }
+}
+
+/* ───────── Idioma ───────── */
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    #[default]
+    Es,
+    En,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::Es, Language::En];
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::Es => "es",
+            Language::En => "en",
+        }
+    }
+
+    /// Nombre del idioma en ese mismo idioma (no se traduce).
+    pub fn label(self) -> &'static str {
+        match self {
+            Language::Es => "Español",
+            Language::En => "English",
+        }
+    }
+
+    /// "es", "en-US", "en_GB.UTF-8"… → idioma soportado.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let primary = code.trim().split(['-', '_', '.']).next()?.to_ascii_lowercase();
+        Self::ALL.into_iter().find(|l| l.code() == primary)
+    }
+
+    /// Idioma inicial: el guardado en `rb_lang` (perfil / lobby), si no
+    /// el del navegador o del sistema, si no español.
+    pub fn detect() -> Self {
+        storage::get(LANGUAGE_KEY)
+            .and_then(|code| Self::from_code(&code))
+            .or_else(|| system_locale().and_then(|code| Self::from_code(&code)))
+            .unwrap_or_default()
+    }
+
+    pub fn next(self) -> Self {
+        let idx = Self::ALL.iter().position(|l| *l == self).unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn system_locale() -> Option<String> {
+    web_sys::window()?.navigator().language()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn system_locale() -> Option<String> {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .find_map(|var| std::env::var(var).ok().filter(|v| !v.is_empty()))
+}
+
+/* ───────── Ajustes ───────── */
+
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameSettings {
+    pub keys: KeyBindings,
+    /// Giro de la mira por frame con la tecla pulsada.
+    pub aim_step: f32,
+    /// Potencia que se carga por frame mientras se mantiene el disparo.
+    pub charge_step: f32,
+    pub show_trajectory: bool,
+    pub colorblind: bool,
+    pub language: Language,
+}
+
+impl Default for GameSettings {
+    fn default() -> Self {
+        Self {
+            keys: KeyBindings::default(),
+            aim_step: 0.1,
+            charge_step: 0.02,
+            show_trajectory: true,
+            colorblind: false,
+            language: Language::detect(),
+        }
+    }
+}
+
+impl GameSettings {
+    /// Lee los ajustes guardados (o los de por defecto si no hay o están rotos).
+    pub fn load() -> Self {
+        let Some(raw) = storage::get(STORAGE_KEY) else { return Self::default() };
+        match serde_json::from_str::<Self>(&raw) {
+            Ok(mut s) => {
+                s.aim_step = s.aim_step.clamp(AIM_STEP_RANGE.0, AIM_STEP_RANGE.1);
+                s.charge_step = s.charge_step.clamp(CHARGE_STEP_RANGE.0, CHARGE_STEP_RANGE.1);
+                // El idioma del perfil (webapp) pisa al guardado por el juego
+                if let Some(lang) = storage::get(LANGUAGE_KEY).and_then(|c| Language::from_code(&c)) {
+                    s.language = lang;
+                }
+                s
+            }
+            Err(e) => {
+                warn!("⚠️ Ajustes guardados inválidos ({e}); se usan los de por defecto");
+                Self::default()
+            }
+        }
+    }
+
+    pub fn save(&self) {
+        if let Ok(json) = serde_json::to_string(self) {
+            storage::set(STORAGE_KEY, &json);
+        }
+        storage::set(LANGUAGE_KEY, self.language.code());
+    }
+}
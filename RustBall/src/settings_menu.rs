@@ -0,0 +1,461 @@
+//! src/settings_menu.rs
+//! --------------------------------------------------------------
+//! Pantalla de ajustes y menú de pausa.
+//!
+//!   • Se abre con el botón "Ajustes" del menú de formaciones o desde
+//!     la pausa (`Esc` durante el partido).
+//!   • Edita `AudioSettings` y `GameSettings` y los guarda al instante.
+//!   • Reasignar una tecla: clic en la acción y pulsar la nueva tecla
+//!     (`Esc` cancela).
+//!   • La pausa bloquea el input de turno; en hot-seat además detiene
+//!     la física.  En línea el rival sigue jugando.
+//!   • Las etiquetas llevan `Localized`: al cambiar de idioma se
+//!     retraducen sin reabrir el panel.
+//! --------------------------------------------------------------
+
+use bevy::prelude::*;
+use bevy::ui::FocusPolicy;
+use bevy_rapier2d::prelude::RapierConfiguration;
+
+use crate::{
+    audio::AudioSettings,
+    i18n::{localized_text, t},
+    resources::GameMode,
+    settings::{key_name, GameSettings, KeyAction, AIM_STEP_RANGE, BINDABLE_KEYS, CHARGE_STEP_RANGE},
+};
+
+const FONT: &str = "fonts/FiraSans-Bold.ttf";
+const VOLUME_STEP: f32 = 0.1;
+const AIM_STEP_DELTA: f32 = 0.02;
+const CHARGE_STEP_DELTA: f32 = 0.005;
+
+/* ───────── Recursos y componentes ───────── */
+
+/// Partido en pausa (bloquea apuntar / cargar / disparar).
+#[derive(Resource, Default)]
+pub struct Paused(pub bool);
+
+/// Acción esperando su nueva tecla.
+#[derive(Resource, Default)]
+pub struct RebindingKey(pub Option<KeyAction>);
+
+/// Raíz de la pantalla de ajustes.
+#[derive(Component)]
+pub struct SettingsPanel;
+
+/// Raíz del menú de pausa.
+#[derive(Component)]
+pub struct PauseOverlay;
+
+#[derive(Component, Clone, Copy, Debug)]
+pub struct SettingsButton(pub SettingsAction);
+
+/// Texto que muestra el valor actual de un ajuste.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct SettingsValue(pub SettingsField);
+
+#[derive(Clone, Copy, Debug)]
+pub enum SettingsAction {
+    Open,
+    Close,
+    Resume,
+    Master(f32),
+    Music(f32),
+    Effects(f32),
+    ToggleMute,
+    AimStep(f32),
+    ChargeStep(f32),
+    ToggleTrajectory,
+    ToggleColorblind,
+    NextLanguage,
+    Rebind(KeyAction),
+    ResetDefaults,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SettingsField {
+    Master,
+    Music,
+    Effects,
+    Muted,
+    AimStep,
+    ChargeStep,
+    Trajectory,
+    Colorblind,
+    Language,
+    Key(KeyAction),
+}
+
+/* ───────── Construcción de la UI ───────── */
+
+fn text_style(asset_server: &AssetServer, size: f32) -> TextStyle {
+    TextStyle { font: asset_server.load(FONT), font_size: size, color: Color::WHITE }
+}
+
+/// Botón genérico de la pantalla de ajustes (también lo usa el menú de
+/// formaciones); `label_key` es la clave del texto en `locales/*.ftl`.
+pub fn spawn_settings_button(
+    parent: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    label_key: &'static str,
+    action: SettingsAction,
+    width: f32,
+) {
+    spawn_button(parent, localized_text(label_key, text_style(asset_server, 18.0)), action, width);
+}
+
+fn spawn_button(parent: &mut ChildBuilder, label: impl Bundle, action: SettingsAction, width: f32) {
+    parent
+        .spawn((
+            ButtonBundle {
+                style: Style {
+                    width: Val::Px(width),
+                    height: Val::Px(36.0),
+                    margin: UiRect::all(Val::Px(4.0)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                background_color: Color::DARK_GRAY.into(),
+                ..default()
+            },
+            SettingsButton(action),
+        ))
+        .with_children(|b| {
+            b.spawn(label);
+        });
+}
+
+/// Fila "etiqueta · valor" con controles opcionales a los lados.
+fn spawn_row(
+    parent: &mut ChildBuilder,
+    asset_server: &AssetServer,
+    label_key: &'static str,
+    field: SettingsField,
+    controls: Option<(SettingsAction, SettingsAction)>,
+    on_value_click: Option<SettingsAction>,
+) {
+    parent
+        .spawn(NodeBundle {
+            style: Style {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                margin: UiRect::vertical(Val::Px(2.0)),
+                ..default()
+            },
+            ..default()
+        })
+        .with_children(|row| {
+            let (label, localized) = localized_text(label_key, text_style(asset_server, 18.0));
+            row.spawn((label.with_style(Style { width: Val::Px(240.0), ..default() }), localized));
+
+            if let Some((minus, _)) = controls {
+                spawn_button(row, TextBundle::from_section("-", text_style(asset_server, 18.0)), minus, 36.0);
+            }
+
+            match on_value_click {
+                Some(action) => {
+                    row.spawn((
+                        ButtonBundle {
+                            style: Style {
+                                width: Val::Px(180.0),
+                                height: Val::Px(36.0),
+                                margin: UiRect::all(Val::Px(4.0)),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                ..default()
+                            },
+                            background_color: Color::DARK_GRAY.into(),
+                            ..default()
+                        },
+                        SettingsButton(action),
+                    ))
+                    .with_children(|b| {
+                        b.spawn((TextBundle::from_section("", text_style(asset_server, 18.0)), SettingsValue(field)));
+                    });
+                }
+                None => {
+                    row.spawn((
+                        TextBundle::from_section("", text_style(asset_server, 18.0)).with_style(Style {
+                            width: Val::Px(100.0),
+                            justify_content: JustifyContent::Center,
+                            margin: UiRect::horizontal(Val::Px(8.0)),
+                            ..default()
+                        }),
+                        SettingsValue(field),
+                    ));
+                }
+            }
+
+            if let Some((_, plus)) = controls {
+                spawn_button(row, TextBundle::from_section("+", text_style(asset_server, 18.0)), plus, 36.0);
+            }
+        });
+}
+
+fn spawn_overlay_root(commands: &mut Commands, marker: impl Bundle, z: i32) -> Entity {
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    flex_direction: FlexDirection::Column,
+                    ..default()
+                },
+                background_color: Color::rgba(0.0, 0.0, 0.0, 0.75).into(),
+                focus_policy: FocusPolicy::Block,
+                z_index: ZIndex::Global(z),
+                ..default()
+            },
+            marker,
+        ))
+        .id()
+}
+
+fn spawn_settings_panel(commands: &mut Commands, asset_server: &AssetServer) {
+    let root = spawn_overlay_root(commands, SettingsPanel, 30);
+    commands.entity(root).with_children(|parent| {
+        let (title, localized) = localized_text("ajustes", text_style(asset_server, 36.0));
+        parent.spawn((title.with_style(Style { margin: UiRect::bottom(Val::Px(12.0)), ..default() }), localized));
+
+        use SettingsAction as A;
+        use SettingsField as F;
+
+        spawn_row(parent, asset_server, "ajustes-volumen", F::Master, Some((A::Master(-VOLUME_STEP), A::Master(VOLUME_STEP))), None);
+        spawn_row(parent, asset_server, "ajustes-musica", F::Music, Some((A::Music(-VOLUME_STEP), A::Music(VOLUME_STEP))), None);
+        spawn_row(parent, asset_server, "ajustes-efectos", F::Effects, Some((A::Effects(-VOLUME_STEP), A::Effects(VOLUME_STEP))), None);
+        spawn_row(parent, asset_server, "ajustes-silencio", F::Muted, None, Some(A::ToggleMute));
+        spawn_row(parent, asset_server, "ajustes-mira", F::AimStep, Some((A::AimStep(-AIM_STEP_DELTA), A::AimStep(AIM_STEP_DELTA))), None);
+        spawn_row(parent, asset_server, "ajustes-carga", F::ChargeStep, Some((A::ChargeStep(-CHARGE_STEP_DELTA), A::ChargeStep(CHARGE_STEP_DELTA))), None);
+        spawn_row(parent, asset_server, "ajustes-trayectoria", F::Trajectory, None, Some(A::ToggleTrajectory));
+        spawn_row(parent, asset_server, "ajustes-daltonicos", F::Colorblind, None, Some(A::ToggleColorblind));
+        spawn_row(parent, asset_server, "ajustes-idioma", F::Language, None, Some(A::NextLanguage));
+        for action in KeyAction::ALL {
+            spawn_row(parent, asset_server, action.label_key(), F::Key(action), None, Some(A::Rebind(action)));
+        }
+
+        parent
+            .spawn(NodeBundle {
+                style: Style { flex_direction: FlexDirection::Row, margin: UiRect::top(Val::Px(12.0)), ..default() },
+                ..default()
+            })
+            .with_children(|row| {
+                spawn_settings_button(row, asset_server, "ajustes-restablecer", A::ResetDefaults, 180.0);
+                spawn_settings_button(row, asset_server, "ajustes-volver", A::Close, 180.0);
+            });
+    });
+}
+
+fn spawn_pause_overlay(commands: &mut Commands, asset_server: &AssetServer, online: bool) {
+    let root = spawn_overlay_root(commands, PauseOverlay, 20);
+    commands.entity(root).with_children(|parent| {
+        let (title, localized) = localized_text("pausa", text_style(asset_server, 48.0));
+        parent.spawn((title.with_style(Style { margin: UiRect::bottom(Val::Px(16.0)), ..default() }), localized));
+        spawn_settings_button(parent, asset_server, "pausa-reanudar", SettingsAction::Resume, 220.0);
+        spawn_settings_button(parent, asset_server, "ajustes", SettingsAction::Open, 220.0);
+        if online {
+            let (note, localized) = localized_text("pausa-en-linea", text_style(asset_server, 16.0));
+            parent.spawn((note.with_style(Style { margin: UiRect::top(Val::Px(16.0)), ..default() }), localized));
+        }
+    });
+}
+
+/* ───────── Sistemas ───────── */
+
+/// Condición para el input de turno.
+pub fn not_paused(paused: Res<Paused>) -> bool {
+    !paused.0
+}
+
+/// Clics en la pantalla de ajustes y en la pausa.
+#[allow(clippy::too_many_arguments)]
+pub fn handle_settings_buttons(
+    mut commands: Commands,
+    buttons: Query<(&Interaction, &SettingsButton), Changed<Interaction>>,
+    panels: Query<Entity, With<SettingsPanel>>,
+    overlays: Query<Entity, With<PauseOverlay>>,
+    mut settings: ResMut<GameSettings>,
+    mut audio: ResMut<AudioSettings>,
+    mut rebinding: ResMut<RebindingKey>,
+    mut paused: ResMut<Paused>,
+    asset_server: Res<AssetServer>,
+) {
+    for (interaction, button) in &buttons {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let audio_before = *audio;
+        let settings_before = settings.clone();
+
+        match button.0 {
+            SettingsAction::Open => {
+                if panels.is_empty() {
+                    spawn_settings_panel(&mut commands, &asset_server);
+                }
+            }
+            SettingsAction::Close => {
+                rebinding.0 = None;
+                for e in &panels {
+                    commands.entity(e).despawn_recursive();
+                }
+            }
+            SettingsAction::Resume => {
+                for e in &overlays {
+                    commands.entity(e).despawn_recursive();
+                }
+                paused.0 = false;
+            }
+            SettingsAction::Master(d) => audio.master = (audio.master + d).clamp(0.0, 1.0),
+            SettingsAction::Music(d) => audio.music = (audio.music + d).clamp(0.0, 1.0),
+            SettingsAction::Effects(d) => audio.effects = (audio.effects + d).clamp(0.0, 1.0),
+            SettingsAction::ToggleMute => audio.muted = !audio.muted,
+            SettingsAction::AimStep(d) => {
+                settings.aim_step = (settings.aim_step + d).clamp(AIM_STEP_RANGE.0, AIM_STEP_RANGE.1)
+            }
+            SettingsAction::ChargeStep(d) => {
+                settings.charge_step = (settings.charge_step + d).clamp(CHARGE_STEP_RANGE.0, CHARGE_STEP_RANGE.1)
+            }
+            SettingsAction::ToggleTrajectory => settings.show_trajectory = !settings.show_trajectory,
+            SettingsAction::ToggleColorblind => settings.colorblind = !settings.colorblind,
+            SettingsAction::NextLanguage => settings.language = settings.language.next(),
+            SettingsAction::Rebind(action) => rebinding.0 = Some(action),
+            SettingsAction::ResetDefaults => {
+                *settings = GameSettings::default();
+                *audio = AudioSettings::default();
+            }
+        }
+
+        if *audio != audio_before {
+            audio.save();
+        }
+        if *settings != settings_before {
+            info!("⚙️ Ajustes: {:?}", *settings);
+            settings.save();
+        }
+    }
+}
+
+/// Con una acción pendiente de reasignar, la próxima tecla válida la toma.
+pub fn capture_rebind_key(
+    keys: Res<Input<KeyCode>>,
+    mut rebinding: ResMut<RebindingKey>,
+    mut settings: ResMut<GameSettings>,
+) {
+    let Some(action) = rebinding.0 else { return };
+
+    if keys.just_pressed(KeyCode::Escape) {
+        rebinding.0 = None;
+        return;
+    }
+    let Some(key) = keys.get_just_pressed().copied().find(|k| BINDABLE_KEYS.contains(k)) else { return };
+
+    settings.keys.assign(action, key);
+    rebinding.0 = None;
+    info!("⌨️ {} → {}", action.label(), key_name(key));
+    settings.save();
+}
+
+/// Refresca los valores mostrados cuando cambia algún ajuste.
+pub fn refresh_settings_values(
+    settings: Res<GameSettings>,
+    audio: Res<AudioSettings>,
+    rebinding: Res<RebindingKey>,
+    mut values: Query<(&SettingsValue, &mut Text)>,
+    added: Query<(), Added<SettingsValue>>,
+) {
+    if !settings.is_changed() && !audio.is_changed() && !rebinding.is_changed() && added.is_empty() {
+        return;
+    }
+
+    let yes_no = |b: bool| if b { t!("ajustes-si") } else { t!("ajustes-no") };
+    let percent = |v: f32| format!("{:.0}%", v * 100.0);
+    let defaults = GameSettings::default();
+
+    for (value, mut text) in &mut values {
+        text.sections[0].value = match value.0 {
+            SettingsField::Master => percent(audio.master),
+            SettingsField::Music => percent(audio.music),
+            SettingsField::Effects => percent(audio.effects),
+            SettingsField::Muted => yes_no(audio.muted),
+            SettingsField::AimStep => format!("x{:.1}", settings.aim_step / defaults.aim_step),
+            SettingsField::ChargeStep => format!("x{:.2}", settings.charge_step / defaults.charge_step),
+            SettingsField::Trajectory => yes_no(settings.show_trajectory),
+            SettingsField::Colorblind => yes_no(settings.colorblind),
+            SettingsField::Language => settings.language.label().to_string(),
+            SettingsField::Key(action) if rebinding.0 == Some(action) => t!("ajustes-pulsa-tecla"),
+            SettingsField::Key(action) => key_name(settings.keys.get(action)),
+        };
+    }
+}
+
+pub fn animate_settings_buttons(
+    mut buttons: Query<(&Interaction, &mut BackgroundColor), (Changed<Interaction>, With<SettingsButton>)>,
+) {
+    for (interaction, mut color) in &mut buttons {
+        *color = match *interaction {
+            Interaction::Pressed => Color::rgb(0.20, 0.70, 0.20),
+            Interaction::Hovered => Color::rgb(0.50, 0.50, 0.90),
+            Interaction::None => Color::DARK_GRAY,
+        }
+        .into();
+    }
+}
+
+/// `Esc` en el partido: cierra los ajustes, o abre / cierra la pausa.
+#[allow(clippy::too_many_arguments)]
+pub fn toggle_pause_menu(
+    mut commands: Commands,
+    keys: Res<Input<KeyCode>>,
+    rebinding: Res<RebindingKey>,
+    panels: Query<Entity, With<SettingsPanel>>,
+    overlays: Query<Entity, With<PauseOverlay>>,
+    mut paused: ResMut<Paused>,
+    mode: Res<GameMode>,
+    asset_server: Res<AssetServer>,
+) {
+    // Con una tecla pendiente, `Esc` cancela la reasignación
+    if !keys.just_pressed(KeyCode::Escape) || rebinding.0.is_some() {
+        return;
+    }
+
+    if !panels.is_empty() {
+        for e in &panels {
+            commands.entity(e).despawn_recursive();
+        }
+    } else if !overlays.is_empty() {
+        for e in &overlays {
+            commands.entity(e).despawn_recursive();
+        }
+        paused.0 = false;
+    } else {
+        spawn_pause_overlay(&mut commands, &asset_server, mode.is_online());
+        paused.0 = true;
+        info!("⏸️ Pausa");
+    }
+}
+
+/// En hot-seat la pausa también congela la física.
+pub fn apply_pause_to_physics(paused: Res<Paused>, mode: Res<GameMode>, mut rapier: ResMut<RapierConfiguration>) {
+    if paused.is_changed() && !mode.is_online() {
+        rapier.physics_pipeline_active = !paused.0;
+    }
+}
+
+/// OnExit(InGame): un gol o el final cierran la pausa y los ajustes abiertos.
+pub fn close_pause_menu(
+    mut commands: Commands,
+    roots: Query<Entity, Or<(With<PauseOverlay>, With<SettingsPanel>)>>,
+    mut paused: ResMut<Paused>,
+    mut rebinding: ResMut<RebindingKey>,
+) {
+    for e in &roots {
+        commands.entity(e).despawn_recursive();
+    }
+    paused.0 = false;
+    rebinding.0 = None;
+}
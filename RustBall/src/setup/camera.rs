@@ -2,6 +2,10 @@
 #[derive(Component)]
 pub struct GameCamera;
 
+// Fondo de la cancha (se borra al terminar el partido)
+#[derive(Component)]
+pub struct FieldBackground;
+
 pub fn spawn_camera_and_background(commands: &mut Commands, asset_server: &Res<AssetServer>) {
     // Cámara 2D que renderiza todo (UI incluida)
     commands.spawn((
@@ -10,15 +14,18 @@ pub fn spawn_camera_and_background(commands: &mut Commands, asset_server: &Res<A
     ));
 
     // Fondo de cancha (con z = -20 para no tapar texto)
-    commands.spawn(SpriteBundle {
-        texture: asset_server.load("cancha.png"),
-        transform: Transform {
-            translation: Vec3::new(0.0, 0.0, -20.0),
-            scale: Vec3::splat(1.0),
+    commands.spawn((
+        SpriteBundle {
+            texture: asset_server.load("cancha.png"),
+            transform: Transform {
+                translation: Vec3::new(0.0, 0.0, -20.0),
+                scale: Vec3::splat(1.0),
+                ..default()
+            },
             ..default()
         },
-        ..default()
-    });
+        FieldBackground,
+    ));
 }
 /// Elimina todas las cámaras activas para evitar duplicadas y warnings.
 use bevy::prelude::*;
@@ -1,12 +1,21 @@
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 
+use rustball_shared::field::{FIELD_H, FIELD_W};
+
+// Las medidas viven en `rustball_shared` para que el bot del servidor
+// simule la misma cancha
+pub use rustball_shared::field::{GOAL_GAP, WALL_RESTITUTION, WALL_THICKNESS};
+
+/// Tamaño interior de la cancha (ancho × alto) en píxeles.
+pub const FIELD_BOUNDS: Vec2 = Vec2::new(FIELD_W, FIELD_H);
+
 pub fn spawn_walls(commands: &mut Commands) {
-    let wall_thickness = 10.0;
-    let bounds = Vec2::new(1100.0, 741.0);
+    let wall_thickness = WALL_THICKNESS;
+    let bounds = FIELD_BOUNDS;
     let half_w = bounds.x / 2.0;
     let half_h = bounds.y / 2.0;
-    let goal_gap = 200.0; // misma altura que los arcos
+    let goal_gap = GOAL_GAP;
     let side_wall_height = (bounds.y - goal_gap) / 2.0;
 
     // ⬅️ Pared izquierda arriba
@@ -21,7 +30,7 @@ pub fn spawn_walls(commands: &mut Commands) {
     })
         .insert(Collider::cuboid(wall_thickness / 2.0, side_wall_height / 2.0))
         .insert(RigidBody::Fixed)
-        .insert(Restitution::coefficient(1.0));
+        .insert(Restitution::coefficient(WALL_RESTITUTION));
 
     // ⬅️ Pared izquierda abajo
     commands.spawn(SpriteBundle {
@@ -35,7 +44,7 @@ pub fn spawn_walls(commands: &mut Commands) {
     })
         .insert(Collider::cuboid(wall_thickness / 2.0, side_wall_height / 2.0))
         .insert(RigidBody::Fixed)
-        .insert(Restitution::coefficient(1.0));
+        .insert(Restitution::coefficient(WALL_RESTITUTION));
 
     // ➡️ Pared derecha arriba
     commands.spawn(SpriteBundle {
@@ -49,7 +58,7 @@ pub fn spawn_walls(commands: &mut Commands) {
     })
         .insert(Collider::cuboid(wall_thickness / 2.0, side_wall_height / 2.0))
         .insert(RigidBody::Fixed)
-        .insert(Restitution::coefficient(1.0));
+        .insert(Restitution::coefficient(WALL_RESTITUTION));
 
     // ➡️ Pared derecha abajo
     commands.spawn(SpriteBundle {
@@ -63,7 +72,7 @@ pub fn spawn_walls(commands: &mut Commands) {
     })
         .insert(Collider::cuboid(wall_thickness / 2.0, side_wall_height / 2.0))
         .insert(RigidBody::Fixed)
-        .insert(Restitution::coefficient(1.0));
+        .insert(Restitution::coefficient(WALL_RESTITUTION));
 
     // ⬆️ Pared superior
     commands.spawn(SpriteBundle {
@@ -77,7 +86,7 @@ pub fn spawn_walls(commands: &mut Commands) {
     })
         .insert(Collider::cuboid(bounds.x / 2.0, wall_thickness / 2.0))
         .insert(RigidBody::Fixed)
-        .insert(Restitution::coefficient(1.0));
+        .insert(Restitution::coefficient(WALL_RESTITUTION));
 
     // ⬇️ Pared inferior
     commands.spawn(SpriteBundle {
@@ -91,7 +100,7 @@ pub fn spawn_walls(commands: &mut Commands) {
     })
         .insert(Collider::cuboid(bounds.x / 2.0, wall_thickness / 2.0))
         .insert(RigidBody::Fixed)
-        .insert(Restitution::coefficient(1.0));
+        .insert(Restitution::coefficient(WALL_RESTITUTION));
 
     // 🟣 Esquinas especiales (rotadas)
     let corner_size = 100.0;
@@ -48,6 +48,8 @@ pub fn spawn_goals(commands: &mut Commands, asset_server: &Res<AssetServer>) {
         Collider::cuboid(2.5, half_h),
         RigidBody::Fixed,
         Restitution::coefficient(6.5),
+        GoalFrame,
+        GoalPost,
     ));
 
     commands.spawn((
@@ -62,6 +64,7 @@ pub fn spawn_goals(commands: &mut Commands, asset_server: &Res<AssetServer>) {
         },
         Collider::cuboid(half_w, wall_thickness / 2.0),
         RigidBody::Fixed,
+        GoalFrame,
     ));
 
     commands.spawn((
@@ -76,6 +79,7 @@ pub fn spawn_goals(commands: &mut Commands, asset_server: &Res<AssetServer>) {
         },
         Collider::cuboid(half_w, wall_thickness / 2.0),
         RigidBody::Fixed,
+        GoalFrame,
     ));
 
     // ================= DERECHO =================
@@ -109,6 +113,8 @@ pub fn spawn_goals(commands: &mut Commands, asset_server: &Res<AssetServer>) {
         Collider::cuboid(wall_thickness / 2.0, half_h),
         RigidBody::Fixed,
         Restitution::coefficient(6.5),
+        GoalFrame,
+        GoalPost,
     ));
 
     commands.spawn((
@@ -123,6 +129,7 @@ pub fn spawn_goals(commands: &mut Commands, asset_server: &Res<AssetServer>) {
         },
         Collider::cuboid(half_w, wall_thickness / 2.0),
         RigidBody::Fixed,
+        GoalFrame,
     ));
 
     commands.spawn((
@@ -137,5 +144,6 @@ pub fn spawn_goals(commands: &mut Commands, asset_server: &Res<AssetServer>) {
         },
         Collider::cuboid(half_w, wall_thickness / 2.0),
         RigidBody::Fixed,
+        GoalFrame,
     ));
 }
@@ -27,3 +27,10 @@ pub fn setup(
     spawn_ball(&mut commands, &asset_server);
     spawn_goals(&mut commands, &asset_server);
 }
+
+/// Vuelve a colocar pelota y arcos al regresar a `InGame` tras un cambio de
+/// formación (`reset_for_formation` los despawnea; paredes y UI se conservan).
+pub fn respawn_ball_and_goals(mut commands: Commands, asset_server: Res<AssetServer>) {
+    spawn_ball(&mut commands, &asset_server);
+    spawn_goals(&mut commands, &asset_server);
+}
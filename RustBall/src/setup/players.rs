@@ -1,9 +1,9 @@
 use bevy::prelude::*;
-use bevy_rapier2d::prelude::*;
 
 use crate::components::*;
 use crate::resources::{PlayerFormations, BackendInfo, TurnState};
 use crate::formation::get_formation_positions;
+use crate::physics::disk_body;
 
 pub fn spawn_players_from_selection(
     commands: &mut Commands,
@@ -13,11 +13,6 @@ pub fn spawn_players_from_selection(
     turn_state: Res<TurnState>,
     existing_players: Query<Entity, With<PlayerDisk>>,
 ) {
-    let damping = Damping {
-        linear_damping: 2.0,
-        angular_damping: 2.0,
-    };
-
     // 🧹 Elimina jugadores anteriores
     for entity in &existing_players {
         commands.entity(entity).despawn_recursive();
@@ -38,22 +33,13 @@ pub fn spawn_players_from_selection(
                     transform: Transform::from_xyz(pos.x, pos.y, 10.0),
                     ..default()
                 },
-                RigidBody::Dynamic,
-                Collider::ball(35.0),
-                Restitution::coefficient(0.5),
-                ActiveEvents::COLLISION_EVENTS,
-                ExternalImpulse::default(),
-                ExternalForce::default(),
-                AdditionalMassProperties::Mass(1.0),
-                Velocity::zero(),
-                damping.clone(),
-                LockedAxes::ROTATION_LOCKED,
-                Sleeping::disabled(),
+                disk_body(Vec2::ZERO),
                 PlayerDisk {
                     player_id: 1,
                     id_usuario_real: backend_info.id_left,
                 },
-                                            OwnedBy(backend_info.id_left),
+                OwnedBy(backend_info.id_left),
+                DiskId::for_slot(true, i),
                 Name::new(format!("disk_left_{}", i)),
             ));
 
@@ -82,22 +68,13 @@ pub fn spawn_players_from_selection(
                     transform: Transform::from_xyz(pos.x, pos.y, 10.0),
                     ..default()
                 },
-                RigidBody::Dynamic,
-                Collider::ball(35.0),
-                Restitution::coefficient(0.5),
-                ActiveEvents::COLLISION_EVENTS,
-                ExternalImpulse::default(),
-                ExternalForce::default(),
-                AdditionalMassProperties::Mass(1.0),
-                Velocity::zero(),
-                damping.clone(),
-                LockedAxes::ROTATION_LOCKED,
-                Sleeping::disabled(),
+                disk_body(Vec2::ZERO),
                 PlayerDisk {
                     player_id: 2,
                     id_usuario_real: backend_info.id_right,
                 },
                 OwnedBy(backend_info.id_right),
+                DiskId::for_slot(false, i),
                 Name::new(format!("disk_right_{}", i)),
             ));
 
@@ -1,12 +1,13 @@
 use bevy::prelude::*;
 use crate::components::{TurnText, ScoreText, PowerBar};
+use crate::i18n::t;
 use crate::resources::PowerBarBackground;
 
 pub fn spawn_ui(commands: &mut Commands, asset_server: &Res<AssetServer>) {
     // Texto del turno
     commands.spawn((
         TextBundle::from_section(
-            "Turno: Jugador 1",
+            t!("hud-turno-inicial"),
             TextStyle {
                 font: asset_server.load("fonts/Linebeam.ttf"),
                 font_size: 40.0,
@@ -25,7 +26,7 @@ pub fn spawn_ui(commands: &mut Commands, asset_server: &Res<AssetServer>) {
     // Texto del puntaje
     commands.spawn((
         TextBundle::from_section(
-            "Jugador 1: 0 | Jugador 2: 0",
+            t!("hud-marcador-inicial"),
             TextStyle {
                 font: asset_server.load("fonts/Linebeam.ttf"),
                 font_size: 30.0,
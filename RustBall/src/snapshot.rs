@@ -2,14 +2,15 @@ use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 use crate::resources::BackendInfo;
+use crate::events::RandomEvent;
 use crate::{
-    components::PlayerDisk,
-    formation::spawn_formation_for,
+    components::{Ball, DiskId, PlayerDisk},
+    formation::{board_from_formations, spawn_formation_for},
     resources::{
-        AppState, CurrentPlayerId, PlayerNames, Scores, TurnState,
-        UltimoTurnoAplicado, WsInbox,
+        AppState, CurrentPlayerId, MatchRules, PlayerNames, RondaActual, Scores, TurnState,
+        UltimoTurnoAplicado,
     },
-    systems::{apply_board_snapshot, PendingTurn},
+    systems::{apply_board_snapshot, BoardEffects, Kickoff, PendingTurn, ShotReplay},
 };
 
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
@@ -28,11 +29,85 @@ pub struct PiezaPos {
     pub y: f32,
     #[serde(default)]
     pub id_usuario_real: i32,
+    /// Velocidad que le quedaba al cerrar el turno.
+    #[serde(default)]
+    pub vx: f32,
+    #[serde(default)]
+    pub vy: f32,
+    /// `PowerUpType` pendiente de usar (0 velocidad, 1 rebote, 2 doble turno).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub powerup: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct BallPos {
+    pub x: f32,
+    pub y: f32,
+    #[serde(default)]
+    pub vx: f32,
+    #[serde(default)]
+    pub vy: f32,
 }
 
+/// Power-up que está en el campo esperando a que lo toquen.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct PowerUpPos {
+    pub tipo: usize,
+    pub x: f32,
+    pub y: f32,
+}
+
+/// Zona de un evento aleatorio con la vida que le queda.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct ZonaSnapshot {
+    pub tipo: RandomEvent,
+    pub x: f32,
+    pub y: f32,
+    pub ancho: f32,
+    pub alto: f32,
+    pub turnos_restantes: u8,
+    pub ultimo_turno: u8,
+}
+
+/// Entrada del tiro que produjo una jugada, para que el rival lo vea
+/// repetido con rapier en vez de sólo ver el resultado.
 #[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ShotInput {
+    /// `DiskId` de la ficha disparada.
+    pub id_ficha: u32,
+    pub dir_x: f32,
+    pub dir_y: f32,
+    /// Potencia de la barra (0‥1); la velocidad es `potencia * SHOT_MAX_SPEED`.
+    pub potencia: f32,
+    /// `PowerUpType` que tenía pendientes la ficha al disparar.
+    #[serde(default)]
+    pub powerups: Vec<usize>,
+    /// Tablero justo antes del tiro.
+    pub tablero_previo: Box<BoardSnapshot>,
+}
+
+/// Tablero completo guardado en `Turno.jugada`.  Los campos opcionales
+/// faltan en jugadas antiguas y se toman como “nada activo”.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct BoardSnapshot {
     pub piezas: Vec<PiezaPos>,
+    /// Jugadas antiguas no guardaban la pelota.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pelota: Option<BallPos>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub powerup: Option<PowerUpPos>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub zonas: Vec<ZonaSnapshot>,
+    /// Marcador (izquierda, derecha) al cerrar el turno; el del backend manda.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub marcador: Option<(u32, u32)>,
+    /// Tiro que llevó a este tablero (falta en jugadas antiguas).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tiro: Option<ShotInput>,
+    /// Lo que pasó durante el tiro (sólo al enviarlo; el backend lo
+    /// guarda aparte y no vuelve en los snapshots).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub eventos: Vec<crate::systems::TurnEvent>,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -40,6 +115,15 @@ pub struct FormacionData {
     pub id_usuario: i32,
     pub formacion: String,
     pub turno_inicio: i32,
+    /// Posiciones (lado derecho) si la formación es personalizada.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub posiciones: Option<Vec<FormationPos>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct FormationPos {
+    pub x: f32,
+    pub y: f32,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -52,50 +136,72 @@ pub struct TurnoData {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SnapshotFromServer {
     pub estado: String,
+    /// Ronda de saque; `formaciones` y `turnos` son sólo los de esta ronda.
+    #[serde(default = "primera_ronda")]
+    pub ronda: i32,
     pub marcador: (u32, u32),
     pub formaciones: Vec<FormacionData>,
     pub turnos: Vec<TurnoData>,
     pub proximo_turno: i32,
     pub nombre_jugador_1: String,
     pub nombre_jugador_2: String,
+    /// Partida clasificatoria: la decide el servidor al crearla.
+    #[serde(default)]
+    pub clasificatoria: bool,
 }
 
-thread_local! {
-    static APP_STATE: std::cell::RefCell<Option<(SnapshotFromServer, i32)>> =
-        const { std::cell::RefCell::new(None) };
+fn primera_ronda() -> i32 {
+    1
 }
-static LAST_TURNO: std::sync::Mutex<i32> = std::sync::Mutex::new(0);
+
+/// `(ronda, ya se juega, último numero_turno)`: un snapshot es más nuevo
+/// que otro si su versión es mayor.
+pub type SnapshotVersion = (i32, bool, i32);
+
+impl SnapshotFromServer {
+    pub fn jugando(&self) -> bool {
+        self.estado == "playing" && self.proximo_turno != 0
+    }
+
+    pub fn version(&self) -> SnapshotVersion {
+        let ultimo = self.turnos.last().map(|t| t.numero_turno).unwrap_or(0);
+        (self.ronda, self.jugando(), ultimo)
+    }
+}
+
+// Compartido entre hilos: en escritorio `set_game_state` corre en el runtime de tokio
+static APP_STATE: std::sync::Mutex<Option<(SnapshotFromServer, i32)>> = std::sync::Mutex::new(None);
+static LAST_VERSION: std::sync::Mutex<SnapshotVersion> = std::sync::Mutex::new((0, false, 0));
 
 #[wasm_bindgen]
 pub fn set_game_state(json_str: &str, uid: i32) {
-    web_sys::console::log_1(&"🧠 set_game_state() fue llamado".into());
+    info!("🧠 set_game_state() fue llamado");
 
     match serde_json::from_str::<SnapshotFromServer>(json_str) {
         Ok(snap) => {
-            web_sys::console::log_1(&"✅ SnapshotFromServer parseado con éxito".into());
+            info!("✅ SnapshotFromServer parseado con éxito");
 
-            if snap.estado != "playing" || snap.proximo_turno == 0 {
+            let mut last = LAST_VERSION.lock().unwrap();
+
+            // Un snapshot en espera sólo interesa si abre una ronda (hubo gol)
+            if !snap.jugando() && snap.ronda <= last.0 {
                 warn!("⏳ Partida aún no está en estado 'playing' o turno inválido. Ignorando snapshot.");
                 return;
             }
 
-            let mut last = LAST_TURNO.lock().unwrap();
-
-            info!(
-                "📥 Recibido snapshot turno {} (último aplicado {})",
-                snap.proximo_turno, *last
-            );
+            let version = snap.version();
+            info!("📥 Recibido snapshot {:?} (último aplicado {:?})", version, *last);
 
-            if snap.proximo_turno > *last {
-                *last = snap.proximo_turno;
-                APP_STATE.with(|c| *c.borrow_mut() = Some((snap, uid)));
+            if version > *last {
+                *last = version;
+                *APP_STATE.lock().unwrap() = Some((snap, uid));
                 info!("✅ Snapshot en cola para ser aplicado");
             } else {
                 warn!("📛 Snapshot descartado (antiguo)");
             }
         }
         Err(e) => {
-            web_sys::console::error_1(&format!("❌ Error al parsear snapshot JSON: {:?}", e).into());
+            error!("❌ Error al parsear snapshot JSON: {:?}", e);
         }
     }
 }
@@ -106,15 +212,19 @@ pub fn snapshot_apply_system(
     mut scores: ResMut<Scores>,
     mut ts: ResMut<TurnState>,
     mut ultimo_turno: ResMut<UltimoTurnoAplicado>,
+    mut ronda: ResMut<RondaActual>,
     mut current_player_id: ResMut<CurrentPlayerId>,
-    q_disks: Query<Entity, With<PlayerDisk>>,
+    q_disks: Query<(Entity, &DiskId, &Transform), With<PlayerDisk>>,
+    q_ball: Query<(Entity, &Transform), (With<Ball>, Without<PlayerDisk>)>,
     state: Res<State<AppState>>,
     mut next_state: ResMut<NextState<AppState>>,
     asset_server: Res<AssetServer>,
     backend_info: Res<BackendInfo>,
     player_names: Option<Res<PlayerNames>>,
+    pending: Option<ResMut<PendingTurn>>,
+    mut effects: BoardEffects,
 ) {
-    let Some((snap, my_uid)) = APP_STATE.with(|c| c.borrow_mut().take()) else { return; };
+    let Some((snap, my_uid)) = APP_STATE.lock().unwrap().take() else { return; };
 
     info!("🔄 Aplicando snapshot – turno {}", snap.proximo_turno);
 
@@ -122,40 +232,91 @@ pub fn snapshot_apply_system(
         left_name: snap.nombre_jugador_1.clone(),
         right_name: snap.nombre_jugador_2.clone(),
     });
+    // 🏆 Las partidas clasificatorias desactivan la ayuda de trayectoria
+    commands.insert_resource(if snap.clasificatoria { MatchRules::ranked() } else { MatchRules::default() });
 
-    if snap.proximo_turno == ultimo_turno.0 {
+    if snap.version() <= ultimo_turno.0 {
         return;
     }
-    ultimo_turno.0 = snap.proximo_turno;
+    ultimo_turno.0 = snap.version();
+
+    // ⚽ Ronda nueva sin formaciones todavía: hubo gol (quizá detectado
+    //    por el rival).  Marcador al día y a elegir formación.
+    if !snap.jugando() {
+        info!("⚽ Ronda {} — a elegir formación", snap.ronda);
+        ronda.0 = snap.ronda;
+        *scores = Scores { left: snap.marcador.0, right: snap.marcador.1 };
+        commands.remove_resource::<ShotReplay>();
+        commands.insert_resource(MyTurn(false));
+        ts.in_motion = false;
+        ts.selected_entity = None;
+        if *state == AppState::InGame {
+            next_state.set(AppState::GoalScored);
+        }
+        return;
+    }
+    ronda.0 = snap.ronda;
 
     if let Some(last) = snap.turnos.last() {
-        if let Ok(board_raw) = serde_json::from_value::<BoardSnapshot>(last.jugada.clone()) {
-            let mapped = BoardSnapshot {
-                piezas: board_raw.piezas.into_iter().map(|p| PiezaPos {
-                    id: p.id,
-                    x: p.x,
-                    y: p.y,
-                    id_usuario_real: p.id_usuario_real,
-                }).collect(),
-            };
+        if let Ok(mut board) = serde_json::from_value::<BoardSnapshot>(last.jugada.clone()) {
+            match board.tiro.take() {
+                // Tiro del rival con el tablero ya cargado: se repite antes de conciliar
+                Some(mut tiro) if last.id_usuario != my_uid && !q_disks.is_empty() => {
+                    let previo = std::mem::take(&mut *tiro.tablero_previo);
+                    effects.restore(&previo, &mut commands, &asset_server);
+                    apply_board_snapshot(
+                        previo,
+                        &mut commands,
+                        backend_info.clone(),
+                        &q_disks,
+                        &q_ball,
+                        0, // nadie controla fichas durante la repetición
+                        player_names.map(|r| (*r).clone()),
+                        &asset_server,
+                    );
+                    commands.insert_resource(ShotReplay::new(tiro, board, snap.proximo_turno));
+                }
+                _ => {
+                    effects.restore(&board, &mut commands, &asset_server);
+                    apply_board_snapshot(
+                        board,
+                        &mut commands,
+                        backend_info.clone(),
+                        &q_disks,
+                        &q_ball,
+                        snap.proximo_turno,
+                        player_names.map(|r| (*r).clone()),
+                        &asset_server,
+                    );
+                }
+            }
 
+            commands.insert_resource(NextTurn(last.numero_turno + 1));
+            commands.remove_resource::<Kickoff>();
+        }
+    } else if snap.formaciones.len() >= 2 {
+        // Saque (inicio o tras un gol): sin power-up en el campo ni zonas
+        effects.restore(&BoardSnapshot::default(), &mut commands, &asset_server);
+        if q_disks.is_empty() {
+            for f in &snap.formaciones {
+                spawn_formation_for(f, &mut commands, &asset_server, &backend_info);
+            }
+        } else {
+            // Ya hay fichas (p. ej. tras un gol): se recolocan en vez de duplicarlas
             apply_board_snapshot(
-                mapped,
+                board_from_formations(&snap.formaciones, &backend_info),
                 &mut commands,
                 backend_info.clone(),
-                q_disks,
+                &q_disks,
+                &q_ball,
                 snap.proximo_turno,
                 player_names.map(|r| (*r).clone()),
                 &asset_server,
             );
-
-            commands.insert_resource(NextTurn(last.numero_turno + 1));
-        }
-    } else if snap.formaciones.len() >= 2 {
-        for f in &snap.formaciones {
-            spawn_formation_for(f, &mut commands, &asset_server, &backend_info);
         }
         commands.insert_resource(NextTurn(1));
+        commands.insert_resource(Kickoff { kicker: snap.proximo_turno });
+        info!("🏁 Saque de la ronda {}: {}", snap.ronda, snap.proximo_turno);
     }
 
     *scores = Scores { left: snap.marcador.0, right: snap.marcador.1 };
@@ -171,11 +332,11 @@ pub fn snapshot_apply_system(
     info!("🕑 MyTurn = {}", is_my_turn);
 
     if is_my_turn {
-        if let Some(mut pending) = commands.remove_resource::<PendingTurn>() {
-            if let Some(payload) = pending.0.take() {
-                info!("📤 Reenviando jugada pendiente tras aplicar snapshot");
-                commands.insert_resource(PendingTurn(Some(payload)));
-            }
+        // `maybe_send_pending_turn` la manda en cuanto vea el recurso nuevo
+        if let Some(payload) = pending.and_then(|mut p| p.0.take()) {
+            info!("📤 Reenviando jugada pendiente tras aplicar snapshot");
+            commands.remove_resource::<PendingTurn>();
+            commands.insert_resource(PendingTurn(Some(payload)));
         }
     }
 
@@ -184,20 +345,19 @@ pub fn snapshot_apply_system(
     }
 }
 
+#[cfg(target_arch = "wasm32")]
+use crate::resources::WsInbox;
+
 #[cfg(target_arch = "wasm32")]
 pub fn fetch_snapshot_on_ws_message(mut inbox: ResMut<WsInbox>) {
     inbox.0.clear();
 }
 
-#[cfg(target_arch = "wasm32")]
 pub fn poll_snapshot_when_forming(
     time: Res<Time>,
     mut timer: ResMut<crate::resources::SnapshotPollTimer>,
     backend: Option<Res<BackendInfo>>,
 ) {
-    use gloo_net::http::Request;
-    use wasm_bindgen_futures::spawn_local;
-
     if !timer.0.tick(time.delta()).just_finished() {
         return;
     }
@@ -206,12 +366,11 @@ pub fn poll_snapshot_when_forming(
         let pid = b.partida_id;
         let uid = b.my_uid;
 
-        spawn_local(async move {
-            if let Ok(resp) = Request::get(&format!("/api/snapshot/{pid}")).send().await {
-                if let Ok(snap) = resp.json::<SnapshotFromServer>().await {
-                    if snap.proximo_turno != 0 {
-                        crate::snapshot::set_game_state(&serde_json::to_string(&snap).unwrap(), uid);
-                    }
+        crate::net::get(&format!("/snapshot/{pid}"), move |res| {
+            let Ok(resp) = res else { return };
+            if let Ok(snap) = serde_json::from_str::<SnapshotFromServer>(&resp.body) {
+                if snap.proximo_turno != 0 {
+                    set_game_state(&serde_json::to_string(&snap).unwrap(), uid);
                 }
             }
         });
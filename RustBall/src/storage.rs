@@ -0,0 +1,83 @@
+//! src/storage.rs
+//! --------------------------------------------------------------
+//! Preferencias locales del jugador (audio, ajustes…).
+//!
+//!   • WASM: `window.localStorage`, igual que las claves `rb_*` que
+//!     deja el lobby.
+//!   • Escritorio: un JSON plano en `~/.rustball/preferencias.json`
+//!     (o junto al ejecutable si no hay `HOME`).
+//! --------------------------------------------------------------
+
+/// Lee la clave `key`; `None` si no existe o no hay almacenamiento.
+pub fn get(key: &str) -> Option<String> {
+    imp::get(key)
+}
+
+/// Guarda `value` en la clave `key` (los errores sólo se registran).
+pub fn set(key: &str, value: &str) {
+    imp::set(key, value)
+}
+
+#[cfg(target_arch = "wasm32")]
+mod imp {
+    use web_sys::window;
+
+    fn storage() -> Option<web_sys::Storage> {
+        window().and_then(|w| w.local_storage().ok().flatten())
+    }
+
+    pub fn get(key: &str) -> Option<String> {
+        storage()?.get_item(key).ok().flatten()
+    }
+
+    pub fn set(key: &str, value: &str) {
+        match storage() {
+            Some(s) => {
+                if s.set_item(key, value).is_err() {
+                    bevy::log::warn!("⚠️ No se pudo guardar '{key}' en localStorage");
+                }
+            }
+            None => bevy::log::warn!("⚠️ localStorage no disponible"),
+        }
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod imp {
+    use std::path::PathBuf;
+
+    use serde_json::{Map, Value};
+
+    fn path() -> PathBuf {
+        let home = std::env::var_os("HOME").or_else(|| std::env::var_os("USERPROFILE"));
+        match home {
+            Some(h) => PathBuf::from(h).join(".rustball").join("preferencias.json"),
+            None => PathBuf::from("preferencias.json"),
+        }
+    }
+
+    fn load() -> Map<String, Value> {
+        std::fs::read_to_string(path())
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn get(key: &str) -> Option<String> {
+        load().get(key).and_then(Value::as_str).map(str::to_owned)
+    }
+
+    pub fn set(key: &str, value: &str) {
+        let mut prefs = load();
+        prefs.insert(key.to_owned(), Value::String(value.to_owned()));
+
+        let path = path();
+        let result = path
+            .parent()
+            .map_or(Ok(()), std::fs::create_dir_all)
+            .and_then(|_| std::fs::write(&path, serde_json::to_string_pretty(&prefs).unwrap_or_default()));
+        if let Err(e) = result {
+            bevy::log::warn!("⚠️ No se pudo guardar '{key}' en {}: {e}", path.display());
+        }
+    }
+}
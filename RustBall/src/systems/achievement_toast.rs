@@ -0,0 +1,84 @@
+//! src/systems/achievement_toast.rs
+//! --------------------------------------------------------------
+//! Aviso de logro desbloqueado durante la partida (sólo online).
+//!
+//!   • El backend evalúa los logros y manda por el WS de la partida
+//!     `{"tipo":"logro","contenido":{"logro","detalle","id_usuario"}}`;
+//!     `process_ws_messages` encola los del jugador local.
+//!   • Se muestran de a uno, abajo a la derecha, durante `TOAST_SECS`.
+//! --------------------------------------------------------------
+
+use std::collections::VecDeque;
+
+use bevy::prelude::*;
+use serde::Deserialize;
+
+use crate::i18n::t;
+
+const TOAST_SECS: f32 = 4.0;
+
+/// Logro tal como llega en `contenido`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct UnlockedAchievement {
+    pub logro: String,
+    #[serde(default)]
+    pub detalle: String,
+    pub id_usuario: i32,
+}
+
+#[derive(Resource, Default, Debug)]
+pub struct AchievementToasts(pub VecDeque<UnlockedAchievement>);
+
+#[derive(Component)]
+pub struct AchievementToast(Timer);
+
+fn achievement_name(a: &UnlockedAchievement) -> String {
+    match a.logro.as_str() {
+        "primera_victoria" => t!("logro-primera-victoria"),
+        "hat_trick" => t!("logro-hat-trick"),
+        "porteria_a_cero" => t!("logro-porteria-a-cero"),
+        "gol_de_poste" => t!("logro-gol-de-poste"),
+        "ganar_con" => t!("logro-ganar-con", formacion = a.detalle.clone()),
+        "coleccionista" => t!("logro-coleccionista"),
+        otro => otro.to_owned(),
+    }
+}
+
+pub fn show_achievement_toasts(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut queue: ResMut<AchievementToasts>,
+    asset_server: Res<AssetServer>,
+    mut toasts: Query<(Entity, &mut AchievementToast)>,
+) {
+    // El que está a la vista: se va al cumplir su tiempo
+    if let Ok((entity, mut toast)) = toasts.get_single_mut() {
+        if toast.0.tick(time.delta()).finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    }
+
+    let Some(achievement) = queue.0.pop_front() else { return };
+    info!("🏅 Logro desbloqueado: {achievement:?}");
+
+    commands.spawn((
+        TextBundle::from_section(
+            format!("🏅 {}\n{}", t!("logro-desbloqueado"), achievement_name(&achievement)),
+            TextStyle {
+                font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                font_size: 26.0,
+                color: Color::GOLD,
+            },
+        )
+            .with_style(Style {
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(40.0),
+                right: Val::Px(40.0),
+                padding: UiRect::all(Val::Px(12.0)),
+                ..default()
+            })
+            .with_background_color(Color::rgba(0.0, 0.0, 0.0, 0.7)),
+        AchievementToast(Timer::from_seconds(TOAST_SECS, TimerMode::Once)),
+    ));
+}
@@ -1,110 +1,196 @@
 //! src/systems/apply_snapshot.rs
 //! --------------------------------------------------------------
-//! Reconstruye todas las fichas a partir del snapshot que envía
-//! el backend.  A partir de ahora cada `PlayerDisk` guarda
-//! `id_usuario_real` (el UID de MySQL) y el control del turno se
-//! concede usando ese UID, de modo que cada cliente sólo pueda
-//! mover sus propias fichas.
+//! Lleva el tablero al estado que envía el backend.  Cada
+//! `PlayerDisk` guarda `id_usuario_real` (el UID de MySQL) y el
+//! control del turno se concede usando ese UID, de modo que cada
+//! cliente sólo pueda mover sus propias fichas.
 //!
-//!   • Se eliminó por completo el mapeo `0/1/2 → UID`.
+//!   • Las fichas se identifican por `DiskId`: las que ya existen se
+//!     reutilizan (conservan etiquetas y power-ups) y se desplazan
+//!     con un `BoardTween` hasta su nueva posición; sólo se spawnean
+//!     las que faltan y se eliminan las que ya no vienen.
+//!   • La pelota también se interpola si el snapshot la incluye.
+//!   • Al terminar la animación cada cuerpo recupera la velocidad que
+//!     traía, y cada ficha el power-up que tenía pendiente.
 //!   • Las texturas se cargan vía `AssetServer`, por lo que ya no
 //!     aparecen “cuadrados blancos” al refrescar la página.
 //! --------------------------------------------------------------
 
+use std::collections::HashMap;
+
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 
 use crate::{
-    components::{OwnedBy, PlayerDisk, TurnControlled},
+    components::{Ball, BoardTween, DiskId, OwnedBy, PlayerDisk, TurnControlled},
+    physics::disk_body,
+    powerup::grant_power_up,
+    systems::ShotReplay,
     resources::{BackendInfo, PlayerNames},
     snapshot::BoardSnapshot,
 };
 
-/// Crea (o recrea) todas las fichas que vienen dentro de `board`.
+/// Duración de la animación de una ficha hacia su nueva posición.
+pub const TWEEN_SECS: f32 = 0.6;
+/// Por debajo de esta distancia no vale la pena animar.
+const TWEEN_MIN_DISTANCE: f32 = 1.0;
+
+/// Lleva las fichas (y la pelota, si viene) a las posiciones de `board`.
 ///
-/// * **existing_disks** – todas las entidades actuales con `PlayerDisk` serán
-///   despawneadas antes de spawnear las nuevas.
-/// * **current_turn_id** – UID real del jugador al que le toca mover; sólo la
+/// * **existing_disks** – fichas actuales; se reutilizan por `DiskId` y las
+///   que no aparecen en `board` se despawnean.
+/// * **current_turn_id** – UID real del jugador al que le toca mover; sólo la
 ///   primera ficha de ese jugador recibe `TurnControlled`.
+#[allow(clippy::too_many_arguments)]
 pub fn apply_board_snapshot(
     board: BoardSnapshot,
     commands: &mut Commands,
     backend_info: BackendInfo,
-    existing_disks: Query<Entity, With<PlayerDisk>>,
+    existing_disks: &Query<(Entity, &DiskId, &Transform), With<PlayerDisk>>,
+    balls: &Query<(Entity, &Transform), (With<Ball>, Without<PlayerDisk>)>,
     current_turn_id: i32,
     names: Option<PlayerNames>,
     asset_server: &Res<AssetServer>,
 ) {
-    /* ─── 1. Limpiar fichas anteriores ─────────────────────────────── */
-    for entity in existing_disks.iter() {
-        commands.entity(entity).despawn_recursive();
-    }
+    /* ─── 1. Índice de fichas actuales ─────────────────────────────── */
+    let mut current: HashMap<DiskId, (Entity, Vec2)> = existing_disks
+        .iter()
+        .map(|(e, id, tf)| (*id, (e, tf.translation.truncate())))
+        .collect();
 
-    /* ─── 2. Recursos comunes (texturas + damping) ─────────────────── */
+    /* ─── 2. Texturas ───────────────────────────────────────────────── */
     let tex_left  = asset_server.load("circulobarca.png");
     let tex_right = asset_server.load("circuloparis.png");
 
-    let damping = Damping {
-        linear_damping: 2.0,
-        angular_damping: 2.0,
-    };
-
-    /* ─── 3. Spawnear cada pieza ────────────────────────────────────── */
+    /* ─── 3. Mover o spawnear cada pieza ────────────────────────────── */
     let my_uid          = backend_info.my_uid;
     let mut control_set = false; // sólo una ficha recibe TurnControlled
 
     for pieza in board.piezas {
         let uid_real = pieza.id_usuario_real;
+        let disk_id  = DiskId(pieza.id);
+        let target   = Vec2::new(pieza.x, pieza.y);
+        let velocity = Vec2::new(pieza.vx, pieza.vy);
 
-        /* ¿Es jugador izquierdo o derecho? */
-        let is_left   = uid_real == backend_info.id_left;
-        let texture   = if is_left { tex_left.clone() } else { tex_right.clone() };
+        let entity = if let Some((entity, from)) = current.remove(&disk_id) {
+            /* — Ficha conocida: se reutiliza y se anima — */
+            commands.entity(entity).remove::<TurnControlled>();
+            start_tween(commands, entity, from, target, velocity);
+            entity
+        } else {
+            /* ¿Es jugador izquierdo o derecho? */
+            let is_left   = uid_real == backend_info.id_left;
+            let texture   = if is_left { tex_left.clone() } else { tex_right.clone() };
 
-        let name_log = match &names {
-            Some(n) if is_left => &n.left_name,
-            Some(n)            => &n.right_name,
-            None               => "desconocido",
-        };
+            let name_log = match &names {
+                Some(n) if is_left => &n.left_name,
+                Some(n)            => &n.right_name,
+                None               => "desconocido",
+            };
 
-        info!("🧩 Spawn ficha UID {uid_real} – jugador {name_log}");
+            info!("🧩 Spawn ficha {disk_id:?} UID {uid_real} – jugador {name_log}");
 
-        /* — Sprite + cuerpo físico — */
-        let mut ecmd = commands.spawn((
-            SpriteBundle {
-                texture,
-                transform: Transform::from_xyz(pieza.x, pieza.y, 10.0),
-                sprite: Sprite {
-                    color: Color::WHITE,
-                    custom_size: Some(Vec2::splat(70.0)),
+            /* — Sprite + cuerpo físico — */
+            commands.spawn((
+                SpriteBundle {
+                    texture,
+                    transform: Transform::from_xyz(pieza.x, pieza.y, 10.0),
+                    sprite: Sprite {
+                        color: Color::WHITE,
+                        custom_size: Some(Vec2::splat(70.0)),
+                        ..default()
+                    },
                     ..default()
                 },
-                ..default()
-            },
-            RigidBody::Dynamic,
-            Collider::ball(35.0),
-            Restitution::coefficient(0.5),
-            ActiveEvents::COLLISION_EVENTS,
-            ExternalImpulse::default(),
-            ExternalForce::default(),
-            AdditionalMassProperties::Mass(1.0),
-            Velocity::zero(),
-            damping.clone(),
-            LockedAxes::ROTATION_LOCKED,
-            Sleeping::disabled(),
-            /* El `player_id` local (1 izquierda, 2 derecha) sólo se usa
-               para colorear la UI; el UID real se guarda aparte        */
-            PlayerDisk {
-                player_id: if is_left { 1 } else { 2 },
-                id_usuario_real: uid_real,
-            },
-            OwnedBy(uid_real),
-            Name::new(format!("disk_user_{uid_real}")),
-        ));
+                disk_body(velocity),
+                /* El `player_id` local (1 izquierda, 2 derecha) sólo se usa
+                   para colorear la UI; el UID real se guarda aparte        */
+                PlayerDisk {
+                    player_id: if is_left { 1 } else { 2 },
+                    id_usuario_real: uid_real,
+                },
+                OwnedBy(uid_real),
+                disk_id,
+                Name::new(format!("disk_user_{uid_real}_{}", disk_id.0)),
+            )).id()
+        };
+
+        grant_power_up(commands, entity, pieza.powerup);
 
         /* — Dar control a la primera ficha de mi turno — */
         if uid_real == my_uid && uid_real == current_turn_id && !control_set {
-            ecmd.insert(TurnControlled);
+            commands.entity(entity).insert(TurnControlled);
             control_set = true;
         }
     }
+
+    /* ─── 4. Fichas que ya no están en el tablero ──────────────────── */
+    for (disk_id, (entity, _)) in current {
+        info!("🗑️ Ficha {disk_id:?} no viene en el snapshot; se elimina");
+        commands.entity(entity).despawn_recursive();
+    }
+
+    /* ─── 5. Pelota ─────────────────────────────────────────────────── */
+    if let Some(pelota) = board.pelota {
+        for (entity, tf) in balls.iter() {
+            start_tween(
+                commands,
+                entity,
+                tf.translation.truncate(),
+                Vec2::new(pelota.x, pelota.y),
+                Vec2::new(pelota.vx, pelota.vy),
+            );
+        }
+    }
+}
+
+/// Congela el cuerpo y lo anima de `from` a `to`; al llegar se suelta
+/// con `velocity`.
+fn start_tween(commands: &mut Commands, entity: Entity, from: Vec2, to: Vec2, velocity: Vec2) {
+    if from.distance(to) < TWEEN_MIN_DISTANCE {
+        commands.entity(entity).insert(Velocity::linear(velocity));
+        return;
+    }
+
+    commands.entity(entity).insert((
+        RigidBody::KinematicPositionBased,
+        Velocity::zero(),
+        BoardTween {
+            from,
+            to,
+            timer: Timer::from_seconds(TWEEN_SECS, TimerMode::Once),
+            velocity,
+        },
+    ));
+}
+
+/// Avanza los `BoardTween` con *ease-in-out* y devuelve el cuerpo a
+/// `Dynamic` al llegar.
+pub fn animate_board_tweens(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut tweens: Query<(Entity, &mut BoardTween, &mut Transform)>,
+) {
+    for (entity, mut tween, mut tf) in &mut tweens {
+        tween.timer.tick(time.delta());
+
+        let t = tween.timer.percent();
+        let eased = t * t * (3.0 - 2.0 * t);
+        let pos = tween.from.lerp(tween.to, eased);
+        tf.translation.x = pos.x;
+        tf.translation.y = pos.y;
+
+        if tween.timer.finished() {
+            commands
+                .entity(entity)
+                .remove::<BoardTween>()
+                .insert((RigidBody::Dynamic, Velocity::linear(tween.velocity)));
+        }
+    }
+}
+
+/// Condición: no hay ninguna ficha ni la pelota animándose, ni se está
+/// repitiendo el tiro del rival.
+pub fn board_settled(tweens: Query<(), With<BoardTween>>, replay: Option<Res<ShotReplay>>) -> bool {
+    tweens.is_empty() && replay.is_none()
 }
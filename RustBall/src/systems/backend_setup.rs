@@ -1,9 +1,14 @@
 use bevy::prelude::*;
-use crate::resources::BackendInfo;
+use crate::i18n::t;
+use crate::resources::{BackendInfo, GameMode, MatchRules, PlayerNames};
 
 #[cfg(target_arch = "wasm32")]
 use web_sys::window;
 
+use crate::ai::difficulty_label;
+#[cfg(target_arch = "wasm32")]
+use crate::ai::BotDifficulty;
+
 #[cfg(target_arch = "wasm32")]
 use crate::INIT;
 
@@ -16,6 +21,21 @@ pub fn insert_backend_info(mut commands: Commands) {
             .unwrap_or_default()
     };
 
+    // 🪑 Modo local (hot-seat): no hace falta partida ni usuario
+    match ls_val("rb_modo").as_str() {
+        "local" => {
+            insert_local_mode(&mut commands, GameMode::HotSeat);
+            return;
+        }
+        // 🤖 Contra la máquina: igual que local, con la IA en el lado derecho
+        "bot" => {
+            let difficulty = BotDifficulty::parse(&ls_val("rb_bot")).unwrap_or_default();
+            insert_local_mode(&mut commands, GameMode::VsBot(difficulty));
+            return;
+        }
+        _ => {}
+    }
+
     let pid_str = ls_val("rb_pid");
     let uid_str = ls_val("rb_uid");
     let id_left_str = ls_val("rb_id_left");
@@ -36,7 +56,46 @@ pub fn insert_backend_info(mut commands: Commands) {
 
     let info = BackendInfo::new_with_snapshot(pid, uid_me, id_left, id_right, None); // ✅ con snapshot opcional
     commands.insert_resource(info.clone());
+    commands.insert_resource(GameMode::Online);
     info!("✅ BackendInfo registrado: {:?}", info);
+    // Las reglas (`MatchRules`) llegan con el snapshot
+}
+
+/// Escritorio: lo decide `native::run` a partir de la línea de comandos.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn insert_backend_info(mut commands: Commands) {
+    use crate::native::{launch, NativeLaunch};
+
+    match launch() {
+        Some(NativeLaunch::Online(info)) => {
+            commands.insert_resource(info.clone());
+            commands.insert_resource(GameMode::Online);
+            info!("✅ BackendInfo registrado desde la CLI: {:?}", info);
+
+            // Igual que bootstrap.js: snapshot inicial si la partida ya arrancó
+            crate::systems::poll_turn::fetch_and_apply_snapshot(info.partida_id, info.my_uid);
+        }
+        Some(NativeLaunch::Local(mode)) => insert_local_mode(&mut commands, *mode),
+        None => insert_local_mode(&mut commands, GameMode::HotSeat),
+    }
+}
+
+/// Inserta los recursos de una partida sin backend (hot-seat o contra la IA).
+fn insert_local_mode(commands: &mut Commands, mode: GameMode) {
+    let info = BackendInfo::local();
+    let names = match mode {
+        GameMode::VsBot(difficulty) => PlayerNames {
+            left_name: t!("jugador-tu"),
+            right_name: t!("jugador-bot", dificultad = difficulty_label(difficulty)),
+        },
+        _ => PlayerNames::default(),
+    };
+
+    commands.insert_resource(info.clone());
+    commands.insert_resource(mode);
+    commands.insert_resource(MatchRules::default());
+    commands.insert_resource(names);
+    info!("🪑 Modo local activado ({:?}): {:?}", mode, info);
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -0,0 +1,161 @@
+//! src/systems/board_state.rs
+//! --------------------------------------------------------------
+//! Foto completa del tablero que viaja en `Turno.jugada`.
+//!
+//!   • `BoardReader::capture` arma el `BoardSnapshot` al cerrar el
+//!     turno: fichas (con `DiskId`, velocidad y power-up pendiente),
+//!     pelota, power-up del campo, zonas vivas y marcador.
+//!   • `BoardEffects::restore` hace el camino inverso para lo que no
+//!     son fichas, así que recargar la página o reconectar reproduce
+//!     el tablero exacto (las fichas las resuelve
+//!     `apply_board_snapshot`).
+//! --------------------------------------------------------------
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use crate::{
+    components::{Ball, DiskId, PlayerDisk},
+    events::RandomEvent,
+    powerup::{spawn_power_up_at, PowerUp, PowerUpControl, PowerUpType},
+    resources::{EventControl, Scores},
+    snapshot::{BallPos, BoardSnapshot, PiezaPos, PowerUpPos, ZonaSnapshot},
+    zone::{spawn_zone, BouncePad, SlowZone, ZoneLifetime},
+};
+
+/// Lectura del mundo necesaria para `capture`.
+#[derive(SystemParam)]
+pub struct BoardReader<'w, 's> {
+    disks: Query<
+        'w,
+        's,
+        (&'static DiskId, &'static Transform, &'static PlayerDisk, Option<&'static Velocity>, Option<&'static PowerUpType>),
+    >,
+    balls: Query<'w, 's, (&'static Transform, Option<&'static Velocity>), (With<Ball>, Without<PlayerDisk>)>,
+    field_powerups: Query<'w, 's, (&'static Transform, &'static PowerUpType), (With<PowerUp>, Without<PlayerDisk>)>,
+    zones: Query<
+        'w,
+        's,
+        (&'static Transform, &'static Sprite, &'static ZoneLifetime, Option<&'static SlowZone>, Option<&'static BouncePad>),
+    >,
+    scores: Res<'w, Scores>,
+}
+
+impl BoardReader<'_, '_> {
+    pub fn capture(&self) -> BoardSnapshot {
+        let linvel = |v: Option<&Velocity>| v.map(|v| v.linvel).unwrap_or(Vec2::ZERO);
+
+        let mut piezas: Vec<PiezaPos> = self
+            .disks
+            .iter()
+            .map(|(disk_id, tf, disk, vel, pup)| {
+                let v = linvel(vel);
+                PiezaPos {
+                    id: disk_id.0,
+                    x: tf.translation.x,
+                    y: tf.translation.y,
+                    id_usuario_real: disk.id_usuario_real,
+                    vx: v.x,
+                    vy: v.y,
+                    powerup: pup.map(|p| p.0),
+                }
+            })
+            .collect();
+        piezas.sort_by_key(|p| p.id);
+
+        let pelota = self.balls.iter().next().map(|(tf, vel)| {
+            let v = linvel(vel);
+            BallPos { x: tf.translation.x, y: tf.translation.y, vx: v.x, vy: v.y }
+        });
+
+        let powerup = self
+            .field_powerups
+            .iter()
+            .next()
+            .map(|(tf, t)| PowerUpPos { tipo: t.0, x: tf.translation.x, y: tf.translation.y });
+
+        let zonas = self
+            .zones
+            .iter()
+            .map(|(tf, sprite, life, slow, bounce)| {
+                let size = sprite.custom_size.unwrap_or(Vec2::ZERO);
+                let tipo = if slow.is_some() {
+                    RandomEvent::SlowZone
+                } else if bounce.is_some() {
+                    RandomEvent::BouncePad
+                } else {
+                    RandomEvent::SlipperyZone
+                };
+                ZonaSnapshot {
+                    tipo,
+                    x: tf.translation.x,
+                    y: tf.translation.y,
+                    ancho: size.x,
+                    alto: size.y,
+                    turnos_restantes: life.turns_remaining,
+                    ultimo_turno: life.last_turn_owner,
+                }
+            })
+            .collect();
+
+        BoardSnapshot {
+            piezas,
+            pelota,
+            powerup,
+            zonas,
+            marcador: Some((self.scores.left, self.scores.right)),
+            tiro: None,
+            eventos: Vec::new(),
+        }
+    }
+}
+
+/// Power-up del campo y zonas actuales, más sus recursos de control.
+#[derive(SystemParam)]
+pub struct BoardEffects<'w, 's> {
+    field_powerups: Query<'w, 's, Entity, (With<PowerUp>, Without<PlayerDisk>)>,
+    zones: Query<'w, 's, Entity, With<ZoneLifetime>>,
+    powerup_control: ResMut<'w, PowerUpControl>,
+    event_control: ResMut<'w, EventControl>,
+}
+
+impl BoardEffects<'_, '_> {
+    /// Sustituye el power-up del campo y las zonas por los de `board`.
+    pub fn restore(&mut self, board: &BoardSnapshot, commands: &mut Commands, asset_server: &AssetServer) {
+        for entity in self.field_powerups.iter().chain(self.zones.iter()) {
+            commands.entity(entity).despawn_recursive();
+        }
+
+        /* — Power-up del campo — */
+        self.powerup_control.active = board.powerup.is_some();
+        if let Some(p) = board.powerup {
+            spawn_power_up_at(commands, asset_server, Vec2::new(p.x, p.y), p.tipo);
+            self.powerup_control.last_type = Some(p.tipo);
+        }
+
+        /* — Zonas — */
+        for z in &board.zonas {
+            spawn_zone(
+                commands,
+                z.tipo,
+                Vec2::new(z.x, z.y),
+                Vec2::new(z.ancho, z.alto),
+                ZoneLifetime { turns_remaining: z.turnos_restantes, last_turn_owner: z.ultimo_turno },
+            );
+        }
+
+        match board.zonas.last() {
+            Some(z) => {
+                self.event_control.current_event = Some(z.tipo);
+                self.event_control.event_active = true;
+            }
+            None if self.event_control.event_active => {
+                self.event_control.current_event = None;
+                self.event_control.event_active = false;
+                self.event_control.turns_since_last = 0;
+            }
+            None => {}
+        }
+    }
+}
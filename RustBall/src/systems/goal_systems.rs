@@ -2,8 +2,12 @@ use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 use crate::components::*;
 use crate::events::*;
+use crate::i18n::t;
 use crate::resources::*;
-use crate::setup::camera::GameCamera;
+use crate::setup::camera::{FieldBackground, GameCamera};
+use crate::zone::{ActiveEffectText, ZoneLifetime};
+use crate::powerup::PowerUp;
+use super::opponent_presence::OpponentOfflineBanner;
 
 #[derive(Component)]
 pub struct GoalBanner;
@@ -69,8 +73,8 @@ pub fn handle_goal(
         turn_state.aim_direction = Vec2::ZERO;
         turn_state.power = 0.0;
 
-        // 🔁 Cambiar al otro jugador (basado en ID reales)
-        turn_state.current_turn_id = if turn_state.current_turn_id == backend_info.id_left {
+        // 🔁 Saca quien recibió el gol (en línea lo confirma el backend)
+        turn_state.current_turn_id = if event.scored_by_left {
             backend_info.id_right
         } else {
             backend_info.id_left
@@ -80,7 +84,7 @@ pub fn handle_goal(
         commands.spawn((
             TextBundle {
                 text: Text::from_section(
-                    "¡GOOOOL!",
+                    t!("gol"),
                     TextStyle {
                         font: asset_server.load("fonts/FiraSans-Bold.ttf"),
                         font_size: 80.0,
@@ -158,6 +162,16 @@ pub fn despawn_game_entities(
     goals: Query<Entity, With<GoalZone>>,
     fixed_bodies: Query<Entity, (With<RigidBody>, Without<PlayerDisk>, Without<Ball>)>,
     cameras: Query<Entity, With<GameCamera>>,
+    leftovers: Query<Entity, Or<(
+        With<TurnText>,
+        With<ScoreText>,
+        With<ActiveEffectText>,
+        With<GoalBanner>,
+        With<FieldBackground>,
+        With<ZoneLifetime>,
+        With<PowerUp>,
+        With<OpponentOfflineBanner>,
+    )>>,
 ) {
     for entity in players.iter() {
         if commands.get_entity(entity).is_some() {
@@ -184,6 +198,12 @@ pub fn despawn_game_entities(
             commands.entity(entity).despawn_recursive();
         }
     }
+    // HUD, fondo, zonas y power-ups: la pantalla final no los muestra
+    for entity in leftovers.iter() {
+        if commands.get_entity(entity).is_some() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
 
     println!("✅ Entidades físicas y visuales eliminadas tras GameOver.");
 }
@@ -0,0 +1,37 @@
+//! src/systems/kickoff.rs
+//! --------------------------------------------------------------
+//! Saque de cada ronda (inicio del partido y tras cada gol).
+//!
+//!   • Pelota y fichas vuelven a las posiciones de las formaciones
+//!     (`board_from_formations` / `spawn_formation_for`); las de quien
+//!     no saca quedan fuera del círculo central (`kickoff_positions`).
+//!   • Saca quien recibió el gol (el backend lo impone en línea;
+//!     `handle_goal` lo decide en local).
+//!   • `Kickoff` vive hasta el primer tiro de la ronda y mientras tanto
+//!     se dibuja el círculo central.
+//! --------------------------------------------------------------
+
+use bevy::prelude::*;
+
+use crate::{formation::KICKOFF_RADIUS, resources::TurnState};
+
+/// Saque pendiente: nadie ha tirado todavía en esta ronda.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct Kickoff {
+    pub kicker: i32,
+}
+
+/// Dibuja el círculo central mientras el saque está pendiente.
+pub fn draw_kickoff_circle(kickoff: Option<Res<Kickoff>>, mut gizmos: Gizmos) {
+    if kickoff.is_some() {
+        gizmos.circle_2d(Vec2::ZERO, KICKOFF_RADIUS, Color::rgba(1.0, 1.0, 1.0, 0.6));
+    }
+}
+
+/// El primer tiro de la ronda cierra el saque.
+pub fn end_kickoff_on_shot(mut commands: Commands, kickoff: Option<Res<Kickoff>>, turn_state: Res<TurnState>) {
+    if kickoff.is_some() && turn_state.in_motion {
+        info!("🏁 Saque realizado");
+        commands.remove_resource::<Kickoff>();
+    }
+}
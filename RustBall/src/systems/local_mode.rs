@@ -0,0 +1,145 @@
+//! src/systems/local_mode.rs
+//! --------------------------------------------------------------
+//! Modo local "hot-seat": dos jugadores, una pantalla, sin backend.
+//!
+//!   • Los lados usan los UIDs ficticios `LOCAL_LEFT_UID` /
+//!     `LOCAL_RIGHT_UID`.
+//!   • `BackendInfo.my_uid` sigue siempre al jugador de turno, así
+//!     que la selección de fichas y el input funcionan igual que online.
+//!   • Ninguno de los `send_*_to_backend` se ejecuta en este modo
+//!     (ver las condiciones `in_online_mode` / `in_local_mode`).
+//!   • Contra la máquina (`GameMode::VsBot`) el "yo" queda fijo en el
+//!     lado izquierdo y el derecho lo juega `ai::bot_take_turn`.
+//! --------------------------------------------------------------
+
+use bevy::prelude::*;
+
+use crate::{
+    components::FormationMenu,
+    events::TurnFinishedEvent,
+    formation::{formation_data, spawn_formation_for},
+    formation_selection::FormationTitle,
+    i18n::t,
+    resources::*,
+    snapshot::MyTurn,
+    systems::Kickoff,
+};
+
+/* ───────── Condiciones de ejecución ───────── */
+
+pub fn in_online_mode(mode: Res<GameMode>) -> bool {
+    mode.is_online()
+}
+
+pub fn in_local_mode(mode: Res<GameMode>) -> bool {
+    !mode.is_online()
+}
+
+/* ───────── Selección de formaciones por turnos ───────── */
+
+/// Indica en el menú qué lado está eligiendo.
+pub fn update_local_formation_title(
+    formations: Res<PlayerFormations>,
+    names: Res<PlayerNames>,
+    mut titles: Query<&mut Text, With<FormationTitle>>,
+) {
+    let msg = match (formations.player1, formations.player2) {
+        (None, _)          => t!("formacion-elige-jugador", nombre = names.left_name.as_str()),
+        (Some(_), None)    => t!("formacion-elige-jugador", nombre = names.right_name.as_str()),
+        (Some(_), Some(_)) => t!("formacion-a-jugar"),
+    };
+
+    for mut text in &mut titles {
+        if text.sections[0].value != msg {
+            text.sections[0].value = msg.clone();
+        }
+    }
+}
+
+/// Cuando ambos lados eligieron formación spawnea las fichas y arranca.
+pub fn start_local_match(
+    mut commands: Commands,
+    formations: Res<PlayerFormations>,
+    backend: Res<BackendInfo>,
+    asset_server: Res<AssetServer>,
+    mut turn_state: ResMut<TurnState>,
+    mut next_state: ResMut<NextState<AppState>>,
+    menus: Query<Entity, With<FormationMenu>>,
+) {
+    let (Some(left), Some(right)) = (formations.player1, formations.player2) else {
+        return;
+    };
+
+    // 🎲 Primer saque al azar; tras un gol saca quien lo recibió (handle_goal)
+    if turn_state.current_turn_id == 0 {
+        turn_state.current_turn_id = if rand::random() { backend.id_left } else { backend.id_right };
+    }
+    let kicker = turn_state.current_turn_id;
+
+    for (uid, formation) in [(backend.id_left, left), (backend.id_right, right)] {
+        let mut data = formation_data(uid, formation);
+        data.turno_inicio = if uid == kicker { 1 } else { 2 };
+        spawn_formation_for(&data, &mut commands, &asset_server, &backend);
+    }
+    commands.insert_resource(Kickoff { kicker });
+    turn_state.in_motion = false;
+    turn_state.selected_entity = None;
+
+    for entity in &menus {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    info!("🎮 Partida local: arranca UID {}", turn_state.current_turn_id);
+    next_state.set(AppState::InGame);
+}
+
+/* ───────── Alternancia de turnos ───────── */
+
+/// Pasa el turno al otro lado al terminar cada jugada.
+pub fn advance_local_turn(
+    mut reader: EventReader<TurnFinishedEvent>,
+    mut turn_state: ResMut<TurnState>,
+    backend: Res<BackendInfo>,
+) {
+    for _ in reader.read() {
+        if turn_state.skip_turn_switch {
+            turn_state.skip_turn_switch = false;
+            info!("🔁 Doble turno: repite UID {}", turn_state.current_turn_id);
+            continue;
+        }
+
+        turn_state.current_turn_id = if turn_state.current_turn_id == backend.id_left {
+            backend.id_right
+        } else {
+            backend.id_left
+        };
+        info!("🔁 Turno local → UID {}", turn_state.current_turn_id);
+    }
+}
+
+/// Hace que el "yo" local sea siempre el jugador de turno
+/// (salvo cuando juega la IA: entonces el humano sólo espera).
+/// Sólo toca el input; el texto de turno lo saca `update_turn_text`
+/// de `TurnState`.
+pub fn sync_local_controller(
+    mode: Res<GameMode>,
+    turn_state: Res<TurnState>,
+    mut backend: ResMut<BackendInfo>,
+    mut current_player_id: ResMut<CurrentPlayerId>,
+    mut my_turn: ResMut<MyTurn>,
+) {
+    let bot_turn = mode.bot_uid() == Some(turn_state.current_turn_id);
+    let controller = if bot_turn { LOCAL_LEFT_UID } else { turn_state.current_turn_id };
+
+    if backend.my_uid != controller {
+        backend.my_uid = controller;
+    }
+    if current_player_id.0 != controller {
+        current_player_id.0 = controller;
+    }
+
+    let should_play = !turn_state.in_motion && !bot_turn;
+    if my_turn.0 != should_play {
+        my_turn.0 = should_play;
+    }
+}
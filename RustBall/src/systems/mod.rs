@@ -25,6 +25,7 @@ pub mod ui_systems;
 pub mod visual_systems;
 pub mod reset_for_formation;
 pub mod poll_turn;
+pub mod local_mode;
 
 // ────────────────────────── MÓDULOS PRIVADOS ──────────────────────────
 mod random_event_system;
@@ -33,13 +34,21 @@ mod send_goal;
 mod send_formacion;
 mod send_turn;
 mod apply_snapshot;
+mod board_state;
+mod shot_replay;
+mod turn_events;
+mod kickoff;
 mod process_ws;
+mod opponent_presence;
+mod achievement_toast;
 
 // ────────────────────────── RE-EXPORTES ÚTILES ─────────────────────────
 // Basta con:   use systems::*;
 
 pub use random_event_system::trigger_random_event_system;
-pub use backend_setup::{insert_backend_info, load_backend_info_if_available};
+pub use backend_setup::insert_backend_info;
+#[cfg(target_arch = "wasm32")]
+pub use backend_setup::load_backend_info_if_available;
 
 // — Envíos al backend ───────────────────────────────────────────────────
 pub use send_goal::send_goal_to_backend;
@@ -51,13 +60,19 @@ pub use send_turn::{
 };
 
 // — Snapshot al tablero ────────────────────────────────────────────────
-pub use apply_snapshot::apply_board_snapshot;
+pub use apply_snapshot::{apply_board_snapshot, animate_board_tweens, board_settled};
+pub use board_state::{BoardReader, BoardEffects};
+pub use shot_replay::{record_shot_input, run_shot_replay, replay_idle, LastShot, ShotReplay};
+pub use kickoff::{draw_kickoff_circle, end_kickoff_on_shot, Kickoff};
+pub use turn_events::{record_turn_events, start_turn_event_log, TurnEvent, TurnEventLog};
 
 // — Polling (turnos) ───────────────────────────────────────────────────
 pub use poll_turn::{poll_turn_tick_system, handle_turn_finished_event};
 
 // — WebSocket (mensajes entrantes) ─────────────────────────────────────
 pub use process_ws::process_ws_messages;
+pub use opponent_presence::{show_opponent_presence, OpponentPresence};
+pub use achievement_toast::{show_achievement_toasts, AchievementToasts};
 
 // — Goles ──────────────────────────────────────────────────────────────
 pub use goal_systems::{
@@ -72,6 +87,16 @@ pub use goal_systems::{
 // — Systems de turno (aim / charge / fire actualizados) ───────────────
 pub use turn_systems::*;
 
+// — Modo local (hot-seat) ───────────────────────────────────────────────
+pub use local_mode::{
+    in_online_mode,
+    in_local_mode,
+    start_local_match,
+    advance_local_turn,
+    sync_local_controller,
+    update_local_formation_title,
+};
+
 // — HUD / UI / Visuales ────────────────────────────────────────────────
 pub use ui_systems::*;
 pub use visual_systems::*;
@@ -0,0 +1,62 @@
+//! src/systems/opponent_presence.rs
+//! --------------------------------------------------------------
+//! Aviso de "rival desconectado" (sólo online).
+//!
+//!   • El backend manda `rival_conectado` / `rival_desconectado` por el
+//!     WS de la partida cuando el rival abre o cierra su sesión
+//!     (`process_ws_messages` los guarda en `OpponentPresence`).
+//!   • Mientras esté desconectado se muestra un cartel arriba; se quita
+//!     solo cuando vuelve.  Al principio no se sabe nada: como la
+//!     partida es por turnos, que el rival no esté no es un aviso.
+//! --------------------------------------------------------------
+
+use bevy::prelude::*;
+
+use crate::i18n::t;
+
+/// `None` mientras no llegó ningún aviso del rival.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpponentPresence(pub Option<bool>);
+
+#[derive(Component)]
+pub struct OpponentOfflineBanner;
+
+pub fn show_opponent_presence(
+    mut commands: Commands,
+    presence: Res<OpponentPresence>,
+    asset_server: Res<AssetServer>,
+    banners: Query<Entity, With<OpponentOfflineBanner>>,
+) {
+    if !presence.is_changed() {
+        return;
+    }
+
+    let offline = presence.0 == Some(false);
+    if !offline {
+        for entity in &banners {
+            commands.entity(entity).despawn_recursive();
+        }
+        return;
+    }
+    if !banners.is_empty() {
+        return;
+    }
+
+    commands.spawn((
+        TextBundle::from_section(
+            t!("rival-desconectado"),
+            TextStyle {
+                font: asset_server.load("fonts/FiraSans-Bold.ttf"),
+                font_size: 28.0,
+                color: Color::ORANGE_RED,
+            },
+        )
+            .with_style(Style {
+                position_type: PositionType::Absolute,
+                top: Val::Px(60.0),
+                left: Val::Percent(35.0),
+                ..default()
+            }),
+        OpponentOfflineBanner,
+    ));
+}
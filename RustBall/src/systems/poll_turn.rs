@@ -1,5 +1,4 @@
 use bevy::prelude::*;
-use gloo_net::http::Request;
 use std::{
     sync::{
         Arc,
@@ -8,12 +7,12 @@ use std::{
     },
     time::Duration,
 };
-use wasm_bindgen_futures::spawn_local;
 
 use crate::{
     events::TurnFinishedEvent,
+    net,
     resources::BackendInfo,
-    snapshot::{MyTurn, TurnoData},
+    snapshot::{set_game_state, MyTurn, SnapshotFromServer, TurnoData},
 };
 
 /* ────────────── Recurso global ────────────── */
@@ -70,15 +69,14 @@ pub fn poll_turn_tick_system(
     let notify_flag = Arc::clone(&state.notify);
     let last_turn_ref = Arc::clone(&state.last_turn_number);
 
-    spawn_local(async move {
-        if let Ok(resp) = Request::get(&format!("/api/estado/{pid}")).send().await {
-            if let Ok(turnos) = resp.json::<Vec<TurnoData>>().await {
-                if let Some(ultimo) = turnos.last() {
-                    let mut last = last_turn_ref.lock().unwrap();
-                    if ultimo.numero_turno > *last {
-                        notify_flag.store(true, Ordering::Release);
-                        *last = ultimo.numero_turno;
-                    }
+    net::get(&format!("/estado/{pid}"), move |res| {
+        let Ok(resp) = res else { return };
+        if let Ok(turnos) = serde_json::from_str::<Vec<TurnoData>>(&resp.body) {
+            if let Some(ultimo) = turnos.last() {
+                let mut last = last_turn_ref.lock().unwrap();
+                if ultimo.numero_turno > *last {
+                    notify_flag.store(true, Ordering::Release);
+                    *last = ultimo.numero_turno;
                 }
             }
         }
@@ -86,29 +84,24 @@ pub fn poll_turn_tick_system(
 }
 
 /* ────────────── Aplicar snapshot al recibir TurnFinishedEvent ────────────── */
-#[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))]
 pub fn handle_turn_finished_event(
     mut reader: EventReader<TurnFinishedEvent>,
     backend: Option<Res<BackendInfo>>,
 ) {
-    #[cfg(target_arch = "wasm32")]
-    {
-        use crate::snapshot::{set_game_state, SnapshotFromServer};
-
-        if reader.read().next().is_some() {
-            if let Some(b) = backend {
-                let pid = b.partida_id;
-                let uid = b.my_uid;
-
-                spawn_local(async move {
-                    if let Ok(resp) = Request::get(&format!("/api/snapshot/{}", pid)).send().await {
-                        if let Ok(snapshot) = resp.json::<SnapshotFromServer>().await {
-                            let json = serde_json::to_string(&snapshot).unwrap();
-                            set_game_state(&json, uid); // ✅ ← uso correcto con referencia
-                        }
-                    }
-                });
-            }
+    if reader.read().next().is_some() {
+        if let Some(b) = backend {
+            fetch_and_apply_snapshot(b.partida_id, b.my_uid);
         }
     }
 }
+
+/// Pide `/snapshot/:pid` y lo deja en cola para `snapshot_apply_system`.
+pub fn fetch_and_apply_snapshot(pid: i32, uid: i32) {
+    net::get(&format!("/snapshot/{pid}"), move |res| {
+        let Ok(resp) = res else { return };
+        if let Ok(snapshot) = serde_json::from_str::<SnapshotFromServer>(&resp.body) {
+            let json = serde_json::to_string(&snapshot).unwrap();
+            set_game_state(&json, uid);
+        }
+    });
+}
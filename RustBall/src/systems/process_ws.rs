@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 use crate::resources::{WsInbox, AppState, BackendInfo};
+use super::achievement_toast::{AchievementToasts, UnlockedAchievement};
+use super::opponent_presence::OpponentPresence;
 
 /* —––––––––– SECCIÓN WASM (web_sys) —––––––––––––––––––––––––––––––––– */
 #[cfg(target_arch = "wasm32")]
@@ -69,21 +71,88 @@ mod wasm_ws {
     }
 }
 
+/* —––––––––– SECCIÓN ESCRITORIO (tokio-tungstenite) —––––––––––––––––– */
+#[cfg(not(target_arch = "wasm32"))]
+mod native_ws {
+    use super::*;
+    use crate::snapshot::set_game_state;
+    use crate::systems::poll_turn::fetch_and_apply_snapshot;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    static WS_STARTED: AtomicBool = AtomicBool::new(false);
+
+    pub fn ensure_ws_connected(backend: &BackendInfo) {
+        if WS_STARTED.swap(true, Ordering::AcqRel) {
+            return;
+        }
+
+        let pid = backend.partida_id;
+        let uid = backend.my_uid;
+
+        crate::net::connect_ws(pid, uid, move |raw| {
+            // Mismo protocolo que `initWebSocket` en bootstrap.js
+            let (tipo, msg) = match serde_json::from_str::<serde_json::Value>(&raw) {
+                Ok(data) => {
+                    if data["uid_origen"] == serde_json::json!(uid) {
+                        return;
+                    }
+                    if data["tipo"] == "snapshot" {
+                        info!("📦 Snapshot reenviado recibido");
+                        set_game_state(&data["contenido"].to_string(), uid);
+                        return;
+                    }
+                    let contenido = match &data["contenido"] {
+                        serde_json::Value::String(s) => s.clone(),
+                        serde_json::Value::Null => raw.clone(),
+                        other => other.to_string(),
+                    };
+                    (data["tipo"].as_str().map(str::to_owned), contenido)
+                }
+                Err(_) => (None, raw),
+            };
+            debug!("📥 WS (tipo {:?}): {msg}", tipo);
+
+            // 1. Empujar a la bandeja
+            if let Some(lock) = crate::WS_INBOX.get() {
+                if let Ok(mut inbox) = lock.lock() {
+                    inbox.0.push(msg.clone());
+                }
+            }
+
+            // 2. Si es “start” o “turno_finalizado” ⇒ fetch + aplicar snapshot
+            if msg == "start" || msg == "turno_finalizado" {
+                info!("⚡ WS: {msg}");
+                fetch_and_apply_snapshot(pid, uid);
+            }
+        });
+    }
+}
+
 /* —––––––––– SISTEMA BEVY —––––––––––––––––––––––––––––––––––––––––––– */
 
-#[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))]
 pub fn process_ws_messages(
     mut inbox: ResMut<WsInbox>,
     backend: Option<Res<BackendInfo>>,
     mut next_state: ResMut<NextState<AppState>>,
+    mut opponent: ResMut<OpponentPresence>,
+    mut achievements: ResMut<AchievementToasts>,
 ) {
-    // 1. En WASM: garantizar que el WebSocket esté conectado
-    #[cfg(target_arch = "wasm32")]
+    // 1. Garantizar que el WebSocket esté conectado
     if let Some(ref be) = backend {
+        #[cfg(target_arch = "wasm32")]
         wasm_ws::ensure_ws_connected(be);
+        #[cfg(not(target_arch = "wasm32"))]
+        native_ws::ensure_ws_connected(be);
     }
 
-    // 2. Procesar mensajes empujados desde JS → Bandeja
+    // 2. Pasar a la bandeja de Bevy lo que llegó por el socket (JS o tokio)
+    if let Some(lock) = crate::WS_INBOX.get() {
+        if let Ok(mut shared) = lock.lock() {
+            inbox.0.append(&mut shared.0);
+        }
+    }
+
+    // 3. Procesar mensajes de la bandeja
     for msg in inbox.0.drain(..) {
         match msg.as_str() {
             "turno_finalizado" => {
@@ -93,7 +162,23 @@ pub fn process_ws_messages(
             "start" => {
                 info!("🟢 WsInbox: start recibido → posiblemente cambiar estado");
             }
-            other => warn!("❓ WsInbox: mensaje no reconocido: {other}"),
+            "rival_conectado" => {
+                info!("👀 WsInbox: el rival está conectado");
+                opponent.set_if_neq(OpponentPresence(Some(true)));
+            }
+            "rival_desconectado" => {
+                info!("👀 WsInbox: el rival se desconectó");
+                opponent.set_if_neq(OpponentPresence(Some(false)));
+            }
+            // 🏅 `contenido` de `{"tipo":"logro"}`: sólo los propios
+            other => match serde_json::from_str::<UnlockedAchievement>(other) {
+                Ok(logro) => {
+                    if backend.as_ref().is_some_and(|be| be.my_uid == logro.id_usuario) {
+                        achievements.0.push_back(logro);
+                    }
+                }
+                Err(_) => warn!("❓ WsInbox: mensaje no reconocido: {other}"),
+            },
         }
     }
 }
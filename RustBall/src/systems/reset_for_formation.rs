@@ -1,5 +1,5 @@
 use bevy::prelude::*;
-use crate::components::{PlayerDisk, FormationMenu, Ball, GoalZone};
+use crate::components::{PlayerDisk, FormationMenu, Ball, GoalZone, GoalFrame};
 use crate::resources::PlayerFormations;
 use crate::formation_selection::show_formation_ui;
 
@@ -9,7 +9,7 @@ pub fn reset_for_formation(
     disks: Query<Entity, With<PlayerDisk>>,
     menus: Query<Entity, With<FormationMenu>>,
     balls: Query<Entity, With<Ball>>,
-    goals: Query<Entity, With<GoalZone>>,
+    goals: Query<Entity, Or<(With<GoalZone>, With<GoalFrame>)>>,
     mut formations: ResMut<PlayerFormations>,
     asset_server: Res<AssetServer>,
 ) {
@@ -2,15 +2,9 @@ use bevy::prelude::*;
 use serde::Serialize;
 
 use crate::events::FormationChosenEvent;
+use crate::net;
 use crate::resources::BackendInfo;
 
-#[cfg(target_arch = "wasm32")]
-use gloo_net::http::Request;
-#[cfg(target_arch = "wasm32")]
-use wasm_bindgen_futures::spawn_local;
-#[cfg(target_arch = "wasm32")]
-use web_sys::console;
-
 /* ——— payload que espera el backend ——— */
 #[derive(Serialize)]
 struct FormacionPayload {
@@ -18,6 +12,8 @@ struct FormacionPayload {
     id_usuario: i32,
     formacion: String,
     turno_inicio: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id_formacion: Option<i32>,
 }
 
 /* ——— sistema que envía la elección ——— */
@@ -33,30 +29,13 @@ pub fn send_formacion_to_backend(
             id_usuario: my_uid,
             formacion: ev.formacion.clone(),
             turno_inicio: 0, // ⬅️ el servidor decide quién arranca
+            id_formacion: ev.id_formacion,
         };
 
-        #[cfg(target_arch = "wasm32")]
-        spawn_local(async move {
-            match Request::post("/api/formacion")
-                .header("Content-Type", "application/json")
-                .body(serde_json::to_string(&payload).unwrap())
-                .unwrap()
-                .send()
-                .await
-            {
-                Ok(response) => {
-                    let status = response.status();
-                    if status >= 400 {
-                        let text = response.text().await.unwrap_or_default();
-                        console::log_1(&format!("❌ Error /api/formacion: status={} body={}", status, text).into());
-                    } else {
-                        console::log_1(&"✅ Formación enviada correctamente.".into());
-                    }
-                }
-                Err(e) => {
-                    console::log_1(&format!("❌ Fallo de red /api/formacion: {e:?}").into());
-                }
-            }
+        net::post_json("/formacion", serde_json::to_string(&payload).unwrap(), |res| match res {
+            Ok(r) if r.is_success() => info!("✅ Formación enviada correctamente."),
+            Ok(r) => error!("❌ Error /api/formacion: status={} body={}", r.status, r.body),
+            Err(e) => error!("❌ Fallo de red /api/formacion: {e}"),
         });
     }
 }
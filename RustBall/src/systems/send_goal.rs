@@ -2,30 +2,33 @@
 
 use bevy::prelude::*;
 use serde::Serialize;
+use serde_json::{json, Value};
 
+use super::{LastShot, TurnEventLog};
 use crate::events::GoalEvent;
-use crate::resources::BackendInfo;
-
-#[cfg(target_arch = "wasm32")]
-use gloo_net::http::Request;
-#[cfg(target_arch = "wasm32")]
-use wasm_bindgen_futures::spawn_local;
-
-#[cfg(not(target_arch = "wasm32"))]
-use tokio::task;
-#[cfg(not(target_arch = "wasm32"))]
-use reqwest::Client;
+use crate::net;
+use crate::resources::{BackendInfo, RondaActual};
 
 #[derive(Serialize)]
 struct GolPayload {
     id_partida:  i32,
     id_goleador: i32,
+    /// Ronda en la que se marcó: el backend descarta avisos repetidos.
+    ronda:       i32,
+    /// Nuestro tiro que metió el gol con sus eventos: el turno no se
+    /// cierra, así que no sale por `send_turn_to_backend`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    jugada:      Option<Value>,
 }
 
-/// Escucha `GoalEvent` y notifica el gol al backend.
+/// Escucha `GoalEvent` y notifica el gol al backend, que abre la ronda
+/// siguiente y avisa a ambos clientes con un snapshot.
 pub fn send_goal_to_backend(
     mut ev_goal: EventReader<GoalEvent>,
     backend:     Res<BackendInfo>,
+    ronda:       Res<RondaActual>,
+    mut last_shot: ResMut<LastShot>,
+    mut event_log: ResMut<TurnEventLog>,
 ) {
     for ev in ev_goal.read() {
         let id_goleador = if ev.scored_by_left {
@@ -37,29 +40,16 @@ pub fn send_goal_to_backend(
         let payload = GolPayload {
             id_partida:  backend.partida_id,
             id_goleador,
+            ronda: ronda.0,
+            jugada: last_shot.0.take().map(|tiro| {
+                json!({ "tiro": tiro, "eventos": std::mem::take(&mut event_log.0) })
+            }),
         };
 
-        // ——— WebAssembly: gloo-net + wasm-bindgen-futures ———
-        #[cfg(target_arch = "wasm32")]
-        spawn_local(async move {
-            let _ = Request::post("https://rustball.lat/api/gol")
-                .header("Content-Type", "application/json")
-                .body(serde_json::to_string(&payload).unwrap())
-                .unwrap()
-                .send()
-                .await;
+        net::post_json("/gol", serde_json::to_string(&payload).unwrap(), |res| match res {
+            Ok(r) if r.is_success() => info!("⚽ Gol registrado en el backend: {}", r.body),
+            Ok(r) => error!("⚠️ POST /api/gol falló ({}): {}", r.status, r.body),
+            Err(e) => error!("❌ Error de red al enviar gol: {e}"),
         });
-
-        // ——— Nativo (desktop): reqwest + tokio ———
-        #[cfg(not(target_arch = "wasm32"))]
-        {
-            task::spawn(async move {
-                let _ = Client::new()
-                    .post("https://rustball.lat/api/gol")
-                    .json(&payload)
-                    .send()
-                    .await;
-            });
-        }
     }
 }
@@ -2,9 +2,10 @@ use bevy::prelude::*;
 use serde::Serialize;
 use serde_json::json;
 
+use super::{BoardReader, LastShot, TurnEventLog};
 use crate::{
-    components::PlayerDisk,
     events::TurnFinishedEvent,
+    net,
     resources::{BackendInfo, TurnState},
     snapshot::{NextTurn, MyTurn},
 };
@@ -28,25 +29,19 @@ pub fn send_turn_to_backend(
     backend: Res<BackendInfo>,
     _turn_state: Res<TurnState>,
     next_turn: Res<NextTurn>,
-    query: Query<(Entity, &Transform, &PlayerDisk)>,
+    board: BoardReader,
+    mut last_shot: ResMut<LastShot>,
+    mut event_log: ResMut<TurnEventLog>,
     mut commands: Commands,
 ) {
     for _ in ev_end.read() {
         info!("📤 Evento TurnFinished recibido. UID actual: {}", backend.my_uid);
 
-        let piezas: Vec<_> = query
-            .iter()
-            .map(|(entity, transform, disk)| {
-                json!({
-                    "id": entity.index(),
-                    "id_usuario_real": disk.id_usuario_real,
-                    "x": transform.translation.x,
-                    "y": transform.translation.y
-                })
-            })
-            .collect();
+        let mut snapshot = board.capture();
+        snapshot.tiro = last_shot.0.take();
+        snapshot.eventos = std::mem::take(&mut event_log.0);
 
-        if piezas.is_empty() {
+        if snapshot.piezas.is_empty() {
             warn!("⚠️ No se encontraron piezas en el Query. No se enviará jugada.");
             return;
         }
@@ -55,7 +50,7 @@ pub fn send_turn_to_backend(
             id_partida: backend.partida_id,
             numero_turno: next_turn.0,
             id_usuario: backend.my_uid,
-            jugada: json!({ "piezas": piezas }),
+            jugada: serde_json::to_value(&snapshot).unwrap_or_else(|_| json!({})),
         };
 
         info!("✅ Jugada lista para enviar:");
@@ -69,11 +64,6 @@ pub fn send_turn_to_backend(
     }
 }
 
-#[cfg(target_arch = "wasm32")]
-use gloo_net::http::Request;
-#[cfg(target_arch = "wasm32")]
-use wasm_bindgen_futures::spawn_local;
-
 // 🚀 Enviar jugada si es mi turno y hay jugada pendiente
 pub fn maybe_send_pending_turn(
     my_turn: Res<MyTurn>,
@@ -92,36 +82,12 @@ pub fn maybe_send_pending_turn(
         info!("🔢 numero_turno = {}", payload.numero_turno);
         info!("📐 jugada = {}", payload.jugada);
 
-        #[cfg(target_arch = "wasm32")]
-        spawn_local(async move {
-            let json = serde_json::to_string(&payload).unwrap();
-            let req = Request::post("/api/jugada")
-                .header("Content-Type", "application/json")
-                .body(json.clone());
-
-            let res = match req {
-                Ok(r) => r.send().await,
-                Err(e) => {
-                    error!("❌ Error al construir petición POST /api/jugada: {:?}", e);
-                    return;
-                }
-            };
-
-            match res {
-                Ok(resp) => {
-                    let status = resp.status();
-                    let text = resp.text().await.unwrap_or_else(|_| "❌ Sin cuerpo en respuesta".to_string());
-
-                    if status >= 200 && status < 300 {
-                        info!("✅ POST /api/jugada registrado con éxito ({}): {}", status, text);
-                    } else {
-                        error!("⚠️ POST /api/jugada falló ({}): {}", status, text);
-                    }
-                }
-                Err(err) => {
-                    error!("❌ Error de red al enviar jugada: {:?}", err);
-                }
+        net::post_json("/jugada", serde_json::to_string(&payload).unwrap(), |res| match res {
+            Ok(r) if r.is_success() => {
+                info!("✅ POST /api/jugada registrado con éxito ({}): {}", r.status, r.body);
             }
+            Ok(r) => error!("⚠️ POST /api/jugada falló ({}): {}", r.status, r.body),
+            Err(e) => error!("❌ Error de red al enviar jugada: {e}"),
         });
     }
 }
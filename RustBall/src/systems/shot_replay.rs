@@ -0,0 +1,158 @@
+//! src/systems/shot_replay.rs
+//! --------------------------------------------------------------
+//! Repetición del tiro del rival.
+//!
+//!   • Quien dispara guarda en `LastShot` la ficha, dirección,
+//!     potencia, power-ups y el tablero previo; `send_turn_to_backend`
+//!     lo adjunta como `tiro` dentro de la jugada.
+//!   • Quien espera, al recibir esa jugada, vuelve al tablero previo,
+//!     dispara la misma ficha con rapier y, cuando todo se detiene (o
+//!     se agota `REPLAY_MAX_SECS`), concilia con las posiciones que
+//!     mandó el backend, que son las que valen.
+//!   • Mientras dura la repetición no se aceptan tiros ni se cuentan
+//!     goles locales: el gol ya lo registró quien disparó.
+//! --------------------------------------------------------------
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+use super::{apply_board_snapshot, BoardEffects, BoardReader};
+use crate::{
+    components::{Ball, BoardTween, DiskId, PlayerDisk, TurnControlled},
+    powerup::PowerUpType,
+    resources::{BackendInfo, PlayerNames, TurnState},
+    settings::GameSettings,
+    snapshot::{BoardSnapshot, MyTurn, ShotInput},
+    systems::turn_systems::SHOT_MAX_SPEED,
+};
+
+/// Tope de la repetición por si algo queda rodando indefinidamente.
+const REPLAY_MAX_SECS: f32 = 8.0;
+/// Igual que `check_turn_end`.
+const STOP_THRESHOLD: f32 = 0.5;
+
+/// Último tiro propio, pendiente de enviarse con la jugada.
+#[derive(Resource, Default)]
+pub struct LastShot(pub Option<ShotInput>);
+
+/// Repetición en curso del tiro del rival.
+#[derive(Resource)]
+pub struct ShotReplay {
+    shot: ShotInput,
+    final_board: BoardSnapshot,
+    current_turn_id: i32,
+    phase: ReplayPhase,
+}
+
+enum ReplayPhase {
+    /// Las fichas vuelven (con tween) al tablero previo.
+    Positioning,
+    /// El tiro está en marcha.
+    Running(Timer),
+}
+
+impl ShotReplay {
+    pub fn new(shot: ShotInput, final_board: BoardSnapshot, current_turn_id: i32) -> Self {
+        Self { shot, final_board, current_turn_id, phase: ReplayPhase::Positioning }
+    }
+}
+
+/// Guarda la entrada del tiro justo antes de que `fire_selected_disk`
+/// lo ejecute (mismas condiciones de disparo).
+pub fn record_shot_input(
+    keys: Res<Input<KeyCode>>,
+    settings: Res<GameSettings>,
+    my_turn: Res<MyTurn>,
+    turn_state: Res<TurnState>,
+    shooter: Query<(&DiskId, Option<&PowerUpType>), With<TurnControlled>>,
+    board: BoardReader,
+    mut last_shot: ResMut<LastShot>,
+) {
+    if !my_turn.0 || !keys.just_released(settings.keys.shoot) || turn_state.in_motion {
+        return;
+    }
+    let Some((disk_id, pup)) = shooter.iter().next() else { return };
+
+    let dir = turn_state.aim_direction.normalize_or_zero();
+    last_shot.0 = Some(ShotInput {
+        id_ficha: disk_id.0,
+        dir_x: dir.x,
+        dir_y: dir.y,
+        potencia: turn_state.power,
+        powerups: pup.map(|p| vec![p.0]).unwrap_or_default(),
+        tablero_previo: Box::new(board.capture()),
+    });
+}
+
+/// Avanza la repetición: dispara cuando el tablero previo está en su
+/// sitio y concilia con el tablero final cuando todo se detiene.
+#[allow(clippy::too_many_arguments)]
+pub fn run_shot_replay(
+    mut commands: Commands,
+    time: Res<Time>,
+    replay: Option<ResMut<ShotReplay>>,
+    tweens: Query<(), With<BoardTween>>,
+    disks: Query<(Entity, &DiskId, &Transform), With<PlayerDisk>>,
+    balls: Query<(Entity, &Transform), (With<Ball>, Without<PlayerDisk>)>,
+    mut velocities: Query<&mut Velocity>,
+    mut effects: BoardEffects,
+    backend_info: Res<BackendInfo>,
+    names: Option<Res<PlayerNames>>,
+    asset_server: Res<AssetServer>,
+) {
+    let Some(mut replay) = replay else { return };
+    let replay = &mut *replay;
+
+    match &mut replay.phase {
+        ReplayPhase::Positioning => {
+            if !tweens.is_empty() {
+                return;
+            }
+
+            let shooter = disks
+                .iter()
+                .find(|(_, id, _)| id.0 == replay.shot.id_ficha)
+                .map(|(e, _, _)| e);
+
+            if let Some(entity) = shooter {
+                if let Ok(mut vel) = velocities.get_mut(entity) {
+                    let dir = Vec2::new(replay.shot.dir_x, replay.shot.dir_y);
+                    vel.linvel = dir * replay.shot.potencia * SHOT_MAX_SPEED;
+                    commands.entity(entity).remove::<Sleeping>();
+                }
+                info!("🎬 Repitiendo tiro del rival con la ficha {}", replay.shot.id_ficha);
+            } else {
+                warn!("🎬 Ficha {} no encontrada; se salta la repetición", replay.shot.id_ficha);
+            }
+
+            replay.phase = ReplayPhase::Running(Timer::from_seconds(REPLAY_MAX_SECS, TimerMode::Once));
+        }
+        ReplayPhase::Running(timer) => {
+            timer.tick(time.delta());
+            let moving = velocities.iter().any(|v| v.linvel.length_squared() >= STOP_THRESHOLD);
+            if moving && !timer.finished() {
+                return;
+            }
+
+            info!("🎬 Repetición terminada; conciliando con el tablero del backend");
+            let final_board = std::mem::take(&mut replay.final_board);
+            effects.restore(&final_board, &mut commands, &asset_server);
+            apply_board_snapshot(
+                final_board,
+                &mut commands,
+                backend_info.clone(),
+                &disks,
+                &balls,
+                replay.current_turn_id,
+                names.map(|n| (*n).clone()),
+                &asset_server,
+            );
+            commands.remove_resource::<ShotReplay>();
+        }
+    }
+}
+
+/// Condición: no hay una repetición en curso.
+pub fn replay_idle(replay: Option<Res<ShotReplay>>) -> bool {
+    replay.is_none()
+}
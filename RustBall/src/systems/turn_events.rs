@@ -0,0 +1,116 @@
+//! src/systems/turn_events.rs
+//! --------------------------------------------------------------
+//! Registro de lo que pasa durante el tiro propio (sólo online).
+//!
+//!   • Contactos ficha-pelota, rebotes contra paredes y postes,
+//!     power-ups recogidos y entradas a zonas, con el `DiskId` de la
+//!     ficha involucrada.
+//!   • El registro se vacía al disparar (`LastShot` nuevo) y
+//!     `send_turn_to_backend` lo manda como `jugada.eventos`; el
+//!     backend lo guarda en `EventoTurno` para las estadísticas.
+//!   • El disparo y el gol no van aquí: el backend los saca de
+//!     `jugada.tiro` y de `POST /gol`.  Los rebotes contra un poste
+//!     (`GoalPost`) van marcados: si el tiro termina en gol,
+//!     `send_goal_to_backend` manda el registro con el gol y el backend
+//!     decide ahí el logro de gol de poste.
+//! --------------------------------------------------------------
+
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use bevy_rapier2d::rapier::geometry::CollisionEventFlags;
+use serde::{Deserialize, Serialize};
+
+use super::LastShot;
+use crate::{
+    components::{Ball, DiskId, GoalPost},
+    events::PowerUpPickedEvent,
+    zone::{BouncePad, SlipperyZone, SlowZone},
+};
+
+/// Tope por turno (el backend tampoco guarda más).
+const MAX_EVENTS: usize = 200;
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "tipo", rename_all = "snake_case")]
+pub enum TurnEvent {
+    ContactoPelota { id_ficha: u32 },
+    /// `id_ficha` vacío: rebotó la pelota.  `poste`: contra un poste del arco.
+    Rebote {
+        id_ficha: Option<u32>,
+        #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+        poste: bool,
+    },
+    Powerup { id_ficha: u32, powerup: usize },
+    Zona { id_ficha: u32, zona: String },
+}
+
+#[derive(Resource, Default, Debug)]
+pub struct TurnEventLog(pub Vec<TurnEvent>);
+
+impl TurnEventLog {
+    fn push(&mut self, event: TurnEvent) {
+        if self.0.len() < MAX_EVENTS {
+            self.0.push(event);
+        }
+    }
+}
+
+/// Empieza un registro nuevo con cada tiro propio.
+pub fn start_turn_event_log(last_shot: Res<LastShot>, mut log: ResMut<TurnEventLog>) {
+    if last_shot.is_changed() && last_shot.0.is_some() {
+        log.0.clear();
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn record_turn_events(
+    mut log: ResMut<TurnEventLog>,
+    mut collisions: EventReader<CollisionEvent>,
+    mut picked: EventReader<PowerUpPickedEvent>,
+    disks: Query<&DiskId>,
+    balls: Query<(), With<Ball>>,
+    zones: Query<(Option<&SlipperyZone>, Option<&SlowZone>, Option<&BouncePad>)>,
+    walls: Query<&RigidBody>,
+    posts: Query<(), With<GoalPost>>,
+) {
+    for ev in collisions.read() {
+        let CollisionEvent::Started(a, b, flags) = ev else { continue };
+
+        // Sensores: sólo interesan las zonas (arcos y power-ups van por otro lado)
+        if flags.contains(CollisionEventFlags::SENSOR) {
+            let (disk, zone) = match (disks.get(*a), disks.get(*b)) {
+                (Ok(id), _) => (id, *b),
+                (_, Ok(id)) => (id, *a),
+                _ => continue,
+            };
+            let zona = match zones.get(zone) {
+                Ok((Some(_), _, _)) => "resbalosa",
+                Ok((_, Some(_), _)) => "lenta",
+                Ok((_, _, Some(_))) => "trampolin",
+                _ => continue,
+            };
+            log.push(TurnEvent::Zona { id_ficha: disk.0, zona: zona.into() });
+            continue;
+        }
+
+        let is_ball = |e: Entity| balls.contains(e);
+        let is_wall = |e: Entity| matches!(walls.get(e), Ok(RigidBody::Fixed));
+        let rebote = |id_ficha: Option<u32>, pared: Entity| TurnEvent::Rebote { id_ficha, poste: posts.contains(pared) };
+
+        match (disks.get(*a).ok(), disks.get(*b).ok()) {
+            (Some(id), None) if is_ball(*b) => log.push(TurnEvent::ContactoPelota { id_ficha: id.0 }),
+            (None, Some(id)) if is_ball(*a) => log.push(TurnEvent::ContactoPelota { id_ficha: id.0 }),
+            (Some(id), None) if is_wall(*b) => log.push(rebote(Some(id.0), *b)),
+            (None, Some(id)) if is_wall(*a) => log.push(rebote(Some(id.0), *a)),
+            (None, None) if is_ball(*a) && is_wall(*b) => log.push(rebote(None, *b)),
+            (None, None) if is_ball(*b) && is_wall(*a) => log.push(rebote(None, *a)),
+            _ => {}
+        }
+    }
+
+    for ev in picked.read() {
+        if let Ok(id) = disks.get(ev.disk) {
+            log.push(TurnEvent::Powerup { id_ficha: id.0, powerup: ev.tipo });
+        }
+    }
+}
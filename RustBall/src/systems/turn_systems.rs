@@ -6,6 +6,10 @@
 #[derive(SystemSet, Debug, Hash, PartialEq, Eq, Clone)]
 pub struct CheckTurnEndSet;
 
+/// Velocidad de salida de un disparo con la barra de potencia al máximo
+/// (compartida con el bot del servidor).
+pub use rustball_shared::field::SHOT_MAX_SPEED;
+
 use bevy::input::keyboard::KeyCode;
 use bevy::prelude::*;
 
@@ -14,6 +18,7 @@ use crate::components::*;
 use crate::events::TurnFinishedEvent;
 use crate::powerup::PowerUpControl;
 use crate::resources::*;
+use crate::settings::GameSettings;
 use crate::snapshot::MyTurn;
 
 /* ───────────────────────────────────────────────────────────── */
@@ -51,29 +56,31 @@ pub fn auto_select_first_disk(
 }
 
 /* ───────────────────────────────────────────────────────────── */
-/* 2. Pulsar TAB para alternar ficha                             */
+/* 2. Pulsar TAB (o la tecla asignada) para alternar ficha      */
 /* ───────────────────────────────────────────────────────────── */
 
 pub fn cycle_disk_selection(
     keys: Res<Input<KeyCode>>,
-    disks: Query<(Entity, &OwnedBy), (With<RigidBody>, With<PlayerDisk>)>,
+    settings: Res<GameSettings>,
+    disks: Query<(Entity, &OwnedBy, &DiskId), (With<RigidBody>, With<PlayerDisk>)>,
     mut sprites: Query<&mut Sprite>,
     mut turn_state: ResMut<TurnState>,
     mut commands: Commands,
     backend_info: Res<BackendInfo>,
 ) {
-    if !(keys.just_pressed(KeyCode::Tab) && !turn_state.in_motion) {
+    if !keys.just_pressed(settings.keys.cycle_disk) || turn_state.in_motion {
         return;
     }
 
     // Todas las fichas que me pertenecen
-    let mut my_disks: Vec<Entity> = disks
+    let mut my_disks: Vec<(Entity, DiskId)> = disks
         .iter()
-        .filter(|(_, o)| o.0 == backend_info.my_uid)
-        .map(|(e, _)| e)
+        .filter(|(_, o, _)| o.0 == backend_info.my_uid)
+        .map(|(e, _, id)| (e, *id))
         .collect();
 
-    my_disks.sort_by_key(|e| e.index());
+    my_disks.sort_by_key(|(_, id)| id.0);
+    let my_disks: Vec<Entity> = my_disks.into_iter().map(|(e, _)| e).collect();
     if my_disks.is_empty() {
         return;
     }
@@ -107,11 +114,12 @@ pub fn cycle_disk_selection(
 }
 
 /* ───────────────────────────────────────────────────────────── */
-/* 3. Control de dirección con flechas                           */
+/* 3. Control de dirección (flechas por defecto)                 */
 /* ───────────────────────────────────────────────────────────── */
 
 pub fn aim_with_keyboard(
     keys: Res<Input<KeyCode>>,
+    settings: Res<GameSettings>,
     my_turn: Res<MyTurn>,
     mut turn_state: ResMut<TurnState>,
 ) {
@@ -119,18 +127,19 @@ pub fn aim_with_keyboard(
         return;
     }
 
+    let (bindings, step) = (&settings.keys, settings.aim_step);
     let mut dir = turn_state.aim_direction;
-    if keys.pressed(KeyCode::Left) {
-        dir.x -= 0.1;
+    if keys.pressed(bindings.aim_left) {
+        dir.x -= step;
     }
-    if keys.pressed(KeyCode::Right) {
-        dir.x += 0.1;
+    if keys.pressed(bindings.aim_right) {
+        dir.x += step;
     }
-    if keys.pressed(KeyCode::Up) {
-        dir.y += 0.1;
+    if keys.pressed(bindings.aim_up) {
+        dir.y += step;
     }
-    if keys.pressed(KeyCode::Down) {
-        dir.y -= 0.1;
+    if keys.pressed(bindings.aim_down) {
+        dir.y -= step;
     }
     turn_state.aim_direction = dir.clamp_length_max(1.0);
 }
@@ -141,6 +150,7 @@ pub fn aim_with_keyboard(
 
 pub fn charge_shot_power(
     keys: Res<Input<KeyCode>>,
+    settings: Res<GameSettings>,
     my_turn: Res<MyTurn>,
     mut turn_state: ResMut<TurnState>,
 ) {
@@ -148,8 +158,8 @@ pub fn charge_shot_power(
         return;
     }
 
-    if keys.pressed(KeyCode::Space) {
-        turn_state.power = (turn_state.power + 0.02).min(1.0);
+    if keys.pressed(settings.keys.shoot) {
+        turn_state.power = (turn_state.power + settings.charge_step).min(1.0);
     }
 }
 
@@ -161,18 +171,19 @@ use bevy_rapier2d::prelude::{RigidBody, Velocity, Sleeping};   // ① importa Sl
 
 pub fn fire_selected_disk(
     keys: Res<Input<KeyCode>>,
+    settings: Res<GameSettings>,
     my_turn: Res<MyTurn>,
     mut turn_state: ResMut<TurnState>,
     // ② ahora pedimos también el Entity
     mut velocities: Query<(Entity, &mut Velocity), With<TurnControlled>>,
     mut commands: Commands,
 ) {
-    if !my_turn.0 || !keys.just_released(KeyCode::Space) || turn_state.in_motion {
+    if !my_turn.0 || !keys.just_released(settings.keys.shoot) || turn_state.in_motion {
         return;
     }
 
     let dir   = turn_state.aim_direction.normalize_or_zero();
-    let speed = turn_state.power * 800.0;
+    let speed = turn_state.power * SHOT_MAX_SPEED;
 
     let mut any_fired = false;
 
@@ -4,6 +4,8 @@ use bevy::ui::{BackgroundColor, Interaction};
 use crate::components::*;
 use crate::resources::*;
 use crate::formation_selection::SelectionButton;
+use crate::i18n::t;
+use crate::settings::GameSettings;
 
 /* ───────────────────────── HUD dinámico ───────────────────────── */
 
@@ -11,18 +13,35 @@ use crate::formation_selection::SelectionButton;
 
 use crate::resources::BackendInfo;
 
+/// El texto sale de `TurnState` (de quién es el turno), no de
+/// `CurrentPlayerId` (quién maneja el input): contra la IA el humano
+/// conserva el input mientras mueve la máquina.
 pub fn update_turn_text(
-    current_player_id: Res<CurrentPlayerId>,
+    turn_state: Res<TurnState>,
     backend_info: Res<BackendInfo>,
+    mode: Res<GameMode>,
+    names: Res<PlayerNames>,
+    settings: Res<GameSettings>,
     mut query: Query<&mut Text, With<TurnText>>,
 ) {
-    if current_player_id.is_changed() {
-        for mut text in &mut query {
-            if current_player_id.0 == backend_info.my_uid {
-                text.sections[0].value = "Tu turno".to_string();
-            } else {
-                text.sections[0].value = format!("Turno del rival (UID {})", current_player_id.0);
-            }
+    // También al cambiar de idioma desde los ajustes
+    if !turn_state.is_changed() && !settings.is_changed() {
+        return;
+    }
+    let turn = turn_state.current_turn_id;
+    let msg = if !mode.is_online() {
+        // 🪑 Local: nombre del lado que mueve (humano o IA)
+        let name = if turn == backend_info.id_left { &names.left_name } else { &names.right_name };
+        t!("turno-de", nombre = name.as_str())
+    } else if turn == backend_info.my_uid {
+        t!("turno-tuyo")
+    } else {
+        t!("turno-rival", uid = turn)
+    };
+
+    for mut text in &mut query {
+        if text.sections[0].value != msg {
+            text.sections[0].value = msg.clone();
         }
     }
 }
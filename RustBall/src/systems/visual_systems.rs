@@ -5,17 +5,89 @@ use bevy::gizmos::gizmos::Gizmos;
 
 use crate::components::*;
 use crate::resources::*;
+use crate::settings::GameSettings;
+use crate::systems::turn_systems::SHOT_MAX_SPEED;
+use crate::trajectory::{predict_shot, SimBody, DISK_RADIUS};
 
+/// Rebotes contra paredes que se muestran en la predicción.
+pub const PREVIEW_BOUNCES: usize = 2;
+
+/// Dibuja la dirección de apuntado y, si las reglas y los ajustes lo
+/// permiten y hay potencia cargada, la trayectoria predicha del disparo con sus primeros
+/// rebotes y hacia dónde saldría empujado lo que golpee.
 pub fn draw_aim_direction_gizmo(
     mut gizmos: Gizmos,
     turn_state: Res<TurnState>,
+    rules: Res<MatchRules>,
+    settings: Res<GameSettings>,
     query: Query<&Transform, With<TurnControlled>>,
+    bodies: Query<(Entity, &Transform, Option<&Ball>), Or<(With<PlayerDisk>, With<Ball>)>>,
+) {
+    let Some(entity) = turn_state.selected_entity else { return };
+    let Ok(transform) = query.get(entity) else { return };
+
+    let start = transform.translation.truncate();
+    let dir = turn_state.aim_direction.normalize_or_zero();
+
+    if !rules.allows_trajectory_preview() || !settings.show_trajectory || turn_state.power <= 0.0 || dir == Vec2::ZERO {
+        let end = start + turn_state.aim_direction * 100.0;
+        gizmos.line_2d(start, end, Color::YELLOW);
+        return;
+    }
+
+    let others: Vec<SimBody> = bodies
+        .iter()
+        .filter(|(e, _, _)| *e != entity)
+        .map(|(e, tf, ball)| {
+            let pos = tf.translation.truncate();
+            if ball.is_some() { SimBody::ball(Some(e), pos) } else { SimBody::disk(Some(e), pos) }
+        })
+        .collect();
+
+    let prediction = predict_shot(
+        SimBody::disk(Some(entity), start),
+        dir * turn_state.power * SHOT_MAX_SPEED,
+        &others,
+        PREVIEW_BOUNCES,
+    );
+
+    // 🟡 Recorrido de la ficha y sus rebotes
+    gizmos.linestrip_2d(prediction.path.iter().copied(), Color::YELLOW);
+    for &bounce in &prediction.bounces {
+        gizmos.circle_2d(bounce, 6.0, Color::ORANGE);
+    }
+
+    // 🟢 Primer contacto y hacia dónde sale el cuerpo golpeado
+    if let Some(contact) = &prediction.contact {
+        gizmos.circle_2d(contact.point, DISK_RADIUS, Color::YELLOW.with_a(0.5));
+
+        let color = if contact.target.is_ball { Color::GREEN } else { Color::ORANGE_RED };
+        gizmos.linestrip_2d(contact.pushed_path.iter().copied(), color);
+        if let Some(&end) = contact.pushed_path.last() {
+            gizmos.circle_2d(end, contact.target.radius, color.with_a(0.6));
+        }
+    }
+}
+
+/// Colores para daltónicos: azul y círculo a la izquierda, naranja y
+/// cuadrado a la derecha (paleta Okabe-Ito), además de los escudos.
+pub fn draw_colorblind_team_markers(
+    mut gizmos: Gizmos,
+    settings: Res<GameSettings>,
+    disks: Query<(&Transform, &DiskId), With<PlayerDisk>>,
 ) {
-    if let Some(entity) = turn_state.selected_entity {
-        if let Ok(transform) = query.get(entity) {
-            let start = transform.translation.truncate();
-            let end = start + turn_state.aim_direction * 100.0;
-            gizmos.line_2d(start, end, Color::YELLOW);
+    if !settings.colorblind {
+        return;
+    }
+
+    let left = Color::rgb(0.0, 0.447, 0.698);
+    let right = Color::rgb(0.902, 0.624, 0.0);
+    for (tf, id) in &disks {
+        let pos = tf.translation.truncate();
+        if id.0 < DiskId::PER_SIDE {
+            gizmos.circle_2d(pos, DISK_RADIUS + 4.0, left);
+        } else {
+            gizmos.rect_2d(pos, 0.0, Vec2::splat((DISK_RADIUS + 4.0) * 2.0), right);
         }
     }
 }
@@ -0,0 +1,103 @@
+//! src/trajectory.rs
+//! --------------------------------------------------------------
+//! Predicción simplificada de la trayectoria de un disparo.
+//!
+//! La simulación vive en `rustball_shared::sim` (la misma que usa el
+//! bot del servidor); aquí sólo se traduce a tipos de Bevy y se
+//! recuerda a qué entidad corresponde cada cuerpo.
+//!
+//!   • Las esquinas rotadas y los postes de los arcos se ignoran.
+//!   • Las masas replican las de Rapier (densidad 1 + masa extra 1).
+//! --------------------------------------------------------------
+
+use bevy::prelude::*;
+use rustball_shared::{sim, V2};
+
+pub use rustball_shared::field::DISK_RADIUS;
+
+/// Cuerpo circular participante en la simulación.
+#[derive(Clone, Copy, Debug)]
+pub struct SimBody {
+    pub entity: Option<Entity>,
+    pub pos: Vec2,
+    pub radius: f32,
+    pub restitution: f32,
+    pub is_ball: bool,
+}
+
+impl SimBody {
+    pub fn disk(entity: Option<Entity>, pos: Vec2) -> Self {
+        Self::from_shared(entity, sim::Body::disk(to_v2(pos)))
+    }
+
+    pub fn ball(entity: Option<Entity>, pos: Vec2) -> Self {
+        Self::from_shared(entity, sim::Body::ball(to_v2(pos)))
+    }
+
+    fn from_shared(entity: Option<Entity>, body: sim::Body) -> Self {
+        Self {
+            entity,
+            pos: to_vec2(body.pos),
+            radius: body.radius,
+            restitution: body.restitution,
+            is_ball: body.is_ball,
+        }
+    }
+
+    pub fn shared(&self) -> sim::Body {
+        sim::Body { pos: to_v2(self.pos), radius: self.radius, restitution: self.restitution, is_ball: self.is_ball }
+    }
+}
+
+/// Primer contacto del disparo con otro cuerpo.
+#[derive(Clone, Debug)]
+pub struct ShotContact {
+    /// Posición de la ficha disparada en el momento del choque.
+    pub point: Vec2,
+    pub target: SimBody,
+    /// Velocidad con la que sale despedido el cuerpo golpeado.
+    pub push_velocity: Vec2,
+    /// Recorrido estimado del cuerpo golpeado (sólo contra paredes).
+    pub pushed_path: Vec<Vec2>,
+}
+
+/// Resultado de `predict_shot`.
+#[derive(Clone, Debug, Default)]
+pub struct ShotPrediction {
+    /// Origen, puntos de rebote y punto final de la ficha.
+    pub path: Vec<Vec2>,
+    pub bounces: Vec<Vec2>,
+    pub contact: Option<ShotContact>,
+}
+
+/// Simula el disparo de `shooter` con `velocity` inicial
+/// (ver `rustball_shared::sim::predict_shot`).
+pub fn predict_shot(
+    shooter: SimBody,
+    velocity: Vec2,
+    others: &[SimBody],
+    max_bounces: usize,
+) -> ShotPrediction {
+    let shared: Vec<sim::Body> = others.iter().map(SimBody::shared).collect();
+    let prediction = sim::predict_shot(shooter.shared(), to_v2(velocity), &shared, max_bounces);
+
+    let path = |p: &[V2]| p.iter().copied().map(to_vec2).collect::<Vec<_>>();
+    ShotPrediction {
+        path: path(&prediction.path),
+        bounces: path(&prediction.bounces),
+        contact: prediction.contact.map(|c| ShotContact {
+            point: to_vec2(c.point),
+            target: others[c.index],
+            push_velocity: to_vec2(c.push_velocity),
+            pushed_path: path(&c.pushed_path),
+        }),
+    }
+}
+
+pub fn to_v2(v: Vec2) -> V2 {
+    V2::from(v.to_array())
+}
+
+pub fn to_vec2(v: V2) -> Vec2 {
+    Vec2::from_array(v.into())
+}
@@ -3,6 +3,7 @@ use bevy_rapier2d::prelude::*;
 use crate::components::PlayerDisk;
 use crate::resources::{TurnState, EventControl};
 use crate::events::RandomEvent;
+use crate::i18n::t;
 
 #[derive(Component)]
 pub struct SlipperyZone;
@@ -13,7 +14,7 @@ pub struct BouncePad;
 #[derive(Component)]
 pub struct ActiveEffectText;
 
-#[derive(Component)]
+#[derive(Component, Clone, Copy, Debug)]
 pub struct ZoneLifetime {
     pub turns_remaining: u8,
     pub last_turn_owner: u8,
@@ -21,7 +22,7 @@ pub struct ZoneLifetime {
 
 // === SPAWNS ===
 
-pub fn spawn_slippery_zone(commands: &mut Commands, position: Vec2, size: Vec2, current_turn: u8) {
+pub fn spawn_slippery_zone(commands: &mut Commands, position: Vec2, size: Vec2, current_turn: u8) -> Entity {
     commands.spawn((
         SlipperyZone,
         ZoneLifetime {
@@ -40,10 +41,10 @@ pub fn spawn_slippery_zone(commands: &mut Commands, position: Vec2, size: Vec2,
         Collider::cuboid(size.x / 2.0, size.y / 2.0),
         Friction::coefficient(0.01),
         Sensor,
-    ));
+    )).id()
 }
 
-pub fn spawn_slow_zone(commands: &mut Commands, position: Vec2, size: Vec2, current_turn: u8) {
+pub fn spawn_slow_zone(commands: &mut Commands, position: Vec2, size: Vec2, current_turn: u8) -> Entity {
     commands.spawn((
         SlowZone,
         ZoneLifetime {
@@ -62,10 +63,10 @@ pub fn spawn_slow_zone(commands: &mut Commands, position: Vec2, size: Vec2, curr
         Collider::cuboid(size.x / 2.0, size.y / 2.0),
         Friction::coefficient(1.5),
         Sensor,
-    ));
+    )).id()
 }
 
-pub fn spawn_bounce_pad(commands: &mut Commands, position: Vec2, size: Vec2, current_turn: u8) {
+pub fn spawn_bounce_pad(commands: &mut Commands, position: Vec2, size: Vec2, current_turn: u8) -> Entity {
     commands.spawn((
         BouncePad,
         ZoneLifetime {
@@ -84,7 +85,24 @@ pub fn spawn_bounce_pad(commands: &mut Commands, position: Vec2, size: Vec2, cur
         Collider::cuboid(size.x / 2.0, size.y / 2.0),
         Restitution::coefficient(2.5),
         Sensor,
-    ));
+    )).id()
+}
+
+/// Recrea una zona tal como venía en un snapshot, con la vida que le quedaba.
+pub fn spawn_zone(
+    commands: &mut Commands,
+    kind: RandomEvent,
+    position: Vec2,
+    size: Vec2,
+    lifetime: ZoneLifetime,
+) -> Entity {
+    let entity = match kind {
+        RandomEvent::SlipperyZone => spawn_slippery_zone(commands, position, size, lifetime.last_turn_owner),
+        RandomEvent::SlowZone => spawn_slow_zone(commands, position, size, lifetime.last_turn_owner),
+        RandomEvent::BouncePad => spawn_bounce_pad(commands, position, size, lifetime.last_turn_owner),
+    };
+    commands.entity(entity).insert(lifetime);
+    entity
 }
 
 // === LIFETIME ===
@@ -158,14 +176,16 @@ pub fn update_active_effect_text(
     asset_server: Res<AssetServer>,
 ) {
     let mensaje = match control.current_event {
-        Some(RandomEvent::SlipperyZone) => "Efecto actual: Zona resbalosa",
-        Some(RandomEvent::SlowZone) => "Efecto actual: Zona lenta",
-        Some(RandomEvent::BouncePad) => "Efecto actual: Trampolín",
-        None => "",
+        Some(RandomEvent::SlipperyZone) => t!("efecto-resbalosa"),
+        Some(RandomEvent::SlowZone) => t!("efecto-lenta"),
+        Some(RandomEvent::BouncePad) => t!("efecto-trampolin"),
+        None => String::new(),
     };
 
     if let Some((_, mut text)) = query.iter_mut().next() {
-        text.sections[0].value = mensaje.to_string();
+        if text.sections[0].value != mensaje {
+            text.sections[0].value = mensaje;
+        }
     } else if !mensaje.is_empty() {
         commands.spawn((
             TextBundle::from_section(
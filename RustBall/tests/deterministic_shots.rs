@@ -0,0 +1,115 @@
+//! Tiros grabados simulados sin ventana: con la física determinista el
+//! mismo tablero y el mismo tiro deben dar exactamente el mismo tablero
+//! final, byte a byte, también tras pasar por JSON como en `Turno.jugada`.
+//!
+//! El tablero final de la secuencia grabada se compara además con
+//! `tests/fixtures/recorded_sequence.json`, así un cambio de versión de
+//! rapier, de compilador o de plataforma que mueva un bit se nota.  El
+//! feature `deterministic` (`enhanced-determinism`) lo activa la
+//! dev-dependency de `Cargo.toml`, así que corre con `cargo test` a secas.
+//!
+//! Tras un cambio de física intencionado se regenera con
+//!
+//!   RUSTBALL_BLESS=1 cargo test --test deterministic_shots
+
+use rustball::formation::board_from_formations;
+use rustball::physics::simulate_shot;
+use rustball::resources::BackendInfo;
+use rustball::snapshot::{BoardSnapshot, FormacionData, ShotInput};
+
+const LEFT: i32 = 1;
+const RIGHT: i32 = 2;
+
+fn kickoff_board() -> BoardSnapshot {
+    let formaciones = [
+        FormacionData { id_usuario: LEFT, formacion: "1-2-1-1".into(), turno_inicio: 1, posiciones: None },
+        FormacionData { id_usuario: RIGHT, formacion: "2-2-1".into(), turno_inicio: 1, posiciones: None },
+    ];
+    board_from_formations(&formaciones, &BackendInfo::new(0, LEFT, LEFT, RIGHT))
+}
+
+fn shot(board: &BoardSnapshot, id_ficha: u32, dir: (f32, f32), potencia: f32) -> ShotInput {
+    let len = (dir.0 * dir.0 + dir.1 * dir.1).sqrt();
+    ShotInput {
+        id_ficha,
+        dir_x: dir.0 / len,
+        dir_y: dir.1 / len,
+        potencia,
+        powerups: Vec::new(),
+        tablero_previo: Box::new(board.clone()),
+    }
+}
+
+/// Tiros grabados: ficha, dirección y potencia.
+fn recorded_shots() -> Vec<(u32, (f32, f32), f32)> {
+    vec![
+        (0, (1.0, 0.0), 1.0),
+        (5, (-1.0, 0.2), 0.8),
+        (1, (0.7, -0.7), 0.55),
+        (7, (-0.3, 1.0), 1.0),
+        (2, (1.0, 0.05), 0.35),
+    ]
+}
+
+fn bytes(board: &BoardSnapshot) -> Vec<u8> {
+    serde_json::to_vec(board).unwrap()
+}
+
+#[test]
+fn same_shot_gives_identical_board() {
+    let board = kickoff_board();
+    let input = shot(&board, 0, (1.0, 0.0), 1.0);
+
+    let a = simulate_shot(&board, &input);
+    let b = simulate_shot(&board, &input);
+
+    assert_eq!(bytes(&a), bytes(&b));
+    assert_ne!(bytes(&a), bytes(&board), "el tiro debería mover algo");
+}
+
+fn play_recorded() -> BoardSnapshot {
+    let mut board = kickoff_board();
+    for (id, dir, potencia) in recorded_shots() {
+        let input = shot(&board, id, dir, potencia);
+        board = simulate_shot(&board, &input);
+    }
+    board
+}
+
+#[test]
+fn recorded_sequence_is_reproducible() {
+    assert_eq!(bytes(&play_recorded()), bytes(&play_recorded()));
+}
+
+#[test]
+fn recorded_sequence_matches_golden_file() {
+    let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/recorded_sequence.json");
+    let actual = serde_json::to_string_pretty(&play_recorded()).unwrap() + "\n";
+
+    if std::env::var_os("RUSTBALL_BLESS").is_some() {
+        std::fs::write(&path, &actual).unwrap();
+        return;
+    }
+
+    let golden = std::fs::read_to_string(&path).expect("falta tests/fixtures/recorded_sequence.json");
+    assert!(
+        actual == golden,
+        "el tablero final cambió respecto de {}; si el cambio de física es intencionado, regenerar con RUSTBALL_BLESS=1",
+        path.display()
+    );
+}
+
+#[test]
+fn replay_from_json_matches_original() {
+    // Quien dispara simula y guarda la jugada; el rival la recibe como JSON
+    let board = kickoff_board();
+    let mut jugada = simulate_shot(&board, &shot(&board, 5, (-1.0, 0.2), 0.8));
+    jugada.tiro = Some(shot(&board, 5, (-1.0, 0.2), 0.8));
+
+    let recibida: BoardSnapshot = serde_json::from_str(&serde_json::to_string(&jugada).unwrap()).unwrap();
+    let tiro = recibida.tiro.clone().unwrap();
+    let mut repetida = simulate_shot(&tiro.tablero_previo, &tiro);
+    repetida.tiro = recibida.tiro.clone();
+
+    assert_eq!(bytes(&repetida), bytes(&recibida));
+}
@@ -0,0 +1,105 @@
+# API error messages (English)
+
+error-interno = Internal server error
+error-snapshot = Could not build the snapshot
+
+credenciales-invalidas = Invalid credentials
+estadisticas-no-encontradas = No statistics found for this user
+partida-no-encontrada = Match { $partida } not found
+no-juega-partida = User { $usuario } is not playing match { $partida }
+idioma-desconocido = Unknown language: { $codigo }
+partida-terminada = Match { $partida } is already over
+usuario-no-encontrado = User { $usuario } not found
+usuario-existente = That username or email is already registered
+
+## Turns and rounds
+
+ronda-no-empezada = Round { $ronda } has not started yet
+ronda-ya-empezada = Round { $ronda } already started; formations are chosen after the next goal
+fuera-de-turno = It is not user { $usuario }'s turn. Current turn: { $turno }
+turno-duplicado = Duplicate turn number
+saque-de-otro = Round { $ronda } kickoff belongs to user { $usuario }
+saque-invalido = Invalid kickoff: an opponent disk is not on its kickoff spot ({ $x }, { $y })
+saque-sin-tiro = Invalid kickoff: the shot with the previous board is missing
+saque-fichas-rival = Invalid kickoff: the opponent has { $tiene } disks on the previous board (expected { $esperadas })
+gol-repetido = The goal for round { $ronda } is already recorded
+
+## Formations
+
+formacion-desconocida = Unknown formation: { $nombre }
+formacion-ajena = Formation { $id } is not yours or does not exist
+formacion-corrupta = Stored formation is corrupt: { $detalle }
+formacion-no-encontrada = Formation not found
+formacion-cantidad = A formation needs { $esperadas } disks (it has { $tiene })
+ficha-coordenadas = Disk { $n }: invalid coordinates
+ficha-campo = Disk { $n }: must be fully inside your half
+ficha-fuera = Disk { $n }: is outside the pitch
+ficha-area = Disk { $n }: cannot be inside the box
+fichas-superpuestas = Disks { $a } and { $b }: overlap
+nombre-longitud = The name must be between 1 and { $max } characters
+nombre-predefinida = '{ $nombre }' is the name of a built-in formation
+maximo-formaciones = At most { $max } formations per user
+
+## Friends and challenges
+
+amistad-consigo-mismo = You cannot add yourself as a friend
+solicitud-no-encontrada = There is no pending friend request from that user
+amistad-no-encontrada = You have no friendship or request with that user
+reto-consigo-mismo = You cannot challenge yourself
+reto-no-encontrado = Challenge { $reto } not found
+reto-no-pendiente = Challenge { $reto } is no longer pending
+reto-ajeno = Challenge { $reto } does not belong to user { $usuario }
+
+## Tournaments
+
+torneo-no-encontrado = Tournament { $torneo } not found
+torneo-formato = Unknown tournament format: { $formato } (use eliminacion or liga)
+torneo-participantes = A tournament needs between { $min } and { $max } participants
+torneo-repetidos = Some participants are repeated
+
+## Seasons
+
+temporada-no-encontrada = Season { $temporada } not found
+temporada-sin-activa = There is no season in progress
+
+## Correspondence
+
+plazo-invalido = The turn window must be between 1 and { $maximo } hours
+
+## Notifications
+
+notif-webhook-invalido = The webhook URL must start with http:// or https:// and be at most { $max } characters long
+notif-webhook-interno = The webhook URL must point to a public address (not localhost or a private network)
+notif-reto-asunto = { $rival } challenged you on RustBall
+notif-reto-cuerpo = { $rival } challenged you to a match. Open the lobby to accept or decline.
+notif-reto-correspondencia = It is a correspondence match: { $horas } hours per turn.
+notif-turno-asunto = Your move in match { $partida }
+notif-turno-cuerpo = It is your move in match { $partida } against { $rival }.
+notif-turno-plazo = You have until { $fecha }; otherwise you lose the match on time.
+notif-fin-asunto = Match { $partida } is over
+notif-fin-ganada = You won match { $partida } against { $rival } ({ $marcador }).
+notif-fin-perdida = You lost match { $partida } against { $rival } ({ $marcador }).
+notif-fin-empate = You drew match { $partida } against { $rival } ({ $marcador }).
+notif-fin-anulada = Match { $partida } against { $rival } was voided: nobody moved in time.
+
+## Accounts
+
+correo-invalido = The email address { $correo } is not valid
+token-invalido = The link is not valid, was already used or has expired
+correo-ya-verificado = The email address is already verified
+token-reciente = We sent you a link a moment ago; wait { $segundos } seconds before asking for another one
+contrasena-corta = The password must be at least { $minimo } characters long
+restablecer-enviado = If the address belongs to an account, you will receive a link to choose a new password
+contrasena-cambiada = Password changed; you can log in now
+notif-verificacion-asunto = Verify your email on RustBall
+notif-verificacion-cuerpo = Hi { $nombre }: open this link to verify your email address:
+
+    { $enlace }
+
+    If you did not create a RustBall account, ignore this message.
+notif-restablecer-asunto = Reset your RustBall password
+notif-restablecer-cuerpo = Hi { $nombre }: open this link to choose a new password (it works once and expires in one hour):
+
+    { $enlace }
+
+    If you did not ask for it, ignore this message: your password stays the same.
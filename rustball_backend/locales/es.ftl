@@ -0,0 +1,105 @@
+# Mensajes de error de la API (español, idioma por defecto)
+
+error-interno = Error interno del servidor
+error-snapshot = Error generando snapshot
+
+credenciales-invalidas = Credenciales inválidas
+estadisticas-no-encontradas = No se encontraron estadísticas para este usuario
+partida-no-encontrada = Partida { $partida } no encontrada
+no-juega-partida = El usuario { $usuario } no juega la partida { $partida }
+idioma-desconocido = Idioma desconocido: { $codigo }
+partida-terminada = La partida { $partida } ya terminó
+usuario-no-encontrado = Usuario { $usuario } no encontrado
+usuario-existente = El nombre de usuario o el correo ya están registrados
+
+## Turnos y rondas
+
+ronda-no-empezada = La ronda { $ronda } todavía no empezó
+ronda-ya-empezada = La ronda { $ronda } ya empezó; la formación se elige tras el próximo gol
+fuera-de-turno = No es el turno del usuario { $usuario }. Turno actual: { $turno }
+turno-duplicado = Número de turno duplicado
+saque-de-otro = El saque de la ronda { $ronda } es del usuario { $usuario }
+saque-invalido = Saque inválido: una ficha del rival no está en su posición de saque ({ $x }, { $y })
+saque-sin-tiro = Saque inválido: falta el tiro con el tablero previo
+saque-fichas-rival = Saque inválido: el rival tiene { $tiene } fichas en el tablero previo (deberían ser { $esperadas })
+gol-repetido = El gol de la ronda { $ronda } ya está registrado
+
+## Formaciones
+
+formacion-desconocida = Formación desconocida: { $nombre }
+formacion-ajena = La formación { $id } no es tuya o no existe
+formacion-corrupta = Formación guardada corrupta: { $detalle }
+formacion-no-encontrada = Formación no encontrada
+formacion-cantidad = La formación debe tener { $esperadas } fichas (tiene { $tiene })
+ficha-coordenadas = Ficha { $n }: coordenadas inválidas
+ficha-campo = Ficha { $n }: debe quedar entera en tu campo
+ficha-fuera = Ficha { $n }: queda fuera de la cancha
+ficha-area = Ficha { $n }: no puede estar dentro del área
+fichas-superpuestas = Fichas { $a } y { $b }: se superponen
+nombre-longitud = El nombre debe tener entre 1 y { $max } caracteres
+nombre-predefinida = '{ $nombre }' es el nombre de una formación predefinida
+maximo-formaciones = Máximo { $max } formaciones por usuario
+
+## Amigos y retos
+
+amistad-consigo-mismo = No puedes agregarte como amigo
+solicitud-no-encontrada = No hay una solicitud de amistad pendiente de ese usuario
+amistad-no-encontrada = No tienes amistad ni solicitud con ese usuario
+reto-consigo-mismo = No puedes retarte a ti mismo
+reto-no-encontrado = Reto { $reto } no encontrado
+reto-no-pendiente = El reto { $reto } ya no está pendiente
+reto-ajeno = El reto { $reto } no es del usuario { $usuario }
+
+## Torneos
+
+torneo-no-encontrado = Torneo { $torneo } no encontrado
+torneo-formato = Formato de torneo desconocido: { $formato } (usa eliminacion o liga)
+torneo-participantes = Un torneo necesita entre { $min } y { $max } participantes
+torneo-repetidos = Hay participantes repetidos
+
+## Temporadas
+
+temporada-no-encontrada = Temporada { $temporada } no encontrada
+temporada-sin-activa = No hay ninguna temporada en curso
+
+## Correspondencia
+
+plazo-invalido = El plazo por turno debe estar entre 1 y { $maximo } horas
+
+## Notificaciones
+
+notif-webhook-invalido = La URL del webhook debe empezar por http:// o https:// y tener como mucho { $max } caracteres
+notif-webhook-interno = La URL del webhook debe apuntar a una dirección pública (no a localhost ni a una red privada)
+notif-reto-asunto = { $rival } te ha retado en RustBall
+notif-reto-cuerpo = { $rival } te ha retado a una partida. Entra al lobby para aceptar o rechazar el reto.
+notif-reto-correspondencia = Es por correspondencia: { $horas } horas por turno.
+notif-turno-asunto = Te toca en la partida { $partida }
+notif-turno-cuerpo = Te toca mover en la partida { $partida } contra { $rival }.
+notif-turno-plazo = Tienes hasta el { $fecha }; si no, pierdes la partida por tiempo.
+notif-fin-asunto = Terminó la partida { $partida }
+notif-fin-ganada = Ganaste la partida { $partida } contra { $rival } ({ $marcador }).
+notif-fin-perdida = Perdiste la partida { $partida } contra { $rival } ({ $marcador }).
+notif-fin-empate = Empataste la partida { $partida } contra { $rival } ({ $marcador }).
+notif-fin-anulada = La partida { $partida } contra { $rival } se anuló: nadie movió a tiempo.
+
+## Cuentas
+
+correo-invalido = El correo { $correo } no es válido
+token-invalido = El enlace no es válido, ya se usó o caducó
+correo-ya-verificado = El correo ya está verificado
+token-reciente = Ya te enviamos un enlace hace poco; espera { $segundos } segundos para pedir otro
+contrasena-corta = La contraseña debe tener al menos { $minimo } caracteres
+restablecer-enviado = Si el correo corresponde a una cuenta, te llegará un enlace para elegir una contraseña nueva
+contrasena-cambiada = Contraseña cambiada; ya puedes iniciar sesión
+notif-verificacion-asunto = Verifica tu correo en RustBall
+notif-verificacion-cuerpo = Hola { $nombre }: para verificar tu correo abre este enlace:
+
+    { $enlace }
+
+    Si no creaste una cuenta en RustBall, ignora este mensaje.
+notif-restablecer-asunto = Restablecer tu contraseña de RustBall
+notif-restablecer-cuerpo = Hola { $nombre }: para elegir una contraseña nueva abre este enlace (sirve una sola vez y caduca en una hora):
+
+    { $enlace }
+
+    Si no lo pediste, ignora este mensaje: tu contraseña no cambia.
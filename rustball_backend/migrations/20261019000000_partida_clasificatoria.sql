@@ -0,0 +1,6 @@
+-- Partidas clasificatorias: reglas más estrictas en el cliente (sin
+-- predicción de trayectoria).  Lo decide el servidor al crear la partida
+-- y el cliente lo lee del snapshot.
+--   Partida.clasificatoria  TRUE en las que se piden con
+--                           `clasificatoria: true` en POST /partida
+ALTER TABLE Partida ADD COLUMN clasificatoria BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,9 @@
+-- Usuarios bot del servidor (ver src/bot.rs).
+-- La dificultad sale del nombre; la contraseña es inutilizable a propósito.
+INSERT INTO Usuario (nombre_usuario, correo, contrasena)
+SELECT * FROM (
+    SELECT 'bot_facil'   AS nombre_usuario, 'bot_facil@rustball.lat'   AS correo, '!' AS contrasena UNION ALL
+    SELECT 'bot_medio',                     'bot_medio@rustball.lat',             '!'               UNION ALL
+    SELECT 'bot_dificil',                   'bot_dificil@rustball.lat',           '!'
+) AS bots
+WHERE NOT EXISTS (SELECT 1 FROM Usuario u WHERE u.nombre_usuario = bots.nombre_usuario);
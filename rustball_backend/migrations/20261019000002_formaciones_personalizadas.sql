@@ -0,0 +1,14 @@
+-- Formaciones personalizadas por usuario (ver src/formaciones.rs).
+-- `posiciones` es un arreglo JSON de 5 objetos {x, y} en coordenadas del lado derecho.
+CREATE TABLE IF NOT EXISTS FormacionPersonalizada (
+    id_formacion   INT AUTO_INCREMENT PRIMARY KEY,
+    id_usuario     INT          NOT NULL,
+    nombre         VARCHAR(40)  NOT NULL,
+    posiciones     JSON         NOT NULL,
+    fecha_creacion TIMESTAMP    DEFAULT CURRENT_TIMESTAMP,
+    UNIQUE KEY uq_formacion_usuario_nombre (id_usuario, nombre),
+    FOREIGN KEY (id_usuario) REFERENCES Usuario(id_usuario) ON DELETE CASCADE
+);
+
+-- Copia de las posiciones elegidas para la partida (NULL = formación predefinida)
+ALTER TABLE FormacionElegida ADD COLUMN posiciones JSON NULL;
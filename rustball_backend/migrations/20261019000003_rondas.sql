@@ -0,0 +1,12 @@
+-- Rondas de saque: cada gol abre una ronda nueva con formaciones nuevas.
+--   Partida.ronda           ronda en juego (la primera es 1)
+--   Turno.ronda             ronda a la que pertenece cada jugada
+--   FormacionElegida.ronda  una formación por jugador y ronda
+ALTER TABLE Partida ADD COLUMN ronda INT NOT NULL DEFAULT 1;
+ALTER TABLE Turno ADD COLUMN ronda INT NOT NULL DEFAULT 1;
+ALTER TABLE FormacionElegida ADD COLUMN ronda INT NOT NULL DEFAULT 1;
+
+-- La clave (id_partida, id_usuario) pasa a incluir la ronda
+ALTER TABLE FormacionElegida DROP PRIMARY KEY, ADD PRIMARY KEY (id_partida, id_usuario, ronda);
+
+CREATE INDEX idx_turno_partida_ronda ON Turno (id_partida, ronda);
@@ -0,0 +1,2 @@
+-- Idioma preferido del usuario ('es', 'en'); NULL = el del navegador.
+ALTER TABLE Usuario ADD COLUMN idioma VARCHAR(5) NULL;
@@ -0,0 +1,11 @@
+-- Fin de partida y rating Elo (ver src/resultado.rs).
+--   Usuario.elo                  rating actual (todos arrancan en 1000)
+--   Partida.ganador              id del ganador; NULL mientras se juega
+--   Partida.elo_cambio_j1 / _j2  puntos que ganó o perdió cada jugador
+--   Partida.fecha_fin            cuándo se marcó el gol final
+ALTER TABLE Usuario ADD COLUMN elo INT NOT NULL DEFAULT 1000;
+ALTER TABLE Partida
+    ADD COLUMN ganador INT NULL,
+    ADD COLUMN elo_cambio_j1 INT NULL,
+    ADD COLUMN elo_cambio_j2 INT NULL,
+    ADD COLUMN fecha_fin DATETIME NULL;
@@ -0,0 +1,22 @@
+-- Eventos de cada turno para las estadísticas (ver src/eventos.rs).
+--   tipo      disparo | contacto_pelota | rebote | powerup | powerup_usado | zona | gol
+--   id_ficha  ficha involucrada (NULL en el rebote de la pelota y en el gol)
+--   valor     potencia del disparo (0‥1)
+--   detalle   tipo de power-up o de zona
+CREATE TABLE IF NOT EXISTS EventoTurno (
+    id_evento     INT AUTO_INCREMENT PRIMARY KEY,
+    id_partida    INT NOT NULL,
+    numero_turno  INT NOT NULL,
+    ronda         INT NOT NULL,
+    id_usuario    INT NOT NULL,
+    tipo          VARCHAR(20) NOT NULL,
+    id_ficha      INT NULL,
+    valor         DOUBLE NULL,
+    detalle       VARCHAR(20) NULL,
+    fecha         TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    FOREIGN KEY (id_partida) REFERENCES Partida(id_partida) ON DELETE CASCADE,
+    FOREIGN KEY (id_usuario) REFERENCES Usuario(id_usuario) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_evento_partida ON EventoTurno (id_partida, id_usuario);
+CREATE INDEX idx_evento_usuario ON EventoTurno (id_usuario, tipo);
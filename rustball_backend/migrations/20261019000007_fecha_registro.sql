@@ -0,0 +1,16 @@
+-- Fecha de alta del usuario para el perfil (ver src/perfil.rs).
+ALTER TABLE Usuario ADD COLUMN fecha_registro DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP;
+
+-- Usuarios previos: su primera partida es la mejor aproximación
+UPDATE Usuario u
+JOIN (
+    SELECT id_usuario, MIN(fecha_inicio) AS primera
+    FROM (
+        SELECT id_jugador1 AS id_usuario, fecha_inicio FROM Partida
+        UNION ALL
+        SELECT id_jugador2 AS id_usuario, fecha_inicio FROM Partida
+    ) jugadas
+    GROUP BY id_usuario
+) p ON p.id_usuario = u.id_usuario
+SET u.fecha_registro = LEAST(u.fecha_registro, p.primera)
+WHERE p.primera IS NOT NULL;
@@ -0,0 +1,32 @@
+-- Amigos y retos directos (ver src/amigos.rs y src/retos.rs).
+--   Amistad.estado   pendiente (la pidió id_solicitante) | aceptada
+--   Reto.estado      pendiente | aceptado | rechazado | expirado | cancelado
+--   Reto.id_partida  partida creada al aceptar
+--   Reto.fecha_expira  pasada esta fecha un reto pendiente se marca expirado
+CREATE TABLE IF NOT EXISTS Amistad (
+    id_solicitante  INT NOT NULL,
+    id_destinatario INT NOT NULL,
+    estado          ENUM('pendiente', 'aceptada') NOT NULL DEFAULT 'pendiente',
+    fecha           TIMESTAMP DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (id_solicitante, id_destinatario),
+    FOREIGN KEY (id_solicitante)  REFERENCES Usuario(id_usuario) ON DELETE CASCADE,
+    FOREIGN KEY (id_destinatario) REFERENCES Usuario(id_usuario) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_amistad_destinatario ON Amistad (id_destinatario, estado);
+
+CREATE TABLE IF NOT EXISTS Reto (
+    id_reto         INT AUTO_INCREMENT PRIMARY KEY,
+    id_retador      INT NOT NULL,
+    id_retado       INT NOT NULL,
+    estado          ENUM('pendiente', 'aceptado', 'rechazado', 'expirado', 'cancelado') NOT NULL DEFAULT 'pendiente',
+    id_partida      INT NULL,
+    fecha_creacion  DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    fecha_expira    DATETIME NOT NULL,
+    FOREIGN KEY (id_retador) REFERENCES Usuario(id_usuario) ON DELETE CASCADE,
+    FOREIGN KEY (id_retado)  REFERENCES Usuario(id_usuario) ON DELETE CASCADE,
+    FOREIGN KEY (id_partida) REFERENCES Partida(id_partida) ON DELETE SET NULL
+);
+
+CREATE INDEX idx_reto_retado ON Reto (id_retado, estado);
+CREATE INDEX idx_reto_retador ON Reto (id_retador, estado);
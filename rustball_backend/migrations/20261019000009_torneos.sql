@@ -0,0 +1,48 @@
+-- Torneos (ver src/torneos.rs).
+--   Torneo.formato         eliminacion (directa, con byes) | liga (todos contra todos)
+--   Torneo.ida_y_vuelta    liga: cada pareja juega dos veces
+--   Torneo.ronda_actual    ronda abierta; la siguiente se abre al terminar todas sus partidas
+--   Torneo.campeon         ganador final (NULL mientras se juega)
+--   TorneoParticipante.semilla  1 = el de más Elo al crear el torneo
+--   TorneoEncuentro        cruce de una ronda; en eliminación los jugadores de
+--                          rondas futuras se completan con los ganadores
+--                          (posicion / 2 de la ronda siguiente).  Un cruce con
+--                          un solo jugador es un bye y se gana sin partida.
+CREATE TABLE IF NOT EXISTS Torneo (
+    id_torneo      INT AUTO_INCREMENT PRIMARY KEY,
+    nombre         VARCHAR(60) NOT NULL,
+    formato        ENUM('eliminacion', 'liga') NOT NULL,
+    ida_y_vuelta   BOOLEAN NOT NULL DEFAULT FALSE,
+    estado         ENUM('en_curso', 'terminado') NOT NULL DEFAULT 'en_curso',
+    ronda_actual   INT NOT NULL DEFAULT 1,
+    id_creador     INT NOT NULL,
+    campeon        INT NULL,
+    fecha_creacion DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    fecha_fin      DATETIME NULL,
+    FOREIGN KEY (id_creador) REFERENCES Usuario(id_usuario) ON DELETE CASCADE,
+    FOREIGN KEY (campeon)    REFERENCES Usuario(id_usuario) ON DELETE SET NULL
+);
+
+CREATE TABLE IF NOT EXISTS TorneoParticipante (
+    id_torneo  INT NOT NULL,
+    id_usuario INT NOT NULL,
+    semilla    INT NOT NULL,
+    PRIMARY KEY (id_torneo, id_usuario),
+    FOREIGN KEY (id_torneo)  REFERENCES Torneo(id_torneo) ON DELETE CASCADE,
+    FOREIGN KEY (id_usuario) REFERENCES Usuario(id_usuario) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS TorneoEncuentro (
+    id_encuentro INT AUTO_INCREMENT PRIMARY KEY,
+    id_torneo    INT NOT NULL,
+    ronda        INT NOT NULL,
+    posicion     INT NOT NULL,
+    id_jugador1  INT NULL,
+    id_jugador2  INT NULL,
+    id_partida   INT NULL,
+    ganador      INT NULL,
+    UNIQUE KEY uq_encuentro (id_torneo, ronda, posicion),
+    UNIQUE KEY uq_encuentro_partida (id_partida),
+    FOREIGN KEY (id_torneo)  REFERENCES Torneo(id_torneo) ON DELETE CASCADE,
+    FOREIGN KEY (id_partida) REFERENCES Partida(id_partida) ON DELETE SET NULL
+);
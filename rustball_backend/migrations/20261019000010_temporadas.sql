@@ -0,0 +1,41 @@
+-- Temporadas (ver src/temporadas.rs).
+--   Temporada.estado                 activa (sólo una a la vez) | cerrada
+--   TemporadaJugador                 estadísticas y rating de la temporada; la fila
+--                                    se crea con la primera partida que termina en ella
+--   TemporadaJugador.elo_inicial     rating con el que empezó a jugarla
+--   TemporadaJugador.elo             rating al terminar su última partida de la temporada
+--   TemporadaJugador.posicion        puesto final; se archiva al cerrar (NULL mientras sigue)
+--   Partida.id_temporada             temporada en la que terminó la partida
+CREATE TABLE IF NOT EXISTS Temporada (
+    id_temporada INT AUTO_INCREMENT PRIMARY KEY,
+    nombre       VARCHAR(60) NOT NULL,
+    fecha_inicio DATETIME NOT NULL,
+    fecha_fin    DATETIME NOT NULL,
+    estado       ENUM('activa', 'cerrada') NOT NULL DEFAULT 'activa'
+);
+
+CREATE TABLE IF NOT EXISTS TemporadaJugador (
+    id_temporada    INT NOT NULL,
+    id_usuario      INT NOT NULL,
+    elo_inicial     INT NOT NULL,
+    elo             INT NOT NULL,
+    elo_maximo      INT NOT NULL,
+    jugadas         INT NOT NULL DEFAULT 0,
+    ganadas         INT NOT NULL DEFAULT 0,
+    empatadas       INT NOT NULL DEFAULT 0,
+    perdidas        INT NOT NULL DEFAULT 0,
+    goles_a_favor   INT NOT NULL DEFAULT 0,
+    goles_en_contra INT NOT NULL DEFAULT 0,
+    posicion        INT NULL,
+    PRIMARY KEY (id_temporada, id_usuario),
+    INDEX idx_temporada_elo (id_temporada, elo),
+    FOREIGN KEY (id_temporada) REFERENCES Temporada(id_temporada) ON DELETE CASCADE,
+    FOREIGN KEY (id_usuario)   REFERENCES Usuario(id_usuario) ON DELETE CASCADE
+);
+
+ALTER TABLE Partida ADD COLUMN id_temporada INT NULL;
+
+-- Primera temporada: empieza ya y dura lo mismo que las siguientes
+INSERT INTO Temporada (nombre, fecha_inicio, fecha_fin)
+SELECT 'Temporada 1', NOW(), NOW() + INTERVAL 90 DAY
+WHERE NOT EXISTS (SELECT 1 FROM Temporada);
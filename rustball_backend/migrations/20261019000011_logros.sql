@@ -0,0 +1,18 @@
+-- Logros desbloqueados (ver src/logros.rs).
+--   codigo            primera_victoria | hat_trick | porteria_a_cero | gol_de_poste
+--                     | ganar_con | coleccionista
+--   detalle           formación de `ganar_con` (hay uno por formación); '' en el resto
+--   id_partida        partida en la que se desbloqueó
+--   fecha_desbloqueo  cuándo
+-- EventoTurno.detalle = 'poste': en un rebote, fue contra un poste; en un gol, la
+--                              pelota rebotó en un poste antes de entrar.
+CREATE TABLE IF NOT EXISTS LogroUsuario (
+    id_usuario       INT NOT NULL,
+    codigo           VARCHAR(30) NOT NULL,
+    detalle          VARCHAR(20) NOT NULL DEFAULT '',
+    id_partida       INT NULL,
+    fecha_desbloqueo DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (id_usuario, codigo, detalle),
+    FOREIGN KEY (id_usuario) REFERENCES Usuario(id_usuario) ON DELETE CASCADE,
+    FOREIGN KEY (id_partida) REFERENCES Partida(id_partida) ON DELETE SET NULL
+);
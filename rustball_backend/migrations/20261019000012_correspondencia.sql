@@ -0,0 +1,17 @@
+-- Partidas por correspondencia (ver src/correspondencia.rs).
+--   Partida.modo               tiempo_real | correspondencia
+--   Partida.plazo_turno_horas  horas que tiene cada uno para mover (NULL en tiempo real)
+--   Partida.fecha_limite       cuándo vence la espera actual (formación o tiro); se
+--                              renueva cada vez que la partida pasa a esperar a alguien
+--   Reto.plazo_turno_horas     si no es NULL, el reto crea una partida por correspondencia
+--   Torneo.plazo_turno_horas   plazo de sus partidas, que siempre son por correspondencia
+ALTER TABLE Partida
+    ADD COLUMN modo              ENUM('tiempo_real', 'correspondencia') NOT NULL DEFAULT 'tiempo_real',
+    ADD COLUMN plazo_turno_horas INT NULL,
+    ADD COLUMN fecha_limite      DATETIME NULL;
+
+CREATE INDEX idx_partida_limite ON Partida (modo, estado, fecha_limite);
+
+ALTER TABLE Reto ADD COLUMN plazo_turno_horas INT NULL;
+
+ALTER TABLE Torneo ADD COLUMN plazo_turno_horas INT NOT NULL DEFAULT 48;
@@ -0,0 +1,35 @@
+-- Avisos por correo y webhook (ver src/notificaciones.rs).
+--   PreferenciaNotificacion            sin fila: todos los eventos, sólo por correo
+--   PreferenciaNotificacion.webhook_url  si no es NULL, también se avisa por POST
+--   Notificacion                       outbox: un aviso por canal, ya traducido
+--   Notificacion.estado                pendiente | enviada | fallida (agotó los intentos)
+--   Notificacion.proximo_intento       no se entrega antes de esta fecha (reintentos)
+CREATE TABLE IF NOT EXISTS PreferenciaNotificacion (
+    id_usuario        INT PRIMARY KEY,
+    por_correo        BOOLEAN NOT NULL DEFAULT TRUE,
+    webhook_url       VARCHAR(255) NULL,
+    reto              BOOLEAN NOT NULL DEFAULT TRUE,
+    turno             BOOLEAN NOT NULL DEFAULT TRUE,
+    partida_terminada BOOLEAN NOT NULL DEFAULT TRUE,
+    FOREIGN KEY (id_usuario) REFERENCES Usuario(id_usuario) ON DELETE CASCADE
+);
+
+CREATE TABLE IF NOT EXISTS Notificacion (
+    id_notificacion INT AUTO_INCREMENT PRIMARY KEY,
+    id_usuario      INT NOT NULL,
+    evento          ENUM('reto', 'turno', 'partida_terminada') NOT NULL,
+    canal           ENUM('correo', 'webhook') NOT NULL,
+    destino         VARCHAR(255) NOT NULL,
+    asunto          VARCHAR(200) NOT NULL,
+    cuerpo          TEXT NOT NULL,
+    datos           JSON NOT NULL,
+    estado          ENUM('pendiente', 'enviada', 'fallida') NOT NULL DEFAULT 'pendiente',
+    intentos        INT NOT NULL DEFAULT 0,
+    ultimo_error    VARCHAR(500) NULL,
+    proximo_intento DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    fecha_creacion  DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    fecha_envio     DATETIME NULL,
+    FOREIGN KEY (id_usuario) REFERENCES Usuario(id_usuario) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_notificacion_cola ON Notificacion (estado, proximo_intento);
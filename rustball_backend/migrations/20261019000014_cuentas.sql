@@ -0,0 +1,22 @@
+-- Verificación de correo y restablecimiento de contraseña (ver src/cuentas.rs).
+--   Usuario.correo_verificado  el usuario abrió el enlace de verificación
+--   TokenCuenta                enlaces de un solo uso que caducan en fecha_expira
+--   TokenCuenta.fecha_uso      no NULL una vez usado
+-- Los usuarios que ya existían quedan sin verificar: los avisos por correo
+-- esperan a que verifiquen (pueden pedir el enlace desde el perfil).
+ALTER TABLE Usuario ADD COLUMN correo_verificado BOOLEAN NOT NULL DEFAULT FALSE;
+
+CREATE TABLE IF NOT EXISTS TokenCuenta (
+    token          CHAR(64) PRIMARY KEY,
+    id_usuario     INT NOT NULL,
+    tipo           ENUM('verificacion', 'restablecer') NOT NULL,
+    fecha_creacion DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    fecha_expira   DATETIME NOT NULL,
+    fecha_uso      DATETIME NULL,
+    FOREIGN KEY (id_usuario) REFERENCES Usuario(id_usuario) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_token_usuario ON TokenCuenta (id_usuario, tipo);
+
+ALTER TABLE Notificacion
+    MODIFY evento ENUM('reto', 'turno', 'partida_terminada', 'verificacion', 'restablecer') NOT NULL;
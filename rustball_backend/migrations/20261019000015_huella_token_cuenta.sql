@@ -0,0 +1,7 @@
+-- Los tokens de cuenta se guardan como SHA-256 (ver src/cuentas.rs).
+--   TokenCuenta.huella  hex del SHA-256 del token que va en el enlace
+-- Los enlaces ya enviados quedan sin valor: se borran y quien los
+-- necesite pide otro (reenviar la verificación o restablecer de nuevo).
+DELETE FROM TokenCuenta;
+
+ALTER TABLE TokenCuenta RENAME COLUMN token TO huella;
@@ -0,0 +1,285 @@
+// -----------------------------------------------------------------
+//! Amigos: búsqueda por nombre, solicitudes y lista de amigos.
+//!
+//!   • Una fila de `Amistad` por pareja: `pendiente` mientras el
+//!     destinatario no la acepta, `aceptada` después.  Si los dos se
+//!     piden amistad a la vez, la segunda solicitud acepta la primera.
+//!   • `DELETE /amigos/:u/:amigo` sirve para quitar un amigo, rechazar
+//!     una solicitud recibida o cancelar una enviada.
+//!   • Cada cambio se avisa al otro por el WebSocket del lobby
+//!     (`routes::lobby::notificar`).
+//!   • La lista de amigos trae la presencia de cada uno (ver `presencia.rs`).
+// -----------------------------------------------------------------
+
+use axum::{
+    extract::{Extension, Path, Query},
+    http::StatusCode,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sqlx::MySqlPool;
+
+use crate::i18n::{tr, Idioma};
+use crate::models::AmistadPayload;
+use crate::presencia::{presencia, Presencia};
+use crate::routes::lobby::notificar;
+
+/// Resultados de la búsqueda por nombre.
+const MAX_BUSQUEDA: i64 = 20;
+
+/// Datos públicos de otro usuario (sin correo).
+#[derive(Debug, Serialize)]
+pub struct UsuarioPublico {
+    pub id_usuario: i32,
+    pub nombre_usuario: String,
+    pub elo: i32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Amigo {
+    #[serde(flatten)]
+    pub usuario: UsuarioPublico,
+    pub presencia: Presencia,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ListaAmigos {
+    pub amigos: Vec<Amigo>,
+    /// Solicitudes que el usuario tiene que responder.
+    pub recibidas: Vec<UsuarioPublico>,
+    /// Solicitudes del usuario todavía sin respuesta.
+    pub enviadas: Vec<UsuarioPublico>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BusquedaQuery {
+    /// Principio del nombre de usuario.
+    pub q: String,
+    /// Quien busca (se excluye de los resultados).
+    #[serde(default)]
+    pub u: Option<i32>,
+}
+
+async fn nombre_de(pool: &MySqlPool, id_usuario: i32) -> Result<Option<String>, sqlx::Error> {
+    sqlx::query_scalar!("SELECT nombre_usuario FROM Usuario WHERE id_usuario = ?", id_usuario)
+        .fetch_optional(pool)
+        .await
+}
+
+/// Patrón `LIKE` de prefijo; `%` y `_` del texto se buscan literalmente.
+fn patron_busqueda(texto: &str) -> String {
+    format!("{}%", texto.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_"))
+}
+
+// GET /usuarios/buscar?q=nom&u=ID
+pub async fn get_buscar_usuarios(
+    idioma: Idioma,
+    Query(q): Query<BusquedaQuery>,
+    Extension(pool): Extension<MySqlPool>,
+) -> Result<Json<Vec<UsuarioPublico>>, (StatusCode, String)> {
+    let texto = q.q.trim();
+    if texto.is_empty() {
+        return Ok(Json(Vec::new()));
+    }
+
+    let patron = patron_busqueda(texto);
+
+    let usuarios = sqlx::query_as!(
+        UsuarioPublico,
+        r#"
+        SELECT id_usuario, nombre_usuario, elo
+        FROM   Usuario
+        WHERE  nombre_usuario LIKE ?
+          AND  id_usuario <> COALESCE(?, 0)
+        ORDER  BY nombre_usuario
+        LIMIT  ?
+        "#,
+        patron,
+        q.u,
+        MAX_BUSQUEDA
+    )
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("❌ SQL en get_buscar_usuarios: {e}");
+            (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+        })?;
+
+    Ok(Json(usuarios))
+}
+
+// GET /amigos/:u
+pub async fn get_amigos(
+    idioma: Idioma,
+    Path(id_usuario): Path<i32>,
+    Extension(pool): Extension<MySqlPool>,
+) -> Result<Json<ListaAmigos>, (StatusCode, String)> {
+    let filas = sqlx::query!(
+        r#"
+        SELECT u.id_usuario, u.nombre_usuario, u.elo,
+               a.estado AS "estado!: String",
+               (a.id_solicitante = ?) AS "enviada!: bool"
+        FROM   Amistad a
+        JOIN   Usuario u ON u.id_usuario = IF(a.id_solicitante = ?, a.id_destinatario, a.id_solicitante)
+        WHERE  a.id_solicitante = ? OR a.id_destinatario = ?
+        ORDER  BY u.nombre_usuario
+        "#,
+        id_usuario,
+        id_usuario,
+        id_usuario,
+        id_usuario
+    )
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("❌ SQL en get_amigos: {e}");
+            (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+        })?;
+
+    let mut lista = ListaAmigos { amigos: Vec::new(), recibidas: Vec::new(), enviadas: Vec::new() };
+    for f in filas {
+        let usuario = UsuarioPublico { id_usuario: f.id_usuario, nombre_usuario: f.nombre_usuario, elo: f.elo };
+        match (f.estado.as_str(), f.enviada) {
+            ("aceptada", _) => lista.amigos.push(Amigo { presencia: presencia(usuario.id_usuario), usuario }),
+            (_, true) => lista.enviadas.push(usuario),
+            (_, false) => lista.recibidas.push(usuario),
+        }
+    }
+    Ok(Json(lista))
+}
+
+// POST /amigos/solicitud
+pub async fn post_solicitud_amistad(
+    idioma: Idioma,
+    Extension(pool): Extension<MySqlPool>,
+    Json(payload): Json<AmistadPayload>,
+) -> Result<Json<&'static str>, (StatusCode, String)> {
+    let AmistadPayload { id_usuario, id_amigo } = payload;
+    let error_sql = |e: sqlx::Error| {
+        tracing::error!("❌ SQL en post_solicitud_amistad: {e}");
+        (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+    };
+
+    if id_usuario == id_amigo {
+        return Err((StatusCode::BAD_REQUEST, tr!(idioma, "amistad-consigo-mismo")));
+    }
+    let nombre = nombre_de(&pool, id_usuario)
+        .await
+        .map_err(error_sql)?
+        .ok_or_else(|| (StatusCode::NOT_FOUND, tr!(idioma, "usuario-no-encontrado", usuario = id_usuario)))?;
+    if nombre_de(&pool, id_amigo).await.map_err(error_sql)?.is_none() {
+        return Err((StatusCode::NOT_FOUND, tr!(idioma, "usuario-no-encontrado", usuario = id_amigo)));
+    }
+
+    // ¿El otro ya me la había pedido?  Entonces esto la acepta
+    let aceptada = sqlx::query!(
+        "UPDATE Amistad SET estado = 'aceptada' WHERE id_solicitante = ? AND id_destinatario = ?",
+        id_amigo,
+        id_usuario
+    )
+        .execute(&pool)
+        .await
+        .map_err(error_sql)?
+        .rows_affected()
+        > 0;
+
+    if aceptada {
+        notificar(id_amigo, json!({ "tipo": "amistad_aceptada", "id_usuario": id_usuario, "nombre_usuario": nombre }));
+        return Ok(Json("Amistad aceptada"));
+    }
+
+    let nueva = sqlx::query!(
+        "INSERT IGNORE INTO Amistad (id_solicitante, id_destinatario) VALUES (?, ?)",
+        id_usuario,
+        id_amigo
+    )
+        .execute(&pool)
+        .await
+        .map_err(error_sql)?
+        .rows_affected()
+        > 0;
+
+    if nueva {
+        tracing::info!("🤝 Solicitud de amistad {id_usuario} → {id_amigo}");
+        notificar(id_amigo, json!({ "tipo": "solicitud_amistad", "id_usuario": id_usuario, "nombre_usuario": nombre }));
+    }
+    Ok(Json("Solicitud enviada"))
+}
+
+// POST /amigos/aceptar
+pub async fn post_aceptar_amistad(
+    idioma: Idioma,
+    Extension(pool): Extension<MySqlPool>,
+    Json(payload): Json<AmistadPayload>,
+) -> Result<Json<&'static str>, (StatusCode, String)> {
+    let AmistadPayload { id_usuario, id_amigo } = payload;
+    let error_sql = |e: sqlx::Error| {
+        tracing::error!("❌ SQL en post_aceptar_amistad: {e}");
+        (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+    };
+
+    let res = sqlx::query!(
+        "UPDATE Amistad SET estado = 'aceptada' WHERE id_solicitante = ? AND id_destinatario = ? AND estado = 'pendiente'",
+        id_amigo,
+        id_usuario
+    )
+        .execute(&pool)
+        .await
+        .map_err(error_sql)?;
+
+    if res.rows_affected() == 0 {
+        return Err((StatusCode::NOT_FOUND, tr!(idioma, "solicitud-no-encontrada")));
+    }
+
+    let nombre = nombre_de(&pool, id_usuario).await.map_err(error_sql)?.unwrap_or_default();
+    notificar(id_amigo, json!({ "tipo": "amistad_aceptada", "id_usuario": id_usuario, "nombre_usuario": nombre }));
+    Ok(Json("Amistad aceptada"))
+}
+
+// DELETE /amigos/:u/:amigo
+pub async fn delete_amistad(
+    idioma: Idioma,
+    Path((id_usuario, id_amigo)): Path<(i32, i32)>,
+    Extension(pool): Extension<MySqlPool>,
+) -> Result<Json<&'static str>, (StatusCode, String)> {
+    let res = sqlx::query!(
+        r#"
+        DELETE FROM Amistad
+        WHERE (id_solicitante = ? AND id_destinatario = ?)
+           OR (id_solicitante = ? AND id_destinatario = ?)
+        "#,
+        id_usuario,
+        id_amigo,
+        id_amigo,
+        id_usuario
+    )
+        .execute(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("❌ SQL en delete_amistad: {e}");
+            (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+        })?;
+
+    if res.rows_affected() == 0 {
+        return Err((StatusCode::NOT_FOUND, tr!(idioma, "amistad-no-encontrada")));
+    }
+
+    notificar(id_amigo, json!({ "tipo": "amistad_eliminada", "id_usuario": id_usuario }));
+    Ok(Json("Amistad eliminada"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn busqueda_por_prefijo() {
+        assert_eq!(patron_busqueda("ana"), "ana%");
+    }
+
+    #[test]
+    fn comodines_se_escapan() {
+        assert_eq!(patron_busqueda("50%_a\\b"), "50\\%\\_a\\\\b%");
+    }
+}
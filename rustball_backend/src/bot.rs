@@ -0,0 +1,429 @@
+//! src/bot.rs
+//! --------------------------------------------------------------
+//! Usuarios bot del servidor (`bot_facil`, `bot_medio`, `bot_dificil`).
+//!
+//!   • Se retan como a cualquier usuario con `POST /partida`; la
+//!     formación del bot se elige sola al crear la partida.
+//!   • Cuando el turno pasa al bot, `schedule_turn` lanza una tarea
+//!     que lee el último tablero, elige el tiro con
+//!     `rustball_shared::bot` (el mismo modelo físico simplificado y
+//!     las mismas tablas que el bot local del cliente) y envía el
+//!     resultado por `post_jugada`.
+//!   • El modelo no es Rapier: sólo mueve la ficha disparada y el
+//!     primer cuerpo que toca.
+//! --------------------------------------------------------------
+
+use axum::{extract::Extension, Json};
+use rand::seq::SliceRandom;
+use rustball_shared::bot::{others_of, plan_shot, Difficulty, ShotPlan, MAX_BOUNCES};
+use rustball_shared::sim::{goal_side, predict_shot, Body};
+use rustball_shared::V2;
+use serde_json::{json, Value};
+use sqlx::MySqlPool;
+use tokio::sync::broadcast;
+
+use crate::formaciones::{posiciones_saque, PREDEFINIDAS};
+use crate::i18n::Idioma;
+use crate::models::{GolPayload, JugadaPayload};
+
+/// Prefijo de los nombres de usuario que maneja el servidor.
+pub const BOT_PREFIX: &str = "bot_";
+
+/// Pausa antes de que el bot juegue, para que el rival vea su propia jugada.
+const THINK_DELAY_MS: u64 = 1200;
+
+/* ───────── Dificultad ───────── */
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dificultad {
+    Facil,
+    Medio,
+    Dificil,
+}
+
+impl Dificultad {
+    /// `bot_facil` → `Facil`, … ; `None` si el usuario no es un bot.
+    pub fn from_nombre(nombre: &str) -> Option<Self> {
+        match nombre.strip_prefix(BOT_PREFIX)? {
+            "facil" => Some(Self::Facil),
+            "medio" => Some(Self::Medio),
+            "dificil" => Some(Self::Dificil),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Facil => "facil",
+            Self::Medio => "medio",
+            Self::Dificil => "dificil",
+        }
+    }
+
+    /// Tablas de búsqueda y puntería (compartidas con el bot del cliente).
+    pub fn nivel(&self) -> Difficulty {
+        match self {
+            Self::Facil => Difficulty::Easy,
+            Self::Medio => Difficulty::Medium,
+            Self::Dificil => Difficulty::Hard,
+        }
+    }
+}
+
+/* ───────── Consultas ───────── */
+
+/// Devuelve `(uid, dificultad)` si alguno de los jugadores de la partida es bot.
+pub async fn bot_de_partida(pool: &MySqlPool, id_partida: i32) -> Option<(i32, Dificultad)> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT u.id_usuario, u.nombre_usuario
+        FROM   Partida p
+        JOIN   Usuario u ON u.id_usuario IN (p.id_jugador1, p.id_jugador2)
+        WHERE  p.id_partida = ?
+        "#,
+        id_partida
+    )
+        .fetch_all(pool)
+        .await
+        .map_err(|e| tracing::error!("❌ Error buscando bot de la partida {id_partida}: {e:?}"))
+        .ok()?;
+
+    rows.into_iter()
+        .find_map(|r| Dificultad::from_nombre(&r.nombre_usuario).map(|d| (r.id_usuario, d)))
+}
+
+/// Formación al azar para el bot (mismos nombres que usa el cliente).
+pub fn formacion_aleatoria() -> &'static str {
+    PREDEFINIDAS
+        .choose(&mut rand::thread_rng())
+        .map(|(nombre, _)| *nombre)
+        .unwrap_or("1-2-1-1")
+}
+
+/// Guarda una formación al azar del bot para la ronda `ronda`.
+pub async fn elegir_formacion(pool: &MySqlPool, id_partida: i32, bot_uid: i32, ronda: i32) -> Result<(), sqlx::Error> {
+    let formacion = formacion_aleatoria();
+    sqlx::query!(
+        r#"
+        INSERT INTO FormacionElegida (id_partida, id_usuario, ronda, formacion, turno_inicio)
+        VALUES (?, ?, ?, ?, 0)
+        ON DUPLICATE KEY UPDATE formacion = VALUES(formacion)
+        "#,
+        id_partida,
+        bot_uid,
+        ronda,
+        formacion
+    )
+        .execute(pool)
+        .await?;
+
+    tracing::info!("🤖 Bot {bot_uid} eligió la formación {formacion} (partida {id_partida}, ronda {ronda})");
+    Ok(())
+}
+
+/* ───────── Turno del bot ───────── */
+
+/// Lanza en segundo plano el turno del bot `bot_uid`.
+///
+/// No es `async` a propósito: `post_jugada` la llama y la tarea llama
+/// a `post_jugada`, así que el futuro se crea aparte con `tokio::spawn`.
+pub fn schedule_turn(pool: MySqlPool, tx: broadcast::Sender<String>, id_partida: i32, bot_uid: i32) {
+    tokio::spawn(async move {
+        tokio::time::sleep(std::time::Duration::from_millis(THINK_DELAY_MS)).await;
+
+        if let Err(e) = play_turn(&pool, &tx, id_partida, bot_uid).await {
+            tracing::error!("🤖 El bot {bot_uid} no pudo jugar en la partida {id_partida}: {e}");
+        }
+    });
+}
+
+async fn play_turn(
+    pool: &MySqlPool,
+    tx: &broadcast::Sender<String>,
+    id_partida: i32,
+    bot_uid: i32,
+) -> Result<(), String> {
+    let Some((uid, dificultad)) = bot_de_partida(pool, id_partida).await else {
+        return Err("la partida no tiene bot".into());
+    };
+    if uid != bot_uid {
+        return Err(format!("el usuario {bot_uid} no es el bot de la partida"));
+    }
+
+    let partida = sqlx::query!(
+        r#"SELECT id_jugador1, id_jugador2, turno_actual, ronda, estado AS "estado!: String" FROM Partida WHERE id_partida = ?"#,
+        id_partida
+    )
+        .fetch_one(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if partida.estado != "playing" || partida.turno_actual != Some(bot_uid) {
+        tracing::info!("🤖 Ya no es turno del bot en la partida {id_partida}");
+        return Ok(());
+    }
+
+    // El cliente pone a la izquierda al UID menor
+    let id_left = partida.id_jugador1.min(partida.id_jugador2);
+    let bot_is_left = bot_uid == id_left;
+
+    let ultimo = sqlx::query!(
+        "SELECT jugada FROM Turno WHERE id_partida = ? AND ronda = ? ORDER BY numero_turno DESC LIMIT 1",
+        id_partida,
+        partida.ronda
+    )
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let previa = ultimo.map(|row| row.jugada);
+    let (mut piezas, pelota) = match &previa {
+        Some(jugada) => parse_board(jugada),
+        None => {
+            let formaciones = sqlx::query!(
+                "SELECT id_usuario, formacion, posiciones, turno_inicio FROM FormacionElegida WHERE id_partida = ? AND ronda = ?",
+                id_partida,
+                partida.ronda
+            )
+                .fetch_all(pool)
+                .await
+                .map_err(|e| e.to_string())?;
+
+            let mut piezas = Vec::new();
+            for f in formaciones {
+                let is_left = f.id_usuario == id_left;
+                for (x, y) in posiciones_saque(&f.formacion, f.posiciones.as_ref(), is_left, f.turno_inicio != 2) {
+                    let id = piezas.len() as u32;
+                    piezas.push(Pieza { id, id_usuario_real: f.id_usuario, pos: V2 { x, y }, powerup: None });
+                }
+            }
+            (piezas, V2::ZERO)
+        }
+    };
+
+    // El bot ataca el arco contrario a su lado
+    let attacks_left = !bot_is_left;
+    let (bodies, shooters) = cuerpos(&piezas, pelota, bot_uid);
+    let Some(plan) = plan_shot(&shooters, &bodies, attacks_left, dificultad.nivel(), &mut rand::thread_rng()) else {
+        return Err("no hay fichas del bot en el tablero".into());
+    };
+
+    // Tablero previo para que el rival pueda ver el tiro repetido
+    let tablero_previo = tablero_json(&piezas, pelota, previa.as_ref());
+    let resultado = apply_shot(&mut piezas, &bodies, &plan);
+    let id_ficha = piezas[plan.shooter - 1].id;
+    tracing::info!(
+        "🤖 Bot {bot_uid} ({}) dispara la ficha {id_ficha} (puntuación {:.1})",
+        dificultad.as_str(),
+        plan.score
+    );
+
+    let mut jugada = tablero_json(&piezas, resultado.pelota, previa.as_ref());
+    jugada["tiro"] = json!({
+        "id_ficha": id_ficha,
+        "dir_x": plan.direction.x,
+        "dir_y": plan.direction.y,
+        "potencia": plan.power,
+        "powerups": [],
+        "tablero_previo": tablero_previo,
+    });
+
+    // La jugada queda en la ronda en la que se tiró; el gol abre la siguiente
+    crate::post_jugada(
+        Idioma::default(),
+        Extension(pool.clone()),
+        Extension(tx.clone()),
+        Json(JugadaPayload { id_partida, numero_turno: 0, id_usuario: bot_uid, jugada }),
+    )
+        .await
+        .map_err(|(_, e)| e)?;
+
+    if let Some(left) = resultado.gol {
+        let goleador = if left == attacks_left {
+            bot_uid
+        } else if bot_uid == partida.id_jugador1 {
+            partida.id_jugador2
+        } else {
+            partida.id_jugador1
+        };
+        crate::post_gol(
+            Idioma::default(),
+            Extension(pool.clone()),
+            Extension(tx.clone()),
+            Json(GolPayload { id_partida, id_goleador: goleador, ronda: partida.ronda, jugada: None }),
+        )
+            .await
+            .map_err(|(_, e)| e)?;
+    }
+
+    Ok(())
+}
+
+/// Arma el JSON de `Turno.jugada` (mismo formato que `BoardSnapshot` del cliente).
+/// Zonas y power-up del campo no los simula el bot: se copian de `previa`.
+fn tablero_json(piezas: &[Pieza], pelota: V2, previa: Option<&Value>) -> Value {
+    let mut jugada = json!({
+        "piezas": piezas.iter().map(|p| json!({
+            "id": p.id,
+            "id_usuario_real": p.id_usuario_real,
+            "x": p.pos.x,
+            "y": p.pos.y,
+            "vx": 0.0,
+            "vy": 0.0,
+            "powerup": p.powerup,
+        })).collect::<Vec<_>>(),
+        "pelota": { "x": pelota.x, "y": pelota.y, "vx": 0.0, "vy": 0.0 },
+    });
+
+    if let Some(previa) = previa {
+        for clave in ["zonas", "powerup"] {
+            if let Some(v) = previa.get(clave) {
+                jugada[clave] = v.clone();
+            }
+        }
+    }
+    jugada
+}
+
+/// Lee `piezas` (y `pelota` si existe) del JSON guardado en `Turno.jugada`.
+fn parse_board(jugada: &Value) -> (Vec<Pieza>, V2) {
+    let num = |v: &Value, k: &str| v.get(k).and_then(Value::as_f64).unwrap_or(0.0) as f32;
+
+    let piezas = jugada
+        .get("piezas")
+        .and_then(Value::as_array)
+        .map(|arr| {
+            arr.iter()
+                .enumerate()
+                .map(|(i, p)| Pieza {
+                    id: p.get("id").and_then(Value::as_u64).map(|id| id as u32).unwrap_or(i as u32),
+                    id_usuario_real: p.get("id_usuario_real").and_then(Value::as_i64).unwrap_or(0) as i32,
+                    pos: V2 { x: num(p, "x"), y: num(p, "y") },
+                    powerup: p.get("powerup").and_then(Value::as_u64),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let pelota = jugada
+        .get("pelota")
+        .map(|b| V2 { x: num(b, "x"), y: num(b, "y") })
+        .unwrap_or(V2::ZERO);
+
+    (piezas, pelota)
+}
+
+/* ───────── Tablero → cuerpos de `rustball_shared` ───────── */
+
+#[derive(Debug, Clone)]
+struct Pieza {
+    id: u32,
+    id_usuario_real: i32,
+    pos: V2,
+    /// Power-up pendiente (`PowerUpType` del cliente); el bot no lo usa pero lo conserva.
+    powerup: Option<u64>,
+}
+
+struct Resultado {
+    pelota: V2,
+    /// `Some(true)` si la pelota terminó en el arco izquierdo.
+    gol: Option<bool>,
+}
+
+/// Cuerpos de la simulación: índice 0 = pelota, `i + 1` = `piezas[i]`.
+/// Devuelve además los índices de las fichas de `bot_uid`.
+fn cuerpos(piezas: &[Pieza], pelota: V2, bot_uid: i32) -> (Vec<Body>, Vec<usize>) {
+    let bodies = std::iter::once(Body::ball(pelota))
+        .chain(piezas.iter().map(|p| Body::disk(p.pos)))
+        .collect();
+    let shooters = piezas
+        .iter()
+        .enumerate()
+        .filter(|(_, p)| p.id_usuario_real == bot_uid)
+        .map(|(i, _)| i + 1)
+        .collect();
+    (bodies, shooters)
+}
+
+/// Aplica el tiro elegido sobre `piezas` y devuelve dónde queda la pelota.
+fn apply_shot(piezas: &mut [Pieza], bodies: &[Body], plan: &ShotPlan) -> Resultado {
+    let (others, map) = others_of(bodies, plan.shooter);
+    let prediction = predict_shot(bodies[plan.shooter], plan.velocity(), &others, MAX_BOUNCES);
+
+    if let Some(end) = prediction.end() {
+        piezas[plan.shooter - 1].pos = end;
+    }
+    let mut resultado = Resultado { pelota: bodies[0].pos, gol: None };
+    if let Some(contact) = &prediction.contact {
+        let end = contact.pushed_path.last().copied().unwrap_or(contact.target.pos);
+        match map[contact.index] {
+            0 => resultado.pelota = end,
+            i => piezas[i - 1].pos = end,
+        }
+    }
+
+    // Tras un gol la pelota vuelve al centro
+    resultado.gol = goal_side(resultado.pelota);
+    if resultado.gol.is_some() {
+        resultado.pelota = V2::ZERO;
+    }
+    resultado
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pieza(id: u32, id_usuario_real: i32, x: f32, y: f32) -> Pieza {
+        Pieza { id, id_usuario_real, pos: V2::new(x, y), powerup: None }
+    }
+
+    #[test]
+    fn dificultad_por_nombre_de_usuario() {
+        assert_eq!(Dificultad::from_nombre("bot_dificil"), Some(Dificultad::Dificil));
+        assert_eq!(Dificultad::from_nombre("bot_medio").map(|d| d.nivel()), Some(Difficulty::Medium));
+        assert_eq!(Dificultad::from_nombre("facil"), None);
+        for d in [Dificultad::Facil, Dificultad::Medio, Dificultad::Dificil] {
+            assert_eq!(d.nivel().as_str(), d.as_str());
+        }
+    }
+
+    #[test]
+    fn cuerpos_pone_la_pelota_primero() {
+        let piezas = [pieza(0, 1, -100.0, 0.0), pieza(1, 2, 100.0, 0.0), pieza(2, 2, 200.0, 50.0)];
+        let (bodies, shooters) = cuerpos(&piezas, V2::ZERO, 2);
+
+        assert!(bodies[0].is_ball);
+        assert_eq!(bodies.len(), 4);
+        assert_eq!(shooters, vec![2, 3]);
+        assert_eq!(bodies[3].pos, piezas[2].pos);
+    }
+
+    #[test]
+    fn apply_shot_mueve_ficha_y_pelota_y_detecta_el_gol() {
+        // Pelota en la boca del arco izquierdo y la ficha del bot detrás
+        let pelota = V2::new(-450.0, 0.0);
+        let mut piezas = vec![pieza(7, 2, -300.0, 0.0)];
+        let (bodies, _) = cuerpos(&piezas, pelota, 2);
+        let plan = ShotPlan { shooter: 1, direction: V2::new(-1.0, 0.0), power: 1.0, score: 0.0 };
+
+        let resultado = apply_shot(&mut piezas, &bodies, &plan);
+        assert_eq!(resultado.gol, Some(true));
+        assert_eq!(resultado.pelota, V2::ZERO);
+        assert!(piezas[0].pos.x < -300.0);
+    }
+
+    #[test]
+    fn parse_board_lee_piezas_y_pelota() {
+        let jugada = json!({
+            "piezas": [{ "id": 3, "id_usuario_real": 9, "x": 10.0, "y": -5.0, "powerup": 2 }],
+            "pelota": { "x": 1.5, "y": 2.5 },
+        });
+        let (piezas, pelota) = parse_board(&jugada);
+
+        assert_eq!(piezas.len(), 1);
+        assert_eq!((piezas[0].id, piezas[0].id_usuario_real, piezas[0].powerup), (3, 9, Some(2)));
+        assert_eq!(piezas[0].pos, V2::new(10.0, -5.0));
+        assert_eq!(pelota, V2::new(1.5, 2.5));
+    }
+}
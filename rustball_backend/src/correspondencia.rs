@@ -0,0 +1,357 @@
+// -----------------------------------------------------------------
+//! Partidas por correspondencia: cada jugador mueve cuando puede, con
+//! plazos de horas o días, y puede tener muchas abiertas a la vez.
+//!
+//!   • Se elige al crearla: `post_partida` (o el reto que la origina)
+//!     con `plazo_turno_horas` entre 1 y `PLAZO_MAXIMO_HORAS`.  Sin
+//!     plazo la partida es `tiempo_real`, como hasta ahora.  Las de
+//!     torneo son siempre por correspondencia (`Torneo.plazo_turno_horas`,
+//!     `PLAZO_TORNEO_HORAS` si el organizador no elige otro), así el
+//!     cuadro no se queda esperando a alguien que no se presenta.
+//!   • Cada vez que la partida pasa a esperar a alguien (formaciones de
+//!     la ronda, o el tiro de `turno_actual`) `fecha_limite` se renueva a
+//!     ahora + plazo; en tiempo real queda NULL.  A quien le toca se le
+//!     avisa por el lobby y por correo/webhook (`avisar_turno`).
+//!   • `lanzar_vencimientos` revisa cada `INTERVALO_VENCIMIENTO_S` los
+//!     plazos vencidos: quien no movió pierde por tiempo (el rival se
+//!     anota `GOLES_PARA_GANAR` y se cierra con `cerrar_partida`, así
+//!     Elo, temporada y torneo lo cuentan como cualquier derrota).  Si se
+//!     esperaba a los dos (ninguno eligió formación) la partida se anula:
+//!     queda terminada sin ganador ni cambio de Elo.
+//!   • `GET /bandeja/:u`: las partidas en las que me toca algo, las que
+//!     vencen antes primero.
+// -----------------------------------------------------------------
+
+use axum::{
+    extract::{Extension, Path},
+    http::StatusCode,
+    Json,
+};
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use serde_json::json;
+use sqlx::{MySqlConnection, MySqlPool};
+
+use crate::i18n::{tr, Idioma};
+use crate::resultado::GOLES_PARA_GANAR;
+use crate::routes::{lobby::notificar, websocket::enviar_a_partida};
+
+/// Plazo por turno más largo que se puede pedir (una semana).
+pub const PLAZO_MAXIMO_HORAS: i32 = 7 * 24;
+/// Plazo por turno de un torneo si no se pide otro (dos días; el mismo
+/// valor que el DEFAULT de la columna).
+pub const PLAZO_TORNEO_HORAS: i32 = 48;
+/// Cada cuánto se buscan plazos vencidos.
+const INTERVALO_VENCIMIENTO_S: u64 = 5 * 60;
+
+#[derive(Debug, Serialize)]
+pub struct PartidaBandeja {
+    pub id_partida: i32,
+    pub id_jugador1: i32,
+    pub id_jugador2: i32,
+    pub id_rival: i32,
+    pub nombre_rival: String,
+    /// tiempo_real | correspondencia
+    pub modo: String,
+    /// "formacion" (elegirla para la ronda) o "tirar".
+    pub accion: &'static str,
+    pub ronda: i32,
+    pub gol_j1: i32,
+    pub gol_j2: i32,
+    pub plazo_turno_horas: Option<i32>,
+    /// `None` en tiempo real.
+    pub fecha_limite: Option<NaiveDateTime>,
+}
+
+/// `Partida.modo` según el plazo pedido.
+pub fn modo(plazo_turno_horas: Option<i32>) -> &'static str {
+    if plazo_turno_horas.is_some() { "correspondencia" } else { "tiempo_real" }
+}
+
+/// Sin plazo (tiempo real) o uno entre 1 y `PLAZO_MAXIMO_HORAS`.
+pub fn validar_plazo(idioma: Idioma, plazo_turno_horas: Option<i32>) -> Result<(), (StatusCode, String)> {
+    match plazo_turno_horas {
+        Some(h) if !(1..=PLAZO_MAXIMO_HORAS).contains(&h) => Err((
+            StatusCode::BAD_REQUEST,
+            tr!(idioma, "plazo-invalido", maximo = PLAZO_MAXIMO_HORAS),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// A quiénes espera la partida: al que tira, o a los que todavía no
+/// eligieron la formación de la ronda.
+async fn esperando_a(
+    conn: &mut MySqlConnection,
+    id_partida: i32,
+    (estado, ronda): (&str, i32),
+    turno_actual: Option<i32>,
+    jugadores: [i32; 2],
+) -> Result<Vec<i32>, sqlx::Error> {
+    match estado {
+        "playing" => Ok(turno_actual.into_iter().collect()),
+        "waiting" => {
+            let elegidas = sqlx::query_scalar!(
+                "SELECT id_usuario FROM FormacionElegida WHERE id_partida = ? AND ronda = ?",
+                id_partida,
+                ronda
+            )
+                .fetch_all(&mut *conn)
+                .await?;
+            Ok(jugadores.into_iter().filter(|u| !elegidas.contains(u)).collect())
+        }
+        _ => Ok(Vec::new()),
+    }
+}
+
+/* ───────── Avisos ───────── */
+
+/// Tras un cambio de turno o de ronda: avisa (lobby, correo, webhook) a
+/// quien tiene que mover, si la partida es por correspondencia.  Un
+/// fallo sólo se registra en el log.
+pub async fn avisar_turno(pool: &MySqlPool, id_partida: i32) {
+    if let Err(e) = avisar(pool, id_partida).await {
+        tracing::error!("❌ No se pudo avisar el turno de la partida {id_partida}: {e}");
+    }
+}
+
+async fn avisar(pool: &MySqlPool, id_partida: i32) -> Result<(), sqlx::Error> {
+    let mut conn = pool.acquire().await?;
+    let p = sqlx::query!(
+        r#"
+        SELECT id_jugador1, id_jugador2, ronda, turno_actual,
+               estado AS "estado!: String", modo AS "modo!: String",
+               fecha_limite AS "fecha_limite: NaiveDateTime"
+        FROM   Partida
+        WHERE  id_partida = ?
+        "#,
+        id_partida
+    )
+        .fetch_one(&mut *conn)
+        .await?;
+    if p.modo != "correspondencia" {
+        return Ok(());
+    }
+
+    let pendientes = esperando_a(
+        &mut conn,
+        id_partida,
+        (&p.estado, p.ronda),
+        p.turno_actual,
+        [p.id_jugador1, p.id_jugador2],
+    )
+        .await?;
+    for uid in pendientes {
+        notificar(uid, json!({ "tipo": "tu_turno", "id_partida": id_partida, "fecha_limite": p.fecha_limite }));
+        crate::notificaciones::turno(pool, id_partida, uid, p.fecha_limite).await;
+    }
+    Ok(())
+}
+
+/* ───────── Plazos vencidos ───────── */
+
+/// Cierra por tiempo la partida si su plazo sigue vencido (si alguien
+/// movió justo ahora, ya se renovó y no se toca).
+async fn vencer(pool: &MySqlPool, id_partida: i32) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let Some(p) = sqlx::query!(
+        r#"
+        SELECT id_jugador1, id_jugador2, gol_j1, gol_j2, ronda, turno_actual,
+               estado AS "estado!: String"
+        FROM   Partida
+        WHERE  id_partida = ? AND estado <> 'finished' AND fecha_limite <= NOW()
+        FOR UPDATE
+        "#,
+        id_partida
+    )
+        .fetch_optional(&mut *tx)
+        .await?
+    else {
+        return Ok(());
+    };
+
+    let jugadores = (p.id_jugador1, p.id_jugador2);
+    let pendientes = esperando_a(
+        &mut tx,
+        id_partida,
+        (&p.estado, p.ronda),
+        p.turno_actual,
+        [p.id_jugador1, p.id_jugador2],
+    )
+        .await?;
+
+    let ganador = match pendientes.as_slice() {
+        // ⌛ Uno solo dejó pasar el plazo: el rival gana por tiempo
+        [perdedor] => {
+            let (gol_j1, gol_j2) = (p.gol_j1.unwrap_or(0), p.gol_j2.unwrap_or(0));
+            let goles = if *perdedor == p.id_jugador1 {
+                (gol_j1, GOLES_PARA_GANAR)
+            } else {
+                (GOLES_PARA_GANAR, gol_j2)
+            };
+            sqlx::query!("UPDATE Partida SET gol_j1 = ?, gol_j2 = ? WHERE id_partida = ?", goles.0, goles.1, id_partida)
+                .execute(&mut *tx)
+                .await?;
+            crate::resultado::cerrar_partida(&mut tx, id_partida, jugadores, goles).await?
+        }
+        // Ninguno movió: se anula
+        _ => {
+            sqlx::query!(
+                r#"
+                UPDATE Partida
+                SET    estado = 'finished', turno_actual = NULL, fecha_limite = NULL, fecha_fin = NOW()
+                WHERE  id_partida = ?
+                "#,
+                id_partida
+            )
+                .execute(&mut *tx)
+                .await?;
+            None
+        }
+    };
+    tx.commit().await?;
+
+    match ganador {
+        Some(g) => tracing::info!("⌛ Partida {id_partida}: plazo vencido, gana {g} por tiempo"),
+        None => tracing::info!("⌛ Partida {id_partida}: plazo vencido sin movimientos, se anula"),
+    }
+
+    let aviso = json!({ "tipo": "partida_vencida", "id_partida": id_partida, "ganador": ganador });
+    notificar(jugadores.0, aviso.clone());
+    notificar(jugadores.1, aviso);
+    crate::notificaciones::partida_terminada(pool, id_partida).await;
+
+    // 🏅 Ganar por tiempo también desbloquea los logros de victoria
+    if let Some(g) = ganador {
+        crate::logros::evaluar(pool, id_partida, &[g]).await;
+    }
+
+    // Quien tenga la partida abierta ve el final
+    if let Ok(Json(snap)) =
+        crate::handlers::get_snapshot(Idioma::default(), Path(id_partida), Extension(pool.clone())).await
+    {
+        enviar_a_partida(id_partida, json!({ "uid_origen": 0, "tipo": "snapshot", "contenido": snap }).to_string());
+    }
+
+    // 🏆 También si se anuló: el cuadro no puede quedar esperando esta partida
+    if let Err(e) = crate::torneos::partida_terminada(pool, id_partida, ganador).await {
+        tracing::error!("❌ Torneo: no se pudo avanzar tras la partida {id_partida}: {e}");
+    }
+    Ok(())
+}
+
+async fn vencer_plazos(pool: &MySqlPool) -> Result<(), sqlx::Error> {
+    let vencidas = sqlx::query_scalar!(
+        r#"
+        SELECT id_partida FROM Partida
+        WHERE  modo = 'correspondencia' AND estado <> 'finished' AND fecha_limite <= NOW()
+        "#
+    )
+        .fetch_all(pool)
+        .await?;
+
+    for id_partida in vencidas {
+        if let Err(e) = vencer(pool, id_partida).await {
+            tracing::error!("❌ Error cerrando por tiempo la partida {id_partida}: {e}");
+        }
+    }
+    Ok(())
+}
+
+/// Tarea de fondo que cierra las partidas con el plazo vencido.
+pub fn lanzar_vencimientos(pool: MySqlPool) {
+    tokio::spawn(async move {
+        let mut intervalo = tokio::time::interval(std::time::Duration::from_secs(INTERVALO_VENCIMIENTO_S));
+        loop {
+            intervalo.tick().await;
+            if let Err(e) = vencer_plazos(&pool).await {
+                tracing::error!("❌ Error revisando plazos de partidas: {e}");
+            }
+        }
+    });
+}
+
+/* ───────── GET /bandeja/:u ───────── */
+
+/// Partidas sin terminar en las que me toca tirar o elegir formación.
+pub async fn get_bandeja(
+    idioma: Idioma,
+    Path(id_usuario): Path<i32>,
+    Extension(pool): Extension<MySqlPool>,
+) -> Result<Json<Vec<PartidaBandeja>>, (StatusCode, String)> {
+    let filas = sqlx::query!(
+        r#"
+        SELECT p.id_partida, p.id_jugador1, p.id_jugador2, p.ronda, p.gol_j1, p.gol_j2,
+               p.estado AS "estado!: String", p.modo AS "modo!: String", p.plazo_turno_horas,
+               p.fecha_limite AS "fecha_limite: NaiveDateTime",
+               r.id_usuario AS id_rival, r.nombre_usuario AS nombre_rival
+        FROM   Partida p
+        JOIN   Usuario r ON r.id_usuario = IF(p.id_jugador1 = ?, p.id_jugador2, p.id_jugador1)
+        WHERE  (p.id_jugador1 = ? OR p.id_jugador2 = ?)
+          AND  ((p.estado = 'playing' AND p.turno_actual = ?)
+            OR  (p.estado = 'waiting' AND NOT EXISTS (
+                     SELECT 1 FROM FormacionElegida f
+                     WHERE  f.id_partida = p.id_partida AND f.ronda = p.ronda AND f.id_usuario = ?)))
+        ORDER  BY p.fecha_limite IS NULL, p.fecha_limite, p.id_partida DESC
+        "#,
+        id_usuario,
+        id_usuario,
+        id_usuario,
+        id_usuario,
+        id_usuario
+    )
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("❌ SQL en get_bandeja: {e}");
+            (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+        })?;
+
+    let bandeja = filas
+        .into_iter()
+        .map(|f| PartidaBandeja {
+            id_partida: f.id_partida,
+            id_jugador1: f.id_jugador1,
+            id_jugador2: f.id_jugador2,
+            id_rival: f.id_rival,
+            nombre_rival: f.nombre_rival,
+            modo: f.modo,
+            accion: if f.estado == "playing" { "tirar" } else { "formacion" },
+            ronda: f.ronda,
+            gol_j1: f.gol_j1.unwrap_or(0),
+            gol_j2: f.gol_j2.unwrap_or(0),
+            plazo_turno_horas: f.plazo_turno_horas,
+            fecha_limite: f.fecha_limite,
+        })
+        .collect();
+
+    Ok(Json(bandeja))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modo_segun_el_plazo() {
+        assert_eq!(modo(None), "tiempo_real");
+        assert_eq!(modo(Some(24)), "correspondencia");
+    }
+
+    #[test]
+    fn plazos_validos() {
+        assert!(validar_plazo(Idioma::Es, None).is_ok());
+        assert!(validar_plazo(Idioma::Es, Some(1)).is_ok());
+        assert!(validar_plazo(Idioma::Es, Some(PLAZO_MAXIMO_HORAS)).is_ok());
+    }
+
+    #[test]
+    fn plazos_fuera_de_rango() {
+        for h in [0, -3, PLAZO_MAXIMO_HORAS + 1] {
+            let (estado, mensaje) = validar_plazo(Idioma::En, Some(h)).unwrap_err();
+            assert_eq!(estado, StatusCode::BAD_REQUEST);
+            assert_eq!(mensaje, "The turn window must be between 1 and 168 hours");
+        }
+    }
+}
@@ -0,0 +1,447 @@
+// -----------------------------------------------------------------
+//! Cuenta: verificación del correo y restablecimiento de contraseña.
+//!
+//!   • Al registrarse se manda un enlace de verificación; hasta abrirlo
+//!     los avisos de `notificaciones.rs` no van por correo.  Se puede
+//!     pedir otro con `POST /cuenta/verificacion`.
+//!   • `POST /cuenta/restablecer` manda un enlace para elegir contraseña
+//!     nueva; la respuesta es la misma exista o no el correo.
+//!   • Los enlaces llevan un token aleatorio de `TokenCuenta`: un solo
+//!     uso, caducan (`HORAS_VERIFICACION`, `MINUTOS_RESTABLECER`) y sólo
+//!     vale el último de cada tipo.  En la base sólo se guarda su SHA-256
+//!     (`huella`): quien lea la tabla no puede usar los enlaces.
+//!   • Contraseñas de al menos `LARGO_MINIMO_CONTRASENA` caracteres, al
+//!     registrarse y al restablecerla.  Entre dos envíos al mismo usuario
+//!     hay que esperar `ESPERA_ENTRE_ENVIOS_S`.
+//!   • Los correos salen por el outbox de `notificaciones.rs` (en
+//!     desarrollo, `SALIDA_CORREO=log` o `archivo:`, ver `salidas.rs`).
+//!   • Los enlaces apuntan a `cuenta.html` bajo `URL_PUBLICA`.
+// -----------------------------------------------------------------
+
+use axum::{
+    extract::{Extension, Path},
+    http::StatusCode,
+    Json,
+};
+use chrono::NaiveDateTime;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use sqlx::{MySqlConnection, MySqlPool};
+
+use crate::bot::Dificultad;
+use crate::i18n::{tr, Idioma};
+use crate::models::{NuevaContrasenaPayload, ReenvioVerificacionPayload, RestablecerPayload, TokenPayload};
+use crate::notificaciones;
+
+const HORAS_VERIFICACION: i64 = 48;
+const MINUTOS_RESTABLECER: i64 = 60;
+/// Mínimo entre dos enlaces del mismo tipo al mismo usuario.
+const ESPERA_ENTRE_ENVIOS_S: i64 = 60;
+pub const LARGO_MINIMO_CONTRASENA: usize = 8;
+const URL_PUBLICA_POR_DEFECTO: &str = "https://rustball.lat";
+
+static URL_PUBLICA: Lazy<String> = Lazy::new(|| {
+    std::env::var("URL_PUBLICA")
+        .map(|u| u.trim_end_matches('/').to_owned())
+        .unwrap_or_else(|_| URL_PUBLICA_POR_DEFECTO.into())
+});
+
+#[derive(Debug, Clone, Copy)]
+enum Tipo {
+    Verificacion,
+    Restablecer,
+}
+
+impl Tipo {
+    /// Valor en `TokenCuenta.tipo`.
+    fn nombre(self) -> &'static str {
+        match self {
+            Tipo::Verificacion => "verificacion",
+            Tipo::Restablecer => "restablecer",
+        }
+    }
+
+    fn vigencia_minutos(self) -> i64 {
+        match self {
+            Tipo::Verificacion => HORAS_VERIFICACION * 60,
+            Tipo::Restablecer => MINUTOS_RESTABLECER,
+        }
+    }
+
+    /// `cuenta.html?verificar=…` / `cuenta.html?restablecer=…`
+    fn enlace(self, token: &str) -> String {
+        let parametro = match self {
+            Tipo::Verificacion => "verificar",
+            Tipo::Restablecer => "restablecer",
+        };
+        format!("{}/cuenta.html?{parametro}={token}", *URL_PUBLICA)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct EstadoCuenta {
+    pub id_usuario: i32,
+    pub correo_verificado: bool,
+}
+
+/// Lo justo para rechazar lo que seguro no es un correo.
+pub fn correo_valido(correo: &str) -> bool {
+    let Some((usuario, dominio)) = correo.split_once('@') else { return false };
+    !usuario.is_empty()
+        && !dominio.contains('@')
+        && dominio.contains('.')
+        && !dominio.starts_with('.')
+        && !dominio.ends_with('.')
+        && !correo.chars().any(|c| c.is_whitespace() || c.is_control())
+        && correo.len() <= 255
+}
+
+/// Al registrarse y al restablecerla.
+pub fn validar_contrasena(idioma: Idioma, contrasena: &str) -> Result<(), (StatusCode, String)> {
+    if contrasena.chars().count() < LARGO_MINIMO_CONTRASENA {
+        return Err((StatusCode::BAD_REQUEST, tr!(idioma, "contrasena-corta", minimo = LARGO_MINIMO_CONTRASENA)));
+    }
+    Ok(())
+}
+
+fn hexadecimal(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// 32 bytes aleatorios en hexadecimal.
+fn token_nuevo() -> String {
+    hexadecimal(&rand::random::<[u8; 32]>())
+}
+
+/// SHA-256 del token, que es lo que se guarda en `TokenCuenta.huella`.
+fn huella(token: &str) -> String {
+    hexadecimal(&Sha256::digest(token.as_bytes()))
+}
+
+/// Fila de `TokenCuenta` con la hora de la base al leerla.
+struct TokenGuardado {
+    id_usuario: i32,
+    fecha_expira: NaiveDateTime,
+    fecha_uso: Option<NaiveDateTime>,
+    ahora: NaiveDateTime,
+}
+
+impl TokenGuardado {
+    /// Sin usar y sin caducar.
+    fn vigente(&self) -> bool {
+        self.fecha_uso.is_none() && self.fecha_expira > self.ahora
+    }
+}
+
+/// Segundos que faltan para poder mandar otro enlace de `tipo` (None: ya se puede).
+async fn espera_pendiente(pool: &MySqlPool, id_usuario: i32, tipo: Tipo) -> Result<Option<i64>, sqlx::Error> {
+    let transcurrido = sqlx::query_scalar!(
+        r#"
+        SELECT TIMESTAMPDIFF(SECOND, MAX(fecha_creacion), NOW()) AS "transcurrido?: i64"
+        FROM   TokenCuenta
+        WHERE  id_usuario = ? AND tipo = ?
+        "#,
+        id_usuario,
+        tipo.nombre()
+    )
+        .fetch_one(pool)
+        .await?;
+    Ok(transcurrido
+        .map(|s| ESPERA_ENTRE_ENVIOS_S - s)
+        .filter(|&faltan| faltan > 0))
+}
+
+/// Token nuevo de `tipo`; los anteriores del mismo tipo dejan de valer.
+async fn emitir(pool: &MySqlPool, id_usuario: i32, tipo: Tipo) -> Result<String, sqlx::Error> {
+    let token = token_nuevo();
+    let mut tx = pool.begin().await?;
+    sqlx::query!("DELETE FROM TokenCuenta WHERE id_usuario = ? AND tipo = ?", id_usuario, tipo.nombre())
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query!(
+        r#"
+        INSERT INTO TokenCuenta (huella, id_usuario, tipo, fecha_expira)
+        VALUES (?, ?, ?, NOW() + INTERVAL ? MINUTE)
+        "#,
+        huella(&token),
+        id_usuario,
+        tipo.nombre(),
+        tipo.vigencia_minutos()
+    )
+        .execute(&mut *tx)
+        .await?;
+    tx.commit().await?;
+    Ok(token)
+}
+
+/// Marca el token como usado y devuelve su usuario, si es de `tipo`, no
+/// caducó y no se había usado.
+async fn usar_token(conn: &mut MySqlConnection, token: &str, tipo: Tipo) -> Result<Option<i32>, sqlx::Error> {
+    let huella = huella(token);
+    let guardado = sqlx::query_as!(
+        TokenGuardado,
+        r#"
+        SELECT id_usuario,
+               fecha_expira AS "fecha_expira: NaiveDateTime",
+               fecha_uso AS "fecha_uso: NaiveDateTime",
+               NOW() AS "ahora!: NaiveDateTime"
+        FROM   TokenCuenta
+        WHERE  huella = ? AND tipo = ?
+        FOR UPDATE
+        "#,
+        huella,
+        tipo.nombre()
+    )
+        .fetch_optional(&mut *conn)
+        .await?;
+    let Some(guardado) = guardado.filter(TokenGuardado::vigente) else {
+        return Ok(None);
+    };
+    sqlx::query!("UPDATE TokenCuenta SET fecha_uso = NOW() WHERE huella = ?", huella)
+        .execute(&mut *conn)
+        .await?;
+    Ok(Some(guardado.id_usuario))
+}
+
+/// Manda a `id_usuario` un enlace de verificación nuevo.
+pub async fn enviar_verificacion(pool: &MySqlPool, id_usuario: i32) -> Result<(), sqlx::Error> {
+    let nombre = sqlx::query_scalar!("SELECT nombre_usuario FROM Usuario WHERE id_usuario = ?", id_usuario)
+        .fetch_one(pool)
+        .await?;
+    let token = emitir(pool, id_usuario, Tipo::Verificacion).await?;
+    notificaciones::verificacion(pool, id_usuario, nombre, Tipo::Verificacion.enlace(&token)).await
+}
+
+async fn estado(pool: &MySqlPool, id_usuario: i32) -> Result<Option<EstadoCuenta>, sqlx::Error> {
+    sqlx::query_as!(
+        EstadoCuenta,
+        r#"
+        SELECT id_usuario, correo_verificado AS "correo_verificado: bool"
+        FROM   Usuario
+        WHERE  id_usuario = ?
+        "#,
+        id_usuario
+    )
+        .fetch_optional(pool)
+        .await
+}
+
+/* ───────── Handlers ───────── */
+
+// GET /cuenta/:u
+pub async fn get_cuenta(
+    idioma: Idioma,
+    Path(id_usuario): Path<i32>,
+    Extension(pool): Extension<MySqlPool>,
+) -> Result<Json<EstadoCuenta>, (StatusCode, String)> {
+    estado(&pool, id_usuario)
+        .await
+        .map_err(|e| {
+            tracing::error!("❌ SQL en get_cuenta: {e}");
+            (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+        })?
+        .map(Json)
+        .ok_or_else(|| (StatusCode::NOT_FOUND, tr!(idioma, "usuario-no-encontrado", usuario = id_usuario)))
+}
+
+// POST /cuenta/verificacion — reenviar el enlace
+pub async fn post_reenviar_verificacion(
+    idioma: Idioma,
+    Extension(pool): Extension<MySqlPool>,
+    Json(payload): Json<ReenvioVerificacionPayload>,
+) -> Result<Json<EstadoCuenta>, (StatusCode, String)> {
+    let error_sql = |e: sqlx::Error| {
+        tracing::error!("❌ SQL en post_reenviar_verificacion: {e}");
+        (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+    };
+    let id_usuario = payload.id_usuario;
+
+    let cuenta = estado(&pool, id_usuario)
+        .await
+        .map_err(error_sql)?
+        .ok_or_else(|| (StatusCode::NOT_FOUND, tr!(idioma, "usuario-no-encontrado", usuario = id_usuario)))?;
+    if cuenta.correo_verificado {
+        return Err((StatusCode::CONFLICT, tr!(idioma, "correo-ya-verificado")));
+    }
+    if let Some(segundos) = espera_pendiente(&pool, id_usuario, Tipo::Verificacion).await.map_err(error_sql)? {
+        return Err((StatusCode::TOO_MANY_REQUESTS, tr!(idioma, "token-reciente", segundos = segundos)));
+    }
+
+    enviar_verificacion(&pool, id_usuario).await.map_err(error_sql)?;
+    tracing::info!("✉️ Enlace de verificación reenviado a {id_usuario}");
+    Ok(Json(cuenta))
+}
+
+// POST /cuenta/verificar
+pub async fn post_verificar(
+    idioma: Idioma,
+    Extension(pool): Extension<MySqlPool>,
+    Json(payload): Json<TokenPayload>,
+) -> Result<Json<EstadoCuenta>, (StatusCode, String)> {
+    let error_sql = |e: sqlx::Error| {
+        tracing::error!("❌ SQL en post_verificar: {e}");
+        (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+    };
+
+    let mut tx = pool.begin().await.map_err(error_sql)?;
+    let id_usuario = usar_token(&mut *tx, payload.token.trim(), Tipo::Verificacion)
+        .await
+        .map_err(error_sql)?
+        .ok_or_else(|| (StatusCode::BAD_REQUEST, tr!(idioma, "token-invalido")))?;
+    sqlx::query!("UPDATE Usuario SET correo_verificado = TRUE WHERE id_usuario = ?", id_usuario)
+        .execute(&mut *tx)
+        .await
+        .map_err(error_sql)?;
+    tx.commit().await.map_err(error_sql)?;
+
+    tracing::info!("✅ Correo de {id_usuario} verificado");
+    Ok(Json(EstadoCuenta { id_usuario, correo_verificado: true }))
+}
+
+// POST /cuenta/restablecer — pedir el enlace
+pub async fn post_restablecer(
+    idioma: Idioma,
+    Extension(pool): Extension<MySqlPool>,
+    Json(payload): Json<RestablecerPayload>,
+) -> Result<Json<Value>, (StatusCode, String)> {
+    let correo = payload.correo.trim();
+    if !correo_valido(correo) {
+        return Err((StatusCode::BAD_REQUEST, tr!(idioma, "correo-invalido", correo = correo.to_owned())));
+    }
+
+    let cuentas = sqlx::query!("SELECT id_usuario, nombre_usuario FROM Usuario WHERE correo = ?", correo)
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("❌ SQL en post_restablecer: {e}");
+            (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+        })?;
+
+    // Ni los errores ni la espera se cuentan: la respuesta no debe decir
+    // si el correo tiene cuenta
+    for cuenta in cuentas {
+        if Dificultad::from_nombre(&cuenta.nombre_usuario).is_some() {
+            continue;
+        }
+        let id_usuario = cuenta.id_usuario;
+        let envio = async {
+            if espera_pendiente(&pool, id_usuario, Tipo::Restablecer).await?.is_some() {
+                return Ok(false);
+            }
+            let token = emitir(&pool, id_usuario, Tipo::Restablecer).await?;
+            notificaciones::restablecer(&pool, id_usuario, cuenta.nombre_usuario, Tipo::Restablecer.enlace(&token)).await?;
+            Ok::<_, sqlx::Error>(true)
+        };
+        match envio.await {
+            Ok(true) => tracing::info!("🔑 Enlace para restablecer la contraseña de {id_usuario}"),
+            Ok(false) => {}
+            Err(e) => tracing::error!("❌ No se pudo mandar el enlace para restablecer a {id_usuario}: {e}"),
+        }
+    }
+
+    Ok(Json(json!({ "mensaje": tr!(idioma, "restablecer-enviado") })))
+}
+
+// POST /cuenta/restablecer/confirmar — contraseña nueva
+pub async fn post_confirmar_restablecer(
+    idioma: Idioma,
+    Extension(pool): Extension<MySqlPool>,
+    Json(payload): Json<NuevaContrasenaPayload>,
+) -> Result<Json<Value>, (StatusCode, String)> {
+    let error_sql = |e: sqlx::Error| {
+        tracing::error!("❌ SQL en post_confirmar_restablecer: {e}");
+        (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+    };
+    validar_contrasena(idioma, &payload.contrasena)?;
+
+    let mut tx = pool.begin().await.map_err(error_sql)?;
+    let id_usuario = usar_token(&mut *tx, payload.token.trim(), Tipo::Restablecer)
+        .await
+        .map_err(error_sql)?
+        .ok_or_else(|| (StatusCode::BAD_REQUEST, tr!(idioma, "token-invalido")))?;
+    // El enlace llegó a su correo: con eso también queda verificado
+    sqlx::query!(
+        "UPDATE Usuario SET contrasena = ?, correo_verificado = TRUE WHERE id_usuario = ?",
+        payload.contrasena,
+        id_usuario
+    )
+        .execute(&mut *tx)
+        .await
+        .map_err(error_sql)?;
+    sqlx::query!("DELETE FROM TokenCuenta WHERE id_usuario = ? AND fecha_uso IS NULL", id_usuario)
+        .execute(&mut *tx)
+        .await
+        .map_err(error_sql)?;
+    tx.commit().await.map_err(error_sql)?;
+
+    tracing::info!("🔑 Contraseña de {id_usuario} restablecida");
+    Ok(Json(json!({ "mensaje": tr!(idioma, "contrasena-cambiada") })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn guardado(expira_en_min: i64, usado: bool) -> TokenGuardado {
+        let ahora = chrono::Utc::now().naive_utc();
+        TokenGuardado {
+            id_usuario: 7,
+            fecha_expira: ahora + Duration::minutes(expira_en_min),
+            fecha_uso: usado.then(|| ahora - Duration::minutes(1)),
+            ahora,
+        }
+    }
+
+    #[test]
+    fn token_sin_usar_y_sin_caducar_vale() {
+        assert!(guardado(10, false).vigente());
+    }
+
+    #[test]
+    fn token_caducado_no_vale() {
+        assert!(!guardado(-1, false).vigente());
+        assert!(!guardado(0, false).vigente());
+    }
+
+    #[test]
+    fn token_usado_no_vale_otra_vez() {
+        assert!(!guardado(10, true).vigente());
+    }
+
+    #[test]
+    fn vigencia_por_tipo() {
+        assert_eq!(Tipo::Verificacion.vigencia_minutos(), HORAS_VERIFICACION * 60);
+        assert_eq!(Tipo::Restablecer.vigencia_minutos(), MINUTOS_RESTABLECER);
+    }
+
+    #[test]
+    fn tokens_nuevos_distintos_y_en_hexadecimal() {
+        let (a, b) = (token_nuevo(), token_nuevo());
+        assert_eq!(a.len(), 64);
+        assert!(a.chars().all(|c| c.is_ascii_hexdigit()));
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn se_guarda_la_huella_y_no_el_token() {
+        assert_eq!(huella("abc"), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+        let token = token_nuevo();
+        assert_ne!(huella(&token), token);
+        assert_eq!(huella(&token).len(), 64);
+    }
+
+    #[test]
+    fn contrasena_minima() {
+        assert!(validar_contrasena(Idioma::Es, "1234567").is_err());
+        assert!(validar_contrasena(Idioma::Es, "").is_err());
+        assert!(validar_contrasena(Idioma::Es, "12345678").is_ok());
+        // Se cuentan caracteres, no bytes
+        assert!(validar_contrasena(Idioma::Es, "ñandú").is_err());
+        let (estado, mensaje) = validar_contrasena(Idioma::En, "corta").unwrap_err();
+        assert_eq!(estado, StatusCode::BAD_REQUEST);
+        assert_eq!(mensaje, "The password must be at least 8 characters long");
+    }
+}
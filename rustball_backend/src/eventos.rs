@@ -0,0 +1,416 @@
+// -----------------------------------------------------------------
+//! Eventos de cada turno y las estadísticas que salen de ellos.
+//!
+//!   • El cliente que tira manda en `jugada.eventos` lo que pasó en su
+//!     turno: contactos ficha-pelota, rebotes en paredes, power-ups
+//!     recogidos y entradas a zonas.  `post_jugada` los saca de la
+//!     jugada (no se guardan dos veces) y los pasa a `EventoTurno`.
+//!   • El disparo y los power-ups usados salen de `jugada.tiro`, así
+//!     que también quedan para los turnos del bot.  Un power-up recogido
+//!     sólo cuenta si era el que había en el campo antes del tiro
+//!     (`tiro.tablero_previo`), y uno por turno.
+//!   • El tiro que mete un gol no cierra el turno: `post_gol` manda su
+//!     jugada a `registrar_gol`, que guarda sus eventos y marca el gol
+//!     con detalle `poste` si la pelota rebotó en uno durante ese tiro.
+//!   • `GET /partida/:p/estadisticas` agrega los eventos por jugador y
+//!     `carrera` suma los de todas sus partidas para `/estadisticas/:u`.
+// -----------------------------------------------------------------
+
+use axum::{
+    extract::{Extension, Path},
+    http::StatusCode,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::{MySql, MySqlPool, QueryBuilder, Transaction};
+
+use crate::i18n::{tr, Idioma};
+
+/// Tope de eventos por turno (un tiro con muchos rebotes no llega ni cerca).
+const MAX_EVENTOS_POR_TURNO: usize = 200;
+/// Fichas por partida (`DiskId` del cliente: 0‥5 izquierda, 5‥10 derecha).
+const MAX_ID_FICHA: u32 = 10;
+const ZONAS: [&str; 3] = ["resbalosa", "lenta", "trampolin"];
+
+/// Evento tal como lo manda el cliente en `jugada.eventos`.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "tipo", rename_all = "snake_case")]
+enum EventoCliente {
+    ContactoPelota { id_ficha: u32 },
+    /// Sin ficha: rebotó la pelota.
+    Rebote {
+        #[serde(default)]
+        id_ficha: Option<u32>,
+        /// Contra un poste del arco (no una pared).
+        #[serde(default)]
+        poste: bool,
+    },
+    Powerup { id_ficha: u32, powerup: u32 },
+    Zona { id_ficha: u32, zona: String },
+}
+
+/// Fila de `EventoTurno` lista para insertar.
+#[derive(Debug)]
+struct Evento {
+    tipo: &'static str,
+    id_ficha: Option<i32>,
+    valor: Option<f64>,
+    detalle: Option<String>,
+}
+
+impl Evento {
+    fn new(tipo: &'static str, id_ficha: Option<u32>) -> Self {
+        Self { tipo, id_ficha: id_ficha.map(|f| f as i32), valor: None, detalle: None }
+    }
+}
+
+/// Quita `eventos` de la jugada (se guardan aparte) y los devuelve.
+pub fn separar_eventos(jugada: &mut Value) -> Vec<Value> {
+    match jugada.as_object_mut().and_then(|o| o.remove("eventos")) {
+        Some(Value::Array(eventos)) => eventos,
+        _ => Vec::new(),
+    }
+}
+
+/// `PowerUpType` que esperaba en el campo justo antes del tiro.
+fn powerup_en_campo(jugada: &Value) -> Option<u32> {
+    jugada
+        .pointer("/tiro/tablero_previo/powerup/tipo")
+        .and_then(Value::as_u64)
+        .map(|t| t as u32)
+}
+
+/// Disparo y power-ups usados (de `tiro`) más los eventos válidos del cliente.
+fn eventos_del_turno(jugada: &Value, eventos: &[Value]) -> Vec<Evento> {
+    let mut filas = Vec::new();
+    let mut en_campo = powerup_en_campo(jugada);
+
+    if let Some(tiro) = jugada.get("tiro") {
+        let id_ficha = tiro.get("id_ficha").and_then(Value::as_u64).map(|f| f as u32);
+        let mut disparo = Evento::new("disparo", id_ficha);
+        disparo.valor = tiro.get("potencia").and_then(Value::as_f64).map(|p| p.clamp(0.0, 1.0));
+        filas.push(disparo);
+
+        for powerup in tiro.get("powerups").and_then(Value::as_array).into_iter().flatten() {
+            let mut usado = Evento::new("powerup_usado", id_ficha);
+            usado.detalle = Some(powerup.to_string());
+            filas.push(usado);
+        }
+    }
+
+    for valor in eventos.iter().take(MAX_EVENTOS_POR_TURNO) {
+        let evento = match serde_json::from_value::<EventoCliente>(valor.clone()) {
+            Ok(e) => e,
+            Err(e) => {
+                tracing::warn!("⚠️ Evento de turno ignorado ({e}): {valor}");
+                continue;
+            }
+        };
+
+        let fila = match evento {
+            EventoCliente::ContactoPelota { id_ficha } if id_ficha < MAX_ID_FICHA => {
+                Evento::new("contacto_pelota", Some(id_ficha))
+            }
+            EventoCliente::Rebote { id_ficha, poste } if id_ficha.map_or(true, |f| f < MAX_ID_FICHA) => Evento {
+                detalle: poste.then(|| "poste".to_owned()),
+                ..Evento::new("rebote", id_ficha)
+            },
+            // Hay un solo power-up en el campo: se recoge una vez
+            EventoCliente::Powerup { id_ficha, powerup } if id_ficha < MAX_ID_FICHA && en_campo == Some(powerup) => {
+                en_campo = None;
+                Evento { detalle: Some(powerup.to_string()), ..Evento::new("powerup", Some(id_ficha)) }
+            }
+            EventoCliente::Zona { id_ficha, zona } if id_ficha < MAX_ID_FICHA && ZONAS.contains(&zona.as_str()) => {
+                Evento { detalle: Some(zona), ..Evento::new("zona", Some(id_ficha)) }
+            }
+            otro => {
+                tracing::warn!("⚠️ Evento de turno fuera de rango: {otro:?}");
+                continue;
+            }
+        };
+        filas.push(fila);
+    }
+
+    filas
+}
+
+async fn insertar(
+    tx: &mut Transaction<'_, MySql>,
+    (id_partida, numero_turno, ronda, id_usuario): (i32, i32, i32, i32),
+    filas: Vec<Evento>,
+) -> Result<(), sqlx::Error> {
+    if filas.is_empty() {
+        return Ok(());
+    }
+
+    let mut query = QueryBuilder::<MySql>::new(
+        "INSERT INTO EventoTurno (id_partida, numero_turno, ronda, id_usuario, tipo, id_ficha, valor, detalle) ",
+    );
+    query.push_values(filas, |mut fila, e| {
+        fila.push_bind(id_partida)
+            .push_bind(numero_turno)
+            .push_bind(ronda)
+            .push_bind(id_usuario)
+            .push_bind(e.tipo)
+            .push_bind(e.id_ficha)
+            .push_bind(e.valor)
+            .push_bind(e.detalle);
+    });
+    query.build().execute(&mut **tx).await?;
+    Ok(())
+}
+
+/// Guarda los eventos del turno `numero_turno` (dentro de la transacción
+/// de `post_jugada`).
+pub async fn registrar_turno(
+    tx: &mut Transaction<'_, MySql>,
+    (id_partida, numero_turno, ronda): (i32, i32, i32),
+    id_usuario: i32,
+    jugada: &Value,
+    eventos: &[Value],
+) -> Result<(), sqlx::Error> {
+    let filas = eventos_del_turno(jugada, eventos);
+    tracing::debug!("📊 Turno {numero_turno} de la partida {id_partida}: {} eventos", filas.len());
+    insertar(tx, (id_partida, numero_turno, ronda, id_usuario), filas).await
+}
+
+/// ¿La pelota (no una ficha) rebotó en un poste?
+fn pelota_en_poste(filas: &[Evento]) -> bool {
+    filas
+        .iter()
+        .any(|e| e.tipo == "rebote" && e.id_ficha.is_none() && e.detalle.as_deref() == Some("poste"))
+}
+
+/// Gol de `id_goleador` en la ronda `ronda`; queda asociado al último turno
+/// junto con los eventos de `jugada`, el tiro de `tirador` que lo metió.
+pub async fn registrar_gol(
+    tx: &mut Transaction<'_, MySql>,
+    (id_partida, ronda): (i32, i32),
+    (tirador, id_goleador): (i32, i32),
+    jugada: &Value,
+    eventos: &[Value],
+) -> Result<(), sqlx::Error> {
+    let ultimo_turno: i64 = sqlx::query_scalar!(
+        "SELECT COALESCE(MAX(numero_turno), 0) FROM Turno WHERE id_partida = ?",
+        id_partida
+    )
+        .fetch_one(&mut **tx)
+        .await?;
+
+    let tiro = eventos_del_turno(jugada, eventos);
+    let gol = Evento { detalle: pelota_en_poste(&tiro).then(|| "poste".to_owned()), ..Evento::new("gol", None) };
+    insertar(tx, (id_partida, ultimo_turno as i32, ronda, tirador), tiro).await?;
+    insertar(tx, (id_partida, ultimo_turno as i32, ronda, id_goleador), vec![gol]).await
+}
+
+/* ───────── GET /partida/:p/estadisticas ───────── */
+
+#[derive(Debug, Serialize)]
+pub struct EstadisticasJugador {
+    pub id_usuario: i32,
+    pub nombre_usuario: String,
+    pub tiros: i64,
+    /// Potencia media de los disparos (0‥1); `None` sin disparos.
+    pub potencia_media: Option<f64>,
+    pub contactos_pelota: i64,
+    pub rebotes: i64,
+    pub powerups_recogidos: i64,
+    pub powerups_usados: i64,
+    pub zonas: i64,
+    pub goles: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EstadisticasPartida {
+    pub id_partida: i32,
+    /// Jugador 1 y jugador 2, en el orden de la partida.
+    pub jugadores: Vec<EstadisticasJugador>,
+}
+
+pub async fn get_estadisticas_partida(
+    idioma: Idioma,
+    Path(id_partida): Path<i32>,
+    Extension(pool): Extension<MySqlPool>,
+) -> Result<Json<EstadisticasPartida>, (StatusCode, String)> {
+    let filas = sqlx::query!(
+        r#"
+        SELECT u.id_usuario, u.nombre_usuario,
+               COUNT(CASE WHEN e.tipo = 'disparo'         THEN 1 END) AS "tiros!: i64",
+               AVG(CASE WHEN e.tipo = 'disparo'           THEN e.valor END) AS "potencia_media: f64",
+               COUNT(CASE WHEN e.tipo = 'contacto_pelota' THEN 1 END) AS "contactos_pelota!: i64",
+               COUNT(CASE WHEN e.tipo = 'rebote'          THEN 1 END) AS "rebotes!: i64",
+               COUNT(CASE WHEN e.tipo = 'powerup'         THEN 1 END) AS "powerups_recogidos!: i64",
+               COUNT(CASE WHEN e.tipo = 'powerup_usado'   THEN 1 END) AS "powerups_usados!: i64",
+               COUNT(CASE WHEN e.tipo = 'zona'            THEN 1 END) AS "zonas!: i64",
+               COUNT(CASE WHEN e.tipo = 'gol'             THEN 1 END) AS "goles!: i64"
+        FROM   Partida p
+        JOIN   Usuario u ON u.id_usuario IN (p.id_jugador1, p.id_jugador2)
+        LEFT JOIN EventoTurno e ON e.id_partida = p.id_partida AND e.id_usuario = u.id_usuario
+        WHERE  p.id_partida = ?
+        GROUP  BY u.id_usuario, u.nombre_usuario, p.id_jugador1
+        ORDER  BY u.id_usuario <> p.id_jugador1
+        "#,
+        id_partida
+    )
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("❌ Error SQL en estadísticas de la partida {id_partida}: {e:?}");
+            (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+        })?;
+
+    if filas.is_empty() {
+        return Err((StatusCode::NOT_FOUND, tr!(idioma, "partida-no-encontrada", partida = id_partida)));
+    }
+
+    let jugadores = filas
+        .into_iter()
+        .map(|f| EstadisticasJugador {
+            id_usuario: f.id_usuario,
+            nombre_usuario: f.nombre_usuario,
+            tiros: f.tiros,
+            potencia_media: f.potencia_media,
+            contactos_pelota: f.contactos_pelota,
+            rebotes: f.rebotes,
+            powerups_recogidos: f.powerups_recogidos,
+            powerups_usados: f.powerups_usados,
+            zonas: f.zonas,
+            goles: f.goles,
+        })
+        .collect();
+
+    Ok(Json(EstadisticasPartida { id_partida, jugadores }))
+}
+
+/* ───────── Estadísticas de carrera ───────── */
+
+/// Lo que `/estadisticas/:u` agrega a los contadores de `Estadistica`.
+#[derive(Debug, Serialize)]
+pub struct Carrera {
+    pub tiros: i64,
+    /// Tiros por cada gol marcado; `None` si todavía no marcó.
+    pub tiros_por_gol: Option<f64>,
+    pub potencia_media: Option<f64>,
+    /// Formación más elegida (predefinida o nombre de la personalizada).
+    pub formacion_favorita: Option<String>,
+    pub powerups_usados: i64,
+}
+
+pub async fn carrera(pool: &MySqlPool, id_usuario: i32) -> Result<Carrera, sqlx::Error> {
+    let eventos = sqlx::query!(
+        r#"
+        SELECT COUNT(CASE WHEN tipo = 'disparo'       THEN 1 END) AS "tiros!: i64",
+               COUNT(CASE WHEN tipo = 'gol'           THEN 1 END) AS "goles!: i64",
+               AVG(CASE WHEN tipo = 'disparo'         THEN valor END) AS "potencia_media: f64",
+               COUNT(CASE WHEN tipo = 'powerup_usado' THEN 1 END) AS "powerups_usados!: i64"
+        FROM   EventoTurno
+        WHERE  id_usuario = ?
+        "#,
+        id_usuario
+    )
+        .fetch_one(pool)
+        .await?;
+
+    let formacion_favorita = sqlx::query_scalar!(
+        r#"
+        SELECT formacion
+        FROM   FormacionElegida
+        WHERE  id_usuario = ?
+        GROUP  BY formacion
+        ORDER  BY COUNT(*) DESC, formacion
+        LIMIT  1
+        "#,
+        id_usuario
+    )
+        .fetch_optional(pool)
+        .await?;
+
+    Ok(Carrera {
+        tiros: eventos.tiros,
+        tiros_por_gol: (eventos.goles > 0).then(|| eventos.tiros as f64 / eventos.goles as f64),
+        potencia_media: eventos.potencia_media,
+        formacion_favorita,
+        powerups_usados: eventos.powerups_usados,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn separar_eventos_los_quita_de_la_jugada() {
+        let mut jugada = json!({ "piezas": [], "eventos": [{ "tipo": "rebote" }] });
+        assert_eq!(separar_eventos(&mut jugada).len(), 1);
+        assert!(jugada.get("eventos").is_none());
+
+        // Sin eventos o con un valor que no es lista: nada
+        assert!(separar_eventos(&mut json!({ "piezas": [] })).is_empty());
+        assert!(separar_eventos(&mut json!({ "eventos": "x" })).is_empty());
+    }
+
+    #[test]
+    fn disparo_y_powerups_salen_del_tiro() {
+        let jugada = json!({ "tiro": { "id_ficha": 3, "potencia": 1.7, "powerups": [2, 5] } });
+        let filas = eventos_del_turno(&jugada, &[]);
+
+        let tipos: Vec<_> = filas.iter().map(|e| e.tipo).collect();
+        assert_eq!(tipos, ["disparo", "powerup_usado", "powerup_usado"]);
+        assert_eq!(filas[0].id_ficha, Some(3));
+        assert_eq!(filas[0].valor, Some(1.0), "la potencia se recorta a [0, 1]");
+        assert_eq!(filas[2].detalle.as_deref(), Some("5"));
+    }
+
+    #[test]
+    fn eventos_invalidos_o_fuera_de_rango_se_descartan() {
+        let eventos = [
+            json!({ "tipo": "contacto_pelota", "id_ficha": 1 }),
+            json!({ "tipo": "contacto_pelota", "id_ficha": MAX_ID_FICHA }),
+            json!({ "tipo": "rebote", "poste": true }),
+            json!({ "tipo": "zona", "id_ficha": 2, "zona": "lenta" }),
+            json!({ "tipo": "zona", "id_ficha": 2, "zona": "lava" }),
+            json!({ "tipo": "teletransporte" }),
+        ];
+        let filas = eventos_del_turno(&json!({}), &eventos);
+
+        let resumen: Vec<_> = filas.iter().map(|e| (e.tipo, e.id_ficha, e.detalle.as_deref())).collect();
+        assert_eq!(
+            resumen,
+            [
+                ("contacto_pelota", Some(1), None),
+                ("rebote", None, Some("poste")),
+                ("zona", Some(2), Some("lenta")),
+            ]
+        );
+    }
+
+    #[test]
+    fn powerup_solo_si_estaba_en_el_campo() {
+        let recoge = |powerup: u32| json!({ "tipo": "powerup", "id_ficha": 2, "powerup": powerup });
+        let jugada = json!({ "tiro": { "id_ficha": 2, "tablero_previo": { "piezas": [], "powerup": { "tipo": 1, "x": 0.0, "y": 0.0 } } } });
+
+        // Otro tipo, o el mismo dos veces: sólo cuenta el que había
+        let filas = eventos_del_turno(&jugada, &[recoge(0), recoge(1), recoge(1)]);
+        let recogidos: Vec<_> = filas.iter().filter(|e| e.tipo == "powerup").map(|e| e.detalle.as_deref()).collect();
+        assert_eq!(recogidos, [Some("1")]);
+
+        // Sin tiro no se sabe qué había en el campo
+        assert!(eventos_del_turno(&json!({}), &[recoge(1)]).is_empty());
+    }
+
+    #[test]
+    fn gol_de_poste_solo_si_fue_la_pelota() {
+        let filas = |eventos: &[Value]| eventos_del_turno(&json!({}), eventos);
+        assert!(pelota_en_poste(&filas(&[json!({ "tipo": "rebote" }), json!({ "tipo": "rebote", "poste": true })])));
+        assert!(!pelota_en_poste(&filas(&[json!({ "tipo": "rebote", "id_ficha": 3, "poste": true })])));
+        assert!(!pelota_en_poste(&filas(&[json!({ "tipo": "rebote" })])));
+    }
+
+    #[test]
+    fn tope_de_eventos_por_turno() {
+        let eventos = vec![json!({ "tipo": "rebote" }); MAX_EVENTOS_POR_TURNO + 50];
+        assert_eq!(eventos_del_turno(&json!({}), &eventos).len(), MAX_EVENTOS_POR_TURNO);
+    }
+}
@@ -0,0 +1,378 @@
+// -----------------------------------------------------------------
+//! Formaciones: las cuatro predefinidas y las personalizadas que cada
+//! usuario diseña en `formaciones.html`.
+//!
+//!   • Las posiciones se guardan en coordenadas “canónicas” del lado
+//!     derecho (x > 0, arco propio en x = +550); el cliente las refleja
+//!     para quien juega a la izquierda.
+//!   • `validar_posiciones` exige 5 fichas dentro de la cancha, en el
+//!     campo propio, fuera del área chica y sin solaparse (las reglas
+//!     y las predefinidas están en `rustball_shared::formation`, las
+//!     mismas que usa el cliente).
+//!   • `post_formacion` ya no acepta texto libre: `resolver_formacion`
+//!     comprueba el nombre de la predefinida o que la personalizada
+//!     sea del usuario, y copia sus posiciones a `FormacionElegida`.
+//!   • Saque: las fichas de quien no saca arrancan fuera del círculo
+//!     central (`posiciones_saque`) y `validar_saque` comprueba en el
+//!     primer tiro de cada ronda que el rival esté en esas posiciones.
+// -----------------------------------------------------------------
+
+use axum::{
+    extract::{Extension, Path},
+    http::StatusCode,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::MySqlPool;
+
+use crate::i18n::{tr, Idioma};
+use crate::models::FormacionPayload;
+
+/* ───────── Geometría (compartida con el cliente) ───────── */
+
+use rustball_shared::formation::{self as geometria, FormationError};
+use rustball_shared::V2;
+
+/// Formaciones predefinidas (mismo orden y nombres que `Formation` en el cliente).
+pub use rustball_shared::formation::PRESETS as PREDEFINIDAS;
+
+/// Margen para redondeos de coma flotante del cliente al comparar el saque.
+const TOLERANCIA_SAQUE: f32 = 1.0;
+
+const MAX_NOMBRE: usize = 40;
+const MAX_POR_USUARIO: i64 = 10;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct Pos {
+    pub x: f32,
+    pub y: f32,
+}
+
+pub fn predefinida(nombre: &str) -> Option<Vec<Pos>> {
+    geometria::preset(nombre).map(|ps| ps.iter().map(|p| Pos { x: p.x, y: p.y }).collect())
+}
+
+/// Posiciones de una `FormacionElegida` ya reflejadas según el lado.
+/// Usa `posiciones` si la formación es personalizada; si no, la predefinida.
+pub fn posiciones_de(formacion: &str, posiciones: Option<&Value>, is_left: bool) -> Vec<(f32, f32)> {
+    posiciones
+        .and_then(|v| serde_json::from_value::<Vec<Pos>>(v.clone()).ok())
+        .or_else(|| predefinida(formacion))
+        .unwrap_or_else(|| predefinida(PREDEFINIDAS[0].0).unwrap())
+        .into_iter()
+        .map(|p| geometria::mirror(V2::new(p.x, p.y), is_left))
+        .map(|p| (p.x, p.y))
+        .collect()
+}
+
+/// Como `posiciones_de`, pero si el dueño no saca las fichas que invaden
+/// el círculo central se empujan hasta su borde (como `kickoff_positions`).
+pub fn posiciones_saque(
+    formacion: &str,
+    posiciones: Option<&Value>,
+    is_left: bool,
+    saca: bool,
+) -> Vec<(f32, f32)> {
+    let propias = posiciones_de(formacion, posiciones, is_left);
+    if saca {
+        return propias;
+    }
+
+    propias
+        .into_iter()
+        .map(|(x, y)| geometria::kickoff_position(V2::new(x, y), is_left))
+        .map(|p| (p.x, p.y))
+        .collect()
+}
+
+/// Primer tiro de una ronda: las fichas de `rival` en el tablero previo
+/// (`tiro.tablero_previo`) tienen que estar donde las deja el saque.
+/// `esperadas` se reconstruye con `posiciones_saque` desde su
+/// `FormacionElegida`, así que ninguna puede quedar dentro del círculo
+/// central aunque el cliente mande otro tablero.
+pub fn validar_saque(idioma: Idioma, jugada: &Value, rival: i32, esperadas: &[(f32, f32)]) -> Result<(), String> {
+    let Some(piezas) = jugada
+        .pointer("/tiro/tablero_previo/piezas")
+        .and_then(Value::as_array)
+    else {
+        return Err(tr!(idioma, "saque-sin-tiro"));
+    };
+
+    let num = |p: &Value, k: &str| p.get(k).and_then(Value::as_f64).unwrap_or(f64::NAN) as f32;
+    let del_rival: Vec<(f32, f32)> = piezas
+        .iter()
+        .filter(|p| p.get("id_usuario_real").and_then(Value::as_i64) == Some(rival as i64))
+        .map(|p| (num(p, "x"), num(p, "y")))
+        .collect();
+
+    if del_rival.len() != esperadas.len() {
+        return Err(tr!(idioma, "saque-fichas-rival", tiene = del_rival.len(), esperadas = esperadas.len()));
+    }
+    for (x, y) in del_rival {
+        let en_su_lugar = esperadas
+            .iter()
+            .any(|&(ex, ey)| ((x - ex).powi(2) + (y - ey).powi(2)).sqrt() <= TOLERANCIA_SAQUE);
+        if !en_su_lugar {
+            return Err(tr!(idioma, "saque-invalido", x = format!("{x:.0}"), y = format!("{y:.0}")));
+        }
+    }
+    Ok(())
+}
+
+/// Reglas de una formación personalizada (coordenadas canónicas).
+pub fn validar_posiciones(idioma: Idioma, posiciones: &[Pos]) -> Result<(), String> {
+    let posiciones: Vec<V2> = posiciones.iter().map(|p| V2::new(p.x, p.y)).collect();
+
+    geometria::validate_positions(&posiciones).map_err(|e| {
+        let clave = e.key();
+        match e {
+            FormationError::Count(tiene) => tr!(idioma, clave, esperadas = geometria::DISKS_PER_FORMATION, tiene = tiene),
+            FormationError::Overlap(a, b) => tr!(idioma, clave, a = a, b = b),
+            FormationError::Coordinates(n)
+            | FormationError::OwnHalf(n)
+            | FormationError::OutOfField(n)
+            | FormationError::GoalArea(n) => tr!(idioma, clave, n = n),
+        }
+    })
+}
+
+/// Valida el pedido de `POST /formacion` y devuelve `(formacion, posiciones)`
+/// listas para guardar en `FormacionElegida`.
+pub async fn resolver_formacion(
+    idioma: Idioma,
+    pool: &MySqlPool,
+    p: &FormacionPayload,
+) -> Result<(String, Option<Value>), (StatusCode, String)> {
+    let Some(id_formacion) = p.id_formacion else {
+        return match predefinida(&p.formacion) {
+            Some(_) => Ok((p.formacion.clone(), None)),
+            None => Err((StatusCode::BAD_REQUEST, tr!(idioma, "formacion-desconocida", nombre = p.formacion.clone()))),
+        };
+    };
+
+    let fila = sqlx::query!(
+        "SELECT nombre, posiciones FROM FormacionPersonalizada WHERE id_formacion = ? AND id_usuario = ?",
+        id_formacion,
+        p.id_usuario
+    )
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("❌ SQL error SELECT FormacionPersonalizada: {e:?}");
+            (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+        })?
+        .ok_or_else(|| (StatusCode::NOT_FOUND, tr!(idioma, "formacion-ajena", id = id_formacion)))?;
+
+    let posiciones: Vec<Pos> = serde_json::from_value(fila.posiciones.clone())
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "formacion-corrupta", detalle = e.to_string())))?;
+    validar_posiciones(idioma, &posiciones).map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+    Ok((fila.nombre, Some(fila.posiciones)))
+}
+
+/* ───────── Endpoints ───────── */
+
+#[derive(Debug, Serialize)]
+pub struct FormacionPersonalizada {
+    pub id_formacion: i32,
+    pub nombre: String,
+    pub posiciones: Vec<Pos>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NuevaFormacionPayload {
+    pub id_usuario: i32,
+    pub nombre: String,
+    pub posiciones: Vec<Pos>,
+}
+
+/// GET /formaciones/:u — formaciones personalizadas del usuario.
+pub async fn get_formaciones(
+    idioma: Idioma,
+    Path(id_usuario): Path<i32>,
+    Extension(pool): Extension<MySqlPool>,
+) -> Result<Json<Vec<FormacionPersonalizada>>, (StatusCode, String)> {
+    let filas = sqlx::query!(
+        "SELECT id_formacion, nombre, posiciones FROM FormacionPersonalizada WHERE id_usuario = ? ORDER BY id_formacion",
+        id_usuario
+    )
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("❌ SQL error listando formaciones de {id_usuario}: {e:?}");
+            (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+        })?;
+
+    let formaciones = filas
+        .into_iter()
+        .filter_map(|f| {
+            let posiciones = serde_json::from_value(f.posiciones).ok()?;
+            Some(FormacionPersonalizada { id_formacion: f.id_formacion, nombre: f.nombre, posiciones })
+        })
+        .collect();
+
+    Ok(Json(formaciones))
+}
+
+/// POST /formaciones — guarda (o reemplaza, por nombre) una formación personalizada.
+pub async fn post_formacion_personalizada(
+    idioma: Idioma,
+    Extension(pool): Extension<MySqlPool>,
+    Json(p): Json<NuevaFormacionPayload>,
+) -> Result<Json<FormacionPersonalizada>, (StatusCode, String)> {
+    tracing::info!("▶️  POST /formaciones — usuario {} guarda '{}'", p.id_usuario, p.nombre);
+
+    let nombre = p.nombre.trim().to_string();
+    if nombre.is_empty() || nombre.chars().count() > MAX_NOMBRE {
+        return Err((StatusCode::BAD_REQUEST, tr!(idioma, "nombre-longitud", max = MAX_NOMBRE)));
+    }
+    if predefinida(&nombre).is_some() {
+        return Err((StatusCode::BAD_REQUEST, tr!(idioma, "nombre-predefinida", nombre = nombre.clone())));
+    }
+    validar_posiciones(idioma, &p.posiciones).map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+    let cantidad: i64 = sqlx::query_scalar!(
+        "SELECT COUNT(*) FROM FormacionPersonalizada WHERE id_usuario = ? AND nombre <> ?",
+        p.id_usuario,
+        nombre
+    )
+        .fetch_one(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("❌ SQL error contando formaciones de {}: {e:?}", p.id_usuario);
+            (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+        })?;
+    if cantidad >= MAX_POR_USUARIO {
+        return Err((StatusCode::BAD_REQUEST, tr!(idioma, "maximo-formaciones", max = MAX_POR_USUARIO)));
+    }
+
+    let posiciones = serde_json::to_value(&p.posiciones).expect("posiciones serializables");
+    sqlx::query!(
+        r#"
+        INSERT INTO FormacionPersonalizada (id_usuario, nombre, posiciones)
+        VALUES (?, ?, ?)
+        ON DUPLICATE KEY UPDATE posiciones = VALUES(posiciones)
+        "#,
+        p.id_usuario,
+        nombre,
+        posiciones
+    )
+        .execute(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("❌ SQL error guardando formación: {e:?}");
+            (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+        })?;
+
+    let id_formacion = sqlx::query_scalar!(
+        "SELECT id_formacion FROM FormacionPersonalizada WHERE id_usuario = ? AND nombre = ?",
+        p.id_usuario,
+        nombre
+    )
+        .fetch_one(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("❌ SQL error leyendo id de formación guardada: {e:?}");
+            (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+        })?;
+
+    tracing::info!("✅ Formación '{nombre}' guardada (id {id_formacion})");
+    Ok(Json(FormacionPersonalizada { id_formacion, nombre, posiciones: p.posiciones }))
+}
+
+/// DELETE /formaciones/:u/:id — borra una formación personalizada propia.
+pub async fn delete_formacion_personalizada(
+    idioma: Idioma,
+    Path((id_usuario, id_formacion)): Path<(i32, i32)>,
+    Extension(pool): Extension<MySqlPool>,
+) -> Result<Json<&'static str>, (StatusCode, String)> {
+    let res = sqlx::query!(
+        "DELETE FROM FormacionPersonalizada WHERE id_formacion = ? AND id_usuario = ?",
+        id_formacion,
+        id_usuario
+    )
+        .execute(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("❌ SQL error borrando formación {id_formacion}: {e:?}");
+            (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+        })?;
+
+    if res.rows_affected() == 0 {
+        return Err((StatusCode::NOT_FOUND, tr!(idioma, "formacion-no-encontrada")));
+    }
+    Ok(Json("Formación eliminada"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn predefinidas_reflejadas_segun_el_lado() {
+        let derecha = posiciones_de("2-2-1", None, false);
+        let izquierda = posiciones_de("2-2-1", None, true);
+        assert_eq!(derecha[0], (400.0, 100.0));
+        assert_eq!(izquierda[0], (-400.0, 100.0));
+        // Nombre desconocido: la primera predefinida
+        assert_eq!(posiciones_de("4-4-2", None, false), posiciones_de(PREDEFINIDAS[0].0, None, false));
+    }
+
+    #[test]
+    fn personalizada_tiene_prioridad() {
+        let propias = json!([{ "x": 300.0, "y": 0.0 }]);
+        assert_eq!(posiciones_de("1-2-1-1", Some(&propias), true), vec![(-300.0, 0.0)]);
+    }
+
+    #[test]
+    fn quien_no_saca_queda_fuera_del_circulo() {
+        let minima = geometria::KICKOFF_RADIUS + rustball_shared::field::DISK_RADIUS;
+        for (x, y) in posiciones_saque("1-2-1-1", None, true, false) {
+            assert!((x * x + y * y).sqrt() >= minima - 1e-3, "({x}, {y})");
+            assert!(x < 0.0);
+        }
+        // Quien saca conserva la formación tal cual
+        assert_eq!(posiciones_saque("1-2-1-1", None, true, true), posiciones_de("1-2-1-1", None, true));
+    }
+
+    fn jugada_con_rival(rival: i32, posiciones: &[(f32, f32)]) -> Value {
+        let piezas: Vec<Value> = posiciones
+            .iter()
+            .map(|&(x, y)| json!({ "id_usuario_real": rival, "x": x, "y": y }))
+            .chain([json!({ "id_usuario_real": 1, "x": 0.0, "y": 0.0 })])
+            .collect();
+        json!({ "tiro": { "tablero_previo": { "piezas": piezas } } })
+    }
+
+    #[test]
+    fn saque_con_el_rival_en_sus_posiciones() {
+        let esperadas = posiciones_saque("1-2-1-1", None, false, false);
+        assert!(validar_saque(Idioma::Es, &jugada_con_rival(2, &esperadas), 2, &esperadas).is_ok());
+
+        // Una ficha del rival metida en el círculo central
+        let mut movidas = esperadas.clone();
+        movidas[0] = (100.0, 0.0);
+        assert!(validar_saque(Idioma::Es, &jugada_con_rival(2, &movidas), 2, &esperadas).is_err());
+        // Fichas de menos
+        assert!(validar_saque(Idioma::Es, &jugada_con_rival(2, &esperadas[1..]), 2, &esperadas).is_err());
+    }
+
+    #[test]
+    fn saque_sin_tiro_se_rechaza() {
+        let esperadas = posiciones_saque("1-2-1-1", None, false, false);
+        let sin_tiro = json!({ "piezas": [] });
+        assert!(validar_saque(Idioma::Es, &sin_tiro, 2, &esperadas).is_err());
+    }
+
+    #[test]
+    fn errores_de_posiciones_traducidos() {
+        let mut ps = predefinida("1-2-1-1").unwrap();
+        assert_eq!(validar_posiciones(Idioma::Es, &ps), Ok(()));
+
+        ps[4] = Pos { x: 210.0, y: 10.0 };
+        assert_eq!(validar_posiciones(Idioma::Es, &ps).unwrap_err(), "Fichas 4 y 5: se superponen");
+        assert_eq!(validar_posiciones(Idioma::En, &ps[..2]).unwrap_err(), "A formation needs 5 disks (it has 2)");
+    }
+}
@@ -8,11 +8,13 @@
     use serde_json::json; // Asegúrate de que esto está importado
     use sqlx::{MySqlPool}; // Mantendremos las importaciones que tenías si las quieres
     use tokio::sync::broadcast;
+    use crate::i18n::{tr, Idioma};
     use crate::models::*; // Asegúrate de que tus modelos están en scope
     use tracing; // Asegúrate de que tracing está en scope
 
     #[axum::debug_handler]
     pub async fn post_jugada(
+        idioma: Idioma,
         Extension(pool): Extension<MySqlPool>,
         Extension(tx): Extension<broadcast::Sender<String>>,
         Json(payload): Json<JugadaPayload>,
@@ -21,19 +23,29 @@
 
         let mut transaction = pool.begin().await.map_err(|e| {
             tracing::error!("❌ Error al iniciar transacción: {:?}", e);
-            (StatusCode::INTERNAL_SERVER_ERROR, format!("Error al iniciar transacción: {}", e))
+            (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
         })?;
 
-        let turno_actual: Option<i32> = sqlx::query_scalar!(
-        "SELECT turno_actual FROM Partida WHERE id_partida = ?",
+        let partida = sqlx::query!(
+        r#"SELECT id_jugador1, id_jugador2, turno_actual, ronda, estado AS "estado!: String" FROM Partida WHERE id_partida = ?"#,
         payload.id_partida
     )
             .fetch_one(&mut *transaction)
             .await
             .map_err(|e| {
                 tracing::error!("❌ Error al consultar turno_actual: {:?}", e);
-                (StatusCode::INTERNAL_SERVER_ERROR, format!("Error al leer turno_actual: {}", e))
+                (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
             })?;
+        let turno_actual = partida.turno_actual;
+
+        // ⛔ Entre un gol y las formaciones de la ronda siguiente no se juega
+        if partida.estado != "playing" {
+            tracing::warn!("⛔ Jugada en la partida {} con estado '{}'", payload.id_partida, partida.estado);
+            return Err((
+                StatusCode::CONFLICT,
+                tr!(idioma, "ronda-no-empezada", ronda = partida.ronda),
+            ));
+        }
 
         if turno_actual != Some(payload.id_usuario) {
             tracing::warn!(
@@ -43,13 +55,67 @@
         );
             return Err((
                 StatusCode::BAD_REQUEST,
-                format!(
-                    "No es el turno del usuario {}. Turno actual: {:?}",
-                    payload.id_usuario, turno_actual
+                tr!(
+                    idioma,
+                    "fuera-de-turno",
+                    usuario = payload.id_usuario,
+                    turno = format!("{:?}", turno_actual)
                 ),
             ));
         }
 
+        /* ⚽ Saque: la primera jugada de la ronda es de quien saca, con las
+           fichas del rival fuera del círculo central */
+        let jugadas_en_ronda: i64 = sqlx::query_scalar!(
+        "SELECT COUNT(*) FROM Turno WHERE id_partida = ? AND ronda = ?",
+        payload.id_partida,
+        partida.ronda
+    )
+            .fetch_one(&mut *transaction)
+            .await
+            .map_err(|e| {
+                tracing::error!("❌ Error al contar jugadas de la ronda: {:?}", e);
+                (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+            })?;
+
+        if jugadas_en_ronda == 0 {
+            let saque = sqlx::query!(
+            "SELECT id_usuario, formacion, posiciones, turno_inicio FROM FormacionElegida WHERE id_partida = ? AND ronda = ?",
+            payload.id_partida,
+            partida.ronda
+        )
+                .fetch_all(&mut *transaction)
+                .await
+                .map_err(|e| {
+                    tracing::error!("❌ Error al leer quién saca: {:?}", e);
+                    (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+                })?;
+
+            let saca = saque.iter().find(|f| f.turno_inicio == 1).map(|f| f.id_usuario);
+            if saca.is_some() && saca != Some(payload.id_usuario) {
+                tracing::warn!("⛔ Saque de la ronda {} es de {:?}, no de {}", partida.ronda, saca, payload.id_usuario);
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    tr!(idioma, "saque-de-otro", ronda = partida.ronda, usuario = format!("{:?}", saca)),
+                ));
+            }
+
+            // Las posiciones del rival salen de su formación, no del tablero del cliente
+            if let Some(rival) = saque.iter().find(|f| f.id_usuario != payload.id_usuario) {
+                let is_left = rival.id_usuario == partida.id_jugador1.min(partida.id_jugador2);
+                let esperadas = crate::formaciones::posiciones_saque(
+                    &rival.formacion,
+                    rival.posiciones.as_ref(),
+                    is_left,
+                    rival.turno_inicio != 2,
+                );
+                crate::formaciones::validar_saque(idioma, &payload.jugada, rival.id_usuario, &esperadas).map_err(|e| {
+                    tracing::warn!("⛔ {e}");
+                    (StatusCode::BAD_REQUEST, e)
+                })?;
+            }
+        }
+
         let max_turno_i64: i64 = sqlx::query_scalar!(
         "SELECT COALESCE(MAX(numero_turno), 0) FROM Turno WHERE id_partida = ?",
         payload.id_partida
@@ -58,22 +124,25 @@
             .await
             .map_err(|e| {
                 tracing::error!("❌ Error al calcular MAX(numero_turno): {:?}", e);
-                (StatusCode::INTERNAL_SERVER_ERROR, "Error interno al calcular número de turno".into())
+                (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
             })?;
 
         let nuevo_turno = (max_turno_i64 as i32) + 1;
 
-        let jugada_json = payload.jugada.clone();
+        // 📊 Los eventos del turno van a `EventoTurno`, no a la jugada
+        let mut jugada_json = payload.jugada.clone();
+        let eventos = crate::eventos::separar_eventos(&mut jugada_json);
 
         sqlx::query!(
         r#"
-        INSERT INTO Turno (id_partida, numero_turno, id_usuario, jugada)
-        VALUES (?, ?, ?, ?)
+        INSERT INTO Turno (id_partida, numero_turno, id_usuario, jugada, ronda)
+        VALUES (?, ?, ?, ?, ?)
         "#,
         payload.id_partida,
         nuevo_turno,
         payload.id_usuario,
-        jugada_json
+        jugada_json,
+        partida.ronda
     )
             .execute(&mut *transaction)
             .await
@@ -86,13 +155,26 @@
 
                 if is_duplicate {
                     tracing::warn!("⚠️ Turno duplicado detectado");
-                    (StatusCode::CONFLICT, "Número de turno duplicado".into())
+                    (StatusCode::CONFLICT, tr!(idioma, "turno-duplicado"))
                 } else {
                     tracing::error!("❌ Error al insertar turno: {:?}", e);
-                    (StatusCode::INTERNAL_SERVER_ERROR, format!("Error insertando turno: {}", e))
+                    (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
                 }
             })?;
 
+        crate::eventos::registrar_turno(
+            &mut transaction,
+            (payload.id_partida, nuevo_turno, partida.ronda),
+            payload.id_usuario,
+            &jugada_json,
+            &eventos,
+        )
+            .await
+            .map_err(|e| {
+                tracing::error!("❌ Error al guardar los eventos del turno: {:?}", e);
+                (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+            })?;
+
         let (j1, j2) = sqlx::query!(
         "SELECT id_jugador1, id_jugador2 FROM Partida WHERE id_partida = ?",
         payload.id_partida
@@ -102,13 +184,13 @@
             .map(|r| (r.id_jugador1, r.id_jugador2))
             .map_err(|e| {
                 tracing::error!("❌ Error al obtener jugadores: {:?}", e);
-                (StatusCode::INTERNAL_SERVER_ERROR, format!("Error al obtener jugadores: {}", e))
+                (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
             })?;
 
         let siguiente_turno = if payload.id_usuario == j1 { j2 } else { j1 };
 
         sqlx::query!(
-        "UPDATE Partida SET turno_actual = ? WHERE id_partida = ?",
+        "UPDATE Partida SET turno_actual = ?, fecha_limite = NOW() + INTERVAL plazo_turno_horas HOUR WHERE id_partida = ?",
         siguiente_turno,
         payload.id_partida
     )
@@ -116,7 +198,7 @@
             .await
             .map_err(|e| {
                 tracing::error!("❌ Error al actualizar turno_actual: {:?}", e);
-                (StatusCode::INTERNAL_SERVER_ERROR, format!("Error al actualizar turno_actual: {}", e))
+                (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
             })?;
 
         // 🔍 Verificación post-insert de turno_actual
@@ -148,17 +230,18 @@
 
         transaction.commit().await.map_err(|e| {
             tracing::error!("❌ Error al confirmar transacción: {:?}", e);
-            (StatusCode::INTERNAL_SERVER_ERROR, format!("Error al confirmar: {}", e))
+            (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
         })?;
 
         let snap = super::get_snapshot(
+            idioma,
             Path(payload.id_partida),
             Extension(pool.clone()),
         )
             .await
             .map_err(|e| {
                 tracing::error!("❌ Error al generar snapshot: {:?}", e);
-                (StatusCode::INTERNAL_SERVER_ERROR, "Error generando snapshot".into())
+                (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-snapshot"))
             })?
             .0;
 
@@ -172,6 +255,19 @@
             tracing::warn!("📢 No hay oyentes para snapshot: {}", e);
         }
 
+        // 🏅 Power-ups recogidos en el turno
+        crate::logros::evaluar(&pool, payload.id_partida, &[payload.id_usuario]).await;
+
+        // 📬 Por correspondencia: avisar al rival de que le toca
+        crate::correspondencia::avisar_turno(&pool, payload.id_partida).await;
+
+        // 🤖 Si el siguiente turno es de un bot, que juegue en segundo plano
+        if let Some((bot_uid, _)) = crate::bot::bot_de_partida(&pool, payload.id_partida).await {
+            if bot_uid == siguiente_turno {
+                crate::bot::schedule_turn(pool.clone(), tx.clone(), payload.id_partida, bot_uid);
+            }
+        }
+
         Ok(Json("Turno registrado"))
     }
 
@@ -180,6 +276,7 @@
     // 2. GET /estado/:id_partida
     #[axum::debug_handler]
     pub async fn get_estado(
+        idioma: Idioma,
         Path(id_partida): Path<i32>,
         Extension(pool): Extension<MySqlPool>,
     ) -> Result<Json<Vec<TurnoData>>, (StatusCode, String)> {
@@ -202,13 +299,17 @@
 
         match turnos {
             Ok(t) => Ok(Json(t)),
-            Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+            Err(e) => {
+                tracing::error!("❌ SQL en get_estado: {e}");
+                Err((StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno")))
+            }
         }
     }
 
     // 3. GET /usuarios
     #[axum::debug_handler]
     pub async fn get_usuarios(
+        idioma: Idioma,
         Extension(pool): Extension<MySqlPool>,
     ) -> Result<Json<Vec<Usuario>>, (StatusCode, String)> {
         println!("🧪 Entrando al handler GET /usuarios...");
@@ -219,7 +320,8 @@
                 id_usuario,
                 nombre_usuario,
                 correo,
-                contrasena
+                contrasena,
+                idioma
             FROM Usuario
             "#
         )
@@ -235,13 +337,14 @@
                     nombre_usuario: row.nombre_usuario,
                     correo: row.correo,
                     contrasena: row.contrasena,
+                    idioma: row.idioma,
                 }).collect();
 
                 Ok(Json(usuarios))
             }
             Err(e) => {
                 println!("❌ Error en SQLx: {:?}", e);
-                Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+                Err((StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno")))
             }
         }
     }
@@ -249,9 +352,10 @@
     // 4. GET /estadisticas/:id_usuario
     #[axum::debug_handler]
     pub async fn get_estadisticas(
+        idioma: Idioma,
         Path(id_usuario): Path<i32>,
         Extension(pool): Extension<MySqlPool>,
-    ) -> Result<Json<Estadistica>, (StatusCode, String)> {
+    ) -> Result<Json<EstadisticaCompleta>, (StatusCode, String)> {
         let estad = sqlx::query_as!(
             Estadistica,
             "SELECT id_usuario, partidas_jugadas, partidas_ganadas, goles_a_favor, goles_en_contra
@@ -262,15 +366,27 @@
             .fetch_optional(&pool)
             .await;
 
-        match estad {
-            Ok(Some(e)) => Ok(Json(e)),
-            Ok(None) => Err((StatusCode::NOT_FOUND, "No se encontraron estadísticas para este usuario".into())),
-            Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
-        }
+        let base = match estad {
+            Ok(Some(e)) => e,
+            Ok(None) => return Err((StatusCode::NOT_FOUND, tr!(idioma, "estadisticas-no-encontradas"))),
+            Err(e) => {
+                tracing::error!("❌ SQL en get_estadisticas: {e}");
+                return Err((StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno")));
+            }
+        };
+
+        // 📊 Tiros, potencia, formación favorita… a partir de los eventos de turno
+        let carrera = crate::eventos::carrera(&pool, id_usuario).await.map_err(|e| {
+            tracing::error!("❌ Error SQL en estadísticas de carrera de {id_usuario}: {e:?}");
+            (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+        })?;
+
+        Ok(Json(EstadisticaCompleta { base, carrera }))
     }
 
     #[axum::debug_handler]
     pub async fn post_formacion(
+        idioma:          Idioma,
         Extension(pool): Extension<MySqlPool>,
         Extension(tx):   Extension<broadcast::Sender<String>>,
         Json(p):         Json<FormacionPayload>,
@@ -282,36 +398,69 @@
         // Esto es un patrón común: solo empezar la transacción cuando el estado crítico
         // va a ser modificado atómicamente.
 
-        /* 1. INSERT / UPDATE FormacionElegida ------------------------------------------------ */
-        tracing::info!("1️⃣  Guardando formación…");
+        /* 0. Ronda en juego ---------------------------------------------------------------- */
+        let partida = sqlx::query!(
+            r#"SELECT ronda, turno_actual, estado AS "estado!: String" FROM Partida WHERE id_partida = ?"#,
+            p.id_partida
+        )
+            .fetch_one(&pool)
+            .await
+            .map_err(|e| {
+                tracing::error!("❌ SQL error SELECT Partida: {e:?}");
+                (StatusCode::NOT_FOUND, tr!(idioma, "partida-no-encontrada", partida = p.id_partida))
+            })?;
+        let ronda = partida.ronda;
+
+        if partida.estado == "finished" {
+            return Err((
+                StatusCode::CONFLICT,
+                tr!(idioma, "partida-terminada", partida = p.id_partida),
+            ));
+        }
+
+        // La formación sólo se elige antes de cada saque (inicio o tras un gol)
+        if partida.estado == "playing" {
+            tracing::warn!("⛔ Formación fuera de tiempo: la ronda {ronda} ya empezó");
+            return Err((
+                StatusCode::CONFLICT,
+                tr!(idioma, "ronda-ya-empezada", ronda = ronda),
+            ));
+        }
+
+        /* 1. INSERT / UPDATE FormacionElegida (de esta ronda) ------------------------------ */
+        let (formacion, posiciones) = crate::formaciones::resolver_formacion(idioma, &pool, &p).await?;
+        tracing::info!("1️⃣  Guardando formación '{formacion}' para la ronda {ronda}…");
         sqlx::query!(
             r#"
-            INSERT INTO FormacionElegida (id_partida, id_usuario, formacion, turno_inicio)
-            VALUES (?, ?, ?, 0)
-            ON DUPLICATE KEY UPDATE formacion = VALUES(formacion)
+            INSERT INTO FormacionElegida (id_partida, id_usuario, ronda, formacion, turno_inicio, posiciones)
+            VALUES (?, ?, ?, ?, 0, ?)
+            ON DUPLICATE KEY UPDATE formacion = VALUES(formacion), posiciones = VALUES(posiciones)
             "#,
             p.id_partida,
             p.id_usuario,
-            p.formacion
+            ronda,
+            formacion,
+            posiciones
         )
             .execute(&pool) // Usamos el pool directamente aquí
             .await
             .map_err(|e| {
                 tracing::error!("❌ SQL error INSERT/UPDATE FormacionElegida: {e:?}");
-                (StatusCode::INTERNAL_SERVER_ERROR, format!("Error del servidor: {}", e))
+                (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
             })?;
 
-        /* 2. ¿Ya hay 2 formaciones? (Lectura fuera de transacción) ---------------------------------------- */
+        /* 2. ¿Ya hay 2 formaciones en esta ronda? ------------------------------------------ */
         tracing::info!("2️⃣  Comprobando si ya hay 2 formaciones…");
         let formaciones_existentes = sqlx::query!(
-            "SELECT id_usuario, turno_inicio FROM FormacionElegida WHERE id_partida = ?",
-            p.id_partida
+            "SELECT id_usuario, turno_inicio FROM FormacionElegida WHERE id_partida = ? AND ronda = ?",
+            p.id_partida,
+            ronda
         )
-            .fetch_all(&pool) // Usamos el pool directamente para esta lectura
+            .fetch_all(&pool)
             .await
             .map_err(|e| {
                 tracing::error!("❌ SQL error SELECT FormacionElegida: {e:?}");
-                (StatusCode::INTERNAL_SERVER_ERROR, format!("Error del servidor: {}", e))
+                (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
             })?;
 
         if formaciones_existentes.len() < 2 {
@@ -324,28 +473,23 @@
             .await
             .map_err(|e| {
                 tracing::error!("❌ Error al iniciar transacción: {e:?}");
-                (StatusCode::INTERNAL_SERVER_ERROR, format!("Error del servidor: {}", e))
+                (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
             })?;
 
-        /* 3. Calcular turno_inicio=1 (el que arranca) --------------------- */
-        // Re-leer formaciones dentro de la transacción si la consistencia es ultra-crítica
-        // para evitar que otro request las modifique justo antes de la transacción.
-        // Para este caso, la lectura fuera de la transacción para el `if formaciones.len() < 2`
-        // es probablemente suficiente, ya que si cambia, la transacción lo manejará o fallará.
-        // Pero si quieres la máxima seguridad:
+        /* 3. Calcular turno_inicio=1 (el que saca) ----------------------------------------- */
         let formaciones_para_tx = sqlx::query!(
-            "SELECT id_usuario, turno_inicio FROM FormacionElegida WHERE id_partida = ?",
-            p.id_partida
+            "SELECT id_usuario, turno_inicio FROM FormacionElegida WHERE id_partida = ? AND ronda = ?",
+            p.id_partida,
+            ronda
         )
-            .fetch_all(&mut *transaction) // Usar &mut *transaction
+            .fetch_all(&mut *transaction)
             .await
             .map_err(|e| {
                 tracing::error!("❌ SQL error SELECT FormacionElegida (en TX): {e:?}");
-                (StatusCode::INTERNAL_SERVER_ERROR, format!("Error del servidor: {}", e))
+                (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
             })?;
 
-
-        let primero = formaciones_para_tx // Usamos la re-lectura para la lógica de sorteo
+        let primero = formaciones_para_tx
             .iter()
             .find(|f| f.turno_inicio == 1)
             .map(|f| f.id_usuario);
@@ -353,32 +497,39 @@
         let primero = match primero {
             Some(uid) => uid,
             None => {
-                // Si aún no se inicializó turno_inicio, sorteamos y actualizamos.
+                // Primera ronda: sorteo.  Tras un gol saca quien lo recibió
+                // (`post_gol` lo dejó en `turno_actual`).
                 let [a, b] = [formaciones_para_tx[0].id_usuario, formaciones_para_tx[1].id_usuario];
-                let (primero, segundo) = if rand::random() { (a, b) } else { (b, a) };
+                let (primero, segundo) = match partida.turno_actual {
+                    Some(uid) if ronda > 1 && uid == a => (a, b),
+                    Some(uid) if ronda > 1 && uid == b => (b, a),
+                    _ if rand::random() => (a, b),
+                    _ => (b, a),
+                };
 
                 for (uid, idx) in [(primero, 1), (segundo, 2)] {
                     sqlx::query!(
-                        "UPDATE FormacionElegida SET turno_inicio = ? WHERE id_partida = ? AND id_usuario = ?",
+                        "UPDATE FormacionElegida SET turno_inicio = ? WHERE id_partida = ? AND id_usuario = ? AND ronda = ?",
                         idx,
                         p.id_partida,
-                        uid
+                        uid,
+                        ronda
                     )
-                        .execute(&mut *transaction) // Usar &mut *transaction
+                        .execute(&mut *transaction)
                         .await
                         .map_err(|e| {
                             tracing::error!("❌ UPDATE turno_inicio (uid={uid}) en TX: {e:?}");
-                            (StatusCode::INTERNAL_SERVER_ERROR, format!("Error del servidor: {}", e))
+                            (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
                         })?;
                 }
                 primero
             }
         };
-        tracing::info!("3️⃣  turno_actual inicial será uid={primero}");
+        tracing::info!("3️⃣  Ronda {ronda}: saca uid={primero}");
 
         /* 4. UPDATE Partida → estado='playing', turno_actual (dentro de transacción) -------------- */
         sqlx::query!(
-            "UPDATE Partida SET estado = 'playing', turno_actual = ? WHERE id_partida = ?",
+            "UPDATE Partida SET estado = 'playing', turno_actual = ?, fecha_limite = NOW() + INTERVAL plazo_turno_horas HOUR WHERE id_partida = ?",
             primero,
             p.id_partida
         )
@@ -386,7 +537,7 @@
             .await
             .map_err(|e| {
                 tracing::error!("❌ UPDATE Partida en TX: {e:?}");
-                (StatusCode::INTERNAL_SERVER_ERROR, format!("Error del servidor: {}", e))
+                (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
             })?;
         tracing::debug!("✅ Partida actualizada a 'playing' y turno inicial.");
 
@@ -395,20 +546,21 @@
             .await
             .map_err(|e| {
                 tracing::error!("❌ Error al confirmar transacción: {e:?}");
-                (StatusCode::INTERNAL_SERVER_ERROR, format!("Error del servidor: {}", e))
+                (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
             })?;
         tracing::info!("✅ Transacción de formación confirmada.");
 
         /* 5. Generar snapshot inicial y avisar (fuera de transacción) ---------------------------- */
         tracing::info!("5️⃣  Generando snapshot inicial…");
         let snap = super::get_snapshot(
+            idioma,
             Path(p.id_partida),
             Extension(pool.clone()), // Usar el pool original, no la transacción
         )
             .await
             .map_err(|e| {
                 tracing::error!("❌ Error generando snapshot: {e:?}");
-                (StatusCode::INTERNAL_SERVER_ERROR, "Error generando snapshot".into())
+                (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-snapshot"))
             })?
             .0;
 
@@ -419,15 +571,32 @@
         );
         tracing::info!("📡 Snapshot inicial + 'start' enviados");
 
+        // 📬 Por correspondencia: avisar a quien saca
+        crate::correspondencia::avisar_turno(&pool, p.id_partida).await;
+
+        // 🤖 Si arranca el bot, lanza su primer turno
+        if let Some((bot_uid, _)) = crate::bot::bot_de_partida(&pool, p.id_partida).await {
+            if bot_uid == primero {
+                crate::bot::schedule_turn(pool.clone(), tx.clone(), p.id_partida, bot_uid);
+            }
+        }
+
         Ok(Json("Formación registrada y partida arrancada"))
     }
 
     // 6. POST /registro
     #[axum::debug_handler]
     pub async fn post_registro(
+        idioma: Idioma,
         Extension(pool): Extension<MySqlPool>,
-        Json(payload): Json<RegistroPayload>,
+        Json(mut payload): Json<RegistroPayload>,
     ) -> Result<Json<serde_json::Value>, (StatusCode, String)> {
+        payload.correo = payload.correo.trim().to_owned();
+        if !crate::cuentas::correo_valido(&payload.correo) {
+            return Err((StatusCode::BAD_REQUEST, tr!(idioma, "correo-invalido", correo = payload.correo.clone())));
+        }
+        crate::cuentas::validar_contrasena(idioma, &payload.contrasena)?;
+
         let res = sqlx::query!(
             "INSERT INTO Usuario (nombre_usuario, correo, contrasena) VALUES (?, ?, ?)",
             payload.nombre_usuario,
@@ -440,22 +609,43 @@
         match res {
             Ok(r) => {
                 let id = r.last_insert_id() as i32;
+                // La cuenta ya existe: si el correo no sale, se puede reenviar desde el perfil
+                if let Err(e) = crate::cuentas::enviar_verificacion(&pool, id).await {
+                    tracing::error!("❌ No se pudo mandar la verificación de correo a {id}: {e}");
+                }
                 Ok(Json(json!({
                     "id_usuario": id,
                     "nombre_usuario": payload.nombre_usuario,
-                    "correo": payload.correo
+                    "correo": payload.correo,
+                    "correo_verificado": false
                 })))
             }
-            Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+            // 1062: el nombre o el correo ya están registrados
+            Err(e) if e.as_database_error().and_then(|d| d.code()).is_some_and(|c| c == "1062") => {
+                Err((StatusCode::CONFLICT, tr!(idioma, "usuario-existente")))
+            }
+            Err(e) => {
+                tracing::error!("❌ SQL en post_registro: {e}");
+                Err((StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno")))
+            }
         }
     }
 
     #[axum::debug_handler]
     pub async fn post_partida(
+        idioma: Idioma,
         Extension(pool): Extension<MySqlPool>,
         Json(payload): Json<PartidaPayload>,
     ) -> Result<Json<Partida>, (StatusCode, String)> {
-        // Verificar si ya existe la partida
+        crate::correspondencia::validar_plazo(idioma, payload.plazo_turno_horas)?;
+        let error_sql = |e: sqlx::Error| {
+            tracing::error!("❌ SQL en post_partida: {e}");
+            (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+        };
+        let modo = crate::correspondencia::modo(payload.plazo_turno_horas);
+
+        // Verificar si ya existe una partida sin terminar del mismo modo y
+        // las mismas reglas (si no, revancha: una nueva)
         let existente = sqlx::query!(
             r#"
             SELECT
@@ -465,17 +655,24 @@
                 fecha_inicio AS "fecha_inicio: chrono::NaiveDateTime",
                 estado
             FROM Partida
-            WHERE (id_jugador1 = ? AND id_jugador2 = ?)
-               OR (id_jugador1 = ? AND id_jugador2 = ?)
+            WHERE ((id_jugador1 = ? AND id_jugador2 = ?)
+                OR (id_jugador1 = ? AND id_jugador2 = ?))
+              AND estado <> 'finished'
+              AND modo = ?
+              AND clasificatoria = ?
+            ORDER BY id_partida DESC
+            LIMIT 1
             "#,
             payload.id_usuario_1,
             payload.id_usuario_2,
             payload.id_usuario_2,
-            payload.id_usuario_1
+            payload.id_usuario_1,
+            modo,
+            payload.clasificatoria
         )
             .fetch_optional(&pool)
             .await
-            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+            .map_err(error_sql)?;
 
         if let Some(row) = existente {
             let partida = Partida {
@@ -489,18 +686,22 @@
             return Ok(Json(partida));
         }
 
-        // Crear nueva partida (estado 'waiting' por defecto)
+        // Crear nueva partida (estado 'waiting' por defecto; sin plazo, `fecha_limite` queda NULL)
         let result = sqlx::query!(
             r#"
-            INSERT INTO Partida (id_jugador1, id_jugador2)
-            VALUES (?, ?)
+            INSERT INTO Partida (id_jugador1, id_jugador2, modo, plazo_turno_horas, fecha_limite, clasificatoria)
+            VALUES (?, ?, ?, ?, NOW() + INTERVAL ? HOUR, ?)
             "#,
             payload.id_usuario_1,
-            payload.id_usuario_2
+            payload.id_usuario_2,
+            modo,
+            payload.plazo_turno_horas,
+            payload.plazo_turno_horas,
+            payload.clasificatoria
         )
             .execute(&pool)
             .await
-            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+            .map_err(error_sql)?;
 
         let partida_id = result.last_insert_id() as i32;
 
@@ -519,7 +720,7 @@
         )
             .fetch_one(&pool)
             .await
-            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+            .map_err(error_sql)?;
 
         let partida = Partida {
             id_partida: nueva_row.id_partida,
@@ -529,9 +730,54 @@
             estado: nueva_row.estado,
         };
 
+        // 🤖 Contra un bot: su formación queda elegida desde ya
+        if let Some((bot_uid, dificultad)) = crate::bot::bot_de_partida(&pool, partida_id).await {
+            crate::bot::elegir_formacion(&pool, partida_id, bot_uid, 1)
+                .await
+                .map_err(|e| {
+                    tracing::error!("🤖 El bot {bot_uid} no pudo elegir formación (partida {partida_id}): {e}");
+                    (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+                })?;
+            tracing::info!("🤖 Bot {bot_uid} ({}) listo para la partida {partida_id}", dificultad.as_str());
+        }
+
+        // 📬 Por correspondencia: avisar de que hay que elegir formación
+        crate::correspondencia::avisar_turno(&pool, partida_id).await;
+
         Ok(Json(partida))
     }
 
+    // GET /bots — usuarios bot que se pueden retar
+    #[axum::debug_handler]
+    pub async fn get_bots(
+        idioma: Idioma,
+        Extension(pool): Extension<MySqlPool>,
+    ) -> Result<Json<Vec<serde_json::Value>>, (StatusCode, String)> {
+        let rows = sqlx::query!(
+            "SELECT id_usuario, nombre_usuario FROM Usuario WHERE nombre_usuario LIKE 'bot\\_%' ORDER BY id_usuario"
+        )
+            .fetch_all(&pool)
+            .await
+            .map_err(|e| {
+                tracing::error!("❌ SQL en get_bots: {e}");
+                (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+            })?;
+
+        let bots = rows
+            .into_iter()
+            .filter_map(|r| {
+                let dificultad = crate::bot::Dificultad::from_nombre(&r.nombre_usuario)?;
+                Some(json!({
+                    "id_usuario": r.id_usuario,
+                    "nombre_usuario": r.nombre_usuario,
+                    "dificultad": dificultad.as_str(),
+                }))
+            })
+            .collect();
+
+        Ok(Json(bots))
+    }
+
 
     #[derive(Debug, Deserialize)]
     pub struct LoginPayload {
@@ -541,12 +787,19 @@
 
     #[axum::debug_handler]
     pub async fn post_login(
+        idioma: Idioma,
         Extension(pool): Extension<MySqlPool>,
         Json(payload): Json<LoginPayload>,
     ) -> Result<Json<Usuario>, (StatusCode, String)> {
+        // Los bots los juega el servidor: nadie puede entrar con su cuenta
+        // (en minúsculas: la comparación de MySQL no distingue mayúsculas)
+        if crate::bot::Dificultad::from_nombre(&payload.nombre_usuario.trim().to_lowercase()).is_some() {
+            return Err((StatusCode::UNAUTHORIZED, tr!(idioma, "credenciales-invalidas")));
+        }
+
         let resultado = sqlx::query_as!(
             Usuario,
-            "SELECT id_usuario, nombre_usuario, correo, contrasena
+            "SELECT id_usuario, nombre_usuario, correo, contrasena, idioma
              FROM Usuario
              WHERE nombre_usuario = ? AND contrasena = ?",
             payload.nombre_usuario,
@@ -557,13 +810,43 @@
 
         match resultado {
             Ok(Some(usuario)) => Ok(Json(usuario)),
-            Ok(None) => Err((StatusCode::UNAUTHORIZED, "Credenciales inválidas".to_string())),
-            Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+            Ok(None) => Err((StatusCode::UNAUTHORIZED, tr!(idioma, "credenciales-invalidas"))),
+            Err(e) => {
+                tracing::error!("❌ SQL en post_login: {e}");
+                Err((StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno")))
+            }
         }
     }
 
+    // POST /usuario/idioma — guarda el idioma preferido en el perfil
+    #[axum::debug_handler]
+    pub async fn post_idioma_usuario(
+        idioma: Idioma,
+        Extension(pool): Extension<MySqlPool>,
+        Json(p): Json<IdiomaPayload>,
+    ) -> Result<Json<&'static str>, (StatusCode, String)> {
+        let elegido = Idioma::desde_codigo(&p.idioma)
+            .ok_or_else(|| (StatusCode::BAD_REQUEST, tr!(idioma, "idioma-desconocido", codigo = p.idioma.clone())))?;
+
+        sqlx::query!(
+            "UPDATE Usuario SET idioma = ? WHERE id_usuario = ?",
+            elegido.codigo(),
+            p.id_usuario
+        )
+            .execute(&pool)
+            .await
+            .map_err(|e| {
+                tracing::error!("❌ SQL en post_idioma_usuario: {e}");
+                (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+            })?;
+
+        tracing::info!("🌐 Usuario {} usa el idioma '{}'", p.id_usuario, elegido.codigo());
+        Ok(Json("Idioma guardado"))
+    }
+
     #[axum::debug_handler]
     pub async fn get_mis_partidas(
+        idioma: Idioma,
         Path(id_usuario): Path<i32>,
         Extension(pool): Extension<MySqlPool>,
     ) -> Result<Json<Vec<Partida>>, (StatusCode, String)> {
@@ -585,24 +868,82 @@
         )
             .fetch_all(&pool)
             .await
-            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+            .map_err(|e| {
+                tracing::error!("❌ SQL en get_mis_partidas: {e}");
+                (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+            })?;
 
         Ok(Json(partidas))
     }
 
+    /// POST /gol — suma el gol y abre la ronda siguiente: estado 'waiting'
+    /// hasta que lleguen las dos formaciones nuevas, y `turno_actual` queda
+    /// en quien recibió el gol, que es quien saca.  Si es el gol final la
+    /// partida se cierra (`resultado::cerrar_partida`) en vez de seguir.
     #[axum::debug_handler]
     pub async fn post_gol(
+        idioma:          Idioma,
         Extension(pool): Extension<MySqlPool>,
+        Extension(tx):   Extension<broadcast::Sender<String>>,
         Json(p): Json<GolPayload>,
     ) -> Result<Json<(i32, i32)>, (StatusCode, String)> {
-        // Obtener quién es j1 y j2
+        tracing::info!("▶️  POST /gol — partida {} goleador {} ronda {}", p.id_partida, p.id_goleador, p.ronda);
+
+        let error_sql = |e: sqlx::Error| {
+            tracing::error!("❌ SQL en post_gol: {e}");
+            (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+        };
+
+        let mut transaction = pool.begin()
+            .await
+            .map_err(error_sql)?;
+
+        // Obtener quién es j1 y j2 (bloqueando la fila: dos avisos del mismo gol no suman dos)
         let row = sqlx::query!(
-            "SELECT id_jugador1, id_jugador2 FROM Partida WHERE id_partida = ?",
+            r#"SELECT id_jugador1, id_jugador2, turno_actual, ronda, estado AS "estado!: String" FROM Partida WHERE id_partida = ? FOR UPDATE"#,
             p.id_partida
         )
-            .fetch_one(&pool)
+            .fetch_optional(&mut *transaction)
             .await
-            .map_err(|e| (StatusCode::NOT_FOUND, e.to_string()))?;
+            .map_err(error_sql)?
+            .ok_or_else(|| (StatusCode::NOT_FOUND, tr!(idioma, "partida-no-encontrada", partida = p.id_partida)))?;
+
+        // Un gol tardío tras el final no debe volver a sumar Elo ni temporada
+        if row.estado == "finished" {
+            tracing::warn!("⚠️ Gol en la partida {} ya terminada", p.id_partida);
+            return Err((
+                StatusCode::CONFLICT,
+                tr!(idioma, "partida-terminada", partida = p.id_partida),
+            ));
+        }
+
+        if p.ronda != row.ronda {
+            tracing::warn!("⚠️ Gol repetido de la ronda {} (actual {})", p.ronda, row.ronda);
+            return Err((
+                StatusCode::CONFLICT,
+                tr!(idioma, "gol-repetido", ronda = p.ronda),
+            ));
+        }
+
+        // Entre un gol y las formaciones de la ronda siguiente no hay goles
+        if row.estado != "playing" {
+            tracing::warn!("⚠️ Gol en la partida {} con estado '{}'", p.id_partida, row.estado);
+            return Err((
+                StatusCode::CONFLICT,
+                tr!(idioma, "ronda-no-empezada", ronda = row.ronda),
+            ));
+        }
+
+        let recibe = if p.id_goleador == row.id_jugador1 {
+            row.id_jugador2
+        } else if p.id_goleador == row.id_jugador2 {
+            row.id_jugador1
+        } else {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                tr!(idioma, "no-juega-partida", usuario = p.id_goleador, partida = p.id_partida),
+            ));
+        };
 
         // Ejecutar el UPDATE correcto
         if p.id_goleador == row.id_jugador1 {
@@ -610,17 +951,17 @@
                 "UPDATE Partida SET gol_j1 = gol_j1 + 1 WHERE id_partida = ?",
                 p.id_partida
             )
-                .execute(&pool)
+                .execute(&mut *transaction)
                 .await
-                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+                .map_err(error_sql)?;
         } else {
             sqlx::query!(
                 "UPDATE Partida SET gol_j2 = gol_j2 + 1 WHERE id_partida = ?",
                 p.id_partida
             )
-                .execute(&pool)
+                .execute(&mut *transaction)
                 .await
-                .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+                .map_err(error_sql)?;
         }
 
         // Consultar marcador actualizado
@@ -628,20 +969,102 @@
             "SELECT gol_j1, gol_j2 FROM Partida WHERE id_partida = ?",
             p.id_partida
         )
-            .fetch_one(&pool)
+            .fetch_one(&mut *transaction)
+            .await
+            .map_err(error_sql)?;
+        let goles = (marcador.gol_j1.unwrap_or(0), marcador.gol_j2.unwrap_or(0));
+
+        // 📊 El tiro del gol es de quien tenía el turno (puede ser en contra)
+        let tirador = row.turno_actual.unwrap_or(p.id_goleador);
+        let mut jugada = p.jugada.unwrap_or(serde_json::Value::Null);
+        let eventos = crate::eventos::separar_eventos(&mut jugada);
+        crate::eventos::registrar_gol(&mut transaction, (p.id_partida, row.ronda), (tirador, p.id_goleador), &jugada, &eventos)
             .await
-            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+            .map_err(error_sql)?;
+        let terminada = goles.0.max(goles.1) >= crate::resultado::GOLES_PARA_GANAR;
 
-        Ok(Json((
-            marcador.gol_j1.unwrap_or(0),
-            marcador.gol_j2.unwrap_or(0),
-        )))
+        // 🔁 Ronda nueva: se esperan formaciones y saca quien recibió el gol.
+        //    También con el gol final, así los clientes ven un snapshot nuevo.
+        let ronda = row.ronda + 1;
+        sqlx::query!(
+            "UPDATE Partida SET ronda = ?, estado = 'waiting', turno_actual = ?, fecha_limite = NOW() + INTERVAL plazo_turno_horas HOUR WHERE id_partida = ?",
+            ronda,
+            recibe,
+            p.id_partida
+        )
+            .execute(&mut *transaction)
+            .await
+            .map_err(error_sql)?;
+
+        // 🏁 Gol final: partida cerrada y Elo actualizado
+        let mut ganador = None;
+        if terminada {
+            ganador = crate::resultado::cerrar_partida(
+                &mut transaction,
+                p.id_partida,
+                (row.id_jugador1, row.id_jugador2),
+                goles,
+            )
+                .await
+                .map_err(error_sql)?;
+        }
+
+        transaction.commit()
+            .await
+            .map_err(error_sql)?;
+        if terminada {
+            tracing::info!("⚽ Gol final de {} ({}-{})", p.id_goleador, goles.0, goles.1);
+
+            // 🏆 Si era de un torneo, avanza el cuadro (un fallo aquí no anula el gol)
+            if let Err(e) = crate::torneos::partida_terminada(&pool, p.id_partida, ganador).await {
+                tracing::error!("❌ Torneo: no se pudo avanzar tras la partida {}: {e}", p.id_partida);
+            }
+            crate::notificaciones::partida_terminada(&pool, p.id_partida).await;
+        } else {
+            tracing::info!("⚽ Gol de {} — empieza la ronda {ronda}, saca {recibe}", p.id_goleador);
+        }
+
+        // 🏅 Logros del gol (y, si fue el final, también los del rival)
+        if terminada {
+            crate::logros::evaluar(&pool, p.id_partida, &[row.id_jugador1, row.id_jugador2]).await;
+        } else {
+            crate::logros::evaluar(&pool, p.id_partida, &[p.id_goleador]).await;
+        }
+
+        // 🤖 El bot elige ya su formación para la ronda nueva
+        if let Some((bot_uid, _)) = crate::bot::bot_de_partida(&pool, p.id_partida).await.filter(|_| !terminada) {
+            crate::bot::elegir_formacion(&pool, p.id_partida, bot_uid, ronda)
+                .await
+                .map_err(|e| {
+                    tracing::error!("🤖 El bot {bot_uid} no pudo elegir formación (partida {}): {e}", p.id_partida);
+                    (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+                })?;
+        }
+
+        // 📬 Por correspondencia: los dos tienen que elegir formación
+        if !terminada {
+            crate::correspondencia::avisar_turno(&pool, p.id_partida).await;
+        }
 
+        // 📡 Avisar a ambos (uid_origen 0): el rival de quien detectó el gol
+        //    se entera aquí de que toca elegir formación
+        if let Ok(Json(snap)) = get_snapshot(idioma, Path(p.id_partida), Extension(pool.clone())).await {
+            let msg = serde_json::json!({
+                "uid_origen": 0,
+                "tipo": "snapshot",
+                "contenido": snap
+            });
+            let _ = tx.send(msg.to_string());
+        }
+
+        Ok(Json(goles))
     }
+
     use crate::routes::websocket::save_last_snapshot; // 🆕 Agrega este import
 
     #[axum::debug_handler]
     pub async fn get_snapshot(
+        idioma: Idioma,
         Path(id_partida): Path<i32>,
         Extension(pool): Extension<MySqlPool>,
     ) -> Result<Json<Snapshot>, (StatusCode, String)> {
@@ -649,7 +1072,8 @@
 
         let partida_data = sqlx::query!(
             r#"
-            SELECT estado AS "estado!: String", turno_actual, gol_j1, gol_j2
+            SELECT estado AS "estado!: String", turno_actual, gol_j1, gol_j2, ronda,
+                   clasificatoria AS "clasificatoria: bool"
             FROM Partida
             WHERE id_partida = ?
             "#,
@@ -661,7 +1085,7 @@
                 tracing::error!("❌ Error SQL en estado de partida: {:?}", e);
                 (
                     StatusCode::INTERNAL_SERVER_ERROR,
-                    "Error al obtener estado de la partida".into(),
+                    tr!(idioma, "error-interno"),
                 )
             })?;
 
@@ -682,18 +1106,19 @@
                 tracing::error!("❌ Error SQL en nombres de jugadores: {:?}", e);
                 (
                     StatusCode::INTERNAL_SERVER_ERROR,
-                    "Error al obtener nombres de jugadores".into(),
+                    tr!(idioma, "error-interno"),
                 )
             })?;
 
         let formaciones = sqlx::query_as!(
             FormacionData,
             r#"
-            SELECT id_usuario, formacion, turno_inicio
+            SELECT id_usuario, formacion, turno_inicio, posiciones
             FROM   FormacionElegida
-            WHERE  id_partida = ?
+            WHERE  id_partida = ? AND ronda = ?
             "#,
-            id_partida
+            id_partida,
+            partida_data.ronda
         )
             .fetch_all(&pool)
             .await
@@ -701,24 +1126,34 @@
                 tracing::error!("❌ Error SQL en formaciones: {:?}", e);
                 (
                     StatusCode::INTERNAL_SERVER_ERROR,
-                    "Error al obtener formaciones".into(),
+                    tr!(idioma, "error-interno"),
                 )
             })?;
 
+        let marcador = (
+            partida_data.gol_j1.unwrap_or(0),
+            partida_data.gol_j2.unwrap_or(0),
+        );
+
         if formaciones.len() < 2 {
             tracing::warn!(
-                "⚠️ Solo {} formaciones encontradas. Devolviendo snapshot parcial.",
-                formaciones.len()
+                "⚠️ Solo {} formaciones en la ronda {}. Devolviendo snapshot parcial.",
+                formaciones.len(),
+                partida_data.ronda
             );
 
+            // 'finished' tras el gol final; si no, se esperan formaciones
+            let estado = if partida_data.estado == "finished" { "finished" } else { "waiting" };
             let snapshot = Snapshot {
-                estado: "waiting".to_string(),
-                marcador: (0, 0),
+                estado: estado.to_string(),
+                ronda: partida_data.ronda,
+                marcador,
                 formaciones,
                 turnos: vec![],
                 proximo_turno: Some(0),
                 nombre_jugador_1: nombres.nombre_jugador_1,
                 nombre_jugador_2: nombres.nombre_jugador_2,
+                clasificatoria: partida_data.clasificatoria,
             };
 
             // 🧠 Guardar snapshot parcial en memoria también (opcional)
@@ -729,11 +1164,6 @@
             return Ok(Json(snapshot));
         }
 
-        let marcador = (
-            partida_data.gol_j1.unwrap_or(0),
-            partida_data.gol_j2.unwrap_or(0),
-        );
-
         let mut turnos = sqlx::query_as!(
             TurnoData,
             r#"
@@ -742,10 +1172,11 @@
                    jugada,
                    fecha_turno AS "fecha_turno: chrono::NaiveDateTime"
             FROM   Turno
-            WHERE  id_partida = ?
+            WHERE  id_partida = ? AND ronda = ?
             ORDER  BY numero_turno
             "#,
-            id_partida
+            id_partida,
+            partida_data.ronda
         )
             .fetch_all(&pool)
             .await
@@ -753,23 +1184,20 @@
                 tracing::error!("❌ Error SQL al obtener turnos: {:?}", e);
                 (
                     StatusCode::INTERNAL_SERVER_ERROR,
-                    "Error al obtener turnos".into(),
+                    tr!(idioma, "error-interno"),
                 )
             })?;
 
         for t in &mut turnos {
-            if let Some(arr) = t.jugada.get("piezas").and_then(|v| v.as_array()) {
-                let enriched: Vec<_> = arr
-                    .iter()
-                    .map(|p| {
-                        json!({
-                            "id_usuario_real": t.id_usuario,
-                            "x": p.get("x").unwrap_or(&json!(null)),
-                            "y": p.get("y").unwrap_or(&json!(null))
-                        })
-                    })
-                    .collect();
-                t.jugada = json!({ "piezas": enriched });
+            // Se conserva la jugada completa (ids estables, pelota…); sólo se
+            // completa `id_usuario_real` en jugadas antiguas que no lo traían.
+            if let Some(arr) = t.jugada.get_mut("piezas").and_then(|v| v.as_array_mut()) {
+                for (idx, p) in arr.iter_mut().enumerate() {
+                    if let Some(obj) = p.as_object_mut() {
+                        obj.entry("id_usuario_real").or_insert(json!(t.id_usuario));
+                        obj.entry("id").or_insert(json!(idx));
+                    }
+                }
             } else {
                 tracing::warn!(
                     "⚠️ Turno #{} no tiene piezas válidas. Jugada original: {:?}",
@@ -781,12 +1209,14 @@
 
         let snapshot = Snapshot {
             estado: "playing".to_string(),
+            ronda: partida_data.ronda,
             marcador,
             formaciones,
             turnos,
             proximo_turno: partida_data.turno_actual,
             nombre_jugador_1: nombres.nombre_jugador_1,
             nombre_jugador_2: nombres.nombre_jugador_2,
+            clasificatoria: partida_data.clasificatoria,
         };
 
         // ✅ Guardar en memoria el snapshot completo
@@ -800,6 +1230,7 @@
 
     #[axum::debug_handler]
     pub async fn get_partidas_pendientes(
+        idioma: Idioma,
         Path(id_usuario): Path<i32>,
         Extension(pool): Extension<MySqlPool>,
     ) -> Result<Json<Vec<Partida>>, (StatusCode, String)> {
@@ -816,7 +1247,9 @@
             WHERE estado = 'waiting'
               AND (id_jugador1 = ? OR id_jugador2 = ?)
               AND id_partida NOT IN (
-                  SELECT id_partida FROM FormacionElegida WHERE id_usuario = ?
+                  -- Solo la ronda en juego: tras un gol hay que volver a elegir
+                  SELECT id_partida FROM FormacionElegida
+                  WHERE id_usuario = ? AND ronda = Partida.ronda
               )
             "#,
             id_usuario,
@@ -825,13 +1258,17 @@
         )
             .fetch_all(&pool)
             .await
-            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+            .map_err(|e| {
+                tracing::error!("❌ SQL en get_partidas_pendientes: {e}");
+                (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+            })?;
 
         Ok(Json(partidas))
     }
 
     #[axum::debug_handler]
     pub async fn get_partida_detalle(
+        idioma: Idioma,
         Path(id): Path<i32>,
         Extension(pool): Extension<MySqlPool>,
     ) -> Result<Json<Partida>, (StatusCode, String)> {
@@ -848,9 +1285,13 @@
             "#,
             id
         )
-            .fetch_one(&pool)
+            .fetch_optional(&pool)
             .await
-            .map_err(|e| (StatusCode::NOT_FOUND, e.to_string()))?;
+            .map_err(|e| {
+                tracing::error!("❌ SQL en get_partida_detalle: {e}");
+                (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+            })?
+            .ok_or_else(|| (StatusCode::NOT_FOUND, tr!(idioma, "partida-no-encontrada", partida = id)))?;
 
         Ok(Json(row))
     }
@@ -0,0 +1,122 @@
+// -----------------------------------------------------------------
+//! Traducción de los mensajes de error de la API (Fluent).
+//!
+//!   • Los textos viven en `locales/<idioma>.ftl` y se compilan dentro
+//!     del binario.
+//!   • Cada handler recibe el extractor `Idioma`: el de la cabecera
+//!     `Accept-Language` (la webapp y el juego mandan el del perfil o
+//!     el del navegador); si no hay o no se soporta, español.
+//!   • `tr!(idioma, "clave", arg = valor, …)` arma el mensaje.
+// -----------------------------------------------------------------
+
+use std::convert::Infallible;
+
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts};
+use fluent_bundle::{concurrent::FluentBundle, FluentArgs, FluentResource};
+use once_cell::sync::Lazy;
+use unic_langid::LanguageIdentifier;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Idioma {
+    #[default]
+    Es,
+    En,
+}
+
+impl Idioma {
+    pub const TODOS: [Idioma; 2] = [Idioma::Es, Idioma::En];
+
+    pub fn codigo(self) -> &'static str {
+        match self {
+            Idioma::Es => "es",
+            Idioma::En => "en",
+        }
+    }
+
+    /// "es", "en-US", "EN"… → idioma soportado.
+    pub fn desde_codigo(codigo: &str) -> Option<Self> {
+        let primario = codigo.trim().split(['-', '_']).next()?.to_ascii_lowercase();
+        Self::TODOS.into_iter().find(|i| i.codigo() == primario)
+    }
+
+    /// Primer idioma soportado de una cabecera `Accept-Language`
+    /// (se respeta el orden, no se miran los pesos `q=`).
+    pub fn desde_accept_language(cabecera: &str) -> Option<Self> {
+        cabecera
+            .split(',')
+            .filter_map(|parte| parte.split(';').next())
+            .find_map(Self::desde_codigo)
+    }
+
+    fn ftl(self) -> &'static str {
+        match self {
+            Idioma::Es => include_str!("../locales/es.ftl"),
+            Idioma::En => include_str!("../locales/en.ftl"),
+        }
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for Idioma {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(parts
+            .headers
+            .get(axum::http::header::ACCEPT_LANGUAGE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(Idioma::desde_accept_language)
+            .unwrap_or_default())
+    }
+}
+
+static BUNDLES: Lazy<Vec<(Idioma, FluentBundle<FluentResource>)>> = Lazy::new(|| {
+    Idioma::TODOS
+        .into_iter()
+        .map(|idioma| {
+            let langid: LanguageIdentifier = idioma.codigo().parse().expect("código de idioma válido");
+            let recurso = FluentResource::try_new(idioma.ftl().to_string())
+                .unwrap_or_else(|(_, errores)| panic!("❌ locales/{}.ftl inválido: {errores:?}", idioma.codigo()));
+
+            let mut bundle = FluentBundle::new_concurrent(vec![langid]);
+            // Sin marcas de aislamiento Unicode alrededor de los argumentos
+            bundle.set_use_isolating(false);
+            bundle
+                .add_resource(recurso)
+                .unwrap_or_else(|errores| panic!("❌ claves repetidas en {}.ftl: {errores:?}", idioma.codigo()));
+            (idioma, bundle)
+        })
+        .collect()
+});
+
+/// Mensaje `id` en `idioma`; si falta, cae al español y, en último
+/// caso, devuelve la clave tal cual.
+pub fn traducir(idioma: Idioma, id: &str, args: Option<&FluentArgs>) -> String {
+    for candidato in [idioma, Idioma::Es] {
+        let Some((_, bundle)) = BUNDLES.iter().find(|(i, _)| *i == candidato) else { continue };
+        let Some(patron) = bundle.get_message(id).and_then(|m| m.value()) else { continue };
+
+        let mut errores = vec![];
+        let texto = bundle.format_pattern(patron, args, &mut errores);
+        if !errores.is_empty() {
+            tracing::warn!("⚠️ Traducción '{id}' ({}): {errores:?}", candidato.codigo());
+        }
+        return texto.into_owned();
+    }
+
+    tracing::warn!("⚠️ Falta la traducción '{id}'");
+    id.to_string()
+}
+
+/// `tr!(idioma, "clave")` o `tr!(idioma, "clave", nombre = valor, …)`.
+macro_rules! tr {
+    ($idioma:expr, $id:expr) => {
+        $crate::i18n::traducir($idioma, $id, None)
+    };
+    ($idioma:expr, $id:expr, $($arg:ident = $valor:expr),+ $(,)?) => {{
+        let mut args = fluent_bundle::FluentArgs::new();
+        $( args.set(stringify!($arg), $valor); )+
+        $crate::i18n::traducir($idioma, $id, Some(&args))
+    }};
+}
+pub(crate) use tr;
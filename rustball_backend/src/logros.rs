@@ -0,0 +1,342 @@
+// -----------------------------------------------------------------
+//! Logros: se evalúan en el backend a partir de los eventos de partida.
+//!
+//!   • `post_jugada` (quien tiró), `post_gol` (el goleador, o los dos
+//!     si fue el gol final) y `correspondencia::vencer` (quien gana por
+//!     tiempo) llaman a `evaluar` después del commit; un fallo aquí sólo
+//!     se registra en el log.
+//!   • Condiciones:
+//!       primera_victoria   ganar una partida
+//!       hat_trick          `GOLES_HAT_TRICK` goles en una partida
+//!       porteria_a_cero    ganar sin recibir goles
+//!       gol_de_poste       gol con rebote previo de la pelota en un poste
+//!                          (lo decide `eventos::registrar_gol` con los
+//!                          eventos validados del tiro)
+//!       ganar_con          ganar habiendo usado una formación predefinida;
+//!                          uno por formación (`detalle`)
+//!       coleccionista      `POWERUPS_COLECCIONISTA` power-ups recogidos en total
+//!                          (sólo los que `eventos` cruzó con el campo)
+//!   • Cada logro nuevo se guarda con su fecha y se anuncia por el WS de
+//!     la partida (`{"tipo":"logro","contenido":{...}}`, el cliente Bevy
+//!     muestra un aviso) y por el del lobby.
+// -----------------------------------------------------------------
+
+use axum::{
+    extract::{Extension, Path},
+    http::StatusCode,
+    Json,
+};
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use serde_json::json;
+use sqlx::MySqlPool;
+use std::collections::HashMap;
+
+use crate::formaciones::PREDEFINIDAS;
+use crate::i18n::{tr, Idioma};
+use crate::routes::{lobby::notificar, websocket::enviar_a_partida};
+
+const GOLES_HAT_TRICK: i64 = 3;
+const POWERUPS_COLECCIONISTA: i64 = 10;
+
+/// Logros sin `detalle`, en el orden en que se muestran.
+const SIMPLES: [&str; 5] = ["primera_victoria", "hat_trick", "porteria_a_cero", "gol_de_poste", "coleccionista"];
+const GANAR_CON: &str = "ganar_con";
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Logro {
+    pub codigo: String,
+    /// Formación de `ganar_con`; vacío en el resto.
+    pub detalle: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct LogroUsuario {
+    #[serde(flatten)]
+    pub logro: Logro,
+    /// `None` mientras no se desbloqueó.
+    pub fecha_desbloqueo: Option<NaiveDateTime>,
+    pub id_partida: Option<i32>,
+}
+
+/* ───────── Evaluación ───────── */
+
+/// Lo que hizo un jugador y decide sus logros.
+#[derive(Debug, Default)]
+struct Resumen {
+    /// `None` mientras la partida no terminó con él de ganador.
+    victoria: Option<Victoria>,
+    goles: i64,
+    goles_de_poste: i64,
+    /// Power-ups recogidos en todas sus partidas.
+    powerups: i64,
+}
+
+#[derive(Debug, Default)]
+struct Victoria {
+    goles_recibidos: i32,
+    /// Formaciones que eligió en la partida (una por ronda, sin repetir).
+    formaciones: Vec<String>,
+}
+
+/// Lee de la base lo que hizo `id_usuario` hasta ahora en la partida.
+async fn resumen(pool: &MySqlPool, id_partida: i32, id_usuario: i32) -> Result<Resumen, sqlx::Error> {
+    let partida = sqlx::query!(
+        r#"
+        SELECT estado AS "estado!: String", ganador, id_jugador1, gol_j1, gol_j2
+        FROM   Partida
+        WHERE  id_partida = ?
+        "#,
+        id_partida
+    )
+        .fetch_one(pool)
+        .await?;
+
+    let victoria = if partida.estado == "finished" && partida.ganador == Some(id_usuario) {
+        let recibidos = if id_usuario == partida.id_jugador1 { partida.gol_j2 } else { partida.gol_j1 };
+        let formaciones = sqlx::query_scalar!(
+            "SELECT DISTINCT formacion FROM FormacionElegida WHERE id_partida = ? AND id_usuario = ?",
+            id_partida,
+            id_usuario
+        )
+            .fetch_all(pool)
+            .await?;
+        Some(Victoria { goles_recibidos: recibidos.unwrap_or(0), formaciones })
+    } else {
+        None
+    };
+
+    let goles = sqlx::query!(
+        r#"
+        SELECT COUNT(*) AS "goles!: i64",
+               CAST(COALESCE(SUM(detalle = 'poste'), 0) AS SIGNED) AS "de_poste!: i64"
+        FROM   EventoTurno
+        WHERE  id_partida = ? AND id_usuario = ? AND tipo = 'gol'
+        "#,
+        id_partida,
+        id_usuario
+    )
+        .fetch_one(pool)
+        .await?;
+
+    let powerups: i64 = sqlx::query_scalar!(
+        "SELECT COUNT(*) FROM EventoTurno WHERE id_usuario = ? AND tipo = 'powerup'",
+        id_usuario
+    )
+        .fetch_one(pool)
+        .await?;
+
+    Ok(Resumen { victoria, goles: goles.goles, goles_de_poste: goles.de_poste, powerups })
+}
+
+/// Logros que se cumplen con `resumen`.
+fn cumplidos(resumen: &Resumen) -> Vec<Logro> {
+    let logro = |codigo: &str, detalle: &str| Logro { codigo: codigo.to_owned(), detalle: detalle.to_owned() };
+    let mut logros = Vec::new();
+
+    if let Some(victoria) = &resumen.victoria {
+        logros.push(logro("primera_victoria", ""));
+        if victoria.goles_recibidos == 0 {
+            logros.push(logro("porteria_a_cero", ""));
+        }
+        for f in victoria.formaciones.iter().filter(|f| PREDEFINIDAS.iter().any(|(n, _)| n == f)) {
+            logros.push(logro(GANAR_CON, f));
+        }
+    }
+
+    if resumen.goles >= GOLES_HAT_TRICK {
+        logros.push(logro("hat_trick", ""));
+    }
+    if resumen.goles_de_poste > 0 {
+        logros.push(logro("gol_de_poste", ""));
+    }
+    if resumen.powerups >= POWERUPS_COLECCIONISTA {
+        logros.push(logro("coleccionista", ""));
+    }
+
+    logros
+}
+
+/// Guarda los logros cumplidos que faltaban y devuelve los nuevos.
+async fn desbloquear(pool: &MySqlPool, id_partida: i32, id_usuario: i32) -> Result<Vec<Logro>, sqlx::Error> {
+    let mut nuevos = Vec::new();
+    for logro in cumplidos(&resumen(pool, id_partida, id_usuario).await?) {
+        let res = sqlx::query!(
+            "INSERT IGNORE INTO LogroUsuario (id_usuario, codigo, detalle, id_partida) VALUES (?, ?, ?, ?)",
+            id_usuario,
+            logro.codigo,
+            logro.detalle,
+            id_partida
+        )
+            .execute(pool)
+            .await?;
+        if res.rows_affected() > 0 {
+            nuevos.push(logro);
+        }
+    }
+    Ok(nuevos)
+}
+
+/// Evalúa los logros de `usuarios` tras un turno o un gol de la partida
+/// y anuncia los nuevos.
+pub async fn evaluar(pool: &MySqlPool, id_partida: i32, usuarios: &[i32]) {
+    for &uid in usuarios {
+        let nuevos = match desbloquear(pool, id_partida, uid).await {
+            Ok(n) => n,
+            Err(e) => {
+                tracing::error!("❌ No se pudieron evaluar los logros de {uid} (partida {id_partida}): {e}");
+                continue;
+            }
+        };
+
+        for logro in nuevos {
+            tracing::info!("🏅 Logro {}{} para {uid} (partida {id_partida})", logro.codigo, sufijo(&logro.detalle));
+            let contenido = json!({ "logro": logro.codigo, "detalle": logro.detalle, "id_usuario": uid });
+            enviar_a_partida(
+                id_partida,
+                json!({ "uid_origen": 0, "tipo": "logro", "contenido": contenido }).to_string(),
+            );
+            notificar(uid, json!({ "tipo": "logro", "logro": logro.codigo, "detalle": logro.detalle, "id_partida": id_partida }));
+        }
+    }
+}
+
+fn sufijo(detalle: &str) -> String {
+    if detalle.is_empty() { String::new() } else { format!(" ({detalle})") }
+}
+
+/* ───────── GET /logros/:u ───────── */
+
+/// Todos los logros posibles: los simples y un `ganar_con` por formación
+/// predefinida.
+fn catalogo() -> Vec<Logro> {
+    SIMPLES
+        .iter()
+        .map(|c| Logro { codigo: c.to_string(), detalle: String::new() })
+        .chain(PREDEFINIDAS.iter().map(|(f, _)| Logro { codigo: GANAR_CON.to_owned(), detalle: f.to_string() }))
+        .collect()
+}
+
+/// Todos los logros posibles, desbloqueados o no.
+pub async fn get_logros(
+    idioma: Idioma,
+    Path(id_usuario): Path<i32>,
+    Extension(pool): Extension<MySqlPool>,
+) -> Result<Json<Vec<LogroUsuario>>, (StatusCode, String)> {
+    let filas = sqlx::query!(
+        r#"
+        SELECT codigo, detalle, id_partida,
+               fecha_desbloqueo AS "fecha_desbloqueo: NaiveDateTime"
+        FROM   LogroUsuario
+        WHERE  id_usuario = ?
+        "#,
+        id_usuario
+    )
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("❌ SQL en get_logros: {e}");
+            (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+        })?;
+
+    let mut desbloqueados: HashMap<(String, String), (NaiveDateTime, Option<i32>)> = filas
+        .into_iter()
+        .map(|f| ((f.codigo, f.detalle), (f.fecha_desbloqueo, f.id_partida)))
+        .collect();
+
+    let logros = catalogo()
+        .into_iter()
+        .map(|logro| {
+            let hecho = desbloqueados.remove(&(logro.codigo.clone(), logro.detalle.clone()));
+            LogroUsuario {
+                logro,
+                fecha_desbloqueo: hecho.map(|(fecha, _)| fecha),
+                id_partida: hecho.and_then(|(_, p)| p),
+            }
+        })
+        .collect();
+
+    Ok(Json(logros))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn codigos(resumen: &Resumen) -> Vec<(String, String)> {
+        cumplidos(resumen).into_iter().map(|l| (l.codigo, l.detalle)).collect()
+    }
+
+    fn par(codigo: &str, detalle: &str) -> (String, String) {
+        (codigo.to_owned(), detalle.to_owned())
+    }
+
+    #[test]
+    fn sin_ganar_ni_goles_no_hay_logros() {
+        assert!(cumplidos(&Resumen::default()).is_empty());
+    }
+
+    #[test]
+    fn victoria_sin_recibir_goles_con_formacion_predefinida() {
+        let (predefinida, _) = PREDEFINIDAS[0];
+        let resumen = Resumen {
+            victoria: Some(Victoria {
+                goles_recibidos: 0,
+                formaciones: vec![predefinida.to_owned(), "mi_formacion".to_owned()],
+            }),
+            goles: 3,
+            ..Resumen::default()
+        };
+        assert_eq!(
+            codigos(&resumen),
+            [
+                par("primera_victoria", ""),
+                par("porteria_a_cero", ""),
+                par(GANAR_CON, predefinida),
+                par("hat_trick", ""),
+            ]
+        );
+    }
+
+    #[test]
+    fn recibir_un_gol_quita_la_porteria_a_cero() {
+        let resumen = Resumen {
+            victoria: Some(Victoria { goles_recibidos: 1, formaciones: Vec::new() }),
+            ..Resumen::default()
+        };
+        assert_eq!(codigos(&resumen), [par("primera_victoria", "")]);
+    }
+
+    #[test]
+    fn goles_y_powerups_cuentan_aunque_se_pierda() {
+        let justo = Resumen {
+            goles: GOLES_HAT_TRICK,
+            goles_de_poste: 1,
+            powerups: POWERUPS_COLECCIONISTA,
+            ..Resumen::default()
+        };
+        assert_eq!(codigos(&justo), [par("hat_trick", ""), par("gol_de_poste", ""), par("coleccionista", "")]);
+
+        let casi = Resumen { goles: GOLES_HAT_TRICK - 1, powerups: POWERUPS_COLECCIONISTA - 1, ..Resumen::default() };
+        assert!(cumplidos(&casi).is_empty());
+    }
+
+    #[test]
+    fn catalogo_con_los_simples_primero() {
+        let catalogo = catalogo();
+        assert_eq!(catalogo.len(), SIMPLES.len() + PREDEFINIDAS.len());
+        for (logro, codigo) in catalogo.iter().zip(SIMPLES) {
+            assert_eq!(logro.codigo, codigo);
+            assert!(logro.detalle.is_empty());
+        }
+    }
+
+    #[test]
+    fn un_ganar_con_por_formacion() {
+        let catalogo = catalogo();
+        let detalles: Vec<&str> =
+            catalogo.iter().filter(|l| l.codigo == GANAR_CON).map(|l| l.detalle.as_str()).collect();
+        let esperados: Vec<&str> = PREDEFINIDAS.iter().map(|(f, _)| *f).collect();
+        assert_eq!(detalles, esperados);
+    }
+}
@@ -1,6 +1,6 @@
 use axum::{
     extract::Extension,
-    routing::{get, post, get_service},
+    routing::{delete, get, post, get_service},
     Router,
 };
 use std::{net::SocketAddr, path::PathBuf};
@@ -19,9 +19,26 @@ mod models;
 mod handlers;
 mod db_mysql;
 mod routes;
+mod bot;
+mod formaciones;
+mod i18n;
+mod resultado;
+mod eventos;
+mod perfil;
+mod amigos;
+mod retos;
+mod presencia;
+mod torneos;
+mod temporadas;
+mod logros;
+mod correspondencia;
+mod salidas;
+mod notificaciones;
+mod cuentas;
 
 use handlers::*;
 use routes::websocket::websocket_handler;
+use routes::lobby::lobby_ws_handler;
 
 #[tokio::main]
 async fn main() {
@@ -41,6 +58,25 @@ async fn main() {
         }
     };
 
+    // Migraciones pendientes (`migrations/`, ver `_sqlx_migrations`)
+    if let Err(e) = sqlx::migrate!().run(&db_pool).await {
+        error!("❌ No se pudieron aplicar las migraciones: {}", e);
+        std::process::exit(1);
+    }
+    info!("✅ Migraciones al día.");
+
+    // ⌛ Caducidad de los retos sin responder
+    retos::lanzar_expiracion(db_pool.clone());
+
+    // 🗓️ Cambio de temporada cuando vence la activa
+    temporadas::lanzar_cierre(db_pool.clone());
+
+    // 📬 Partidas por correspondencia con el plazo vencido
+    correspondencia::lanzar_vencimientos(db_pool.clone());
+
+    // 📨 Entrega de avisos por correo y webhook (outbox)
+    notificaciones::lanzar_envios(db_pool.clone());
+
     // Canal broadcast para WebSocket
     let (tx, _rx) = broadcast::channel::<String>(100);
 
@@ -51,15 +87,50 @@ async fn main() {
         .route("/usuarios",             get(get_usuarios))
         .route("/estadisticas/:u",      get(get_estadisticas))
         .route("/formacion",            post(post_formacion))
+        .route("/formaciones",          post(formaciones::post_formacion_personalizada))
+        .route("/formaciones/:u",       get(formaciones::get_formaciones))
+        .route("/formaciones/:u/:id",   delete(formaciones::delete_formacion_personalizada))
         .route("/registro",             post(post_registro))
+        .route("/cuenta/:u",            get(cuentas::get_cuenta))
+        .route("/cuenta/verificacion",  post(cuentas::post_reenviar_verificacion))
+        .route("/cuenta/verificar",     post(cuentas::post_verificar))
+        .route("/cuenta/restablecer",   post(cuentas::post_restablecer))
+        .route("/cuenta/restablecer/confirmar", post(cuentas::post_confirmar_restablecer))
         .route("/login",                post(post_login))
+        .route("/usuario/idioma",       post(post_idioma_usuario))
         .route("/partida",              post(post_partida))
+        .route("/bots",                 get(get_bots))
         .route("/mis_partidas/:u",      get(get_mis_partidas))
         .route("/gol",                  post(post_gol))
         .route("/snapshot/:p",          get(get_snapshot))
         .route("/pendientes/:u",        get(get_partidas_pendientes))
+        .route("/bandeja/:u",           get(correspondencia::get_bandeja))
+        .route("/notificaciones/:u/preferencias", get(notificaciones::get_preferencias))
+        .route("/notificaciones/preferencias",    post(notificaciones::post_preferencias))
         .route("/partida_detalle/:p",   get(get_partida_detalle))
+        .route("/partida/:p/resultado", get(resultado::get_resultado))
+        .route("/partida/:p/estadisticas", get(eventos::get_estadisticas_partida))
+        .route("/perfil/:u",            get(perfil::get_perfil))
+        .route("/perfil/:u/temporadas", get(temporadas::get_temporadas_jugador))
+        .route("/logros/:u",            get(logros::get_logros))
+        .route("/usuarios/buscar",      get(amigos::get_buscar_usuarios))
+        .route("/amigos/:u",            get(amigos::get_amigos))
+        .route("/amigos/solicitud",     post(amigos::post_solicitud_amistad))
+        .route("/amigos/aceptar",       post(amigos::post_aceptar_amistad))
+        .route("/amigos/:u/:amigo",     delete(amigos::delete_amistad))
+        .route("/retos",                post(retos::post_reto))
+        .route("/retos/:u",             get(retos::get_retos))
+        .route("/retos/:id/aceptar",    post(retos::post_aceptar_reto))
+        .route("/retos/:id/rechazar",   post(retos::post_rechazar_reto))
+        .route("/presencia",            get(presencia::get_presencia))
+        .route("/torneos",              get(torneos::get_torneos).post(torneos::post_torneo))
+        .route("/torneos/:t",           get(torneos::get_torneo))
+        .route("/torneos/:t/clasificacion", get(torneos::get_clasificacion))
+        .route("/temporadas",           get(temporadas::get_temporadas))
+        .route("/temporadas/actual",    get(temporadas::get_temporada_actual))
+        .route("/temporadas/:t/clasificacion", get(temporadas::get_clasificacion_temporada))
         .route("/ws/:partida/:uid",     get(websocket_handler))
+        .route("/lobby/ws/:uid",        get(lobby_ws_handler))
         .layer(Extension(db_pool.clone()))
         .layer(Extension(tx.clone())); // 🟢 Agregado aquí
 
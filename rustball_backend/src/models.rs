@@ -23,6 +23,8 @@ pub struct Usuario {
     pub nombre_usuario: String,
     pub correo: String,
     pub contrasena: String,
+    /// Idioma preferido ("es", "en"); `None` = el del navegador.
+    pub idioma: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
@@ -34,14 +36,36 @@ pub struct Estadistica {
     pub goles_en_contra: Option<i32>,
 }
 
+/// Respuesta de `/estadisticas/:u`: los contadores de `Estadistica` más
+/// los que salen de los eventos de turno (ver `eventos::carrera`).
+#[derive(Debug, Serialize)]
+pub struct EstadisticaCompleta {
+    #[serde(flatten)]
+    pub base: Estadistica,
+    #[serde(flatten)]
+    pub carrera: crate::eventos::Carrera,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct FormacionPayload {
     pub id_partida: i32,
     pub id_usuario: i32,
+    /// Nombre de una formación predefinida ("1-2-1-1", …); se ignora si viene `id_formacion`.
+    #[serde(default)]
     pub formacion: String,
+    /// Formación personalizada del usuario (ver `formaciones.rs`).
+    #[serde(default)]
+    pub id_formacion: Option<i32>,
+    #[serde(default)]
     pub turno_inicio: i32,
 }
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IdiomaPayload {
+    pub id_usuario: i32,
+    pub idioma: String,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RegistroPayload {
     pub nombre_usuario: String,
@@ -53,6 +77,86 @@ pub struct RegistroPayload {
 pub struct PartidaPayload {
     pub id_usuario_1: i32,
     pub id_usuario_2: i32,
+    /// Partida clasificatoria: el cliente desactiva la predicción de trayectoria.
+    #[serde(default)]
+    pub clasificatoria: bool,
+    /// Horas por turno: si viene, la partida es por correspondencia
+    /// (ver `correspondencia.rs`).
+    #[serde(default)]
+    pub plazo_turno_horas: Option<i32>,
+}
+
+/// Pedir, aceptar o quitar una amistad (ver `amigos.rs`).
+#[derive(Debug, Deserialize)]
+pub struct AmistadPayload {
+    pub id_usuario: i32,
+    pub id_amigo: i32,
+}
+
+/// `POST /retos` (ver `retos.rs`).
+#[derive(Debug, Deserialize)]
+pub struct RetoPayload {
+    pub id_retador: i32,
+    pub id_retado: i32,
+    /// Horas por turno de la partida que crea, si es por correspondencia.
+    #[serde(default)]
+    pub plazo_turno_horas: Option<i32>,
+}
+
+/// Quién acepta o rechaza un reto.
+#[derive(Debug, Deserialize)]
+pub struct RespuestaRetoPayload {
+    pub id_usuario: i32,
+}
+
+/// `POST /torneos` (ver `torneos.rs`).
+#[derive(Debug, Deserialize)]
+pub struct TorneoPayload {
+    pub nombre: String,
+    pub id_creador: i32,
+    /// "eliminacion" o "liga".
+    pub formato: String,
+    /// Liga: cada pareja juega dos veces.
+    #[serde(default)]
+    pub ida_y_vuelta: bool,
+    pub participantes: Vec<i32>,
+    /// Horas por turno de sus partidas; si no viene,
+    /// `correspondencia::PLAZO_TORNEO_HORAS`.
+    #[serde(default)]
+    pub plazo_turno_horas: Option<i32>,
+}
+
+/// `POST /notificaciones/preferencias` (ver `notificaciones.rs`).
+#[derive(Debug, Deserialize)]
+pub struct PreferenciasPayload {
+    pub id_usuario: i32,
+    #[serde(flatten)]
+    pub preferencias: crate::notificaciones::Preferencias,
+}
+
+/// `POST /cuenta/verificacion`: reenviar el enlace de verificación.
+#[derive(Debug, Deserialize)]
+pub struct ReenvioVerificacionPayload {
+    pub id_usuario: i32,
+}
+
+/// `POST /cuenta/verificar`.
+#[derive(Debug, Deserialize)]
+pub struct TokenPayload {
+    pub token: String,
+}
+
+/// `POST /cuenta/restablecer`.
+#[derive(Debug, Deserialize)]
+pub struct RestablecerPayload {
+    pub correo: String,
+}
+
+/// `POST /cuenta/restablecer/confirmar`.
+#[derive(Debug, Deserialize)]
+pub struct NuevaContrasenaPayload {
+    pub token: String,
+    pub contrasena: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, sqlx::FromRow)]
@@ -68,24 +172,38 @@ pub struct Partida {
 pub struct GolPayload {
     pub id_partida:  i32,
     pub id_goleador: i32,   // jugador que anotó
+    /// Ronda en la que se marcó; si no coincide con la actual el gol ya
+    /// estaba registrado y se rechaza.
+    pub ronda: i32,
+    /// Tiro que metió el gol con sus `eventos`: ese turno no se cierra,
+    /// así que no llega por `post_jugada`.
+    #[serde(default)]
+    pub jugada: Option<Value>,
 }
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FormacionData {
     pub id_usuario: i32,
     pub formacion : String,
     pub turno_inicio: i32,
+    /// Posiciones canónicas si la formación es personalizada.
+    pub posiciones: Option<Value>,
 }
 
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Snapshot {
     pub estado: String, // ← NUEVO
+    /// Ronda en juego: empieza en 1 y sube con cada gol.  `formaciones` y
+    /// `turnos` son sólo los de esta ronda.
+    pub ronda: i32,
     pub marcador: (i32, i32),
     pub formaciones: Vec<FormacionData>,
     pub turnos: Vec<TurnoData>,
     pub proximo_turno: Option<i32>,
     pub nombre_jugador_1: String,
     pub nombre_jugador_2: String,
+    /// Reglas clasificatorias (ver `PartidaPayload::clasificatoria`).
+    pub clasificatoria: bool,
 }
 
 
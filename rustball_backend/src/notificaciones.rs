@@ -0,0 +1,484 @@
+// -----------------------------------------------------------------
+//! Avisos fuera del juego (correo y webhook) para quien no tiene el
+//! lobby abierto: reto recibido, turno en una partida por
+//! correspondencia y partida terminada.
+//!
+//!   • Cada aviso se guarda primero en el outbox `Notificacion`, uno por
+//!     canal activo del usuario, con el texto ya traducido a su idioma.
+//!     Quien lo genera (`reto`, `turno`, `partida_terminada`) nunca
+//!     falla por esto: los errores sólo van al log.
+//!   • Preferencias por usuario (`PreferenciaNotificacion`; sin fila,
+//!     todo activo y sólo por correo): qué eventos, si por correo y la
+//!     URL del webhook.  Por correo sólo si está verificado.
+//!   • Los correos de la cuenta (`verificacion`, `restablecer`, ver
+//!     `cuentas.rs`) usan el mismo outbox pero van siempre, sólo por
+//!     correo y sin mirar preferencias.
+//!   • `lanzar_envios` entrega cada `INTERVALO_ENVIO_S` lo pendiente con
+//!     la salida del canal (ver `salidas.rs`).  Si falla reintenta con
+//!     espera creciente y a los `MAX_INTENTOS` la marca `fallida`.
+//!   • El webhook tiene que resolver a direcciones públicas (ver
+//!     `salidas::direcciones_webhook`), al guardarlo y al entregar.
+//!   • Los bots no reciben avisos.
+// -----------------------------------------------------------------
+
+use axum::{
+    extract::{Extension, Path},
+    http::StatusCode,
+    Json,
+};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sqlx::MySqlPool;
+
+use crate::bot::Dificultad;
+use crate::i18n::{tr, Idioma};
+use crate::models::PreferenciasPayload;
+use crate::retos::Reto;
+use crate::salidas::{self, Mensaje};
+
+/// Cada cuánto se entrega lo pendiente del outbox.
+const INTERVALO_ENVIO_S: u64 = 30;
+/// Avisos por pasada.
+const LOTE_ENVIO: i64 = 50;
+/// Intentos antes de darlo por `fallida`.
+const MAX_INTENTOS: i32 = 6;
+const LARGO_MAXIMO_URL: usize = 255;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Preferencias {
+    pub por_correo: bool,
+    /// Si está, cada aviso también se manda por POST a esta URL.
+    pub webhook_url: Option<String>,
+    pub reto: bool,
+    pub turno: bool,
+    pub partida_terminada: bool,
+}
+
+impl Default for Preferencias {
+    fn default() -> Self {
+        Self { por_correo: true, webhook_url: None, reto: true, turno: true, partida_terminada: true }
+    }
+}
+
+enum Aviso {
+    Reto { id_reto: i32, rival: String, plazo_turno_horas: Option<i32> },
+    Turno { id_partida: i32, rival: String, fecha_limite: Option<NaiveDateTime> },
+    /// `resultado`: ganada | perdida | empate | anulada; `goles`: (propios, del rival).
+    PartidaTerminada { id_partida: i32, rival: String, resultado: &'static str, goles: (i32, i32) },
+    Verificacion { nombre: String, enlace: String },
+    Restablecer { nombre: String, enlace: String },
+}
+
+impl Aviso {
+    fn evento(&self) -> &'static str {
+        match self {
+            Aviso::Reto { .. } => "reto",
+            Aviso::Turno { .. } => "turno",
+            Aviso::PartidaTerminada { .. } => "partida_terminada",
+            Aviso::Verificacion { .. } => "verificacion",
+            Aviso::Restablecer { .. } => "restablecer",
+        }
+    }
+
+    /// Correos de la cuenta: no se pueden desactivar.
+    fn de_cuenta(&self) -> bool {
+        matches!(self, Aviso::Verificacion { .. } | Aviso::Restablecer { .. })
+    }
+
+    /// Asunto y cuerpo en el idioma del destinatario.
+    fn textos(&self, idioma: Idioma) -> (String, String) {
+        match self {
+            Aviso::Reto { rival, plazo_turno_horas, .. } => {
+                let mut cuerpo = tr!(idioma, "notif-reto-cuerpo", rival = rival.clone());
+                if let Some(horas) = plazo_turno_horas {
+                    cuerpo = format!("{cuerpo}\n\n{}", tr!(idioma, "notif-reto-correspondencia", horas = *horas));
+                }
+                (tr!(idioma, "notif-reto-asunto", rival = rival.clone()), cuerpo)
+            }
+            Aviso::Turno { id_partida, rival, fecha_limite } => {
+                let mut cuerpo = tr!(idioma, "notif-turno-cuerpo", partida = *id_partida, rival = rival.clone());
+                if let Some(fecha) = fecha_limite {
+                    let fecha = fecha.format("%Y-%m-%d %H:%M").to_string();
+                    cuerpo = format!("{cuerpo}\n\n{}", tr!(idioma, "notif-turno-plazo", fecha = fecha));
+                }
+                (tr!(idioma, "notif-turno-asunto", partida = *id_partida), cuerpo)
+            }
+            Aviso::PartidaTerminada { id_partida, rival, resultado, goles } => (
+                tr!(idioma, "notif-fin-asunto", partida = *id_partida),
+                tr!(
+                    idioma,
+                    &format!("notif-fin-{resultado}"),
+                    partida = *id_partida,
+                    rival = rival.clone(),
+                    marcador = format!("{}–{}", goles.0, goles.1)
+                ),
+            ),
+            Aviso::Verificacion { nombre, enlace } => (
+                tr!(idioma, "notif-verificacion-asunto"),
+                tr!(idioma, "notif-verificacion-cuerpo", nombre = nombre.clone(), enlace = enlace.clone()),
+            ),
+            Aviso::Restablecer { nombre, enlace } => (
+                tr!(idioma, "notif-restablecer-asunto"),
+                tr!(idioma, "notif-restablecer-cuerpo", nombre = nombre.clone(), enlace = enlace.clone()),
+            ),
+        }
+    }
+
+    fn datos(&self) -> Value {
+        match self {
+            Aviso::Reto { id_reto, rival, plazo_turno_horas } => json!({
+                "evento": self.evento(), "id_reto": id_reto, "rival": rival, "plazo_turno_horas": plazo_turno_horas,
+            }),
+            Aviso::Turno { id_partida, rival, fecha_limite } => json!({
+                "evento": self.evento(), "id_partida": id_partida, "rival": rival, "fecha_limite": fecha_limite,
+            }),
+            Aviso::PartidaTerminada { id_partida, rival, resultado, goles } => json!({
+                "evento": self.evento(), "id_partida": id_partida, "rival": rival, "resultado": resultado,
+                "goles_propios": goles.0, "goles_rival": goles.1,
+            }),
+            // Sin el enlace: en un webhook o en el log no debe quedar el token
+            Aviso::Verificacion { .. } | Aviso::Restablecer { .. } => json!({ "evento": self.evento() }),
+        }
+    }
+}
+
+/* ───────── Encolar ───────── */
+
+async fn preferencias(pool: &MySqlPool, id_usuario: i32) -> Result<Preferencias, sqlx::Error> {
+    let fila = sqlx::query_as!(
+        Preferencias,
+        r#"
+        SELECT por_correo AS "por_correo: bool", webhook_url,
+               reto AS "reto: bool", turno AS "turno: bool", partida_terminada AS "partida_terminada: bool"
+        FROM   PreferenciaNotificacion
+        WHERE  id_usuario = ?
+        "#,
+        id_usuario
+    )
+        .fetch_optional(pool)
+        .await?;
+    Ok(fila.unwrap_or_default())
+}
+
+/// Guarda en el outbox el aviso para cada canal activo de `id_usuario`.
+async fn encolar(pool: &MySqlPool, id_usuario: i32, aviso: &Aviso) -> Result<(), sqlx::Error> {
+    let usuario = sqlx::query!(
+        r#"
+        SELECT nombre_usuario, correo, idioma, correo_verificado AS "correo_verificado: bool"
+        FROM   Usuario
+        WHERE  id_usuario = ?
+        "#,
+        id_usuario
+    )
+        .fetch_one(pool)
+        .await?;
+    if Dificultad::from_nombre(&usuario.nombre_usuario).is_some() {
+        return Ok(());
+    }
+
+    let correo = usuario.correo.trim().to_owned();
+    let mut canales = Vec::new();
+    if aviso.de_cuenta() {
+        canales.push(("correo", correo));
+    } else {
+        let prefs = preferencias(pool, id_usuario).await?;
+        let activo = match aviso {
+            Aviso::Reto { .. } => prefs.reto,
+            Aviso::Turno { .. } => prefs.turno,
+            _ => prefs.partida_terminada,
+        };
+        if !activo {
+            return Ok(());
+        }
+        if prefs.por_correo && usuario.correo_verificado && !correo.is_empty() {
+            canales.push(("correo", correo));
+        }
+        if let Some(url) = prefs.webhook_url {
+            canales.push(("webhook", url));
+        }
+    }
+
+    let idioma = usuario.idioma.as_deref().and_then(Idioma::desde_codigo).unwrap_or_default();
+    let (asunto, cuerpo) = aviso.textos(idioma);
+    let datos = aviso.datos();
+    for (canal, destino) in canales {
+        sqlx::query!(
+            r#"
+            INSERT INTO Notificacion (id_usuario, evento, canal, destino, asunto, cuerpo, datos)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            "#,
+            id_usuario,
+            aviso.evento(),
+            canal,
+            destino,
+            asunto,
+            cuerpo,
+            datos
+        )
+            .execute(pool)
+            .await?;
+    }
+    Ok(())
+}
+
+async fn avisar(pool: &MySqlPool, id_usuario: i32, aviso: Aviso) {
+    if let Err(e) = encolar(pool, id_usuario, &aviso).await {
+        tracing::error!("❌ No se pudo encolar el aviso '{}' para {id_usuario}: {e}", aviso.evento());
+    }
+}
+
+/// Reto nuevo: aviso al retado.
+pub async fn reto(pool: &MySqlPool, reto: &Reto) {
+    let aviso = Aviso::Reto {
+        id_reto: reto.id_reto,
+        rival: reto.nombre_retador.clone(),
+        plazo_turno_horas: reto.plazo_turno_horas,
+    };
+    avisar(pool, reto.id_retado, aviso).await;
+}
+
+/// Le toca mover a `id_usuario` (formación o tiro) en una partida por correspondencia.
+pub async fn turno(pool: &MySqlPool, id_partida: i32, id_usuario: i32, fecha_limite: Option<NaiveDateTime>) {
+    let rival = sqlx::query_scalar!(
+        r#"
+        SELECT r.nombre_usuario
+        FROM   Partida p
+        JOIN   Usuario r ON r.id_usuario = IF(p.id_jugador1 = ?, p.id_jugador2, p.id_jugador1)
+        WHERE  p.id_partida = ?
+        "#,
+        id_usuario,
+        id_partida
+    )
+        .fetch_one(pool)
+        .await;
+
+    match rival {
+        Ok(rival) => avisar(pool, id_usuario, Aviso::Turno { id_partida, rival, fecha_limite }).await,
+        Err(e) => tracing::error!("❌ No se pudo avisar el turno de la partida {id_partida}: {e}"),
+    }
+}
+
+/// La partida terminó (o se anuló): aviso a los dos jugadores.
+pub async fn partida_terminada(pool: &MySqlPool, id_partida: i32) {
+    let fila = sqlx::query!(
+        r#"
+        SELECT p.id_jugador1, p.id_jugador2, p.gol_j1, p.gol_j2, p.ganador, p.elo_cambio_j1,
+               u1.nombre_usuario AS nombre_1, u2.nombre_usuario AS nombre_2
+        FROM   Partida p
+        JOIN   Usuario u1 ON u1.id_usuario = p.id_jugador1
+        JOIN   Usuario u2 ON u2.id_usuario = p.id_jugador2
+        WHERE  p.id_partida = ?
+        "#,
+        id_partida
+    )
+        .fetch_one(pool)
+        .await;
+    let p = match fila {
+        Ok(p) => p,
+        Err(e) => {
+            tracing::error!("❌ No se pudo avisar el final de la partida {id_partida}: {e}");
+            return;
+        }
+    };
+
+    let (gol_j1, gol_j2) = (p.gol_j1.unwrap_or(0), p.gol_j2.unwrap_or(0));
+    for (id, rival, goles) in [
+        (p.id_jugador1, p.nombre_2.clone(), (gol_j1, gol_j2)),
+        (p.id_jugador2, p.nombre_1.clone(), (gol_j2, gol_j1)),
+    ] {
+        // Sin cambio de Elo: se anuló (ver `correspondencia.rs`)
+        let resultado = match p.ganador {
+            _ if p.elo_cambio_j1.is_none() => "anulada",
+            Some(g) if g == id => "ganada",
+            Some(_) => "perdida",
+            None => "empate",
+        };
+        avisar(pool, id, Aviso::PartidaTerminada { id_partida, rival, resultado, goles }).await;
+    }
+}
+
+/// Enlace para verificar el correo (ver `cuentas.rs`).
+pub async fn verificacion(pool: &MySqlPool, id_usuario: i32, nombre: String, enlace: String) -> Result<(), sqlx::Error> {
+    encolar(pool, id_usuario, &Aviso::Verificacion { nombre, enlace }).await
+}
+
+/// Enlace para elegir una contraseña nueva (ver `cuentas.rs`).
+pub async fn restablecer(pool: &MySqlPool, id_usuario: i32, nombre: String, enlace: String) -> Result<(), sqlx::Error> {
+    encolar(pool, id_usuario, &Aviso::Restablecer { nombre, enlace }).await
+}
+
+/* ───────── Entrega ───────── */
+
+/// Minutos de espera antes del reintento tras `intentos` fallos: 1, 2, 4, 8…
+fn espera_reintento(intentos: i32) -> i32 {
+    1 << (intentos - 1).clamp(0, 10)
+}
+
+async fn entregar_pendientes(pool: &MySqlPool) -> Result<(), sqlx::Error> {
+    let pendientes = sqlx::query!(
+        r#"
+        SELECT id_notificacion, canal AS "canal!: String", destino, asunto, cuerpo, datos, intentos
+        FROM   Notificacion
+        WHERE  estado = 'pendiente' AND proximo_intento <= NOW()
+        ORDER  BY id_notificacion
+        LIMIT  ?
+        "#,
+        LOTE_ENVIO
+    )
+        .fetch_all(pool)
+        .await?;
+
+    for n in pendientes {
+        let salida = if n.canal == "correo" { salidas::correo() } else { salidas::webhook() };
+        let mensaje = Mensaje { destino: &n.destino, asunto: &n.asunto, cuerpo: &n.cuerpo, datos: &n.datos };
+        let intentos = n.intentos + 1;
+
+        match salida.entregar(&n.canal, &mensaje).await {
+            Ok(()) => {
+                sqlx::query!(
+                    r#"
+                    UPDATE Notificacion
+                    SET    estado = 'enviada', intentos = ?, ultimo_error = NULL, fecha_envio = NOW()
+                    WHERE  id_notificacion = ?
+                    "#,
+                    intentos,
+                    n.id_notificacion
+                )
+                    .execute(pool)
+                    .await?;
+            }
+            Err(error) => {
+                let estado = if intentos >= MAX_INTENTOS { "fallida" } else { "pendiente" };
+                tracing::warn!(
+                    "📭 Aviso {} ({}) no entregado, intento {intentos}/{MAX_INTENTOS}: {error}",
+                    n.id_notificacion,
+                    n.canal
+                );
+                sqlx::query!(
+                    r#"
+                    UPDATE Notificacion
+                    SET    estado = ?, intentos = ?, ultimo_error = ?,
+                           proximo_intento = NOW() + INTERVAL ? MINUTE
+                    WHERE  id_notificacion = ?
+                    "#,
+                    estado,
+                    intentos,
+                    error.chars().take(500).collect::<String>(),
+                    espera_reintento(intentos),
+                    n.id_notificacion
+                )
+                    .execute(pool)
+                    .await?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Tarea de fondo que vacía el outbox.
+pub fn lanzar_envios(pool: MySqlPool) {
+    tokio::spawn(async move {
+        let mut intervalo = tokio::time::interval(std::time::Duration::from_secs(INTERVALO_ENVIO_S));
+        loop {
+            intervalo.tick().await;
+            if let Err(e) = entregar_pendientes(&pool).await {
+                tracing::error!("❌ Error entregando avisos: {e}");
+            }
+        }
+    });
+}
+
+/* ───────── Handlers ───────── */
+
+// GET /notificaciones/:u/preferencias
+pub async fn get_preferencias(
+    idioma: Idioma,
+    Path(id_usuario): Path<i32>,
+    Extension(pool): Extension<MySqlPool>,
+) -> Result<Json<Preferencias>, (StatusCode, String)> {
+    preferencias(&pool, id_usuario)
+        .await
+        .map(Json)
+        .map_err(|e| {
+            tracing::error!("❌ SQL en get_preferencias: {e}");
+            (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+        })
+}
+
+// POST /notificaciones/preferencias
+pub async fn post_preferencias(
+    idioma: Idioma,
+    Extension(pool): Extension<MySqlPool>,
+    Json(payload): Json<PreferenciasPayload>,
+) -> Result<Json<Preferencias>, (StatusCode, String)> {
+    let error_sql = |e: sqlx::Error| {
+        tracing::error!("❌ SQL en post_preferencias: {e}");
+        (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+    };
+    let PreferenciasPayload { id_usuario, mut preferencias } = payload;
+
+    // Vacía = sin webhook
+    preferencias.webhook_url = preferencias.webhook_url.map(|u| u.trim().to_owned()).filter(|u| !u.is_empty());
+    if let Some(url) = &preferencias.webhook_url {
+        let esquema_valido = url.starts_with("http://") || url.starts_with("https://");
+        if !esquema_valido || url.len() > LARGO_MAXIMO_URL {
+            return Err((StatusCode::BAD_REQUEST, tr!(idioma, "notif-webhook-invalido", max = LARGO_MAXIMO_URL)));
+        }
+        // Se vuelve a mirar al entregar, por si el DNS cambió
+        if let Err(e) = salidas::direcciones_webhook(url).await {
+            tracing::warn!("🔔 Webhook rechazado para {id_usuario}: {e}");
+            return Err((StatusCode::BAD_REQUEST, tr!(idioma, "notif-webhook-interno")));
+        }
+    }
+
+    let existe: i64 = sqlx::query_scalar!("SELECT COUNT(*) FROM Usuario WHERE id_usuario = ?", id_usuario)
+        .fetch_one(&pool)
+        .await
+        .map_err(error_sql)?;
+    if existe == 0 {
+        return Err((StatusCode::NOT_FOUND, tr!(idioma, "usuario-no-encontrado", usuario = id_usuario)));
+    }
+
+    sqlx::query!(
+        r#"
+        INSERT INTO PreferenciaNotificacion (id_usuario, por_correo, webhook_url, reto, turno, partida_terminada)
+        VALUES (?, ?, ?, ?, ?, ?)
+        ON DUPLICATE KEY UPDATE
+            por_correo        = VALUES(por_correo),
+            webhook_url       = VALUES(webhook_url),
+            reto              = VALUES(reto),
+            turno             = VALUES(turno),
+            partida_terminada = VALUES(partida_terminada)
+        "#,
+        id_usuario,
+        preferencias.por_correo,
+        preferencias.webhook_url,
+        preferencias.reto,
+        preferencias.turno,
+        preferencias.partida_terminada
+    )
+        .execute(&pool)
+        .await
+        .map_err(error_sql)?;
+
+    tracing::info!("🔔 Preferencias de avisos de {id_usuario}: {preferencias:?}");
+    Ok(Json(preferencias))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn espera_crece_al_doble() {
+        let esperas: Vec<i32> = (1..=MAX_INTENTOS).map(espera_reintento).collect();
+        assert_eq!(esperas, vec![1, 2, 4, 8, 16, 32]);
+    }
+
+    #[test]
+    fn espera_acotada() {
+        assert_eq!(espera_reintento(0), 1);
+        assert_eq!(espera_reintento(50), 1 << 10);
+    }
+}
@@ -0,0 +1,238 @@
+// -----------------------------------------------------------------
+//! Perfil público de un jugador (`perfil.html`, pantalla final).
+//!
+//!   • `GET /perfil/:u` — nombre, fecha de alta, Elo, totales de las
+//!     partidas terminadas, forma reciente (últimos 10 resultados) y
+//!     las estadísticas de carrera de `eventos::carrera` (formación
+//!     favorita incluida).  Nunca expone correo ni contraseña.
+//!   • `GET /perfil/:u?rival=R` agrega el cara a cara contra `R`:
+//!     mismos totales pero sólo de las partidas entre los dos, y sus
+//!     últimos enfrentamientos.
+//!   • Los totales salen de `Partida` (ganador y goles de las partidas
+//!     'finished'), no de `Estadistica`.
+// -----------------------------------------------------------------
+
+use axum::{
+    extract::{Extension, Path, Query},
+    http::StatusCode,
+    Json,
+};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::MySqlPool;
+
+use crate::eventos::Carrera;
+use crate::i18n::{tr, Idioma};
+
+/// Resultados que forman la "forma reciente".
+const FORMA_RECIENTE: i64 = 10;
+/// Enfrentamientos que se listan en el cara a cara.
+const ULTIMOS_CARA_A_CARA: i64 = 5;
+
+#[derive(Debug, Default, Serialize)]
+pub struct Totales {
+    pub jugadas: i64,
+    pub ganadas: i64,
+    pub empatadas: i64,
+    pub perdidas: i64,
+    pub goles_a_favor: i64,
+    pub goles_en_contra: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PartidaReciente {
+    pub id_partida: i32,
+    pub id_rival: i32,
+    pub nombre_rival: String,
+    pub goles_propios: i32,
+    pub goles_rival: i32,
+    /// "G" ganó, "E" empate, "P" perdió.
+    pub resultado: &'static str,
+    pub elo_cambio: Option<i32>,
+    pub fecha_fin: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CaraACara {
+    pub id_rival: i32,
+    pub nombre_rival: String,
+    pub totales: Totales,
+    pub ultimas: Vec<PartidaReciente>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Perfil {
+    pub id_usuario: i32,
+    pub nombre_usuario: String,
+    pub fecha_registro: NaiveDateTime,
+    pub elo: i32,
+    pub totales: Totales,
+    pub forma: Vec<PartidaReciente>,
+    pub carrera: Carrera,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cara_a_cara: Option<CaraACara>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PerfilQuery {
+    #[serde(default)]
+    pub rival: Option<i32>,
+}
+
+/// Totales de `id_usuario` en partidas terminadas (sólo contra `rival` si viene).
+async fn totales(pool: &MySqlPool, id_usuario: i32, rival: Option<i32>) -> Result<Totales, sqlx::Error> {
+    let fila = sqlx::query!(
+        r#"
+        SELECT COUNT(*) AS "jugadas!: i64",
+               COUNT(CASE WHEN ganador = ? THEN 1 END) AS "ganadas!: i64",
+               COUNT(CASE WHEN ganador IS NULL THEN 1 END) AS "empatadas!: i64",
+               CAST(COALESCE(SUM(CASE WHEN id_jugador1 = ? THEN gol_j1 ELSE gol_j2 END), 0) AS SIGNED) AS "goles_a_favor!: i64",
+               CAST(COALESCE(SUM(CASE WHEN id_jugador1 = ? THEN gol_j2 ELSE gol_j1 END), 0) AS SIGNED) AS "goles_en_contra!: i64"
+        FROM   Partida
+        WHERE  estado = 'finished'
+          AND  (id_jugador1 = ? OR id_jugador2 = ?)
+          AND  (? IS NULL OR id_jugador1 = ? OR id_jugador2 = ?)
+        "#,
+        id_usuario,
+        id_usuario,
+        id_usuario,
+        id_usuario,
+        id_usuario,
+        rival,
+        rival,
+        rival
+    )
+        .fetch_one(pool)
+        .await?;
+
+    Ok(Totales {
+        jugadas: fila.jugadas,
+        ganadas: fila.ganadas,
+        empatadas: fila.empatadas,
+        perdidas: fila.jugadas - fila.ganadas - fila.empatadas,
+        goles_a_favor: fila.goles_a_favor,
+        goles_en_contra: fila.goles_en_contra,
+    })
+}
+
+/// Letra de la forma reciente: `G`anó, `P`erdió o `E`mpató.
+fn resultado_de(ganador: Option<i32>, id_usuario: i32) -> &'static str {
+    match ganador {
+        Some(g) if g == id_usuario => "G",
+        Some(_) => "P",
+        None => "E",
+    }
+}
+
+/// Últimas `limite` partidas terminadas de `id_usuario`, de la más nueva
+/// a la más vieja (sólo contra `rival` si viene).
+async fn recientes(
+    pool: &MySqlPool,
+    id_usuario: i32,
+    rival: Option<i32>,
+    limite: i64,
+) -> Result<Vec<PartidaReciente>, sqlx::Error> {
+    let filas = sqlx::query!(
+        r#"
+        SELECT p.id_partida, p.ganador, u.id_usuario AS id_rival, u.nombre_usuario AS nombre_rival,
+               CASE WHEN p.id_jugador1 = ? THEN p.gol_j1 ELSE p.gol_j2 END AS goles_propios,
+               CASE WHEN p.id_jugador1 = ? THEN p.gol_j2 ELSE p.gol_j1 END AS goles_rival,
+               CASE WHEN p.id_jugador1 = ? THEN p.elo_cambio_j1 ELSE p.elo_cambio_j2 END AS elo_cambio,
+               p.fecha_fin AS "fecha_fin: NaiveDateTime"
+        FROM   Partida p
+        JOIN   Usuario u ON u.id_usuario = CASE WHEN p.id_jugador1 = ? THEN p.id_jugador2 ELSE p.id_jugador1 END
+        WHERE  p.estado = 'finished'
+          AND  (p.id_jugador1 = ? OR p.id_jugador2 = ?)
+          AND  (? IS NULL OR u.id_usuario = ?)
+        ORDER  BY p.fecha_fin DESC, p.id_partida DESC
+        LIMIT  ?
+        "#,
+        id_usuario,
+        id_usuario,
+        id_usuario,
+        id_usuario,
+        id_usuario,
+        id_usuario,
+        rival,
+        rival,
+        limite
+    )
+        .fetch_all(pool)
+        .await?;
+
+    Ok(filas
+        .into_iter()
+        .map(|f| PartidaReciente {
+            id_partida: f.id_partida,
+            id_rival: f.id_rival,
+            nombre_rival: f.nombre_rival,
+            goles_propios: f.goles_propios.unwrap_or(0) as i32,
+            goles_rival: f.goles_rival.unwrap_or(0) as i32,
+            resultado: resultado_de(f.ganador, id_usuario),
+            elo_cambio: f.elo_cambio.map(|c| c as i32),
+            fecha_fin: f.fecha_fin,
+        })
+        .collect())
+}
+
+pub async fn get_perfil(
+    idioma: Idioma,
+    Path(id_usuario): Path<i32>,
+    Query(q): Query<PerfilQuery>,
+    Extension(pool): Extension<MySqlPool>,
+) -> Result<Json<Perfil>, (StatusCode, String)> {
+    let error_sql = |e: sqlx::Error| {
+        tracing::error!("❌ Error SQL en el perfil de {id_usuario}: {e:?}");
+        (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+    };
+
+    let usuario = sqlx::query!(
+        r#"SELECT nombre_usuario, elo, fecha_registro AS "fecha_registro: NaiveDateTime" FROM Usuario WHERE id_usuario = ?"#,
+        id_usuario
+    )
+        .fetch_optional(&pool)
+        .await
+        .map_err(error_sql)?
+        .ok_or_else(|| (StatusCode::NOT_FOUND, tr!(idioma, "usuario-no-encontrado", usuario = id_usuario)))?;
+
+    let cara_a_cara = match q.rival.filter(|&r| r != id_usuario) {
+        Some(rival) => {
+            let nombre_rival = sqlx::query_scalar!("SELECT nombre_usuario FROM Usuario WHERE id_usuario = ?", rival)
+                .fetch_optional(&pool)
+                .await
+                .map_err(error_sql)?
+                .ok_or_else(|| (StatusCode::NOT_FOUND, tr!(idioma, "usuario-no-encontrado", usuario = rival)))?;
+
+            Some(CaraACara {
+                id_rival: rival,
+                nombre_rival,
+                totales: totales(&pool, id_usuario, Some(rival)).await.map_err(error_sql)?,
+                ultimas: recientes(&pool, id_usuario, Some(rival), ULTIMOS_CARA_A_CARA).await.map_err(error_sql)?,
+            })
+        }
+        None => None,
+    };
+
+    Ok(Json(Perfil {
+        id_usuario,
+        nombre_usuario: usuario.nombre_usuario,
+        fecha_registro: usuario.fecha_registro,
+        elo: usuario.elo,
+        totales: totales(&pool, id_usuario, None).await.map_err(error_sql)?,
+        forma: recientes(&pool, id_usuario, None, FORMA_RECIENTE).await.map_err(error_sql)?,
+        carrera: crate::eventos::carrera(&pool, id_usuario).await.map_err(error_sql)?,
+        cara_a_cara,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn letra_de_cada_resultado() {
+        assert_eq!(resultado_de(Some(7), 7), "G");
+        assert_eq!(resultado_de(Some(8), 7), "P");
+        assert_eq!(resultado_de(None, 7), "E");
+    }
+}
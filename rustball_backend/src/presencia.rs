@@ -0,0 +1,272 @@
+// -----------------------------------------------------------------
+//! Presencia: quién está conectado y en qué partida.
+//!
+//!   • La alimentan las sesiones WebSocket: el lobby
+//!     (`routes::lobby`) y la partida (`routes::websocket`) llaman a
+//!     `conectar` / `desconectar`.  Un usuario puede tener varias
+//!     pestañas: se cuentan las sesiones de cada lugar.
+//!   • Estados: `en_partida` (tiene abierto el WS de alguna partida),
+//!     `ausente` (sólo el lobby, y lo dejó en segundo plano o lleva
+//!     `AUSENTE_TRAS` sin actividad), `en_linea` y `desconectado`.
+//!   • Cada cambio se avisa a los amigos por el WS del lobby
+//!     (`{"tipo":"presencia", ...}`); el paso a ausente por tiempo no se
+//!     empuja, se ve al recargar la lista.
+//!   • Vive en memoria: al reiniciar el servidor todos quedan
+//!     desconectados hasta que reconecten sus sockets.
+// -----------------------------------------------------------------
+
+use axum::{extract::Query, Json};
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sqlx::MySqlPool;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::routes::lobby::notificar;
+
+/// Sin actividad en el lobby durante este tiempo se pasa a ausente.
+const AUSENTE_TRAS: Duration = Duration::from_secs(5 * 60);
+/// Usuarios por consulta de `GET /presencia`.
+const MAX_CONSULTA: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(tag = "estado", rename_all = "snake_case")]
+pub enum Presencia {
+    Desconectado,
+    EnLinea,
+    Ausente,
+    EnPartida { id_partida: i32 },
+}
+
+/// Dónde está abierta una sesión.
+#[derive(Debug, Clone, Copy)]
+pub enum Lugar {
+    Lobby,
+    Partida(i32),
+}
+
+struct Sesiones {
+    lobby: u32,
+    partidas: HashMap<i32, u32>,
+    /// El lobby avisó que quedó en segundo plano.
+    en_segundo_plano: bool,
+    ultima_actividad: Instant,
+}
+
+impl Sesiones {
+    fn nuevas() -> Self {
+        Self { lobby: 0, partidas: HashMap::new(), en_segundo_plano: false, ultima_actividad: Instant::now() }
+    }
+
+    fn presencia(&self) -> Presencia {
+        // Con varias partidas abiertas se muestra la más nueva
+        if let Some(&id_partida) = self.partidas.keys().max() {
+            return Presencia::EnPartida { id_partida };
+        }
+        if self.lobby == 0 {
+            return Presencia::Desconectado;
+        }
+        if self.en_segundo_plano || self.ultima_actividad.elapsed() >= AUSENTE_TRAS {
+            Presencia::Ausente
+        } else {
+            Presencia::EnLinea
+        }
+    }
+
+    fn abrir(&mut self, lugar: Lugar) {
+        match lugar {
+            Lugar::Lobby => {
+                self.lobby += 1;
+                self.en_segundo_plano = false;
+            }
+            Lugar::Partida(p) => *self.partidas.entry(p).or_insert(0) += 1,
+        }
+        self.ultima_actividad = Instant::now();
+    }
+
+    fn cerrar(&mut self, lugar: Lugar) {
+        match lugar {
+            Lugar::Lobby => self.lobby = self.lobby.saturating_sub(1),
+            Lugar::Partida(p) => {
+                if let Some(n) = self.partidas.get_mut(&p) {
+                    *n -= 1;
+                    if *n == 0 {
+                        self.partidas.remove(&p);
+                    }
+                }
+            }
+        }
+    }
+}
+
+static SESIONES: OnceCell<Mutex<HashMap<i32, Sesiones>>> = OnceCell::new();
+
+fn sesiones() -> &'static Mutex<HashMap<i32, Sesiones>> {
+    SESIONES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Aplica `cambio` a las sesiones de `uid` y, si su presencia cambió,
+/// se lo avisa a sus amigos.
+fn actualizar(pool: &MySqlPool, uid: i32, cambio: impl FnOnce(&mut Sesiones)) {
+    let (antes, despues) = {
+        let mut mapa = sesiones().lock().unwrap();
+        let s = mapa.entry(uid).or_insert_with(Sesiones::nuevas);
+        let antes = s.presencia();
+        cambio(s);
+        let despues = s.presencia();
+        if despues == Presencia::Desconectado {
+            mapa.remove(&uid);
+        }
+        (antes, despues)
+    };
+
+    if antes != despues {
+        tracing::debug!("👀 Presencia uid={uid}: {antes:?} → {despues:?}");
+        let pool = pool.clone();
+        tokio::spawn(async move {
+            if let Err(e) = avisar_amigos(&pool, uid, despues).await {
+                tracing::error!("❌ No se pudo avisar la presencia de {uid}: {e}");
+            }
+        });
+    }
+}
+
+async fn avisar_amigos(pool: &MySqlPool, uid: i32, presencia: Presencia) -> Result<(), sqlx::Error> {
+    let amigos = sqlx::query_scalar!(
+        r#"
+        SELECT IF(id_solicitante = ?, id_destinatario, id_solicitante) AS "id_amigo!: i32"
+        FROM   Amistad
+        WHERE  estado = 'aceptada' AND (id_solicitante = ? OR id_destinatario = ?)
+        "#,
+        uid,
+        uid,
+        uid
+    )
+        .fetch_all(pool)
+        .await?;
+
+    let aviso = json!({ "tipo": "presencia", "id_usuario": uid, "presencia": presencia });
+    for amigo in amigos {
+        notificar(amigo, aviso.clone());
+    }
+    Ok(())
+}
+
+/* ───────── API para las sesiones WS ───────── */
+
+pub fn conectar(pool: &MySqlPool, uid: i32, lugar: Lugar) {
+    actualizar(pool, uid, |s| s.abrir(lugar));
+}
+
+pub fn desconectar(pool: &MySqlPool, uid: i32, lugar: Lugar) {
+    actualizar(pool, uid, |s| s.cerrar(lugar));
+}
+
+/// El usuario hizo algo (mandó un turno, volvió a la pestaña del lobby…).
+pub fn actividad(pool: &MySqlPool, uid: i32) {
+    actualizar(pool, uid, |s| {
+        s.en_segundo_plano = false;
+        s.ultima_actividad = Instant::now();
+    });
+}
+
+/// El lobby quedó en segundo plano.
+pub fn segundo_plano(pool: &MySqlPool, uid: i32) {
+    actualizar(pool, uid, |s| s.en_segundo_plano = true);
+}
+
+pub fn presencia(uid: i32) -> Presencia {
+    sesiones()
+        .lock()
+        .unwrap()
+        .get(&uid)
+        .map_or(Presencia::Desconectado, Sesiones::presencia)
+}
+
+/// ¿`uid` tiene abierto el WS de `partida`?
+pub fn en_partida(uid: i32, partida: i32) -> bool {
+    sesiones()
+        .lock()
+        .unwrap()
+        .get(&uid)
+        .is_some_and(|s| s.partidas.contains_key(&partida))
+}
+
+/* ───────── Handler ───────── */
+
+#[derive(Debug, Deserialize)]
+pub struct PresenciaQuery {
+    /// IDs separados por comas.
+    pub u: String,
+}
+
+// GET /presencia?u=1,2,3
+pub async fn get_presencia(Query(q): Query<PresenciaQuery>) -> Json<HashMap<i32, Presencia>> {
+    let estados = q
+        .u
+        .split(',')
+        .filter_map(|id| id.trim().parse::<i32>().ok())
+        .take(MAX_CONSULTA)
+        .map(|uid| (uid, presencia(uid)))
+        .collect();
+    Json(estados)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sin_sesiones_esta_desconectado() {
+        assert_eq!(Sesiones::nuevas().presencia(), Presencia::Desconectado);
+    }
+
+    #[test]
+    fn lobby_activo_o_en_segundo_plano() {
+        let mut s = Sesiones::nuevas();
+        s.abrir(Lugar::Lobby);
+        assert_eq!(s.presencia(), Presencia::EnLinea);
+        s.en_segundo_plano = true;
+        assert_eq!(s.presencia(), Presencia::Ausente);
+        // Abrir otra pestaña del lobby lo saca del segundo plano
+        s.abrir(Lugar::Lobby);
+        assert_eq!(s.presencia(), Presencia::EnLinea);
+    }
+
+    #[test]
+    fn ausente_tras_inactividad() {
+        let mut s = Sesiones::nuevas();
+        s.abrir(Lugar::Lobby);
+        s.ultima_actividad = Instant::now() - AUSENTE_TRAS;
+        assert_eq!(s.presencia(), Presencia::Ausente);
+    }
+
+    #[test]
+    fn la_partida_manda_sobre_el_lobby() {
+        let mut s = Sesiones::nuevas();
+        s.abrir(Lugar::Lobby);
+        s.abrir(Lugar::Partida(4));
+        s.abrir(Lugar::Partida(9));
+        assert_eq!(s.presencia(), Presencia::EnPartida { id_partida: 9 });
+        s.cerrar(Lugar::Partida(9));
+        assert_eq!(s.presencia(), Presencia::EnPartida { id_partida: 4 });
+        s.cerrar(Lugar::Partida(4));
+        assert_eq!(s.presencia(), Presencia::EnLinea);
+    }
+
+    #[test]
+    fn varias_pestanas_se_cuentan() {
+        let mut s = Sesiones::nuevas();
+        s.abrir(Lugar::Partida(2));
+        s.abrir(Lugar::Partida(2));
+        s.cerrar(Lugar::Partida(2));
+        assert_eq!(s.presencia(), Presencia::EnPartida { id_partida: 2 });
+        s.cerrar(Lugar::Partida(2));
+        // Cerrar de más no hace nada
+        s.cerrar(Lugar::Partida(2));
+        s.cerrar(Lugar::Lobby);
+        assert_eq!(s.presencia(), Presencia::Desconectado);
+    }
+}
@@ -0,0 +1,233 @@
+// -----------------------------------------------------------------
+//! Fin de partida: quién ganó y cuánto Elo cambió.
+//!
+//!   • `post_gol` llama a `cerrar_partida` (dentro de su transacción)
+//!     cuando alguien llega a `GOLES_PARA_GANAR`: la partida pasa a
+//!     'finished', se guarda el ganador y se actualiza el Elo de ambos
+//!     (también en la temporada activa, ver `temporadas.rs`).
+//!   • Elo clásico con K = 32; el empate (no se da con la regla de
+//!     goles, pero sí si algún día se abandona) cuenta medio punto.
+//!   • Solo puntúan las partidas clasificatorias entre dos personas:
+//!     los amistosos y las partidas contra un bot se cierran igual,
+//!     pero con cambio de Elo 0 y sin sumar a la temporada.
+//!   • `GET /partida/:p/resultado` arma la pantalla final del cliente:
+//!     nombres, marcador, ganador y cambio de rating.
+// -----------------------------------------------------------------
+
+use axum::{
+    extract::{Extension, Path},
+    http::StatusCode,
+    Json,
+};
+use serde::Serialize;
+use sqlx::{MySql, MySqlPool, Transaction};
+
+use crate::i18n::{tr, Idioma};
+
+/// Goles que cierran la partida (igual que en el cliente).
+pub const GOLES_PARA_GANAR: i32 = 3;
+const K_ELO: f64 = 32.0;
+
+/// Puntos de Elo para quien tiene `elo_propio`; `puntos` es 1 (ganó),
+/// 0.5 (empate) o 0 (perdió).
+pub fn cambio_elo(elo_propio: i32, elo_rival: i32, puntos: f64) -> i32 {
+    let esperado = 1.0 / (1.0 + 10f64.powf((elo_rival - elo_propio) as f64 / 400.0));
+    (K_ELO * (puntos - esperado)).round() as i32
+}
+
+/// Si la partida cuenta para el Elo: clasificatoria y sin bots.
+pub fn puntua(clasificatoria: bool, nombre_j1: &str, nombre_j2: &str) -> bool {
+    let es_bot = |nombre: &str| crate::bot::Dificultad::from_nombre(nombre).is_some();
+    clasificatoria && !es_bot(nombre_j1) && !es_bot(nombre_j2)
+}
+
+/// Marca la partida como terminada y, si `puntua`, actualiza el Elo de
+/// los dos jugadores.  Devuelve el ganador (`None` si hubo empate).
+pub async fn cerrar_partida(
+    tx: &mut Transaction<'_, MySql>,
+    id_partida: i32,
+    (id_j1, id_j2): (i32, i32),
+    (gol_j1, gol_j2): (i32, i32),
+) -> Result<Option<i32>, sqlx::Error> {
+    let clasificatoria = sqlx::query_scalar!(
+        r#"SELECT clasificatoria AS "clasificatoria: bool" FROM Partida WHERE id_partida = ?"#,
+        id_partida
+    )
+        .fetch_one(&mut **tx)
+        .await?;
+    let j1 = sqlx::query!("SELECT elo, nombre_usuario FROM Usuario WHERE id_usuario = ? FOR UPDATE", id_j1)
+        .fetch_one(&mut **tx)
+        .await?;
+    let j2 = sqlx::query!("SELECT elo, nombre_usuario FROM Usuario WHERE id_usuario = ? FOR UPDATE", id_j2)
+        .fetch_one(&mut **tx)
+        .await?;
+
+    let (puntos_j1, ganador) = match gol_j1.cmp(&gol_j2) {
+        std::cmp::Ordering::Greater => (1.0, Some(id_j1)),
+        std::cmp::Ordering::Less => (0.0, Some(id_j2)),
+        std::cmp::Ordering::Equal => (0.5, None),
+    };
+
+    let cuenta = puntua(clasificatoria, &j1.nombre_usuario, &j2.nombre_usuario);
+    let (cambio_j1, cambio_j2) = if cuenta {
+        let cambios = (
+            cambio_elo(j1.elo, j2.elo, puntos_j1),
+            cambio_elo(j2.elo, j1.elo, 1.0 - puntos_j1),
+        );
+        for (id, cambio) in [(id_j1, cambios.0), (id_j2, cambios.1)] {
+            sqlx::query!("UPDATE Usuario SET elo = elo + ? WHERE id_usuario = ?", cambio, id)
+                .execute(&mut **tx)
+                .await?;
+        }
+        cambios
+    } else {
+        tracing::info!("🤝 Partida {id_partida} sin Elo (amistosa o contra un bot)");
+        (0, 0)
+    };
+
+    sqlx::query!(
+        r#"
+        UPDATE Partida
+        SET estado = 'finished', turno_actual = NULL, fecha_limite = NULL, ganador = ?,
+            elo_cambio_j1 = ?, elo_cambio_j2 = ?, fecha_fin = NOW()
+        WHERE id_partida = ?
+        "#,
+        ganador,
+        cambio_j1,
+        cambio_j2,
+        id_partida
+    )
+        .execute(&mut **tx)
+        .await?;
+
+    // La temporada solo suma las partidas que cuentan para el Elo
+    if cuenta {
+        crate::temporadas::registrar_partida(
+            tx,
+            id_partida,
+            (id_j1, id_j2),
+            (gol_j1, gol_j2),
+            (j1.elo, j2.elo),
+            (cambio_j1, cambio_j2),
+        )
+            .await?;
+    }
+
+    tracing::info!(
+        "🏁 Partida {id_partida} terminada {gol_j1}-{gol_j2}: Elo {id_j1} {cambio_j1:+}, {id_j2} {cambio_j2:+}"
+    );
+    Ok(ganador)
+}
+
+/* ───────── GET /partida/:p/resultado ───────── */
+
+#[derive(Debug, Serialize)]
+pub struct JugadorResultado {
+    pub id_usuario: i32,
+    pub nombre_usuario: String,
+    pub goles: i32,
+    /// Rating actual (ya incluye el cambio si la partida terminó).
+    pub elo: i32,
+    /// `None` mientras la partida no terminó.
+    pub elo_cambio: Option<i32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ResultadoPartida {
+    pub id_partida: i32,
+    pub estado: String,
+    pub ganador: Option<i32>,
+    /// Jugador 1 y jugador 2, en el orden de la partida.
+    pub jugadores: [JugadorResultado; 2],
+}
+
+pub async fn get_resultado(
+    idioma: Idioma,
+    Path(id_partida): Path<i32>,
+    Extension(pool): Extension<MySqlPool>,
+) -> Result<Json<ResultadoPartida>, (StatusCode, String)> {
+    let fila = sqlx::query!(
+        r#"
+        SELECT p.estado AS "estado!: String", p.ganador, p.gol_j1, p.gol_j2,
+               p.elo_cambio_j1, p.elo_cambio_j2,
+               u1.id_usuario AS id_1, u1.nombre_usuario AS nombre_1, u1.elo AS elo_1,
+               u2.id_usuario AS id_2, u2.nombre_usuario AS nombre_2, u2.elo AS elo_2
+        FROM   Partida p
+        JOIN   Usuario u1 ON u1.id_usuario = p.id_jugador1
+        JOIN   Usuario u2 ON u2.id_usuario = p.id_jugador2
+        WHERE  p.id_partida = ?
+        "#,
+        id_partida
+    )
+        .fetch_optional(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("❌ Error SQL en resultado de la partida {id_partida}: {e:?}");
+            (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+        })?
+        .ok_or_else(|| (StatusCode::NOT_FOUND, tr!(idioma, "partida-no-encontrada", partida = id_partida)))?;
+
+    Ok(Json(ResultadoPartida {
+        id_partida,
+        estado: fila.estado,
+        ganador: fila.ganador,
+        jugadores: [
+            JugadorResultado {
+                id_usuario: fila.id_1,
+                nombre_usuario: fila.nombre_1,
+                goles: fila.gol_j1.unwrap_or(0),
+                elo: fila.elo_1,
+                elo_cambio: fila.elo_cambio_j1,
+            },
+            JugadorResultado {
+                id_usuario: fila.id_2,
+                nombre_usuario: fila.nombre_2,
+                goles: fila.gol_j2.unwrap_or(0),
+                elo: fila.elo_2,
+                elo_cambio: fila.elo_cambio_j2,
+            },
+        ],
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entre_iguales_se_reparten_16_puntos() {
+        assert_eq!(cambio_elo(1200, 1200, 1.0), 16);
+        assert_eq!(cambio_elo(1200, 1200, 0.0), -16);
+        assert_eq!(cambio_elo(1200, 1200, 0.5), 0);
+    }
+
+    #[test]
+    fn ganarle_al_favorito_vale_mas() {
+        let sorpresa = cambio_elo(1000, 1400, 1.0);
+        let esperado = cambio_elo(1400, 1000, 1.0);
+        assert_eq!((sorpresa, esperado), (29, 3));
+        assert!(cambio_elo(1000, 1400, 0.5) > 0);
+        assert!(cambio_elo(1400, 1000, 0.5) < 0);
+    }
+
+    #[test]
+    fn suma_cero_y_tope_k() {
+        for (a, b) in [(1200, 1200), (1000, 1400), (1500, 1480), (800, 2400)] {
+            for puntos in [0.0, 0.5, 1.0] {
+                let propio = cambio_elo(a, b, puntos);
+                let rival = cambio_elo(b, a, 1.0 - puntos);
+                // Con el redondeo puede sobrar o faltar un punto
+                assert!((propio + rival).abs() <= 1, "{a} vs {b}, {puntos}: {propio} {rival}");
+                assert!(propio.abs() <= K_ELO as i32);
+            }
+        }
+    }
+
+    #[test]
+    fn solo_puntuan_clasificatorias_sin_bots() {
+        assert!(puntua(true, "ana", "beto"));
+        assert!(!puntua(false, "ana", "beto"));
+        assert!(!puntua(true, "ana", "bot_medio"));
+        assert!(!puntua(true, "bot_dificil", "beto"));
+    }
+}
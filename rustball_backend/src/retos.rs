@@ -0,0 +1,391 @@
+// -----------------------------------------------------------------
+//! Retos directos: invitaciones a jugar que el retado acepta o rechaza.
+//!
+//!   • `POST /retos` crea el reto (o devuelve el que ya estaba pendiente
+//!     entre los dos) y se lo empuja al retado por el WebSocket del lobby.
+//!   • Al aceptarlo se crea la partida con `post_partida` (la misma que
+//!     usa el lobby al escribir un ID) y se avisa al retador.  Si el reto
+//!     trae `plazo_turno_horas`, la partida es por correspondencia.
+//!   • Rechazar: el retado lo marca `rechazado`; si lo retira el
+//!     retador queda `cancelado`.
+//!   • Caducan a las `RETO_TTL_HORAS`: `lanzar_expiracion` los marca
+//!     `expirado` cada minuto y avisa a los dos.
+//!   • Los bots no pueden responder, así que sus retos se aceptan solos.
+// -----------------------------------------------------------------
+
+use axum::{
+    extract::{Extension, Path},
+    http::StatusCode,
+    Json,
+};
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use serde_json::json;
+use sqlx::MySqlPool;
+
+use crate::bot::Dificultad;
+use crate::i18n::{tr, Idioma};
+use crate::models::{PartidaPayload, RespuestaRetoPayload, RetoPayload};
+use crate::routes::lobby::notificar;
+
+/// Horas que un reto puede quedar sin respuesta.
+const RETO_TTL_HORAS: i64 = 24;
+/// Cada cuánto se buscan retos vencidos.
+const INTERVALO_EXPIRACION_S: u64 = 60;
+
+#[derive(Debug, Serialize)]
+pub struct Reto {
+    pub id_reto: i32,
+    pub id_retador: i32,
+    pub nombre_retador: String,
+    pub id_retado: i32,
+    pub nombre_retado: String,
+    /// pendiente | aceptado | rechazado | expirado | cancelado
+    pub estado: String,
+    /// Partida creada al aceptar.
+    pub id_partida: Option<i32>,
+    /// Horas por turno si la partida será por correspondencia.
+    pub plazo_turno_horas: Option<i32>,
+    pub fecha_creacion: NaiveDateTime,
+    pub fecha_expira: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RetosPendientes {
+    pub recibidos: Vec<Reto>,
+    pub enviados: Vec<Reto>,
+}
+
+async fn cargar_reto(pool: &MySqlPool, id_reto: i32) -> Result<Option<Reto>, sqlx::Error> {
+    sqlx::query_as!(
+        Reto,
+        r#"
+        SELECT r.id_reto, r.id_retador, a.nombre_usuario AS nombre_retador,
+               r.id_retado, b.nombre_usuario AS nombre_retado,
+               r.estado AS "estado!: String", r.id_partida, r.plazo_turno_horas,
+               r.fecha_creacion AS "fecha_creacion: NaiveDateTime",
+               r.fecha_expira AS "fecha_expira: NaiveDateTime"
+        FROM   Reto r
+        JOIN   Usuario a ON a.id_usuario = r.id_retador
+        JOIN   Usuario b ON b.id_usuario = r.id_retado
+        WHERE  r.id_reto = ?
+        "#,
+        id_reto
+    )
+        .fetch_optional(pool)
+        .await
+}
+
+/* ───────── Caducidad ───────── */
+
+/// Marca `expirado` los retos pendientes vencidos y avisa a los dos.
+async fn expirar_vencidos(pool: &MySqlPool) -> Result<(), sqlx::Error> {
+    let vencidos = sqlx::query!(
+        "SELECT id_reto, id_retador, id_retado FROM Reto WHERE estado = 'pendiente' AND fecha_expira <= NOW()"
+    )
+        .fetch_all(pool)
+        .await?;
+
+    for r in vencidos {
+        let res = sqlx::query!("UPDATE Reto SET estado = 'expirado' WHERE id_reto = ? AND estado = 'pendiente'", r.id_reto)
+            .execute(pool)
+            .await?;
+        // Si alguien lo respondió justo ahora, no expira
+        if res.rows_affected() == 0 {
+            continue;
+        }
+
+        tracing::info!("⌛ Reto {} expirado", r.id_reto);
+        let aviso = json!({ "tipo": "reto_expirado", "id_reto": r.id_reto });
+        notificar(r.id_retador, aviso.clone());
+        notificar(r.id_retado, aviso);
+    }
+    Ok(())
+}
+
+/// Tarea de fondo que caduca los retos vencidos.
+pub fn lanzar_expiracion(pool: MySqlPool) {
+    tokio::spawn(async move {
+        let mut intervalo = tokio::time::interval(std::time::Duration::from_secs(INTERVALO_EXPIRACION_S));
+        loop {
+            intervalo.tick().await;
+            if let Err(e) = expirar_vencidos(&pool).await {
+                tracing::error!("❌ Error expirando retos: {e}");
+            }
+        }
+    });
+}
+
+/* ───────── Handlers ───────── */
+
+// POST /retos
+pub async fn post_reto(
+    idioma: Idioma,
+    Extension(pool): Extension<MySqlPool>,
+    Json(payload): Json<RetoPayload>,
+) -> Result<Json<Reto>, (StatusCode, String)> {
+    let RetoPayload { id_retador, id_retado, plazo_turno_horas } = payload;
+    let error_sql = |e: sqlx::Error| {
+        tracing::error!("❌ SQL en post_reto: {e}");
+        (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+    };
+
+    if id_retador == id_retado {
+        return Err((StatusCode::BAD_REQUEST, tr!(idioma, "reto-consigo-mismo")));
+    }
+    crate::correspondencia::validar_plazo(idioma, plazo_turno_horas)?;
+    for uid in [id_retador, id_retado] {
+        let existe: i64 = sqlx::query_scalar!("SELECT COUNT(*) FROM Usuario WHERE id_usuario = ?", uid)
+            .fetch_one(&pool)
+            .await
+            .map_err(error_sql)?;
+        if existe == 0 {
+            return Err((StatusCode::NOT_FOUND, tr!(idioma, "usuario-no-encontrado", usuario = uid)));
+        }
+    }
+
+    // Un solo reto pendiente por pareja y sentido
+    let existente = sqlx::query_scalar!(
+        r#"
+        SELECT id_reto FROM Reto
+        WHERE  id_retador = ? AND id_retado = ? AND estado = 'pendiente' AND fecha_expira > NOW()
+        ORDER  BY id_reto DESC
+        LIMIT  1
+        "#,
+        id_retador,
+        id_retado
+    )
+        .fetch_optional(&pool)
+        .await
+        .map_err(error_sql)?;
+
+    let id_reto = match existente {
+        Some(id) => id,
+        None => {
+            let res = sqlx::query!(
+                r#"
+                INSERT INTO Reto (id_retador, id_retado, plazo_turno_horas, fecha_expira)
+                VALUES (?, ?, ?, NOW() + INTERVAL ? HOUR)
+                "#,
+                id_retador,
+                id_retado,
+                plazo_turno_horas,
+                RETO_TTL_HORAS
+            )
+                .execute(&pool)
+                .await
+                .map_err(error_sql)?;
+            res.last_insert_id() as i32
+        }
+    };
+
+    let reto = cargar_reto(&pool, id_reto)
+        .await
+        .map_err(error_sql)?
+        .ok_or_else(|| (StatusCode::NOT_FOUND, tr!(idioma, "reto-no-encontrado", reto = id_reto)))?;
+
+    // 🤖 Los bots aceptan cualquier reto
+    if Dificultad::from_nombre(&reto.nombre_retado).is_some() {
+        return aceptar(idioma, &pool, reto).await.map(Json);
+    }
+
+    if existente.is_none() {
+        tracing::info!("⚔️ Reto {id_reto}: {id_retador} → {id_retado}");
+        notificar(id_retado, json!({ "tipo": "reto", "reto": &reto }));
+        crate::notificaciones::reto(&pool, &reto).await;
+    }
+    Ok(Json(reto))
+}
+
+// GET /retos/:u — retos pendientes recibidos y enviados
+pub async fn get_retos(
+    idioma: Idioma,
+    Path(id_usuario): Path<i32>,
+    Extension(pool): Extension<MySqlPool>,
+) -> Result<Json<RetosPendientes>, (StatusCode, String)> {
+    let error_sql = |e: sqlx::Error| {
+        tracing::error!("❌ SQL en get_retos: {e}");
+        (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+    };
+
+    expirar_vencidos(&pool).await.map_err(error_sql)?;
+
+    let retos = sqlx::query_as!(
+        Reto,
+        r#"
+        SELECT r.id_reto, r.id_retador, a.nombre_usuario AS nombre_retador,
+               r.id_retado, b.nombre_usuario AS nombre_retado,
+               r.estado AS "estado!: String", r.id_partida, r.plazo_turno_horas,
+               r.fecha_creacion AS "fecha_creacion: NaiveDateTime",
+               r.fecha_expira AS "fecha_expira: NaiveDateTime"
+        FROM   Reto r
+        JOIN   Usuario a ON a.id_usuario = r.id_retador
+        JOIN   Usuario b ON b.id_usuario = r.id_retado
+        WHERE  r.estado = 'pendiente'
+          AND  (r.id_retador = ? OR r.id_retado = ?)
+        ORDER  BY r.fecha_creacion DESC
+        "#,
+        id_usuario,
+        id_usuario
+    )
+        .fetch_all(&pool)
+        .await
+        .map_err(error_sql)?;
+
+    let (enviados, recibidos) = retos.into_iter().partition(|r| r.id_retador == id_usuario);
+    Ok(Json(RetosPendientes { recibidos, enviados }))
+}
+
+// POST /retos/:id/aceptar
+pub async fn post_aceptar_reto(
+    idioma: Idioma,
+    Path(id_reto): Path<i32>,
+    Extension(pool): Extension<MySqlPool>,
+    Json(payload): Json<RespuestaRetoPayload>,
+) -> Result<Json<Reto>, (StatusCode, String)> {
+    let reto = reto_pendiente(idioma, &pool, id_reto).await?;
+    if reto.id_retado != payload.id_usuario {
+        return Err((StatusCode::FORBIDDEN, tr!(idioma, "reto-ajeno", reto = id_reto, usuario = payload.id_usuario)));
+    }
+    aceptar(idioma, &pool, reto).await.map(Json)
+}
+
+// POST /retos/:id/rechazar — el retado lo rechaza o el retador lo retira
+pub async fn post_rechazar_reto(
+    idioma: Idioma,
+    Path(id_reto): Path<i32>,
+    Extension(pool): Extension<MySqlPool>,
+    Json(payload): Json<RespuestaRetoPayload>,
+) -> Result<Json<Reto>, (StatusCode, String)> {
+    let error_sql = |e: sqlx::Error| {
+        tracing::error!("❌ SQL en post_rechazar_reto: {e}");
+        (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+    };
+
+    let mut reto = reto_pendiente(idioma, &pool, id_reto).await?;
+    let Some((estado, avisar)) = respuesta_de(&reto, payload.id_usuario) else {
+        return Err((StatusCode::FORBIDDEN, tr!(idioma, "reto-ajeno", reto = id_reto, usuario = payload.id_usuario)));
+    };
+
+    let res = sqlx::query!("UPDATE Reto SET estado = ? WHERE id_reto = ? AND estado = 'pendiente'", estado, id_reto)
+        .execute(&pool)
+        .await
+        .map_err(error_sql)?;
+    if res.rows_affected() == 0 {
+        return Err((StatusCode::CONFLICT, tr!(idioma, "reto-no-pendiente", reto = id_reto)));
+    }
+
+    reto.estado = estado.into();
+    tracing::info!("🚫 Reto {id_reto} {estado}");
+    notificar(avisar, json!({ "tipo": format!("reto_{estado}"), "reto": &reto }));
+    Ok(Json(reto))
+}
+
+/* ───────── Auxiliares ───────── */
+
+/// Estado final y usuario a avisar cuando `id_usuario` rechaza (retado) o
+/// retira (retador) el reto; `None` si el reto no es suyo.
+fn respuesta_de(reto: &Reto, id_usuario: i32) -> Option<(&'static str, i32)> {
+    if id_usuario == reto.id_retado {
+        Some(("rechazado", reto.id_retador))
+    } else if id_usuario == reto.id_retador {
+        Some(("cancelado", reto.id_retado))
+    } else {
+        None
+    }
+}
+
+/// El reto, si existe y sigue pendiente (los vencidos se marcan aquí mismo).
+async fn reto_pendiente(idioma: Idioma, pool: &MySqlPool, id_reto: i32) -> Result<Reto, (StatusCode, String)> {
+    let error_sql = |e: sqlx::Error| {
+        tracing::error!("❌ SQL en reto_pendiente: {e}");
+        (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+    };
+
+    expirar_vencidos(pool).await.map_err(error_sql)?;
+    let reto = cargar_reto(pool, id_reto)
+        .await
+        .map_err(error_sql)?
+        .ok_or_else(|| (StatusCode::NOT_FOUND, tr!(idioma, "reto-no-encontrado", reto = id_reto)))?;
+
+    if reto.estado != "pendiente" {
+        return Err((StatusCode::CONFLICT, tr!(idioma, "reto-no-pendiente", reto = id_reto)));
+    }
+    Ok(reto)
+}
+
+/// Marca el reto aceptado, crea la partida y avisa al retador.
+async fn aceptar(idioma: Idioma, pool: &MySqlPool, mut reto: Reto) -> Result<Reto, (StatusCode, String)> {
+    let error_sql = |e: sqlx::Error| {
+        tracing::error!("❌ SQL en aceptar: {e}");
+        (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+    };
+
+    // Primero se reclama el reto: si dos pestañas aceptan a la vez, sólo una crea la partida
+    let res = sqlx::query!("UPDATE Reto SET estado = 'aceptado' WHERE id_reto = ? AND estado = 'pendiente'", reto.id_reto)
+        .execute(pool)
+        .await
+        .map_err(error_sql)?;
+    if res.rows_affected() == 0 {
+        return Err((StatusCode::CONFLICT, tr!(idioma, "reto-no-pendiente", reto = reto.id_reto)));
+    }
+
+    let Json(partida) = crate::handlers::post_partida(
+        idioma,
+        Extension(pool.clone()),
+        Json(PartidaPayload {
+            id_usuario_1: reto.id_retador,
+            id_usuario_2: reto.id_retado,
+            clasificatoria: false,
+            plazo_turno_horas: reto.plazo_turno_horas,
+        }),
+    )
+        .await?;
+
+    sqlx::query!("UPDATE Reto SET id_partida = ? WHERE id_reto = ?", partida.id_partida, reto.id_reto)
+        .execute(pool)
+        .await
+        .map_err(error_sql)?;
+
+    reto.estado = "aceptado".into();
+    reto.id_partida = Some(partida.id_partida);
+    tracing::info!("✅ Reto {} aceptado: partida {}", reto.id_reto, partida.id_partida);
+    notificar(reto.id_retador, json!({ "tipo": "reto_aceptado", "reto": &reto }));
+    Ok(reto)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reto(id_retador: i32, id_retado: i32) -> Reto {
+        let ahora = chrono::Utc::now().naive_utc();
+        Reto {
+            id_reto: 1,
+            id_retador,
+            nombre_retador: "ana".into(),
+            id_retado,
+            nombre_retado: "bea".into(),
+            estado: "pendiente".into(),
+            id_partida: None,
+            plazo_turno_horas: None,
+            fecha_creacion: ahora,
+            fecha_expira: ahora,
+        }
+    }
+
+    #[test]
+    fn el_retado_rechaza_y_se_avisa_al_retador() {
+        assert_eq!(respuesta_de(&reto(3, 4), 4), Some(("rechazado", 3)));
+    }
+
+    #[test]
+    fn el_retador_cancela_y_se_avisa_al_retado() {
+        assert_eq!(respuesta_de(&reto(3, 4), 3), Some(("cancelado", 4)));
+    }
+
+    #[test]
+    fn un_tercero_no_puede_responder() {
+        assert_eq!(respuesta_de(&reto(3, 4), 5), None);
+    }
+}
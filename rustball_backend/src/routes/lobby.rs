@@ -0,0 +1,149 @@
+//! routes/lobby.rs
+//! WebSocket del lobby: un canal por usuario (`/lobby/ws/:uid`) para
+//! avisarle al momento de retos y solicitudes de amistad.
+//!
+//! Los avisos son JSON `{ "tipo": "...", ... }` (ver `notificar`); si el
+//! usuario no tiene el lobby abierto se descartan y los verá al cargar
+//! `GET /retos/:u` y `GET /amigos/:u`.
+//!
+//! La sesión también alimenta `presencia`: el lobby manda
+//! `{"tipo":"actividad"}` o `{"tipo":"segundo_plano"}` al cambiar de
+//! pestaña.
+
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Extension, Path,
+    },
+    http::StatusCode,
+    response::IntoResponse,
+};
+use futures_util::{SinkExt, StreamExt};
+use once_cell::sync::OnceCell;
+use serde_json::Value;
+use sqlx::MySqlPool;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use tokio::{
+    sync::broadcast::{self, error::RecvError, Sender},
+    time,
+};
+use tracing::{error, info, warn};
+
+use crate::presencia::{self, Lugar};
+
+static USUARIO_CHANNELS: OnceCell<Mutex<HashMap<i32, Sender<String>>>> = OnceCell::new();
+
+fn get_or_create_channel(uid: i32) -> Sender<String> {
+    let map = USUARIO_CHANNELS.get_or_init(|| Mutex::new(HashMap::new()));
+    let mut guard = map.lock().unwrap();
+    guard
+        .entry(uid)
+        .or_insert_with(|| {
+            // Varias pestañas del mismo usuario comparten canal
+            let (tx, _rx) = broadcast::channel::<String>(32);
+            tx
+        })
+        .clone()
+}
+
+// 🧹 Elimina el canal si ya no queda ninguna pestaña conectada
+fn remove_channel_if_empty(uid: i32) {
+    if let Some(map) = USUARIO_CHANNELS.get() {
+        let mut guard = map.lock().unwrap();
+        if guard.get(&uid).is_some_and(|tx| tx.receiver_count() == 0) {
+            guard.remove(&uid);
+        }
+    }
+}
+
+/// Empuja un aviso al lobby de `uid` (si lo tiene abierto).
+pub fn notificar(uid: i32, aviso: Value) {
+    let Some(map) = USUARIO_CHANNELS.get() else { return };
+    let tx = map.lock().unwrap().get(&uid).cloned();
+    match tx {
+        Some(tx) => {
+            let _ = tx.send(aviso.to_string());
+        }
+        None => info!("📭 Lobby de uid={} cerrado; aviso descartado", uid),
+    }
+}
+
+/// Handler de la ruta `/lobby/ws/:uid`
+pub async fn lobby_ws_handler(
+    ws: WebSocketUpgrade,
+    Path(uid): Path<i32>,
+    Extension(pool): Extension<MySqlPool>,
+) -> impl IntoResponse {
+    let existe: i64 = sqlx::query_scalar!("SELECT COUNT(*) FROM Usuario WHERE id_usuario = ?", uid)
+        .fetch_one(&pool)
+        .await
+        .unwrap_or(0);
+
+    if existe == 0 {
+        info!("🚫 LOBBY-WS rechazado: uid={} no existe", uid);
+        return StatusCode::NOT_FOUND.into_response();
+    }
+
+    info!("🌐 LOBBY-WS-OPEN uid={}", uid);
+    let tx = get_or_create_channel(uid);
+    ws.on_upgrade(move |socket| lobby_session(socket, uid, tx, pool))
+}
+
+async fn lobby_session(socket: WebSocket, uid: i32, tx: Sender<String>, pool: MySqlPool) {
+    presencia::conectar(&pool, uid, Lugar::Lobby);
+    let (mut outbound, mut inbound) = socket.split();
+    let mut rx = tx.subscribe();
+    let mut ping_interval = time::interval(time::Duration::from_secs(30));
+
+    let forward = tokio::spawn(async move {
+        loop {
+            tokio::select! {
+                _ = ping_interval.tick() => {
+                    let _ = outbound.send(Message::Ping(b"ping".to_vec())).await;
+                }
+                msg = rx.recv() => match msg {
+                    Ok(text) => {
+                        if outbound.send(Message::Text(text)).await.is_err() {
+                            error!("❌ Error enviando al lobby uid={}", uid);
+                            break;
+                        }
+                    }
+                    // Se perdieron avisos: que el lobby recargue las listas
+                    Err(RecvError::Lagged(n)) => {
+                        warn!("⚠️  Lobby lag ({} avisos perdidos) uid={}", n, uid);
+                        let _ = outbound.send(Message::Text(r#"{"tipo":"recargar"}"#.into())).await;
+                    }
+                    Err(RecvError::Closed) => break,
+                }
+            }
+        }
+    });
+
+    // El lobby sólo avisa si está a la vista o no
+    while let Some(result) = inbound.next().await {
+        match result {
+            Ok(Message::Text(txt)) => {
+                let aviso: Value = serde_json::from_str(&txt).unwrap_or(Value::Null);
+                match aviso["tipo"].as_str() {
+                    Some("actividad") => presencia::actividad(&pool, uid),
+                    Some("segundo_plano") => presencia::segundo_plano(&pool, uid),
+                    _ => warn!("❓ Mensaje de lobby no reconocido uid={}: {}", uid, txt),
+                }
+            }
+            Ok(Message::Close(_)) => break,
+            Ok(_) => {}
+            Err(e) => {
+                error!("❌ Error en el lobby uid={}: {}", uid, e);
+                break;
+            }
+        }
+    }
+
+    // Esperar a que la tarea suelte su receptor antes de contar
+    forward.abort();
+    let _ = forward.await;
+    info!("🔌 LOBBY-WS-CLOSE uid={}", uid);
+    remove_channel_if_empty(uid);
+    presencia::desconectar(&pool, uid, Lugar::Lobby);
+}
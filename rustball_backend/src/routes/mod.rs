@@ -1 +1,2 @@
 pub mod websocket;
+pub mod lobby;
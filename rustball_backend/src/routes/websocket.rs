@@ -1,5 +1,7 @@
 //! routes/websocket.rs
 //! Mejorado: Canales por partida, validación, pings, snapshot etiquetado, filtro por uid y snapshot en memoria
+//! Presencia: cada sesión se anota en `presencia` y avisa al rival con
+//! `{"tipo":"presencia","contenido":"rival_conectado" | "rival_desconectado"}`.
 
 use axum::{
     extract::{
@@ -22,6 +24,7 @@ use tokio::{
 use tracing::{debug, error, info, warn};
 
 use crate::handlers::get_snapshot;
+use crate::presencia::{self, Lugar};
 use axum::extract::Path as AxumPath;
 use http_body_util::BodyExt;
 
@@ -53,6 +56,16 @@ fn remove_channel_if_empty(partida: i32) {
     }
 }
 
+/// Manda `msg` a quienes tengan abierta la partida (si no hay nadie, se pierde).
+pub fn enviar_a_partida(partida: i32, msg: String) {
+    let tx = PARTIDA_CHANNELS
+        .get()
+        .and_then(|map| map.lock().unwrap().get(&partida).cloned());
+    if let Some(tx) = tx {
+        let _ = tx.send(msg);
+    }
+}
+
 // 🧠 Guarda el último snapshot en memoria
 pub fn save_last_snapshot(partida: i32, snapshot_json: String) {
     let map = LAST_SNAPSHOTS.get_or_init(|| Mutex::new(HashMap::new()));
@@ -65,6 +78,19 @@ fn get_last_snapshot(partida: i32) -> Option<String> {
     map.lock().unwrap().get(&partida).cloned()
 }
 
+// 👀 Aviso de presencia con `uid_origen` = quien entró o salió
+fn aviso_presencia(uid: i32, contenido: &str) -> String {
+    json!({ "uid_origen": uid, "tipo": "presencia", "contenido": contenido }).to_string()
+}
+
+async fn rival_de(pool: &MySqlPool, partida: i32, uid: i32) -> Option<i32> {
+    let fila = sqlx::query!("SELECT id_jugador1, id_jugador2 FROM Partida WHERE id_partida = ?", partida)
+        .fetch_optional(pool)
+        .await
+        .ok()??;
+    Some(if fila.id_jugador1 == uid { fila.id_jugador2 } else { fila.id_jugador1 })
+}
+
 /// Handler de la ruta `/ws/:partida/:uid`
 pub async fn websocket_handler(
     ws: WebSocketUpgrade,
@@ -102,6 +128,15 @@ async fn client_session(
     let mut rx: Receiver<String> = tx.subscribe();
     let mut ping_interval = time::interval(time::Duration::from_secs(30));
 
+    // 👀 Entré: se lo digo al rival y, si él ya estaba, me lo digo a mí
+    presencia::conectar(&pool, uid, Lugar::Partida(partida));
+    let _ = tx.send(aviso_presencia(uid, "rival_conectado"));
+    if let Some(rival) = rival_de(&pool, partida, uid).await {
+        if presencia::en_partida(rival, partida) {
+            let _ = tx.send(aviso_presencia(rival, "rival_conectado"));
+        }
+    }
+
     let forward = tokio::spawn({
         async move {
             loop {
@@ -151,6 +186,7 @@ async fn client_session(
         match result {
             Ok(Message::Text(txt)) => {
                 debug!("📨 part={} uid={} → {}", partida, uid, txt);
+                presencia::actividad(&pool, uid);
                 let contenido_json: Value = serde_json::from_str(&txt).unwrap_or(json!(null));
 
                 // ✅ Envolver mensaje con tipo "snapshot" para uniformidad con el frontend
@@ -178,13 +214,19 @@ async fn client_session(
 
     forward.abort();
     info!("🔌 WS-CLOSE partida={} uid={}", partida, uid);
+
+    // 👀 Si no le queda otra pestaña en esta partida, el rival lo ve desconectado
+    presencia::desconectar(&pool, uid, Lugar::Partida(partida));
+    if !presencia::en_partida(uid, partida) {
+        let _ = tx.send(aviso_presencia(uid, "rival_desconectado"));
+    }
     remove_channel_if_empty(partida); // ✅ limpieza al desconectarse
 }
 
 // ❌ Ya no se usa: ahora usamos snapshot en memoria
 #[allow(dead_code)]
 async fn get_snapshot_json(partida: i32, pool: MySqlPool) -> Result<String, ()> {
-    let response: Response = get_snapshot(AxumPath(partida), Extension(pool))
+    let response: Response = get_snapshot(crate::i18n::Idioma::default(), AxumPath(partida), Extension(pool))
         .await
         .into_response();
     let body = response.into_body().collect().await.map_err(|_| ())?.to_bytes();
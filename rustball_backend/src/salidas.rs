@@ -0,0 +1,399 @@
+// -----------------------------------------------------------------
+//! Salidas de los avisos que salen del servidor (correo y webhook).
+//!
+//!   • Cada canal tiene su salida, elegida por variable de entorno al
+//!     arrancar (por defecto `log`):
+//!       SALIDA_CORREO    smtp://host:puerto | archivo:ruta | log
+//!       SALIDA_WEBHOOK   http | archivo:ruta | log
+//!       CORREO_REMITENTE remitente de los correos
+//!   • `smtp://` habla SMTP sin cifrar y sin autenticación, pensado para
+//!     un relay local (postfix, MailHog…) que se encarga del resto.
+//!   • `http` hace un POST JSON a la URL del usuario; sólo `http://`
+//!     (para `https://`, un relay local).  Nunca a direcciones internas
+//!     (loopback, privadas, enlace local): ver `direcciones_webhook`.
+//!   • `archivo:` escribe una línea JSON por aviso (desarrollo y pruebas).
+// -----------------------------------------------------------------
+
+use std::net::{IpAddr, SocketAddr};
+use std::path::PathBuf;
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+use serde_json::{json, Value};
+use tokio::{
+    io::{AsyncBufRead, AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::TcpStream,
+};
+
+/// Tiempo máximo de una entrega (conexión y diálogo incluidos).
+const TIMEOUT_ENTREGA: Duration = Duration::from_secs(30);
+const REMITENTE_POR_DEFECTO: &str = "RustBall <no-reply@rustball.lat>";
+/// Nombre con el que el servidor se presenta (EHLO, User-Agent).
+const NOMBRE_SERVIDOR: &str = "rustball.lat";
+
+#[derive(Debug, Clone)]
+pub enum Salida {
+    /// Correo por SMTP a `servidor` (host:puerto).
+    Smtp { servidor: String, remitente: String },
+    /// POST JSON al destino del aviso.
+    Http,
+    /// Una línea JSON por aviso.
+    Archivo(PathBuf),
+    /// Sólo al log.
+    Log,
+}
+
+/// Aviso listo para entregar: `destino` es un correo o una URL según el canal.
+#[derive(Debug)]
+pub struct Mensaje<'a> {
+    pub destino: &'a str,
+    pub asunto: &'a str,
+    pub cuerpo: &'a str,
+    /// Lo mismo en estructurado, para webhooks y para el archivo.
+    pub datos: &'a Value,
+}
+
+static CORREO: Lazy<Salida> = Lazy::new(|| leer_config("SALIDA_CORREO"));
+static WEBHOOK: Lazy<Salida> = Lazy::new(|| leer_config("SALIDA_WEBHOOK"));
+
+pub fn correo() -> &'static Salida {
+    &CORREO
+}
+
+pub fn webhook() -> &'static Salida {
+    &WEBHOOK
+}
+
+fn leer_config(variable: &str) -> Salida {
+    let valor = std::env::var(variable).unwrap_or_else(|_| "log".into());
+    let remitente = std::env::var("CORREO_REMITENTE").unwrap_or_else(|_| REMITENTE_POR_DEFECTO.into());
+    let salida = Salida::desde_config(&valor, remitente).unwrap_or_else(|| {
+        tracing::warn!("⚠️ {variable}={valor} no es válida; los avisos sólo irán al log");
+        Salida::Log
+    });
+    tracing::info!("📨 {variable}: {salida:?}");
+    salida
+}
+
+impl Salida {
+    /// `smtp://host:puerto`, `http`, `archivo:ruta` o `log`.
+    pub fn desde_config(valor: &str, remitente: String) -> Option<Self> {
+        let valor = valor.trim();
+        if let Some(servidor) = valor.strip_prefix("smtp://") {
+            let servidor = if servidor.contains(':') { servidor.to_owned() } else { format!("{servidor}:25") };
+            return Some(Salida::Smtp { servidor, remitente });
+        }
+        if let Some(ruta) = valor.strip_prefix("archivo:") {
+            return Some(Salida::Archivo(PathBuf::from(ruta)));
+        }
+        match valor {
+            "http" => Some(Salida::Http),
+            "log" => Some(Salida::Log),
+            _ => None,
+        }
+    }
+
+    /// Entrega el mensaje; el error es el texto que queda en el outbox.
+    pub async fn entregar(&self, canal: &str, m: &Mensaje<'_>) -> Result<(), String> {
+        match self {
+            Salida::Smtp { servidor, remitente } => {
+                tokio::time::timeout(TIMEOUT_ENTREGA, enviar_smtp(servidor, remitente, m))
+                    .await
+                    .map_err(|_| format!("SMTP {servidor}: sin respuesta"))?
+            }
+            Salida::Http => {
+                let cuerpo = json!({ "asunto": m.asunto, "cuerpo": m.cuerpo, "datos": m.datos }).to_string();
+                tokio::time::timeout(TIMEOUT_ENTREGA, enviar_http(m.destino, &cuerpo))
+                    .await
+                    .map_err(|_| format!("webhook {}: sin respuesta", m.destino))?
+            }
+            Salida::Archivo(ruta) => {
+                let linea = json!({
+                    "fecha": chrono::Local::now().to_rfc3339(),
+                    "canal": canal,
+                    "destino": m.destino,
+                    "asunto": m.asunto,
+                    "cuerpo": m.cuerpo,
+                    "datos": m.datos,
+                });
+                let mut archivo = tokio::fs::OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(ruta)
+                    .await
+                    .map_err(|e| format!("{}: {e}", ruta.display()))?;
+                archivo
+                    .write_all(format!("{linea}\n").as_bytes())
+                    .await
+                    .map_err(|e| format!("{}: {e}", ruta.display()))
+            }
+            Salida::Log => {
+                tracing::info!("📨 [{canal}] → {}: {}", m.destino, m.asunto);
+                // En desarrollo hace falta el cuerpo (enlaces de verificación, etc.)
+                tracing::debug!("📨 {}", m.cuerpo);
+                Ok(())
+            }
+        }
+    }
+}
+
+/* ───────── SMTP ───────── */
+
+async fn enviar_smtp(servidor: &str, remitente: &str, m: &Mensaje<'_>) -> Result<(), String> {
+    // Nada que pueda colar otra orden u otra cabecera
+    if m.destino.contains(['\r', '\n', '<', '>']) {
+        return Err(format!("SMTP: destino inválido {:?}", m.destino));
+    }
+    let error = |e: std::io::Error| format!("SMTP {servidor}: {e}");
+
+    let (lectura, mut escritura) = TcpStream::connect(servidor).await.map_err(error)?.into_split();
+    let mut lectura = BufReader::new(lectura);
+    respuesta_smtp(&mut lectura, 220).await?;
+
+    let ordenes = [
+        (format!("EHLO {NOMBRE_SERVIDOR}"), 250),
+        (format!("MAIL FROM:<{}>", direccion(remitente)), 250),
+        (format!("RCPT TO:<{}>", m.destino), 250),
+        ("DATA".to_owned(), 354),
+    ];
+    for (orden, esperado) in ordenes {
+        escritura.write_all(format!("{orden}\r\n").as_bytes()).await.map_err(error)?;
+        respuesta_smtp(&mut lectura, esperado).await?;
+    }
+
+    let correo = format!(
+        "From: {remitente}\r\nTo: <{}>\r\nSubject: {}\r\nDate: {}\r\nMIME-Version: 1.0\r\n\
+         Content-Type: text/plain; charset=UTF-8\r\nContent-Transfer-Encoding: 8bit\r\n\r\n{}\r\n.\r\n",
+        m.destino,
+        cabecera_utf8(m.asunto),
+        chrono::Utc::now().to_rfc2822(),
+        cuerpo_smtp(m.cuerpo),
+    );
+    escritura.write_all(correo.as_bytes()).await.map_err(error)?;
+    respuesta_smtp(&mut lectura, 250).await?;
+
+    let _ = escritura.write_all(b"QUIT\r\n").await;
+    Ok(())
+}
+
+/// Lee una respuesta (de una o varias líneas) y comprueba que sea de la
+/// clase de `esperado` (2xx, 3xx…).
+async fn respuesta_smtp<R: AsyncBufRead + Unpin>(lectura: &mut R, esperado: u16) -> Result<(), String> {
+    loop {
+        let mut linea = String::new();
+        let leidos = lectura.read_line(&mut linea).await.map_err(|e| format!("SMTP: {e}"))?;
+        if leidos == 0 {
+            return Err("SMTP: el servidor cerró la conexión".into());
+        }
+        // "250-…" sigue en la línea siguiente; "250 …" es la última
+        if linea.as_bytes().get(3) == Some(&b'-') {
+            continue;
+        }
+        let codigo: u16 = linea
+            .get(..3)
+            .and_then(|c| c.parse().ok())
+            .ok_or_else(|| format!("SMTP: respuesta inválida {:?}", linea.trim_end()))?;
+        return if codigo / 100 == esperado / 100 {
+            Ok(())
+        } else {
+            Err(format!("SMTP: {}", linea.trim_end()))
+        };
+    }
+}
+
+/// "Nombre <correo>" → "correo".
+fn direccion(remitente: &str) -> &str {
+    remitente
+        .split_once('<')
+        .and_then(|(_, resto)| resto.split_once('>'))
+        .map_or(remitente, |(correo, _)| correo)
+}
+
+/// Cabecera con acentos en una "encoded-word" Q (RFC 2047).
+fn cabecera_utf8(texto: &str) -> String {
+    if texto.bytes().all(|b| (b' '..=b'~').contains(&b)) {
+        return texto.to_owned();
+    }
+    let codificado: String = texto
+        .bytes()
+        .map(|b| match b {
+            b' ' => "_".to_owned(),
+            b'0'..=b'9' | b'a'..=b'z' | b'A'..=b'Z' | b'.' | b',' | b'-' | b'!' => (b as char).to_string(),
+            _ => format!("={b:02X}"),
+        })
+        .collect();
+    format!("=?UTF-8?Q?{codificado}?=")
+}
+
+/// Saltos de línea CRLF y un punto de más al principio de las líneas que
+/// ya empiezan con punto (si no, "." sola terminaría el mensaje).
+fn cuerpo_smtp(cuerpo: &str) -> String {
+    cuerpo
+        .lines()
+        .map(|l| if l.starts_with('.') { format!(".{l}") } else { l.to_owned() })
+        .collect::<Vec<_>>()
+        .join("\r\n")
+}
+
+/* ───────── Webhook ───────── */
+
+/// `http(s)://anfitrion[:puerto]/ruta` → (esquema, anfitrión, anfitrión con
+/// puerto, ruta).
+fn partes_url(url: &str) -> Result<(&str, &str, String, String), String> {
+    let (esquema, resto, puerto) = if let Some(resto) = url.strip_prefix("http://") {
+        ("http", resto, 80)
+    } else if let Some(resto) = url.strip_prefix("https://") {
+        ("https", resto, 443)
+    } else {
+        return Err(format!("webhook {url}: sólo se admite http:// o https://"));
+    };
+    let (anfitrion, ruta) = match resto.split_once('/') {
+        Some((anfitrion, ruta)) => (anfitrion, format!("/{ruta}")),
+        None => (resto, "/".to_owned()),
+    };
+    // Sin usuario@ ni nada que pueda colar otra cabecera
+    if anfitrion.is_empty() || anfitrion.contains('@') || url.contains(['\r', '\n', ' ']) {
+        return Err(format!("webhook {url}: URL inválida"));
+    }
+    let direccion = if anfitrion.contains(':') { anfitrion.to_owned() } else { format!("{anfitrion}:{puerto}") };
+    Ok((esquema, anfitrion, direccion, ruta))
+}
+
+/// ¿Se puede mandar un webhook a `ip`?  No a loopback, redes privadas,
+/// enlace local ni otras direcciones que no salen a internet.
+fn ip_publica(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => {
+            let [a, b, ..] = v4.octets();
+            let compartida = a == 100 && (64..128).contains(&b); // 100.64.0.0/10 (CGNAT)
+            !(v4.is_loopback()
+                || v4.is_private()
+                || v4.is_link_local()
+                || v4.is_unspecified()
+                || v4.is_broadcast()
+                || v4.is_multicast()
+                || v4.is_documentation()
+                || compartida
+                || a == 0)
+        }
+        IpAddr::V6(v6) => {
+            if let Some(v4) = v6.to_ipv4_mapped() {
+                return ip_publica(IpAddr::V4(v4));
+            }
+            let inicio = v6.segments()[0];
+            let unica_local = inicio & 0xfe00 == 0xfc00; // fc00::/7
+            let enlace_local = inicio & 0xffc0 == 0xfe80; // fe80::/10
+            !(v6.is_loopback() || v6.is_unspecified() || v6.is_multicast() || unica_local || enlace_local)
+        }
+    }
+}
+
+/// Resuelve el anfitrión del webhook; error si alguna de sus direcciones
+/// es interna.  Se mira al guardar la URL y otra vez al entregar, y la
+/// entrega se conecta a estas mismas direcciones (un DNS que cambie entre
+/// medias no sirve para colarse en la red del servidor).
+pub async fn direcciones_webhook(url: &str) -> Result<Vec<SocketAddr>, String> {
+    let (_, _, direccion, _) = partes_url(url)?;
+    let direcciones: Vec<SocketAddr> = tokio::net::lookup_host(&direccion)
+        .await
+        .map_err(|e| format!("webhook {url}: {e}"))?
+        .collect();
+    if direcciones.is_empty() {
+        return Err(format!("webhook {url}: el anfitrión no tiene direcciones"));
+    }
+    if let Some(interna) = direcciones.iter().find(|d| !ip_publica(d.ip())) {
+        return Err(format!("webhook {url}: {} es una dirección interna", interna.ip()));
+    }
+    Ok(direcciones)
+}
+
+async fn enviar_http(url: &str, cuerpo: &str) -> Result<(), String> {
+    let (esquema, anfitrion, _, ruta) = partes_url(url)?;
+    if esquema != "http" {
+        return Err(format!("webhook {url}: sólo se admite http://"));
+    }
+    let direcciones = direcciones_webhook(url).await?;
+    let error = |e: std::io::Error| format!("webhook {url}: {e}");
+
+    let (lectura, mut escritura) = TcpStream::connect(&direcciones[..]).await.map_err(error)?.into_split();
+    let peticion = format!(
+        "POST {ruta} HTTP/1.1\r\nHost: {anfitrion}\r\nUser-Agent: {NOMBRE_SERVIDOR}\r\n\
+         Content-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{cuerpo}",
+        cuerpo.len()
+    );
+    escritura.write_all(peticion.as_bytes()).await.map_err(error)?;
+
+    // Basta la línea de estado: "HTTP/1.1 204 No Content"
+    let mut estado = String::new();
+    BufReader::new(lectura).read_line(&mut estado).await.map_err(error)?;
+    match estado.split_whitespace().nth(1).and_then(|c| c.parse::<u16>().ok()) {
+        Some(codigo) if (200..300).contains(&codigo) => Ok(()),
+        Some(codigo) => Err(format!("webhook {url}: HTTP {codigo}")),
+        None => Err(format!("webhook {url}: respuesta inválida {:?}", estado.trim_end())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(valor: &str) -> Option<Salida> {
+        Salida::desde_config(valor, REMITENTE_POR_DEFECTO.into())
+    }
+
+    #[test]
+    fn config_smtp_con_puerto_por_defecto() {
+        assert!(matches!(config("smtp://relay"), Some(Salida::Smtp { servidor, .. }) if servidor == "relay:25"));
+        assert!(matches!(config(" smtp://relay:2525 "), Some(Salida::Smtp { servidor, .. }) if servidor == "relay:2525"));
+    }
+
+    #[test]
+    fn config_del_resto_de_salidas() {
+        assert!(matches!(config("http"), Some(Salida::Http)));
+        assert!(matches!(config("log"), Some(Salida::Log)));
+        let archivo = config("archivo:/tmp/avisos.jsonl");
+        assert!(matches!(archivo, Some(Salida::Archivo(r)) if r == PathBuf::from("/tmp/avisos.jsonl")));
+        assert!(config("ftp://x").is_none());
+    }
+
+    #[test]
+    fn partes_de_la_url() {
+        let (esquema, anfitrion, direccion, ruta) = partes_url("http://ejemplo.com/avisos?x=1").unwrap();
+        assert_eq!((esquema, anfitrion), ("http", "ejemplo.com"));
+        assert_eq!((direccion.as_str(), ruta.as_str()), ("ejemplo.com:80", "/avisos?x=1"));
+        let (esquema, _, direccion, ruta) = partes_url("https://ejemplo.com:8443").unwrap();
+        assert_eq!((esquema, direccion.as_str(), ruta.as_str()), ("https", "ejemplo.com:8443", "/"));
+    }
+
+    #[test]
+    fn urls_invalidas() {
+        for url in ["ftp://ejemplo.com", "http://", "http://yo@127.0.0.1/", "http://ejemplo.com/\r\nX: y"] {
+            assert!(partes_url(url).is_err(), "{url}");
+        }
+    }
+
+    #[test]
+    fn direcciones_internas() {
+        for ip in [
+            "127.0.0.1", "10.1.2.3", "172.16.0.1", "192.168.1.1", "169.254.169.254", "0.0.0.0", "100.64.0.1",
+            "::1", "::", "fe80::1", "fd00::1", "::ffff:127.0.0.1",
+        ] {
+            assert!(!ip_publica(ip.parse().unwrap()), "{ip}");
+        }
+    }
+
+    #[test]
+    fn direcciones_publicas() {
+        for ip in ["93.184.216.34", "1.1.1.1", "2606:4700:4700::1111", "::ffff:8.8.8.8"] {
+            assert!(ip_publica(ip.parse().unwrap()), "{ip}");
+        }
+    }
+
+    #[tokio::test]
+    async fn webhook_a_ip_interna_rechazado() {
+        assert!(direcciones_webhook("http://127.0.0.1:8080/avisos").await.is_err());
+        assert!(direcciones_webhook("http://169.254.169.254/latest/meta-data").await.is_err());
+        let publicas = direcciones_webhook("http://93.184.216.34/avisos").await.unwrap();
+        assert_eq!(publicas, vec!["93.184.216.34:80".parse().unwrap()]);
+    }
+}
@@ -0,0 +1,536 @@
+// -----------------------------------------------------------------
+//! Temporadas: clasificación por períodos con reinicio suave del Elo.
+//!
+//!   • Siempre hay una temporada `activa`.  `cerrar_partida` llama a
+//!     `registrar_partida` en las partidas que puntúan, que suma la
+//!     partida a `TemporadaJugador` (rating, máximo, balance y goles
+//!     de la temporada).
+//!   • `lanzar_cierre` revisa cada `INTERVALO_CIERRE_S` si la activa
+//!     venció; al cerrarla archiva el puesto final de cada jugador,
+//!     acerca el Elo de todos a `ELO_BASE` (se queda con
+//!     `FACTOR_REINICIO` de la diferencia) y abre la siguiente por
+//!     `DURACION_DIAS`.
+//!   • La clasificación de la activa se ordena en vivo; la de una
+//!     cerrada sale del puesto archivado.
+// -----------------------------------------------------------------
+
+use axum::{
+    extract::{Extension, Path, Query},
+    http::StatusCode,
+    Json,
+};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::{MySql, MySqlPool, Transaction};
+
+use crate::i18n::{tr, Idioma};
+
+/// Rating al que tiende el reinicio (el de un usuario nuevo).
+const ELO_BASE: i32 = 1000;
+/// Parte de la distancia a `ELO_BASE` que se conserva al cambiar de temporada.
+const FACTOR_REINICIO: f64 = 0.5;
+const DURACION_DIAS: i64 = 90;
+/// Cada cuánto se mira si la temporada activa venció.
+const INTERVALO_CIERRE_S: u64 = 10 * 60;
+/// Filas por página de `GET /temporadas/:t/clasificacion`.
+const LIMITE_CLASIFICACION: i64 = 100;
+
+#[derive(Debug, Serialize)]
+pub struct Temporada {
+    pub id_temporada: i32,
+    pub nombre: String,
+    pub fecha_inicio: NaiveDateTime,
+    pub fecha_fin: NaiveDateTime,
+    /// activa | cerrada
+    pub estado: String,
+    /// Usuarios con al menos una partida en la temporada.
+    pub jugadores: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FilaTemporada {
+    pub posicion: i64,
+    pub id_usuario: i32,
+    pub nombre_usuario: String,
+    pub elo: i32,
+    pub elo_inicial: i32,
+    pub elo_maximo: i32,
+    pub jugadas: i32,
+    pub ganadas: i32,
+    pub empatadas: i32,
+    pub perdidas: i32,
+    pub goles_a_favor: i32,
+    pub goles_en_contra: i32,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ClasificacionTemporada {
+    pub temporada: Temporada,
+    pub filas: Vec<FilaTemporada>,
+}
+
+/// Una temporada en el historial de un jugador.
+#[derive(Debug, Serialize)]
+pub struct TemporadaJugada {
+    pub id_temporada: i32,
+    pub nombre: String,
+    pub estado: String,
+    pub fecha_inicio: NaiveDateTime,
+    pub fecha_fin: NaiveDateTime,
+    /// Puesto final, o el actual si la temporada sigue.
+    pub posicion: i64,
+    pub elo_inicial: i32,
+    pub elo: i32,
+    pub elo_maximo: i32,
+    pub jugadas: i32,
+    pub ganadas: i32,
+    pub empatadas: i32,
+    pub perdidas: i32,
+    pub goles_a_favor: i32,
+    pub goles_en_contra: i32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ClasificacionQuery {
+    #[serde(default)]
+    pub desde: i64,
+    pub limite: Option<i64>,
+}
+
+/* ───────── Registro de partidas ───────── */
+
+/// Suma una partida recién cerrada a la temporada activa.  `elos` es
+/// el rating de cada jugador antes de la partida y `cambios` lo que
+/// ganó o perdió.
+pub async fn registrar_partida(
+    tx: &mut Transaction<'_, MySql>,
+    id_partida: i32,
+    (id_j1, id_j2): (i32, i32),
+    (gol_j1, gol_j2): (i32, i32),
+    (elo_j1, elo_j2): (i32, i32),
+    (cambio_j1, cambio_j2): (i32, i32),
+) -> Result<(), sqlx::Error> {
+    // FOR SHARE: si justo se está cerrando la temporada, se espera y la partida va a la nueva
+    let Some(id_temporada) = sqlx::query_scalar!(
+        "SELECT id_temporada FROM Temporada WHERE estado = 'activa' ORDER BY id_temporada DESC LIMIT 1 FOR SHARE"
+    )
+        .fetch_optional(&mut **tx)
+        .await?
+    else {
+        tracing::warn!("🗓️ No hay temporada activa: la partida {id_partida} no suma a ninguna");
+        return Ok(());
+    };
+
+    for (id, elo, cambio, propios, ajenos) in [
+        (id_j1, elo_j1, cambio_j1, gol_j1, gol_j2),
+        (id_j2, elo_j2, cambio_j2, gol_j2, gol_j1),
+    ] {
+        let (ganada, empatada, perdida) = match propios.cmp(&ajenos) {
+            std::cmp::Ordering::Greater => (1, 0, 0),
+            std::cmp::Ordering::Equal => (0, 1, 0),
+            std::cmp::Ordering::Less => (0, 0, 1),
+        };
+        let elo_nuevo = elo + cambio;
+        sqlx::query!(
+            r#"
+            INSERT INTO TemporadaJugador
+                (id_temporada, id_usuario, elo_inicial, elo, elo_maximo, jugadas,
+                 ganadas, empatadas, perdidas, goles_a_favor, goles_en_contra)
+            VALUES (?, ?, ?, ?, GREATEST(?, ?), 1, ?, ?, ?, ?, ?)
+            ON DUPLICATE KEY UPDATE
+                elo             = VALUES(elo),
+                elo_maximo      = GREATEST(elo_maximo, VALUES(elo)),
+                jugadas         = jugadas + 1,
+                ganadas         = ganadas + VALUES(ganadas),
+                empatadas       = empatadas + VALUES(empatadas),
+                perdidas        = perdidas + VALUES(perdidas),
+                goles_a_favor   = goles_a_favor + VALUES(goles_a_favor),
+                goles_en_contra = goles_en_contra + VALUES(goles_en_contra)
+            "#,
+            id_temporada,
+            id,
+            elo,
+            elo_nuevo,
+            elo,
+            elo_nuevo,
+            ganada,
+            empatada,
+            perdida,
+            propios,
+            ajenos
+        )
+            .execute(&mut **tx)
+            .await?;
+    }
+
+    sqlx::query!("UPDATE Partida SET id_temporada = ? WHERE id_partida = ?", id_temporada, id_partida)
+        .execute(&mut **tx)
+        .await?;
+    Ok(())
+}
+
+/* ───────── Cambio de temporada ───────── */
+
+/// Rating con el que se empieza la temporada siguiente.
+fn elo_reiniciado(elo: i32) -> i32 {
+    ELO_BASE + ((elo - ELO_BASE) as f64 * FACTOR_REINICIO).round() as i32
+}
+
+/// Jugador de la temporada que se cierra, con lo que decide su puesto.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Puntaje {
+    id_usuario: i32,
+    elo: i32,
+    ganadas: i32,
+}
+
+/// Puesto final de cada jugador, en el mismo orden que la clasificación
+/// en vivo: Elo, luego victorias y por último el id.
+fn puestos_finales(mut jugadores: Vec<Puntaje>) -> Vec<(i32, i32)> {
+    jugadores.sort_by(|a, b| {
+        b.elo
+            .cmp(&a.elo)
+            .then(b.ganadas.cmp(&a.ganadas))
+            .then(a.id_usuario.cmp(&b.id_usuario))
+    });
+    jugadores
+        .iter()
+        .enumerate()
+        .map(|(i, j)| (j.id_usuario, i as i32 + 1))
+        .collect()
+}
+
+/// `(id_usuario, elo nuevo)` de quienes cambian con el reinicio suave;
+/// los que ya están en la base no se tocan.
+fn reinicios(ratings: &[(i32, i32)]) -> Vec<(i32, i32)> {
+    ratings
+        .iter()
+        .map(|&(id_usuario, elo)| (id_usuario, elo, elo_reiniciado(elo)))
+        .filter(|&(_, antes, despues)| antes != despues)
+        .map(|(id_usuario, _, despues)| (id_usuario, despues))
+        .collect()
+}
+
+/// Nombre de la temporada que sigue a las `existentes`.
+fn nombre_siguiente(existentes: i64) -> String {
+    format!("Temporada {}", existentes + 1)
+}
+
+/// Cierra la temporada activa si ya venció y abre la siguiente.
+async fn cerrar_vencida(pool: &MySqlPool) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let Some(vencida) = sqlx::query!(
+        "SELECT id_temporada, nombre FROM Temporada WHERE estado = 'activa' AND fecha_fin <= NOW() FOR UPDATE"
+    )
+        .fetch_optional(&mut *tx)
+        .await?
+    else {
+        return Ok(());
+    };
+
+    // 📦 Puesto final de cada jugador
+    let jugadores = sqlx::query!(
+        "SELECT id_usuario, elo, ganadas FROM TemporadaJugador WHERE id_temporada = ? FOR UPDATE",
+        vencida.id_temporada
+    )
+        .fetch_all(&mut *tx)
+        .await?
+        .into_iter()
+        .map(|f| Puntaje { id_usuario: f.id_usuario, elo: f.elo, ganadas: f.ganadas })
+        .collect();
+    for (id_usuario, puesto) in puestos_finales(jugadores) {
+        sqlx::query!(
+            "UPDATE TemporadaJugador SET posicion = ? WHERE id_temporada = ? AND id_usuario = ?",
+            puesto,
+            vencida.id_temporada,
+            id_usuario
+        )
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    sqlx::query!("UPDATE Temporada SET estado = 'cerrada' WHERE id_temporada = ?", vencida.id_temporada)
+        .execute(&mut *tx)
+        .await?;
+
+    // 🔁 Reinicio suave: cada uno conserva parte de lo que se alejó de la base
+    let ratings: Vec<(i32, i32)> = sqlx::query!("SELECT id_usuario, elo FROM Usuario FOR UPDATE")
+        .fetch_all(&mut *tx)
+        .await?
+        .into_iter()
+        .map(|u| (u.id_usuario, u.elo))
+        .collect();
+    let reiniciados = reinicios(&ratings);
+    for &(id_usuario, elo) in &reiniciados {
+        sqlx::query!("UPDATE Usuario SET elo = ? WHERE id_usuario = ?", elo, id_usuario)
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    let existentes: i64 = sqlx::query_scalar!("SELECT COUNT(*) FROM Temporada")
+        .fetch_one(&mut *tx)
+        .await?;
+    let nombre = nombre_siguiente(existentes);
+    sqlx::query!(
+        "INSERT INTO Temporada (nombre, fecha_inicio, fecha_fin) VALUES (?, NOW(), NOW() + INTERVAL ? DAY)",
+        nombre,
+        DURACION_DIAS
+    )
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+    tracing::info!(
+        "🗓️ {} cerrada; empieza {nombre} ({} ratings acercados a {ELO_BASE})",
+        vencida.nombre,
+        reiniciados.len()
+    );
+    Ok(())
+}
+
+/// Tarea de fondo que cambia de temporada cuando vence la activa.
+pub fn lanzar_cierre(pool: MySqlPool) {
+    tokio::spawn(async move {
+        let mut intervalo = tokio::time::interval(std::time::Duration::from_secs(INTERVALO_CIERRE_S));
+        loop {
+            intervalo.tick().await;
+            if let Err(e) = cerrar_vencida(&pool).await {
+                tracing::error!("❌ Error cerrando la temporada: {e}");
+            }
+        }
+    });
+}
+
+/* ───────── Consultas ───────── */
+
+async fn temporada(pool: &MySqlPool, id_temporada: i32) -> Result<Option<Temporada>, sqlx::Error> {
+    sqlx::query_as!(
+        Temporada,
+        r#"
+        SELECT t.id_temporada, t.nombre,
+               t.fecha_inicio AS "fecha_inicio: NaiveDateTime",
+               t.fecha_fin AS "fecha_fin: NaiveDateTime",
+               t.estado AS "estado!: String",
+               (SELECT COUNT(*) FROM TemporadaJugador tj WHERE tj.id_temporada = t.id_temporada) AS "jugadores!: i64"
+        FROM   Temporada t
+        WHERE  t.id_temporada = ?
+        "#,
+        id_temporada
+    )
+        .fetch_optional(pool)
+        .await
+}
+
+/* ───────── Handlers ───────── */
+
+// GET /temporadas
+pub async fn get_temporadas(
+    idioma: Idioma,
+    Extension(pool): Extension<MySqlPool>,
+) -> Result<Json<Vec<Temporada>>, (StatusCode, String)> {
+    let temporadas = sqlx::query_as!(
+        Temporada,
+        r#"
+        SELECT t.id_temporada, t.nombre,
+               t.fecha_inicio AS "fecha_inicio: NaiveDateTime",
+               t.fecha_fin AS "fecha_fin: NaiveDateTime",
+               t.estado AS "estado!: String",
+               (SELECT COUNT(*) FROM TemporadaJugador tj WHERE tj.id_temporada = t.id_temporada) AS "jugadores!: i64"
+        FROM   Temporada t
+        ORDER  BY t.id_temporada DESC
+        "#
+    )
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("❌ SQL en get_temporadas: {e}");
+            (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+        })?;
+
+    Ok(Json(temporadas))
+}
+
+// GET /temporadas/actual
+pub async fn get_temporada_actual(
+    idioma: Idioma,
+    Extension(pool): Extension<MySqlPool>,
+) -> Result<Json<Temporada>, (StatusCode, String)> {
+    let error_sql = |e: sqlx::Error| {
+        tracing::error!("❌ SQL en get_temporada_actual: {e}");
+        (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+    };
+
+    let id = sqlx::query_scalar!("SELECT id_temporada FROM Temporada WHERE estado = 'activa' ORDER BY id_temporada DESC LIMIT 1")
+        .fetch_optional(&pool)
+        .await
+        .map_err(error_sql)?
+        .ok_or_else(|| (StatusCode::NOT_FOUND, tr!(idioma, "temporada-sin-activa")))?;
+
+    temporada(&pool, id)
+        .await
+        .map_err(error_sql)?
+        .map(Json)
+        .ok_or_else(|| (StatusCode::NOT_FOUND, tr!(idioma, "temporada-no-encontrada", temporada = id)))
+}
+
+// GET /temporadas/:t/clasificacion?desde=0&limite=100
+pub async fn get_clasificacion_temporada(
+    idioma: Idioma,
+    Path(id_temporada): Path<i32>,
+    Query(q): Query<ClasificacionQuery>,
+    Extension(pool): Extension<MySqlPool>,
+) -> Result<Json<ClasificacionTemporada>, (StatusCode, String)> {
+    let error_sql = |e: sqlx::Error| {
+        tracing::error!("❌ SQL en get_clasificacion_temporada: {e}");
+        (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+    };
+
+    let temporada = temporada(&pool, id_temporada)
+        .await
+        .map_err(error_sql)?
+        .ok_or_else(|| (StatusCode::NOT_FOUND, tr!(idioma, "temporada-no-encontrada", temporada = id_temporada)))?;
+
+    let desde = q.desde.max(0);
+    let limite = q.limite.unwrap_or(LIMITE_CLASIFICACION).clamp(1, LIMITE_CLASIFICACION);
+
+    // En una cerrada el orden coincide con el puesto archivado
+    let filas = sqlx::query!(
+        r#"
+        SELECT tj.id_usuario, u.nombre_usuario, tj.posicion,
+               tj.elo, tj.elo_inicial, tj.elo_maximo,
+               tj.jugadas, tj.ganadas, tj.empatadas, tj.perdidas,
+               tj.goles_a_favor, tj.goles_en_contra
+        FROM   TemporadaJugador tj
+        JOIN   Usuario u ON u.id_usuario = tj.id_usuario
+        WHERE  tj.id_temporada = ?
+        ORDER  BY COALESCE(tj.posicion, 0), tj.elo DESC, tj.ganadas DESC, tj.id_usuario
+        LIMIT  ? OFFSET ?
+        "#,
+        id_temporada,
+        limite,
+        desde
+    )
+        .fetch_all(&pool)
+        .await
+        .map_err(error_sql)?
+        .into_iter()
+        .enumerate()
+        .map(|(i, f)| FilaTemporada {
+            posicion: f.posicion.map_or(desde + i as i64 + 1, i64::from),
+            id_usuario: f.id_usuario,
+            nombre_usuario: f.nombre_usuario,
+            elo: f.elo,
+            elo_inicial: f.elo_inicial,
+            elo_maximo: f.elo_maximo,
+            jugadas: f.jugadas,
+            ganadas: f.ganadas,
+            empatadas: f.empatadas,
+            perdidas: f.perdidas,
+            goles_a_favor: f.goles_a_favor,
+            goles_en_contra: f.goles_en_contra,
+        })
+        .collect();
+
+    Ok(Json(ClasificacionTemporada { temporada, filas }))
+}
+
+// GET /perfil/:u/temporadas
+pub async fn get_temporadas_jugador(
+    idioma: Idioma,
+    Path(id_usuario): Path<i32>,
+    Extension(pool): Extension<MySqlPool>,
+) -> Result<Json<Vec<TemporadaJugada>>, (StatusCode, String)> {
+    // El puesto en vivo se calcula sobre toda la temporada antes de filtrar al jugador
+    let historial = sqlx::query_as!(
+        TemporadaJugada,
+        r#"
+        SELECT t.id_temporada, t.nombre, t.estado AS "estado!: String",
+               t.fecha_inicio AS "fecha_inicio: NaiveDateTime",
+               t.fecha_fin AS "fecha_fin: NaiveDateTime",
+               CAST(COALESCE(r.posicion, r.puesto) AS SIGNED) AS "posicion!: i64",
+               r.elo_inicial AS "elo_inicial!: i32", r.elo AS "elo!: i32", r.elo_maximo AS "elo_maximo!: i32",
+               r.jugadas AS "jugadas!: i32", r.ganadas AS "ganadas!: i32",
+               r.empatadas AS "empatadas!: i32", r.perdidas AS "perdidas!: i32",
+               r.goles_a_favor AS "goles_a_favor!: i32", r.goles_en_contra AS "goles_en_contra!: i32"
+        FROM (
+            SELECT tj.*,
+                   ROW_NUMBER() OVER (PARTITION BY tj.id_temporada
+                                      ORDER BY tj.elo DESC, tj.ganadas DESC, tj.id_usuario) AS puesto
+            FROM   TemporadaJugador tj
+            WHERE  tj.id_temporada IN (SELECT id_temporada FROM TemporadaJugador WHERE id_usuario = ?)
+        ) r
+        JOIN   Temporada t ON t.id_temporada = r.id_temporada
+        WHERE  r.id_usuario = ?
+        ORDER  BY t.id_temporada DESC
+        "#,
+        id_usuario,
+        id_usuario
+    )
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("❌ SQL en get_temporadas_jugador: {e}");
+            (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+        })?;
+
+    Ok(Json(historial))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reinicio_conserva_la_mitad_de_la_distancia() {
+        assert_eq!(elo_reiniciado(1400), 1200);
+        assert_eq!(elo_reiniciado(600), 800);
+    }
+
+    #[test]
+    fn la_base_no_se_mueve() {
+        assert_eq!(elo_reiniciado(ELO_BASE), ELO_BASE);
+    }
+
+    #[test]
+    fn el_redondeo_es_simetrico() {
+        assert_eq!(elo_reiniciado(ELO_BASE + 1), ELO_BASE + 1);
+        assert_eq!(elo_reiniciado(ELO_BASE - 1), ELO_BASE - 1);
+        assert_eq!(elo_reiniciado(1201) - ELO_BASE, ELO_BASE - elo_reiniciado(799));
+    }
+
+    #[test]
+    fn reinicio_no_cambia_el_orden() {
+        let elos = [640, 999, 1000, 1003, 1250, 1800];
+        let nuevos: Vec<i32> = elos.iter().map(|&e| elo_reiniciado(e)).collect();
+        assert!(nuevos.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    fn puntaje(id_usuario: i32, elo: i32, ganadas: i32) -> Puntaje {
+        Puntaje { id_usuario, elo, ganadas }
+    }
+
+    #[test]
+    fn puestos_por_elo_victorias_e_id() {
+        let puestos = puestos_finales(vec![
+            puntaje(7, 1100, 3),
+            puntaje(3, 1300, 1),
+            puntaje(9, 1100, 5),
+            puntaje(2, 1100, 3),
+        ]);
+        assert_eq!(puestos, vec![(3, 1), (9, 2), (2, 3), (7, 4)]);
+        assert!(puestos_finales(Vec::new()).is_empty());
+    }
+
+    #[test]
+    fn reinicio_solo_de_quien_cambia() {
+        let ratings = [(1, 1400), (2, ELO_BASE), (3, 600), (4, ELO_BASE + 1)];
+        assert_eq!(reinicios(&ratings), vec![(1, 1200), (3, 800)]);
+    }
+
+    #[test]
+    fn la_siguiente_temporada_se_numera_en_orden() {
+        assert_eq!(nombre_siguiente(0), "Temporada 1");
+        assert_eq!(nombre_siguiente(4), "Temporada 5");
+    }
+}
@@ -0,0 +1,902 @@
+// -----------------------------------------------------------------
+//! Torneos: eliminación directa o liga, con las partidas creadas solas.
+//!
+//!   • `POST /torneos` recibe los participantes y las reglas; se
+//!     siembran por Elo (semilla 1 = el de más rating) y se arma todo
+//!     el cuadro de `TorneoEncuentro` de una vez.
+//!   • Eliminación: el cuadro se completa hasta la potencia de 2
+//!     siguiente con byes, que siempre tocan a las mejores semillas y
+//!     se ganan sin jugar.  El ganador de `posicion` pasa a la ronda
+//!     siguiente en `posicion / 2`.
+//!   • Liga: calendario por el método del círculo (con número impar,
+//!     uno descansa cada ronda); `ida_y_vuelta` lo repite con los
+//!     lados cambiados.
+//!   • Las `Partida` de una ronda se crean cuando termina la anterior:
+//!     `post_gol` llama a `partida_terminada` tras cerrar la partida.
+//!     Son por correspondencia con el plazo del torneo: si alguien no
+//!     mueve, pierde por tiempo y el cuadro sigue.
+//!   • Un cruce de eliminación sin ganador (empate o partida anulada)
+//!     elimina a los dos: el rival de la ronda siguiente pasa por bye, y
+//!     si era la final el torneo termina sin campeón.
+//!   • Clasificación: en liga 3 puntos por victoria y 1 por empate; en
+//!     eliminación, por ronda alcanzada.
+// -----------------------------------------------------------------
+
+use axum::{
+    extract::{Extension, Path},
+    http::StatusCode,
+    Json,
+};
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use serde_json::json;
+use sqlx::{MySql, MySqlConnection, MySqlPool, QueryBuilder, Transaction};
+use std::collections::HashMap;
+
+use crate::i18n::{tr, Idioma};
+use crate::models::TorneoPayload;
+use crate::routes::lobby::notificar;
+
+const MIN_PARTICIPANTES: usize = 2;
+const MAX_PARTICIPANTES: usize = 32;
+const MAX_NOMBRE: usize = 60;
+const PUNTOS_VICTORIA: i32 = 3;
+const PUNTOS_EMPATE: i32 = 1;
+/// Torneos que devuelve `GET /torneos`.
+const MAX_LISTADO: i64 = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Formato {
+    Eliminacion,
+    Liga,
+}
+
+impl Formato {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "eliminacion" => Some(Self::Eliminacion),
+            "liga" => Some(Self::Liga),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Eliminacion => "eliminacion",
+            Self::Liga => "liga",
+        }
+    }
+}
+
+/* ───────── Cuadro y calendario ───────── */
+
+/// Un cruce: (ronda, posición, jugador 1, jugador 2).
+type Cruce = (i32, i32, Option<i32>, Option<i32>);
+
+/// Orden de siembra del cuadro de `tam` (potencia de 2): las semillas
+/// 1 y 2 sólo se cruzan en la final, 1-4 y 2-3 en semis, etc.
+fn orden_siembra(tam: usize) -> Vec<usize> {
+    let mut orden = vec![0];
+    while orden.len() < tam {
+        let n = orden.len() * 2;
+        orden = orden.iter().flat_map(|&s| [s, n - 1 - s]).collect();
+    }
+    orden
+}
+
+fn cuadro_eliminacion(sembrados: &[i32]) -> Vec<Cruce> {
+    let tam = sembrados.len().next_power_of_two();
+    let orden = orden_siembra(tam);
+    let mut cruces = Vec::new();
+
+    // Primera ronda con los sembrados (None = bye)
+    for (pos, par) in orden.chunks(2).enumerate() {
+        cruces.push((1, pos as i32, sembrados.get(par[0]).copied(), sembrados.get(par[1]).copied()));
+    }
+    // Rondas siguientes vacías: se llenan con los ganadores
+    let mut ronda = 2;
+    let mut en_ronda = tam / 4;
+    while en_ronda >= 1 {
+        for pos in 0..en_ronda {
+            cruces.push((ronda, pos as i32, None, None));
+        }
+        ronda += 1;
+        en_ronda /= 2;
+    }
+    cruces
+}
+
+fn calendario_liga(sembrados: &[i32], ida_y_vuelta: bool) -> Vec<Cruce> {
+    let mut equipos: Vec<Option<i32>> = sembrados.iter().copied().map(Some).collect();
+    if equipos.len() % 2 == 1 {
+        equipos.push(None); // descansa quien le toque
+    }
+    let n = equipos.len();
+    let rondas = (n - 1) as i32;
+    let mut cruces = Vec::new();
+
+    for ronda in 1..=rondas {
+        let mut pos = 0;
+        for i in 0..n / 2 {
+            if let (Some(a), Some(b)) = (equipos[i], equipos[n - 1 - i]) {
+                // Se alterna quién es el jugador 1 para repartir los lados
+                let (j1, j2) = if ronda % 2 == 0 { (b, a) } else { (a, b) };
+                cruces.push((ronda, pos, Some(j1), Some(j2)));
+                pos += 1;
+            }
+        }
+        // El primero queda fijo y el resto rota
+        let ultimo = equipos.pop().unwrap();
+        equipos.insert(1, ultimo);
+    }
+
+    if ida_y_vuelta {
+        let vuelta: Vec<Cruce> = cruces.iter().map(|&(r, p, a, b)| (r + rondas, p, b, a)).collect();
+        cruces.extend(vuelta);
+    }
+    cruces
+}
+
+/* ───────── Avance ───────── */
+
+/// Partida creada al abrir una ronda (para el bot y los avisos).
+#[derive(Debug)]
+pub struct PartidaNueva {
+    id_partida: i32,
+    id_jugador1: i32,
+    id_jugador2: i32,
+}
+
+/// Pasa `ganador` del cruce (`ronda`, `posicion`) a la ronda siguiente.
+async fn colocar_ganador(
+    tx: &mut Transaction<'_, MySql>,
+    id_torneo: i32,
+    (ronda, posicion): (i32, i32),
+    ganador: i32,
+) -> Result<(), sqlx::Error> {
+    if posicion % 2 == 0 {
+        sqlx::query!(
+            "UPDATE TorneoEncuentro SET id_jugador1 = ? WHERE id_torneo = ? AND ronda = ? AND posicion = ?",
+            ganador,
+            id_torneo,
+            ronda + 1,
+            posicion / 2
+        )
+            .execute(&mut **tx)
+            .await?;
+    } else {
+        sqlx::query!(
+            "UPDATE TorneoEncuentro SET id_jugador2 = ? WHERE id_torneo = ? AND ronda = ? AND posicion = ?",
+            ganador,
+            id_torneo,
+            ronda + 1,
+            posicion / 2
+        )
+            .execute(&mut **tx)
+            .await?;
+    }
+    Ok(())
+}
+
+/// Crea las partidas de `ronda` y resuelve sus byes.
+async fn abrir_ronda(
+    tx: &mut Transaction<'_, MySql>,
+    id_torneo: i32,
+    ronda: i32,
+) -> Result<Vec<PartidaNueva>, sqlx::Error> {
+    let cruces = sqlx::query!(
+        r#"
+        SELECT id_encuentro, posicion, id_jugador1, id_jugador2
+        FROM   TorneoEncuentro
+        WHERE  id_torneo = ? AND ronda = ? AND id_partida IS NULL AND ganador IS NULL
+        "#,
+        id_torneo,
+        ronda
+    )
+        .fetch_all(&mut **tx)
+        .await?;
+
+    let plazo = sqlx::query_scalar!("SELECT plazo_turno_horas FROM Torneo WHERE id_torneo = ?", id_torneo)
+        .fetch_one(&mut **tx)
+        .await?;
+
+    let mut nuevas = Vec::new();
+    for c in cruces {
+        match (c.id_jugador1, c.id_jugador2) {
+            (Some(j1), Some(j2)) => {
+                // Las partidas de torneo se juegan con reglas clasificatorias
+                let id_partida = sqlx::query!(
+                    r#"
+                    INSERT INTO Partida (id_jugador1, id_jugador2, clasificatoria, modo, plazo_turno_horas, fecha_limite)
+                    VALUES (?, ?, TRUE, 'correspondencia', ?, NOW() + INTERVAL ? HOUR)
+                    "#,
+                    j1,
+                    j2,
+                    plazo,
+                    plazo
+                )
+                    .execute(&mut **tx)
+                    .await?
+                    .last_insert_id() as i32;
+                sqlx::query!("UPDATE TorneoEncuentro SET id_partida = ? WHERE id_encuentro = ?", id_partida, c.id_encuentro)
+                    .execute(&mut **tx)
+                    .await?;
+                nuevas.push(PartidaNueva { id_partida, id_jugador1: j1, id_jugador2: j2 });
+            }
+            // Bye: pasa sin jugar
+            (Some(j), None) | (None, Some(j)) => {
+                sqlx::query!("UPDATE TorneoEncuentro SET ganador = ? WHERE id_encuentro = ?", j, c.id_encuentro)
+                    .execute(&mut **tx)
+                    .await?;
+                colocar_ganador(tx, id_torneo, (ronda, c.posicion), j).await?;
+            }
+            (None, None) => {}
+        }
+    }
+    Ok(nuevas)
+}
+
+/// Si la ronda actual terminó, abre la siguiente o cierra el torneo.
+/// Devuelve las partidas nuevas y, si terminó, el campeón.
+async fn avanzar(
+    tx: &mut Transaction<'_, MySql>,
+    id_torneo: i32,
+    formato: Formato,
+    mut ronda_actual: i32,
+) -> Result<(Vec<PartidaNueva>, Option<i32>), sqlx::Error> {
+    let mut nuevas = Vec::new();
+    loop {
+        // Un cruce sin jugadores (los dos anteriores sin ganador) no tiene nada que esperar
+        let pendientes: i64 = sqlx::query_scalar!(
+            r#"
+            SELECT COUNT(*)
+            FROM   TorneoEncuentro e
+            LEFT JOIN Partida p ON p.id_partida = e.id_partida
+            WHERE  e.id_torneo = ? AND e.ronda = ? AND e.ganador IS NULL
+              AND  (e.id_jugador1 IS NOT NULL OR e.id_jugador2 IS NOT NULL)
+              AND  (p.estado IS NULL OR p.estado <> 'finished')
+            "#,
+            id_torneo,
+            ronda_actual
+        )
+            .fetch_one(&mut **tx)
+            .await?;
+        if pendientes > 0 {
+            return Ok((nuevas, None));
+        }
+
+        let hay_siguiente: i64 = sqlx::query_scalar!(
+            "SELECT COUNT(*) FROM TorneoEncuentro WHERE id_torneo = ? AND ronda = ?",
+            id_torneo,
+            ronda_actual + 1
+        )
+            .fetch_one(&mut **tx)
+            .await?;
+        if hay_siguiente == 0 {
+            break;
+        }
+
+        // Si la ronda nueva sólo tiene byes se resuelve sola y se sigue con la otra
+        ronda_actual += 1;
+        sqlx::query!("UPDATE Torneo SET ronda_actual = ? WHERE id_torneo = ?", ronda_actual, id_torneo)
+            .execute(&mut **tx)
+            .await?;
+        tracing::info!("🏆 Torneo {id_torneo}: empieza la ronda {ronda_actual}");
+        nuevas.extend(abrir_ronda(tx, id_torneo, ronda_actual).await?);
+    }
+
+    // Última ronda jugada: campeón
+    let campeon = match formato {
+        Formato::Eliminacion => {
+            sqlx::query_scalar!(
+                "SELECT ganador FROM TorneoEncuentro WHERE id_torneo = ? AND ronda = ?",
+                id_torneo,
+                ronda_actual
+            )
+                .fetch_one(&mut **tx)
+                .await?
+        }
+        Formato::Liga => {
+            let filas = filas_clasificacion(tx, id_torneo).await?;
+            clasificacion(formato, filas).first().map(|f| f.id_usuario)
+        }
+    };
+
+    sqlx::query!(
+        "UPDATE Torneo SET estado = 'terminado', campeon = ?, fecha_fin = NOW() WHERE id_torneo = ?",
+        campeon,
+        id_torneo
+    )
+        .execute(&mut **tx)
+        .await?;
+    tracing::info!("🏆 Torneo {id_torneo} terminado: campeón {campeon:?}");
+    Ok((nuevas, campeon))
+}
+
+/// Después del commit: el bot elige formación y se avisa a los jugadores.
+async fn anunciar(pool: &MySqlPool, id_torneo: i32, nuevas: &[PartidaNueva], campeon: Option<i32>) {
+    for p in nuevas {
+        if let Some((bot_uid, _)) = crate::bot::bot_de_partida(pool, p.id_partida).await {
+            if let Err(e) = crate::bot::elegir_formacion(pool, p.id_partida, bot_uid, 1).await {
+                tracing::error!("🤖 El bot {bot_uid} no pudo elegir formación (partida {}): {e}", p.id_partida);
+            }
+        }
+        let aviso = json!({ "tipo": "torneo_partida", "id_torneo": id_torneo, "id_partida": p.id_partida });
+        notificar(p.id_jugador1, aviso.clone());
+        notificar(p.id_jugador2, aviso);
+        crate::correspondencia::avisar_turno(pool, p.id_partida).await;
+    }
+
+    if let Some(campeon) = campeon {
+        let participantes = sqlx::query_scalar!("SELECT id_usuario FROM TorneoParticipante WHERE id_torneo = ?", id_torneo)
+            .fetch_all(pool)
+            .await
+            .unwrap_or_default();
+        for uid in participantes {
+            notificar(uid, json!({ "tipo": "torneo_terminado", "id_torneo": id_torneo, "campeon": campeon }));
+        }
+    }
+}
+
+/// Llamada por `post_gol` al cerrar una partida: si es de un torneo,
+/// anota el ganador y avanza el cuadro.
+pub async fn partida_terminada(pool: &MySqlPool, id_partida: i32, ganador: Option<i32>) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let Some(cruce) = sqlx::query!(
+        "SELECT id_encuentro, id_torneo, ronda, posicion FROM TorneoEncuentro WHERE id_partida = ?",
+        id_partida
+    )
+        .fetch_optional(&mut *tx)
+        .await?
+    else {
+        return Ok(());
+    };
+
+    // Bloquea el torneo: dos partidas que terminan a la vez no abren dos veces la ronda
+    let torneo = sqlx::query!(
+        r#"SELECT formato AS "formato!: String", ronda_actual FROM Torneo WHERE id_torneo = ? FOR UPDATE"#,
+        cruce.id_torneo
+    )
+        .fetch_one(&mut *tx)
+        .await?;
+    let formato = Formato::from_str(&torneo.formato).unwrap_or(Formato::Liga);
+
+    // Sin ganador (empate o partida anulada) en eliminación no pasa nadie:
+    // el rival de la ronda siguiente avanza por bye
+    sqlx::query!("UPDATE TorneoEncuentro SET ganador = ? WHERE id_encuentro = ?", ganador, cruce.id_encuentro)
+        .execute(&mut *tx)
+        .await?;
+    if let (Formato::Eliminacion, Some(g)) = (formato, ganador) {
+        colocar_ganador(&mut tx, cruce.id_torneo, (cruce.ronda, cruce.posicion), g).await?;
+    }
+
+    let (nuevas, campeon) = avanzar(&mut tx, cruce.id_torneo, formato, torneo.ronda_actual).await?;
+    tx.commit().await?;
+
+    anunciar(pool, cruce.id_torneo, &nuevas, campeon).await;
+    Ok(())
+}
+
+/* ───────── Clasificación ───────── */
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FilaClasificacion {
+    pub id_usuario: i32,
+    pub nombre_usuario: String,
+    pub semilla: i32,
+    pub jugadas: i32,
+    pub ganadas: i32,
+    pub empatadas: i32,
+    pub perdidas: i32,
+    pub goles_a_favor: i32,
+    pub goles_en_contra: i32,
+    pub puntos: i32,
+    /// Eliminación: última ronda en la que aparece.
+    pub ronda_alcanzada: i32,
+    pub eliminado: bool,
+}
+
+/// Participantes y cruces crudos, para `clasificacion`.
+pub struct FilasTorneo {
+    participantes: Vec<(i32, String, i32)>,
+    cruces: Vec<(i32, Option<i32>, Option<i32>, Option<i32>, Option<(i32, i32)>)>,
+}
+
+async fn filas_clasificacion(conn: &mut MySqlConnection, id_torneo: i32) -> Result<FilasTorneo, sqlx::Error> {
+    let participantes = sqlx::query!(
+        r#"
+        SELECT tp.id_usuario, u.nombre_usuario, tp.semilla
+        FROM   TorneoParticipante tp
+        JOIN   Usuario u ON u.id_usuario = tp.id_usuario
+        WHERE  tp.id_torneo = ?
+        "#,
+        id_torneo
+    )
+        .fetch_all(&mut *conn)
+        .await?
+        .into_iter()
+        .map(|f| (f.id_usuario, f.nombre_usuario, f.semilla))
+        .collect();
+
+    // Goles sólo de las partidas terminadas
+    let cruces = sqlx::query!(
+        r#"
+        SELECT e.ronda, e.id_jugador1, e.id_jugador2, e.ganador,
+               p.estado AS "estado?: String", p.gol_j1 AS "gol_j1?", p.gol_j2 AS "gol_j2?"
+        FROM   TorneoEncuentro e
+        LEFT JOIN Partida p ON p.id_partida = e.id_partida
+        WHERE  e.id_torneo = ?
+        "#,
+        id_torneo
+    )
+        .fetch_all(&mut *conn)
+        .await?
+        .into_iter()
+        .map(|f| {
+            let goles = (f.estado.as_deref() == Some("finished"))
+                .then(|| (f.gol_j1.unwrap_or(0), f.gol_j2.unwrap_or(0)));
+            (f.ronda, f.id_jugador1, f.id_jugador2, f.ganador, goles)
+        })
+        .collect();
+
+    Ok(FilasTorneo { participantes, cruces })
+}
+
+fn clasificacion(formato: Formato, filas: FilasTorneo) -> Vec<FilaClasificacion> {
+    let mut tabla: HashMap<i32, FilaClasificacion> = filas
+        .participantes
+        .into_iter()
+        .map(|(id_usuario, nombre_usuario, semilla)| {
+            (id_usuario, FilaClasificacion {
+                id_usuario,
+                nombre_usuario,
+                semilla,
+                jugadas: 0,
+                ganadas: 0,
+                empatadas: 0,
+                perdidas: 0,
+                goles_a_favor: 0,
+                goles_en_contra: 0,
+                puntos: 0,
+                ronda_alcanzada: 0,
+                eliminado: false,
+            })
+        })
+        .collect();
+
+    for (ronda, j1, j2, ganador, goles) in filas.cruces {
+        // Eliminación: queda fuera quien no ganó un cruce ya decidido (sin
+        // ganador, quedan fuera los dos)
+        let decidido = formato == Formato::Eliminacion && (ganador.is_some() || goles.is_some());
+        for j in [j1, j2].into_iter().flatten() {
+            if let Some(f) = tabla.get_mut(&j) {
+                f.ronda_alcanzada = f.ronda_alcanzada.max(ronda);
+                if decidido && ganador != Some(j) {
+                    f.eliminado = true;
+                }
+            }
+        }
+
+        let (Some(j1), Some(j2), Some((g1, g2))) = (j1, j2, goles) else { continue };
+        for (yo, propios, ajenos) in [(j1, g1, g2), (j2, g2, g1)] {
+            let Some(f) = tabla.get_mut(&yo) else { continue };
+            f.jugadas += 1;
+            f.goles_a_favor += propios;
+            f.goles_en_contra += ajenos;
+            match propios.cmp(&ajenos) {
+                std::cmp::Ordering::Greater => {
+                    f.ganadas += 1;
+                    f.puntos += PUNTOS_VICTORIA;
+                }
+                std::cmp::Ordering::Equal => {
+                    f.empatadas += 1;
+                    f.puntos += PUNTOS_EMPATE;
+                }
+                std::cmp::Ordering::Less => f.perdidas += 1,
+            }
+        }
+    }
+
+    let mut filas: Vec<FilaClasificacion> = tabla.into_values().collect();
+    match formato {
+        Formato::Liga => filas.sort_by_key(|f| {
+            (-f.puntos, -(f.goles_a_favor - f.goles_en_contra), -f.goles_a_favor, f.semilla)
+        }),
+        Formato::Eliminacion => filas.sort_by_key(|f| (-f.ronda_alcanzada, f.eliminado, -f.ganadas, f.semilla)),
+    }
+    filas
+}
+
+/* ───────── Vistas ───────── */
+
+#[derive(Debug, Serialize)]
+pub struct TorneoResumen {
+    pub id_torneo: i32,
+    pub nombre: String,
+    pub formato: String,
+    pub ida_y_vuelta: bool,
+    /// en_curso | terminado
+    pub estado: String,
+    pub ronda_actual: i32,
+    pub total_rondas: i32,
+    pub id_creador: i32,
+    pub campeon: Option<i32>,
+    pub participantes: i64,
+    pub fecha_creacion: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EncuentroVista {
+    pub ronda: i32,
+    pub posicion: i32,
+    pub id_jugador1: Option<i32>,
+    pub nombre_jugador1: Option<String>,
+    pub id_jugador2: Option<i32>,
+    pub nombre_jugador2: Option<String>,
+    pub id_partida: Option<i32>,
+    pub estado_partida: Option<String>,
+    pub gol_j1: Option<i32>,
+    pub gol_j2: Option<i32>,
+    pub ganador: Option<i32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TorneoDetalle {
+    #[serde(flatten)]
+    pub torneo: TorneoResumen,
+    pub encuentros: Vec<EncuentroVista>,
+    pub clasificacion: Vec<FilaClasificacion>,
+}
+
+async fn resumen(pool: &MySqlPool, id_torneo: i32) -> Result<Option<TorneoResumen>, sqlx::Error> {
+    sqlx::query_as!(
+        TorneoResumen,
+        r#"
+        SELECT t.id_torneo, t.nombre, t.formato AS "formato!: String",
+               t.ida_y_vuelta AS "ida_y_vuelta!: bool", t.estado AS "estado!: String",
+               t.ronda_actual,
+               (SELECT CAST(COALESCE(MAX(e.ronda), 0) AS SIGNED) FROM TorneoEncuentro e WHERE e.id_torneo = t.id_torneo) AS "total_rondas!: i32",
+               t.id_creador, t.campeon,
+               (SELECT COUNT(*) FROM TorneoParticipante tp WHERE tp.id_torneo = t.id_torneo) AS "participantes!: i64",
+               t.fecha_creacion AS "fecha_creacion: NaiveDateTime"
+        FROM   Torneo t
+        WHERE  t.id_torneo = ?
+        "#,
+        id_torneo
+    )
+        .fetch_optional(pool)
+        .await
+}
+
+/* ───────── Handlers ───────── */
+
+// POST /torneos
+pub async fn post_torneo(
+    idioma: Idioma,
+    Extension(pool): Extension<MySqlPool>,
+    Json(payload): Json<TorneoPayload>,
+) -> Result<Json<TorneoResumen>, (StatusCode, String)> {
+    let error_sql = |e: sqlx::Error| {
+        tracing::error!("❌ SQL en post_torneo: {e}");
+        (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+    };
+
+    let nombre = payload.nombre.trim();
+    if nombre.is_empty() || nombre.chars().count() > MAX_NOMBRE {
+        return Err((StatusCode::BAD_REQUEST, tr!(idioma, "nombre-longitud", max = MAX_NOMBRE)));
+    }
+    let formato = Formato::from_str(&payload.formato)
+        .ok_or_else(|| (StatusCode::BAD_REQUEST, tr!(idioma, "torneo-formato", formato = payload.formato.clone())))?;
+    let plazo = payload.plazo_turno_horas.unwrap_or(crate::correspondencia::PLAZO_TORNEO_HORAS);
+    crate::correspondencia::validar_plazo(idioma, Some(plazo))?;
+
+    let mut ids = payload.participantes.clone();
+    ids.sort_unstable();
+    ids.dedup();
+    if ids.len() != payload.participantes.len() {
+        return Err((StatusCode::BAD_REQUEST, tr!(idioma, "torneo-repetidos")));
+    }
+    if !(MIN_PARTICIPANTES..=MAX_PARTICIPANTES).contains(&ids.len()) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            tr!(idioma, "torneo-participantes", min = MIN_PARTICIPANTES, max = MAX_PARTICIPANTES),
+        ));
+    }
+
+    // Siembra por Elo
+    let mut con_elo = Vec::with_capacity(ids.len());
+    for &uid in &ids {
+        let elo = sqlx::query_scalar!("SELECT elo FROM Usuario WHERE id_usuario = ?", uid)
+            .fetch_optional(&pool)
+            .await
+            .map_err(error_sql)?
+            .ok_or_else(|| (StatusCode::NOT_FOUND, tr!(idioma, "usuario-no-encontrado", usuario = uid)))?;
+        con_elo.push((uid, elo));
+    }
+    con_elo.sort_by_key(|&(uid, elo)| (-elo, uid));
+    let sembrados: Vec<i32> = con_elo.iter().map(|&(uid, _)| uid).collect();
+
+    let cruces = match formato {
+        Formato::Eliminacion => cuadro_eliminacion(&sembrados),
+        Formato::Liga => calendario_liga(&sembrados, payload.ida_y_vuelta),
+    };
+
+    let mut tx = pool.begin().await.map_err(error_sql)?;
+
+    let id_torneo = sqlx::query!(
+        "INSERT INTO Torneo (nombre, formato, ida_y_vuelta, id_creador, plazo_turno_horas) VALUES (?, ?, ?, ?, ?)",
+        nombre,
+        formato.as_str(),
+        payload.ida_y_vuelta && formato == Formato::Liga,
+        payload.id_creador,
+        plazo
+    )
+        .execute(&mut *tx)
+        .await
+        .map_err(error_sql)?
+        .last_insert_id() as i32;
+
+    let mut query = QueryBuilder::<MySql>::new("INSERT INTO TorneoParticipante (id_torneo, id_usuario, semilla) ");
+    query.push_values(sembrados.iter().enumerate(), |mut fila, (i, uid)| {
+        fila.push_bind(id_torneo).push_bind(*uid).push_bind(i as i32 + 1);
+    });
+    query.build().execute(&mut *tx).await.map_err(error_sql)?;
+
+    let mut query = QueryBuilder::<MySql>::new(
+        "INSERT INTO TorneoEncuentro (id_torneo, ronda, posicion, id_jugador1, id_jugador2) ",
+    );
+    query.push_values(&cruces, |mut fila, &(ronda, posicion, j1, j2)| {
+        fila.push_bind(id_torneo).push_bind(ronda).push_bind(posicion).push_bind(j1).push_bind(j2);
+    });
+    query.build().execute(&mut *tx).await.map_err(error_sql)?;
+
+    let nuevas = abrir_ronda(&mut tx, id_torneo, 1).await.map_err(error_sql)?;
+    tx.commit().await.map_err(error_sql)?;
+
+    tracing::info!(
+        "🏆 Torneo {id_torneo} '{nombre}' ({}, {} participantes, {} partidas en la ronda 1)",
+        formato.as_str(),
+        sembrados.len(),
+        nuevas.len()
+    );
+    anunciar(&pool, id_torneo, &nuevas, None).await;
+
+    let torneo = resumen(&pool, id_torneo)
+        .await
+        .map_err(error_sql)?
+        .ok_or_else(|| (StatusCode::NOT_FOUND, tr!(idioma, "torneo-no-encontrado", torneo = id_torneo)))?;
+    Ok(Json(torneo))
+}
+
+// GET /torneos — los más recientes
+pub async fn get_torneos(
+    idioma: Idioma,
+    Extension(pool): Extension<MySqlPool>,
+) -> Result<Json<Vec<TorneoResumen>>, (StatusCode, String)> {
+    let error_sql = |e: sqlx::Error| {
+        tracing::error!("❌ SQL en get_torneos: {e}");
+        (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+    };
+
+    let ids = sqlx::query_scalar!("SELECT id_torneo FROM Torneo ORDER BY id_torneo DESC LIMIT ?", MAX_LISTADO)
+        .fetch_all(&pool)
+        .await
+        .map_err(error_sql)?;
+
+    let mut torneos = Vec::with_capacity(ids.len());
+    for id in ids {
+        if let Some(t) = resumen(&pool, id).await.map_err(error_sql)? {
+            torneos.push(t);
+        }
+    }
+    Ok(Json(torneos))
+}
+
+// GET /torneos/:t — cuadro completo y clasificación
+pub async fn get_torneo(
+    idioma: Idioma,
+    Path(id_torneo): Path<i32>,
+    Extension(pool): Extension<MySqlPool>,
+) -> Result<Json<TorneoDetalle>, (StatusCode, String)> {
+    let error_sql = |e: sqlx::Error| {
+        tracing::error!("❌ SQL en get_torneo: {e}");
+        (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+    };
+
+    let torneo = resumen(&pool, id_torneo)
+        .await
+        .map_err(error_sql)?
+        .ok_or_else(|| (StatusCode::NOT_FOUND, tr!(idioma, "torneo-no-encontrado", torneo = id_torneo)))?;
+
+    let encuentros = sqlx::query_as!(
+        EncuentroVista,
+        r#"
+        SELECT e.ronda, e.posicion,
+               e.id_jugador1, u1.nombre_usuario AS "nombre_jugador1?",
+               e.id_jugador2, u2.nombre_usuario AS "nombre_jugador2?",
+               e.id_partida, p.estado AS "estado_partida?: String",
+               p.gol_j1 AS "gol_j1?", p.gol_j2 AS "gol_j2?", e.ganador
+        FROM   TorneoEncuentro e
+        LEFT JOIN Usuario u1 ON u1.id_usuario = e.id_jugador1
+        LEFT JOIN Usuario u2 ON u2.id_usuario = e.id_jugador2
+        LEFT JOIN Partida p  ON p.id_partida = e.id_partida
+        WHERE  e.id_torneo = ?
+        ORDER  BY e.ronda, e.posicion
+        "#,
+        id_torneo
+    )
+        .fetch_all(&pool)
+        .await
+        .map_err(error_sql)?;
+
+    let formato = Formato::from_str(&torneo.formato).unwrap_or(Formato::Liga);
+    let mut conn = pool.acquire().await.map_err(error_sql)?;
+    let filas = filas_clasificacion(&mut conn, id_torneo).await.map_err(error_sql)?;
+
+    Ok(Json(TorneoDetalle { clasificacion: clasificacion(formato, filas), encuentros, torneo }))
+}
+
+// GET /torneos/:t/clasificacion
+pub async fn get_clasificacion(
+    idioma: Idioma,
+    Path(id_torneo): Path<i32>,
+    Extension(pool): Extension<MySqlPool>,
+) -> Result<Json<Vec<FilaClasificacion>>, (StatusCode, String)> {
+    let error_sql = |e: sqlx::Error| {
+        tracing::error!("❌ SQL en get_clasificacion: {e}");
+        (StatusCode::INTERNAL_SERVER_ERROR, tr!(idioma, "error-interno"))
+    };
+
+    let formato = sqlx::query_scalar!(r#"SELECT formato AS "formato!: String" FROM Torneo WHERE id_torneo = ?"#, id_torneo)
+        .fetch_optional(&pool)
+        .await
+        .map_err(error_sql)?
+        .ok_or_else(|| (StatusCode::NOT_FOUND, tr!(idioma, "torneo-no-encontrado", torneo = id_torneo)))?;
+
+    let mut conn = pool.acquire().await.map_err(error_sql)?;
+    let filas = filas_clasificacion(&mut conn, id_torneo).await.map_err(error_sql)?;
+    Ok(Json(clasificacion(Formato::from_str(&formato).unwrap_or(Formato::Liga), filas)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fila(id_usuario: i32, semilla: i32) -> (i32, String, i32) {
+        (id_usuario, format!("j{id_usuario}"), semilla)
+    }
+
+    #[test]
+    fn siembra_cruza_a_las_mejores_semillas_al_final() {
+        assert_eq!(orden_siembra(1), vec![0]);
+        assert_eq!(orden_siembra(4), vec![0, 3, 1, 2]);
+        assert_eq!(orden_siembra(8), vec![0, 7, 3, 4, 1, 6, 2, 5]);
+    }
+
+    #[test]
+    fn cuadro_completa_con_byes_para_las_mejores_semillas() {
+        let cruces = cuadro_eliminacion(&[10, 20, 30]);
+        assert_eq!(
+            cruces,
+            vec![
+                (1, 0, Some(10), None),
+                (1, 1, Some(20), Some(30)),
+                (2, 0, None, None),
+            ]
+        );
+    }
+
+    #[test]
+    fn cuadro_de_ocho_tiene_siete_cruces() {
+        let sembrados: Vec<i32> = (1..=8).collect();
+        let cruces = cuadro_eliminacion(&sembrados);
+        assert_eq!(cruces.len(), 7);
+        assert_eq!(cruces.iter().filter(|c| c.0 == 1).count(), 4);
+        assert_eq!(cruces.iter().filter(|c| c.0 == 2).count(), 2);
+        assert_eq!(cruces.last(), Some(&(3, 0, None, None)));
+    }
+
+    #[test]
+    fn liga_todos_contra_todos_una_vez() {
+        let sembrados = [1, 2, 3, 4];
+        let cruces = calendario_liga(&sembrados, false);
+        assert_eq!(cruces.len(), 6);
+        assert_eq!(cruces.iter().map(|c| c.0).max(), Some(3));
+
+        let mut parejas: Vec<(i32, i32)> = cruces
+            .iter()
+            .map(|&(_, _, a, b)| (a.unwrap().min(b.unwrap()), a.unwrap().max(b.unwrap())))
+            .collect();
+        parejas.sort_unstable();
+        parejas.dedup();
+        assert_eq!(parejas.len(), 6);
+    }
+
+    #[test]
+    fn liga_impar_deja_uno_descansando_cada_ronda() {
+        let cruces = calendario_liga(&[1, 2, 3], false);
+        assert_eq!(cruces.len(), 3);
+        for ronda in 1..=3 {
+            assert_eq!(cruces.iter().filter(|c| c.0 == ronda).count(), 1);
+        }
+    }
+
+    #[test]
+    fn ida_y_vuelta_cambia_los_lados() {
+        let cruces = calendario_liga(&[1, 2], true);
+        assert_eq!(cruces, vec![(1, 0, Some(1), Some(2)), (2, 0, Some(2), Some(1))]);
+    }
+
+    #[test]
+    fn clasificacion_de_liga_por_puntos_y_diferencia() {
+        let filas = FilasTorneo {
+            participantes: vec![fila(1, 1), fila(2, 2), fila(3, 3)],
+            cruces: vec![
+                (1, Some(1), Some(2), Some(2), Some((0, 2))),
+                (2, Some(2), Some(3), None, Some((1, 1))),
+                (3, Some(3), Some(1), Some(3), Some((3, 0))),
+            ],
+        };
+        let tabla = clasificacion(Formato::Liga, filas);
+        let orden: Vec<i32> = tabla.iter().map(|f| f.id_usuario).collect();
+        // 3 y 2 empatan a 4 puntos; 3 tiene mejor diferencia (+3 frente a +2)
+        assert_eq!(orden, vec![3, 2, 1]);
+        assert_eq!(tabla[0].puntos, 4);
+        assert_eq!((tabla[1].ganadas, tabla[1].empatadas, tabla[1].perdidas), (1, 1, 0));
+        assert_eq!((tabla[2].goles_a_favor, tabla[2].goles_en_contra), (0, 5));
+    }
+
+    #[test]
+    fn cruce_sin_ganador_elimina_a_los_dos() {
+        let filas = FilasTorneo {
+            participantes: vec![fila(1, 1), fila(2, 2), fila(3, 3), fila(4, 4)],
+            cruces: vec![
+                (1, Some(1), Some(4), None, Some((1, 1))),
+                (1, Some(2), Some(3), Some(2), Some((2, 0))),
+                (2, None, Some(2), Some(2), None),
+            ],
+        };
+        let tabla = clasificacion(Formato::Eliminacion, filas);
+        assert_eq!(tabla[0].id_usuario, 2);
+        assert!(!tabla[0].eliminado);
+        assert!(tabla[1..].iter().all(|f| f.eliminado));
+    }
+
+    #[test]
+    fn en_liga_nadie_queda_eliminado() {
+        let filas = FilasTorneo {
+            participantes: vec![fila(1, 1), fila(2, 2)],
+            cruces: vec![(1, Some(1), Some(2), Some(2), Some((0, 1)))],
+        };
+        assert!(clasificacion(Formato::Liga, filas).iter().all(|f| !f.eliminado));
+    }
+
+    #[test]
+    fn partidas_sin_terminar_no_suman() {
+        let filas = FilasTorneo {
+            participantes: vec![fila(1, 1), fila(2, 2)],
+            cruces: vec![(1, Some(1), Some(2), None, None)],
+        };
+        let tabla = clasificacion(Formato::Liga, filas);
+        assert!(tabla.iter().all(|f| f.jugadas == 0 && f.puntos == 0));
+    }
+
+    #[test]
+    fn clasificacion_de_eliminacion_por_ronda_alcanzada() {
+        let filas = FilasTorneo {
+            participantes: vec![fila(1, 1), fila(2, 2), fila(3, 3), fila(4, 4)],
+            cruces: vec![
+                (1, Some(1), Some(4), Some(1), Some((2, 0))),
+                (1, Some(2), Some(3), Some(3), Some((0, 1))),
+                (2, Some(1), Some(3), Some(3), Some((1, 2))),
+            ],
+        };
+        let tabla = clasificacion(Formato::Eliminacion, filas);
+        let orden: Vec<i32> = tabla.iter().map(|f| f.id_usuario).collect();
+        assert_eq!(orden, vec![3, 1, 2, 4]);
+        assert!(!tabla[0].eliminado);
+        assert!(tabla[1].eliminado);
+        assert_eq!(tabla[0].ronda_alcanzada, 2);
+    }
+}
@@ -7,6 +7,14 @@ const maxReconnectAttempts = 5;
 const WS_URL = (window.location.protocol === "https:" ? "wss://" : "ws://") + window.location.host;
 
 async function main() {
+    // 🪑 Modo local / contra la IA: sin snapshot ni WebSocket, todo ocurre dentro de Bevy
+    const modo = localStorage.getItem("rb_modo");
+    if (modo === "local" || modo === "bot") {
+        await initWasm();
+        console.log("✅ WASM inicializado en modo local");
+        return;
+    }
+
     const pid = localStorage.getItem("rb_pid");
     const uid = Number(localStorage.getItem("rb_uid"));
 
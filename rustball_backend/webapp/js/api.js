@@ -1,11 +1,13 @@
 // js/api.js
+import { idioma } from "./i18n.js";
+
 const BASE = "https://rustball.lat/api"; // ✅ Cambiado para entorno en producción
 
 /* POST con body JSON */
 export async function post(path, payload) {
     const res = await fetch(`${BASE}${path}`, {
         method: "POST",
-        headers: { "Content-Type": "application/json" },
+        headers: { "Content-Type": "application/json", "Accept-Language": idioma() },
         body: JSON.stringify(payload),
     });
 
@@ -23,7 +25,23 @@ export async function post(path, payload) {
 
 /* GET simple */
 export async function get(path) {
-    const res = await fetch(`${BASE}${path}`);
+    const res = await fetch(`${BASE}${path}`, { headers: { "Accept-Language": idioma() } });
+
+    const contentType = res.headers.get("content-type");
+    const data = contentType && contentType.includes("application/json")
+        ? await res.json()
+        : await res.text();
+
+    if (!res.ok) {
+        throw new Error(typeof data === "string" ? data : data?.error || res.statusText);
+    }
+
+    return data;
+}
+
+/* DELETE simple */
+export async function del(path) {
+    const res = await fetch(`${BASE}${path}`, { method: "DELETE", headers: { "Accept-Language": idioma() } });
 
     const contentType = res.headers.get("content-type");
     const data = contentType && contentType.includes("application/json")
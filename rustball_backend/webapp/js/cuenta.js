@@ -0,0 +1,66 @@
+/*  Página de los enlaces de la cuenta (ver backend `cuentas.rs`):
+ *    ?verificar=TOKEN    verifica el correo en cuanto se abre
+ *    ?restablecer=TOKEN  pide la contraseña nueva
+ *    sin parámetros      pide el enlace para restablecer             */
+import { post } from "./api.js";
+import { t } from "./i18n.js";
+
+document.addEventListener("DOMContentLoaded", async () => {
+    const $   = (id) => document.getElementById(id);
+    const log = (msg) => { $("resultado").textContent = msg; };
+
+    const titulo = (clave) => { $("cuenta-titulo").dataset.i18n = clave; $("cuenta-titulo").textContent = t(clave); };
+
+    const params = new URLSearchParams(window.location.search);
+    const tokenVerificar   = params.get("verificar");
+    const tokenRestablecer = params.get("restablecer");
+
+    /* ✉️ Verificar correo: no hace falta que el usuario toque nada */
+    if (tokenVerificar) {
+        titulo("cuenta.titulo-verificar");
+        log(t("cuenta.verificando"));
+        try {
+            await post("/cuenta/verificar", { token: tokenVerificar });
+            log(t("cuenta.verificado"));
+        } catch (e) {
+            log(`❌ ${e.message}`);
+        }
+        return;
+    }
+
+    /* 🔑 Contraseña nueva con el token del enlace */
+    if (tokenRestablecer) {
+        titulo("cuenta.titulo-restablecer");
+        $("form-nueva").hidden = false;
+        $("btn-cambiar").addEventListener("click", async () => {
+            const contrasena = $("cuenta-contra").value.trim();    // igual que el login
+            if (!contrasena) return log(t("comun.campos"));
+            if (contrasena !== $("cuenta-repetir").value.trim()) return log(t("cuenta.no-coinciden"));
+
+            try {
+                log(t("comun.enviando"));
+                const r = await post("/cuenta/restablecer/confirmar", { token: tokenRestablecer, contrasena });
+                log(`✅ ${r.mensaje}`);
+                $("form-nueva").hidden = true;
+            } catch (e) {
+                log(`❌ ${e.message}`);
+            }
+        });
+        return;
+    }
+
+    /* 📨 Pedir el enlace */
+    $("form-pedir").hidden = false;
+    $("btn-pedir").addEventListener("click", async () => {
+        const correo = $("cuenta-correo").value.trim();
+        if (!correo) return log(t("comun.campos"));
+
+        try {
+            log(t("comun.enviando"));
+            const r = await post("/cuenta/restablecer", { correo });
+            log(`📨 ${r.mensaje}`);
+        } catch (e) {
+            log(`❌ ${e.message}`);
+        }
+    });
+});
@@ -1,16 +1,20 @@
+import { idioma, t } from "./i18n.js";
+
 document.addEventListener("DOMContentLoaded", async () => {
     const user = JSON.parse(localStorage.getItem("rb_user"));
     const idUsuario = user?.id_usuario;
 
     if (!idUsuario) {
-        alert("⚠️ No se encontró el usuario en sesión");
+        alert(t("comun.sin-sesion"));
         window.location.href = "login.html";
         return;
     }
 
     try {
-        const response = await fetch(`/api/estadisticas/${idUsuario}`);
-        if (!response.ok) throw new Error("Error al cargar estadísticas");
+        const response = await fetch(`/api/estadisticas/${idUsuario}`, {
+            headers: { "Accept-Language": idioma() },
+        });
+        if (!response.ok) throw new Error(await response.text());
 
         const data = await response.json();
 
@@ -18,8 +22,18 @@ document.addEventListener("DOMContentLoaded", async () => {
         document.getElementById("ganadas").textContent = data.partidas_ganadas ?? 0;
         document.getElementById("goles_favor").textContent = data.goles_a_favor ?? 0;
         document.getElementById("goles_contra").textContent = data.goles_en_contra ?? 0;
+        document.getElementById("tiros").textContent = data.tiros ?? 0;
+        document.getElementById("tiros_por_gol").textContent = data.tiros_por_gol?.toFixed(1) ?? "–";
+        document.getElementById("potencia_media").textContent = porcentaje(data.potencia_media);
+        document.getElementById("formacion_favorita").textContent = data.formacion_favorita ?? "–";
+        document.getElementById("powerups_usados").textContent = data.powerups_usados ?? 0;
     } catch (err) {
         console.error(err);
-        alert("❌ Error cargando estadísticas");
+        alert(t("estadisticas.error"));
     }
 });
+
+/* Potencia 0‥1 → "73%" */
+function porcentaje(valor) {
+    return valor == null ? "–" : `${Math.round(valor * 100)}%`;
+}
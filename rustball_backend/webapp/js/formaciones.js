@@ -0,0 +1,198 @@
+// js/formaciones.js
+// Editor de formaciones personalizadas: 5 fichas arrastrables sobre el
+// campo propio (coordenadas canónicas del lado derecho, como en el
+// backend). Las reglas son las mismas que `validar_posiciones`.
+import { get, post, del } from "./api.js";
+import { t } from "./i18n.js";
+
+const HALF_W = 550;        // mitad del ancho de la cancha
+const HALF_H = 370.5;      // mitad del alto
+const RADIO = 35;          // radio de ficha
+const AREA_DEPTH = 100;    // profundidad del área
+const AREA_HALF_H = 150;   // mitad del alto del área
+
+const BASE = [[400, 0], [300, 100], [300, -100], [200, 0], [100, 0]];
+
+const $ = (id) => document.getElementById(id);
+
+const user = JSON.parse(localStorage.getItem("rb_user"));
+const idUsuario = user?.id_usuario;
+
+let fichas = BASE.map(([x, y]) => ({ x, y }));
+let arrastrando = null;
+
+/* ───── Coordenadas canvas ↔ cancha ───── */
+// El canvas muestra x ∈ [0, HALF_W] y y ∈ [-HALF_H, HALF_H] (y hacia arriba)
+const aCanvas = (p) => ({ cx: p.x, cy: HALF_H - p.y });
+const aCancha = (cx, cy) => ({ x: cx, y: HALF_H - cy });
+
+/* ───── Validación (misma que el backend) ───── */
+function validar(ps) {
+    if (ps.length !== 5) return t("formaciones.cantidad", { n: ps.length });
+
+    for (let i = 0; i < ps.length; i++) {
+        const p = ps[i];
+        const n = i + 1;
+        if (p.x - RADIO < 0) return t("formaciones.campo", { n });
+        if (p.x + RADIO > HALF_W || Math.abs(p.y) + RADIO > HALF_H) return t("formaciones.fuera", { n });
+        if (p.x + RADIO > HALF_W - AREA_DEPTH && Math.abs(p.y) - RADIO < AREA_HALF_H) {
+            return t("formaciones.area", { n });
+        }
+        for (let j = i + 1; j < ps.length; j++) {
+            if (Math.hypot(p.x - ps[j].x, p.y - ps[j].y) < 2 * RADIO) return t("formaciones.superpuestas", { a: n, b: j + 1 });
+        }
+    }
+    return null;
+}
+
+/* ───── Dibujo ───── */
+function dibujar() {
+    const ctx = $("cancha").getContext("2d");
+    ctx.clearRect(0, 0, HALF_W, 2 * HALF_H);
+
+    // Línea media (izquierda) y área (derecha)
+    ctx.strokeStyle = "white";
+    ctx.lineWidth = 3;
+    ctx.beginPath();
+    ctx.moveTo(1, 0);
+    ctx.lineTo(1, 2 * HALF_H);
+    ctx.stroke();
+    ctx.fillStyle = "rgba(255, 80, 80, 0.25)";
+    ctx.fillRect(HALF_W - AREA_DEPTH, HALF_H - AREA_HALF_H, AREA_DEPTH, 2 * AREA_HALF_H);
+    ctx.strokeRect(HALF_W - AREA_DEPTH, HALF_H - AREA_HALF_H, AREA_DEPTH, 2 * AREA_HALF_H);
+
+    const error = validar(fichas);
+    fichas.forEach((p, i) => {
+        const { cx, cy } = aCanvas(p);
+        ctx.beginPath();
+        ctx.arc(cx, cy, RADIO, 0, 2 * Math.PI);
+        ctx.fillStyle = error ? "#c62828" : "#1565c0";
+        ctx.fill();
+        ctx.stroke();
+        ctx.fillStyle = "white";
+        ctx.font = "20px sans-serif";
+        ctx.textAlign = "center";
+        ctx.textBaseline = "middle";
+        ctx.fillText(String(i + 1), cx, cy);
+    });
+
+    $("resultado").textContent = error ? `⚠️ ${error}` : t("formaciones.valida");
+}
+
+/* ───── Arrastre ───── */
+function puntoEvento(ev) {
+    const canvas = $("cancha");
+    const r = canvas.getBoundingClientRect();
+    const escala = canvas.width / r.width;
+    return aCancha((ev.clientX - r.left) * escala, (ev.clientY - r.top) * escala);
+}
+
+function activarArrastre() {
+    const canvas = $("cancha");
+
+    canvas.addEventListener("pointerdown", (ev) => {
+        const p = puntoEvento(ev);
+        arrastrando = fichas.findIndex((f) => Math.hypot(f.x - p.x, f.y - p.y) <= RADIO);
+        if (arrastrando < 0) arrastrando = null;
+        else canvas.setPointerCapture(ev.pointerId);
+    });
+
+    canvas.addEventListener("pointermove", (ev) => {
+        if (arrastrando === null) return;
+        const p = puntoEvento(ev);
+        fichas[arrastrando] = {
+            x: Math.round(Math.min(Math.max(p.x, RADIO), HALF_W - RADIO)),
+            y: Math.round(Math.min(Math.max(p.y, -HALF_H + RADIO), HALF_H - RADIO)),
+        };
+        dibujar();
+    });
+
+    canvas.addEventListener("pointerup", () => { arrastrando = null; });
+}
+
+/* ───── Lista ───── */
+async function cargarLista() {
+    const ul = $("lista-formaciones");
+    ul.innerHTML = "";
+
+    try {
+        const lista = await get(`/formaciones/${idUsuario}`);
+        if (lista.length === 0) {
+            ul.innerHTML = `<li>${t("formaciones.vacio")}</li>`;
+            return;
+        }
+
+        for (const f of lista) {
+            const li = document.createElement("li");
+            li.textContent = `${f.nombre} `;
+
+            const editar = document.createElement("button");
+            editar.textContent = t("formaciones.editar");
+            editar.addEventListener("click", () => {
+                fichas = f.posiciones.map(({ x, y }) => ({ x, y }));
+                $("nombre").value = f.nombre;
+                dibujar();
+            });
+
+            const borrar = document.createElement("button");
+            borrar.textContent = t("formaciones.borrar");
+            borrar.addEventListener("click", async () => {
+                if (!confirm(t("formaciones.confirmar-borrar", { nombre: f.nombre }))) return;
+                try {
+                    await del(`/formaciones/${idUsuario}/${f.id_formacion}`);
+                    await cargarLista();
+                } catch (err) {
+                    $("resultado").textContent = `❌ ${err.message}`;
+                }
+            });
+
+            li.append(editar, borrar);
+            ul.appendChild(li);
+        }
+    } catch (err) {
+        console.error(err);
+        ul.innerHTML = `<li>${t("formaciones.error-lista")}</li>`;
+    }
+}
+
+/* ───── Guardar ───── */
+async function guardar() {
+    const nombre = $("nombre").value.trim();
+    if (!nombre) {
+        $("resultado").textContent = t("formaciones.sin-nombre");
+        return;
+    }
+
+    const error = validar(fichas);
+    if (error) {
+        $("resultado").textContent = `⚠️ ${error}`;
+        return;
+    }
+
+    try {
+        const f = await post("/formaciones", { id_usuario: idUsuario, nombre, posiciones: fichas });
+        $("resultado").textContent = t("formaciones.guardada", { nombre: f.nombre });
+        await cargarLista();
+    } catch (err) {
+        $("resultado").textContent = `❌ ${err.message}`;
+    }
+}
+
+document.addEventListener("DOMContentLoaded", () => {
+    if (!idUsuario) {
+        alert(t("comun.sin-sesion"));
+        window.location.href = "login.html";
+        return;
+    }
+
+    activarArrastre();
+    $("btn-guardar").addEventListener("click", guardar);
+    $("btn-nueva").addEventListener("click", () => {
+        fichas = BASE.map(([x, y]) => ({ x, y }));
+        $("nombre").value = "";
+        dibujar();
+    });
+
+    dibujar();
+    cargarLista();
+});
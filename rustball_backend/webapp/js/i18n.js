@@ -0,0 +1,567 @@
+// js/i18n.js
+// Textos de la webapp en español e inglés.
+//
+//   • Idioma: el guardado en `rb_lang` (lo deja el login con el del perfil,
+//     o el selector del lobby); si no hay, el del navegador; si no, español.
+//     El juego (Bevy) lee la misma clave.
+//   • En el HTML: `data-i18n="clave"` (texto) y `data-i18n-placeholder="clave"`.
+//   • En JS: `t("clave", { var: valor })` reemplaza `{var}`.
+
+const TEXTOS = {
+    es: {
+        "comun.esperando": "Esperando acción…",
+        "comun.volver": "⬅️ Volver",
+        "comun.volver-lobby": "⬅️ Volver al Lobby",
+        "comun.sin-sesion": "⚠️ No se encontró el usuario en sesión",
+        "comun.error-servidor": "⚠️ Error al conectar con el servidor.",
+        "comun.campos": "⚠️ Completa todos los campos.",
+        "comun.enviando": "🔄 Enviando datos…",
+        "comun.usuario": "Nombre de usuario",
+        "comun.contrasena": "Contraseña",
+        "comun.idioma": "Idioma",
+
+        "login.titulo": "🔐 Iniciar Sesión",
+        "login.entrar": "Entrar",
+        "login.sin-cuenta": "¿No tienes cuenta?",
+        "login.registrarse": "Registrarse",
+        "login.ok": "✅ Sesión iniciada correctamente.",
+        "login.error": "Credenciales incorrectas",
+        "login.olvido": "¿Olvidaste tu contraseña?",
+
+        "registro.titulo": "👤 Registro de Usuario",
+        "registro.correo": "Correo",
+        "registro.boton": "Registrarse",
+        "registro.con-cuenta": "¿Ya tienes cuenta?",
+        "registro.iniciar": "Iniciar sesión",
+        "registro.ok": "✅ Registro exitoso. Te enviamos un enlace para verificar tu correo. Redirigiendo…",
+        "registro.error": "Error al registrar usuario",
+
+        "lobby.titulo": "🎮 Buscar/Iniciar Partida",
+        "lobby.sesion": "Sesión iniciada como:",
+        "lobby.rival": "ID Usuario rival",
+        "lobby.partida": "Buscar/Iniciar Partida",
+        "lobby.local": "🪑 Jugar en local (misma pantalla)",
+        "lobby.facil": "Fácil",
+        "lobby.medio": "Medio",
+        "lobby.dificil": "Difícil",
+        "lobby.bot": "🤖 Jugar contra la máquina",
+        "lobby.bot-online": "🌐 Retar al bot (online)",
+        "lobby.estadisticas": "📊 Ver Estadísticas",
+        "lobby.partidas": "📁 Ver Mis Partidas",
+        "lobby.formaciones": "🧩 Mis Formaciones",
+        "lobby.perfil": "👤 Mi perfil",
+        "lobby.torneos": "🏆 Torneos",
+        "lobby.clasificacion": "📈 Clasificación",
+        "lobby.retos": "📬 Retos pendientes",
+        "lobby.cargando": "Cargando...",
+        "lobby.sin-retos": "No tienes retos pendientes.",
+        "lobby.error-retos": "Error al cargar retos.",
+        "lobby.reto": "🎮 Jugador {rival} te ha desafiado.",
+        "lobby.aceptar": "Aceptar",
+        "lobby.falta-rival": "⚠️ Ingresa el ID del rival.",
+        "lobby.creando": "🔄 Enviando reto…",
+        "lobby.creando-bot": "🔄 Creando partida contra el bot…",
+        "lobby.sin-bot": "⚠️ No hay bot con esa dificultad.",
+        "lobby.como-jugar": "📖 ¿Cómo Jugar?",
+        "lobby.explicacion": "Rustball es un juego multijugador de fútbol asincrónico. Es decir, funciona por turnos y de manera no simultánea.",
+        "lobby.controles": "Controles (se pueden cambiar en Ajustes dentro del juego):",
+        "lobby.control-flechas": "Flechas: Controlan la trayectoria del jugador",
+        "lobby.control-espacio": "Barra espaciadora: Controla la potencia del disparo",
+        "lobby.control-tab": "Tabulador: Cambia de jugador",
+        "lobby.idioma-guardado": "✅ Idioma guardado.",
+        "lobby.rechazar": "Rechazar",
+        "lobby.cancelar": "Cancelar",
+        "lobby.reto-enviado": "⚔️ Reto enviado a {rival}. Te avisamos cuando lo acepte.",
+        "lobby.reto-esperando": "⏳ Esperando a que {rival} acepte tu reto.",
+        "lobby.tiempo-real": "⚡ Tiempo real",
+        "lobby.plazo-1-dia": "📬 Correspondencia: 1 día por turno",
+        "lobby.plazo-3-dias": "📬 Correspondencia: 3 días por turno",
+        "lobby.plazo-7-dias": "📬 Correspondencia: 7 días por turno",
+        "lobby.correspondencia": "por correspondencia, {horas} h por turno",
+        "lobby.bandeja": "⏳ Tu turno",
+        "lobby.bandeja-vacia": "No te toca mover en ninguna partida.",
+        "lobby.bandeja-error": "Error al cargar tus partidas.",
+        "lobby.bandeja-tirar": "⚽ Partida #{partida} contra {rival} ({marcador}): te toca tirar.",
+        "lobby.bandeja-formacion": "🧩 Partida #{partida} contra {rival} ({marcador}): falta tu formación.",
+        "lobby.bandeja-plazo": "vence el {fecha}",
+        "lobby.jugar": "▶️ Jugar",
+        "lobby.aviso-turno": "📬 Te toca mover en la partida #{partida}.",
+        "lobby.aviso-vencida-ganada": "⌛ Tu rival dejó vencer el plazo: ganaste la partida #{partida}.",
+        "lobby.aviso-vencida-perdida": "⌛ Se venció tu plazo: perdiste la partida #{partida}.",
+        "lobby.aviso-vencida-anulada": "⌛ Nadie movió a tiempo: se anuló la partida #{partida}.",
+        "lobby.aviso-reto": "🔔 {rival} te ha retado.",
+        "lobby.aviso-aceptado": "🔔 {rival} aceptó tu reto: la partida está en Tu turno.",
+        "lobby.aviso-rechazado": "🔔 {rival} rechazó tu reto.",
+        "lobby.aviso-torneo": "🏆 Tienes una partida nueva en un torneo.",
+        "lobby.aviso-torneo-fin": "🏆 Terminó un torneo en el que jugabas.",
+        "lobby.aviso-logro": "🏅 ¡Logro desbloqueado: {logro}!",
+
+        "amigos.titulo": "🤝 Amigos",
+        "amigos.buscar": "Buscar por nombre",
+        "amigos.boton-buscar": "🔍 Buscar",
+        "amigos.sin-resultados": "No hay usuarios con ese nombre.",
+        "amigos.agregar": "➕ Agregar",
+        "amigos.retar": "⚔️ Retar",
+        "amigos.quitar": "🗑️ Quitar",
+        "amigos.confirmar-quitar": "¿Quitar a {nombre} de tus amigos?",
+        "amigos.vacio": "Todavía no tienes amigos agregados.",
+        "amigos.solicitud": "🤝 {nombre} quiere ser tu amigo.",
+        "amigos.enviada": "⏳ Solicitud enviada a {nombre}.",
+        "amigos.solicitud-enviada": "✅ Solicitud enviada a {nombre}.",
+        "amigos.aviso-solicitud": "🔔 {nombre} te envió una solicitud de amistad.",
+        "amigos.aviso-aceptada": "🔔 {nombre} aceptó tu solicitud de amistad.",
+        "amigos.error": "Error al cargar amigos.",
+        "amigos.en_linea": "🟢 En línea",
+        "amigos.ausente": "🟡 Ausente",
+        "amigos.en_partida": "⚽ Jugando (#{partida})",
+        "amigos.desconectado": "⚫ Desconectado",
+
+        "partidas.titulo": "📁 Mis Partidas",
+        "partidas.vacio": "No tienes partidas registradas.",
+        "partidas.fila": "Partida #{partida} vs Jugador #{rival}",
+        "partidas.continuar": "Continuar",
+        "partidas.error": "❌ Error al cargar partidas.",
+        "partidas.error-continuar": "No se pudo continuar la partida.",
+        "partidas.estadisticas": "📊 Estadísticas",
+        "partidas.perfil-rival": "👤 Perfil del rival",
+        "partidas.tiros": "Tiros",
+        "partidas.potencia": "Potencia media",
+        "partidas.contactos": "Toques a la pelota",
+        "partidas.rebotes": "Rebotes",
+        "partidas.powerups": "Power-ups (recogidos / usados)",
+        "partidas.zonas": "Zonas pisadas",
+        "partidas.goles": "Goles",
+        "partidas.error-estadisticas": "❌ Error cargando las estadísticas de la partida.",
+
+        "estadisticas.titulo": "📊 Estadísticas del Jugador",
+        "estadisticas.jugadas": "Partidas Jugadas:",
+        "estadisticas.ganadas": "Partidas Ganadas:",
+        "estadisticas.favor": "Goles a Favor:",
+        "estadisticas.contra": "Goles en Contra:",
+        "estadisticas.tiros": "Tiros:",
+        "estadisticas.tiros-por-gol": "Tiros por gol:",
+        "estadisticas.potencia": "Potencia media:",
+        "estadisticas.formacion": "Formación favorita:",
+        "estadisticas.powerups": "Power-ups usados:",
+        "estadisticas.error": "❌ Error cargando estadísticas",
+
+        "perfil.titulo": "👤 Perfil",
+        "perfil.desde": "Jugando desde:",
+        "perfil.elo": "Elo:",
+        "perfil.jugadas": "Partidas:",
+        "perfil.balance": "Ganadas / empatadas / perdidas:",
+        "perfil.goles": "Goles a favor / en contra:",
+        "perfil.forma": "Forma reciente:",
+        "perfil.forma-G": "G",
+        "perfil.forma-E": "E",
+        "perfil.forma-P": "P",
+        "perfil.ultimas": "🕒 Últimas partidas",
+        "perfil.rival": "Rival",
+        "perfil.resultado": "Resultado",
+        "perfil.sin-partidas": "Todavía no hay partidas terminadas.",
+        "perfil.cara-a-cara": "⚔️ Cara a cara contra {rival}",
+        "perfil.error": "❌ Error cargando el perfil",
+        "perfil.logros": "🏅 Logros",
+        "perfil.temporadas": "🗓️ Temporadas",
+        "perfil.sin-temporadas": "Todavía no jugó ninguna temporada.",
+        "perfil.avisos": "🔔 Avisos",
+        "perfil.avisos-explicacion": "Te avisamos por correo o webhook aunque no tengas el lobby abierto.",
+        "perfil.aviso-correo": "Por correo",
+        "perfil.aviso-webhook": "URL de webhook (opcional)",
+        "perfil.aviso-reto": "Retos recibidos",
+        "perfil.aviso-turno": "Mi turno (partidas por correspondencia)",
+        "perfil.aviso-partida-terminada": "Partidas terminadas",
+        "perfil.guardar-avisos": "💾 Guardar avisos",
+        "perfil.avisos-guardados": "✅ Avisos guardados.",
+        "perfil.correo-verificado": "✅ Correo verificado.",
+        "perfil.correo-sin-verificar": "⚠️ Correo sin verificar: hasta que abras el enlace que te enviamos, los avisos no van por correo.",
+        "perfil.reenviar-verificacion": "✉️ Reenviar enlace",
+        "perfil.verificacion-enviada": "✉️ Te enviamos un enlace nuevo.",
+
+        "cuenta.titulo": "🔑 Recuperar cuenta",
+        "cuenta.titulo-restablecer": "🔑 Nueva contraseña",
+        "cuenta.titulo-verificar": "✉️ Verificar correo",
+        "cuenta.explicacion": "Escribe el correo de tu cuenta y te enviaremos un enlace para elegir una contraseña nueva.",
+        "cuenta.correo": "Correo",
+        "cuenta.enviar": "Enviar enlace",
+        "cuenta.nueva": "Contraseña nueva",
+        "cuenta.repetir": "Repite la contraseña",
+        "cuenta.cambiar": "Cambiar contraseña",
+        "cuenta.no-coinciden": "⚠️ Las contraseñas no coinciden.",
+        "cuenta.verificando": "🔄 Verificando tu correo…",
+        "cuenta.verificado": "✅ Correo verificado. Ya puedes cerrar esta página.",
+        "cuenta.ir-login": "Iniciar sesión",
+
+        "torneos.titulo": "🏆 Torneos",
+        "torneos.crear": "➕ Nuevo torneo",
+        "torneos.nombre": "Nombre del torneo",
+        "torneos.eliminacion": "Eliminación directa",
+        "torneos.liga": "Liga (todos contra todos)",
+        "torneos.ida-y-vuelta": "Ida y vuelta",
+        "torneos.elegir": "Participantes (tú incluido si quieres jugar):",
+        "torneos.otros": "Otros IDs, separados por comas",
+        "torneos.boton-crear": "🏆 Crear torneo",
+        "torneos.recientes": "📋 Torneos recientes",
+        "torneos.vacio": "Todavía no hay torneos.",
+        "torneos.error": "Error al cargar torneos.",
+        "torneos.participantes": "{n} participantes",
+        "torneos.en-curso": "⏳ Ronda {ronda} de {total}",
+        "torneos.campeon": "🏆 Campeón: {nombre}",
+        "torneos.sin-campeon": "🏁 Terminado sin campeón",
+        "torneos.clasificacion": "📊 Clasificación",
+        "torneos.cuadro": "🗂️ Partidas",
+        "torneos.volver": "⬅️ Todos los torneos",
+        "torneos.jugador": "Jugador",
+        "torneos.puntos": "Pts",
+        "torneos.ronda-alcanzada": "Ronda",
+        "torneos.ronda": "Ronda {ronda}",
+        "torneos.semifinal": "Semifinal",
+        "torneos.final": "Final",
+        "torneos.por-definir": "por definir",
+        "torneos.bye": "pasa sin jugar",
+        "torneos.jugando": "(partida #{partida})",
+        "torneos.tu-partida": "⚽ Te toca jugar contra {rival}.",
+        "torneos.jugar": "▶️ Jugar",
+
+        "temporadas.titulo": "📈 Clasificación",
+        "temporadas.temporada": "Temporada",
+        "temporadas.activa": "en curso",
+        "temporadas.hasta": "Del {inicio} al {fin} · {jugadores} jugadores",
+        "temporadas.cerrada": "Cerrada: del {inicio} al {fin} · {jugadores} jugadores",
+        "temporadas.vacia": "Nadie jugó todavía en esta temporada.",
+        "temporadas.error": "Error al cargar la clasificación.",
+        "temporadas.maximo": "Máximo",
+        "temporadas.anterior": "⬅️ Anteriores",
+        "temporadas.siguiente": "Siguientes ➡️",
+
+        "logros.desbloqueado": "Desbloqueado el {fecha}",
+        "logros.primera_victoria": "Primera victoria",
+        "logros.primera_victoria-desc": "Gana una partida.",
+        "logros.hat_trick": "Hat-trick",
+        "logros.hat_trick-desc": "Marca 3 goles en una partida.",
+        "logros.porteria_a_cero": "Portería a cero",
+        "logros.porteria_a_cero-desc": "Gana sin recibir goles.",
+        "logros.gol_de_poste": "Gol de poste",
+        "logros.gol_de_poste-desc": "Marca un gol después de que la pelota rebote en un poste.",
+        "logros.ganar_con": "Ganar con la {formacion}",
+        "logros.ganar_con-desc": "Gana una partida usando la formación {formacion}.",
+        "logros.coleccionista": "Coleccionista de power-ups",
+        "logros.coleccionista-desc": "Recoge 10 power-ups.",
+
+        "formaciones.titulo": "🧩 Mis Formaciones",
+        "formaciones.ayuda": "Arrastra las 5 fichas dentro de tu campo. El arco propio está a la derecha; ninguna ficha puede quedar dentro del área ni encima de otra.",
+        "formaciones.nombre": "Nombre de la formación",
+        "formaciones.guardar": "💾 Guardar",
+        "formaciones.nueva": "➕ Nueva",
+        "formaciones.guardadas": "Guardadas",
+        "formaciones.vacio": "Todavía no guardaste ninguna formación.",
+        "formaciones.error-lista": "❌ Error cargando formaciones",
+        "formaciones.editar": "✏️ Editar",
+        "formaciones.borrar": "🗑️ Borrar",
+        "formaciones.confirmar-borrar": "¿Borrar la formación \"{nombre}\"?",
+        "formaciones.sin-nombre": "⚠️ Ponle un nombre a la formación",
+        "formaciones.guardada": "✅ Formación \"{nombre}\" guardada",
+        "formaciones.valida": "✅ Formación válida",
+        "formaciones.cantidad": "La formación debe tener 5 fichas (tiene {n})",
+        "formaciones.coordenadas": "Ficha {n}: coordenadas inválidas",
+        "formaciones.campo": "Ficha {n}: debe quedar entera en tu campo",
+        "formaciones.fuera": "Ficha {n}: queda fuera de la cancha",
+        "formaciones.area": "Ficha {n}: no puede estar dentro del área",
+        "formaciones.superpuestas": "Fichas {a} y {b}: se superponen",
+    },
+    en: {
+        "comun.esperando": "Waiting for action…",
+        "comun.volver": "⬅️ Back",
+        "comun.volver-lobby": "⬅️ Back to Lobby",
+        "comun.sin-sesion": "⚠️ No user session found",
+        "comun.error-servidor": "⚠️ Could not reach the server.",
+        "comun.campos": "⚠️ Fill in every field.",
+        "comun.enviando": "🔄 Sending…",
+        "comun.usuario": "Username",
+        "comun.contrasena": "Password",
+        "comun.idioma": "Language",
+
+        "login.titulo": "🔐 Log In",
+        "login.entrar": "Log in",
+        "login.sin-cuenta": "Don't have an account?",
+        "login.registrarse": "Sign up",
+        "login.ok": "✅ Logged in.",
+        "login.error": "Wrong credentials",
+        "login.olvido": "Forgot your password?",
+
+        "registro.titulo": "👤 Sign Up",
+        "registro.correo": "Email",
+        "registro.boton": "Sign up",
+        "registro.con-cuenta": "Already have an account?",
+        "registro.iniciar": "Log in",
+        "registro.ok": "✅ Signed up. We sent you a link to verify your email. Redirecting…",
+        "registro.error": "Could not sign up",
+
+        "lobby.titulo": "🎮 Find/Start a Match",
+        "lobby.sesion": "Logged in as:",
+        "lobby.rival": "Opponent user ID",
+        "lobby.partida": "Find/Start Match",
+        "lobby.local": "🪑 Play locally (same screen)",
+        "lobby.facil": "Easy",
+        "lobby.medio": "Medium",
+        "lobby.dificil": "Hard",
+        "lobby.bot": "🤖 Play against the computer",
+        "lobby.bot-online": "🌐 Challenge the bot (online)",
+        "lobby.estadisticas": "📊 Statistics",
+        "lobby.partidas": "📁 My Matches",
+        "lobby.formaciones": "🧩 My Formations",
+        "lobby.perfil": "👤 My profile",
+        "lobby.torneos": "🏆 Tournaments",
+        "lobby.clasificacion": "📈 Leaderboard",
+        "lobby.retos": "📬 Pending challenges",
+        "lobby.cargando": "Loading...",
+        "lobby.sin-retos": "You have no pending challenges.",
+        "lobby.error-retos": "Could not load challenges.",
+        "lobby.reto": "🎮 Player {rival} challenged you.",
+        "lobby.aceptar": "Accept",
+        "lobby.falta-rival": "⚠️ Enter the opponent's ID.",
+        "lobby.creando": "🔄 Sending challenge…",
+        "lobby.creando-bot": "🔄 Creating a match against the bot…",
+        "lobby.sin-bot": "⚠️ There is no bot with that difficulty.",
+        "lobby.como-jugar": "📖 How to Play",
+        "lobby.explicacion": "Rustball is an asynchronous multiplayer football game: players take turns, not at the same time.",
+        "lobby.controles": "Controls (can be changed in the in-game Settings):",
+        "lobby.control-flechas": "Arrows: aim the disk",
+        "lobby.control-espacio": "Space bar: shot power",
+        "lobby.control-tab": "Tab: switch disk",
+        "lobby.idioma-guardado": "✅ Language saved.",
+        "lobby.rechazar": "Decline",
+        "lobby.cancelar": "Cancel",
+        "lobby.reto-enviado": "⚔️ Challenge sent to {rival}. We will let you know when they accept.",
+        "lobby.reto-esperando": "⏳ Waiting for {rival} to accept your challenge.",
+        "lobby.tiempo-real": "⚡ Real time",
+        "lobby.plazo-1-dia": "📬 Correspondence: 1 day per turn",
+        "lobby.plazo-3-dias": "📬 Correspondence: 3 days per turn",
+        "lobby.plazo-7-dias": "📬 Correspondence: 7 days per turn",
+        "lobby.correspondencia": "correspondence, {horas} h per turn",
+        "lobby.bandeja": "⏳ Your turn",
+        "lobby.bandeja-vacia": "It is not your move in any match.",
+        "lobby.bandeja-error": "Could not load your matches.",
+        "lobby.bandeja-tirar": "⚽ Match #{partida} against {rival} ({marcador}): your shot.",
+        "lobby.bandeja-formacion": "🧩 Match #{partida} against {rival} ({marcador}): pick your formation.",
+        "lobby.bandeja-plazo": "due {fecha}",
+        "lobby.jugar": "▶️ Play",
+        "lobby.aviso-turno": "📬 It is your move in match #{partida}.",
+        "lobby.aviso-vencida-ganada": "⌛ Your opponent ran out of time: you won match #{partida}.",
+        "lobby.aviso-vencida-perdida": "⌛ You ran out of time: you lost match #{partida}.",
+        "lobby.aviso-vencida-anulada": "⌛ Nobody moved in time: match #{partida} was voided.",
+        "lobby.aviso-reto": "🔔 {rival} challenged you.",
+        "lobby.aviso-aceptado": "🔔 {rival} accepted your challenge: the match is under Your turn.",
+        "lobby.aviso-rechazado": "🔔 {rival} declined your challenge.",
+        "lobby.aviso-torneo": "🏆 You have a new tournament match.",
+        "lobby.aviso-torneo-fin": "🏆 A tournament you played in has finished.",
+        "lobby.aviso-logro": "🏅 Achievement unlocked: {logro}!",
+
+        "amigos.titulo": "🤝 Friends",
+        "amigos.buscar": "Search by name",
+        "amigos.boton-buscar": "🔍 Search",
+        "amigos.sin-resultados": "No users with that name.",
+        "amigos.agregar": "➕ Add",
+        "amigos.retar": "⚔️ Challenge",
+        "amigos.quitar": "🗑️ Remove",
+        "amigos.confirmar-quitar": "Remove {nombre} from your friends?",
+        "amigos.vacio": "You have not added any friends yet.",
+        "amigos.solicitud": "🤝 {nombre} wants to be your friend.",
+        "amigos.enviada": "⏳ Request sent to {nombre}.",
+        "amigos.solicitud-enviada": "✅ Request sent to {nombre}.",
+        "amigos.aviso-solicitud": "🔔 {nombre} sent you a friend request.",
+        "amigos.aviso-aceptada": "🔔 {nombre} accepted your friend request.",
+        "amigos.error": "Could not load friends.",
+        "amigos.en_linea": "🟢 Online",
+        "amigos.ausente": "🟡 Away",
+        "amigos.en_partida": "⚽ Playing (#{partida})",
+        "amigos.desconectado": "⚫ Offline",
+
+        "partidas.titulo": "📁 My Matches",
+        "partidas.vacio": "You have no matches yet.",
+        "partidas.fila": "Match #{partida} vs Player #{rival}",
+        "partidas.continuar": "Continue",
+        "partidas.error": "❌ Could not load matches.",
+        "partidas.error-continuar": "Could not resume the match.",
+        "partidas.estadisticas": "📊 Statistics",
+        "partidas.perfil-rival": "👤 Opponent profile",
+        "partidas.tiros": "Shots",
+        "partidas.potencia": "Average power",
+        "partidas.contactos": "Ball touches",
+        "partidas.rebotes": "Bounces",
+        "partidas.powerups": "Power-ups (collected / used)",
+        "partidas.zonas": "Zones entered",
+        "partidas.goles": "Goals",
+        "partidas.error-estadisticas": "❌ Could not load the match statistics.",
+
+        "estadisticas.titulo": "📊 Player Statistics",
+        "estadisticas.jugadas": "Matches Played:",
+        "estadisticas.ganadas": "Matches Won:",
+        "estadisticas.favor": "Goals For:",
+        "estadisticas.contra": "Goals Against:",
+        "estadisticas.tiros": "Shots:",
+        "estadisticas.tiros-por-gol": "Shots per goal:",
+        "estadisticas.potencia": "Average power:",
+        "estadisticas.formacion": "Favorite formation:",
+        "estadisticas.powerups": "Power-ups used:",
+        "estadisticas.error": "❌ Could not load statistics",
+
+        "perfil.titulo": "👤 Profile",
+        "perfil.desde": "Playing since:",
+        "perfil.elo": "Elo:",
+        "perfil.jugadas": "Matches:",
+        "perfil.balance": "Won / drawn / lost:",
+        "perfil.goles": "Goals for / against:",
+        "perfil.forma": "Recent form:",
+        "perfil.forma-G": "W",
+        "perfil.forma-E": "D",
+        "perfil.forma-P": "L",
+        "perfil.ultimas": "🕒 Latest matches",
+        "perfil.rival": "Opponent",
+        "perfil.resultado": "Result",
+        "perfil.sin-partidas": "No finished matches yet.",
+        "perfil.cara-a-cara": "⚔️ Head to head against {rival}",
+        "perfil.error": "❌ Could not load the profile",
+        "perfil.logros": "🏅 Achievements",
+        "perfil.temporadas": "🗓️ Seasons",
+        "perfil.sin-temporadas": "No seasons played yet.",
+        "perfil.avisos": "🔔 Notifications",
+        "perfil.avisos-explicacion": "We notify you by email or webhook even when the lobby is closed.",
+        "perfil.aviso-correo": "By email",
+        "perfil.aviso-webhook": "Webhook URL (optional)",
+        "perfil.aviso-reto": "Challenges received",
+        "perfil.aviso-turno": "My turn (correspondence matches)",
+        "perfil.aviso-partida-terminada": "Finished matches",
+        "perfil.guardar-avisos": "💾 Save notifications",
+        "perfil.avisos-guardados": "✅ Notifications saved.",
+        "perfil.correo-verificado": "✅ Email verified.",
+        "perfil.correo-sin-verificar": "⚠️ Email not verified: until you open the link we sent you, no notifications go by email.",
+        "perfil.reenviar-verificacion": "✉️ Resend link",
+        "perfil.verificacion-enviada": "✉️ We sent you a new link.",
+
+        "cuenta.titulo": "🔑 Recover account",
+        "cuenta.titulo-restablecer": "🔑 New password",
+        "cuenta.titulo-verificar": "✉️ Verify email",
+        "cuenta.explicacion": "Enter your account's email and we will send you a link to choose a new password.",
+        "cuenta.correo": "Email",
+        "cuenta.enviar": "Send link",
+        "cuenta.nueva": "New password",
+        "cuenta.repetir": "Repeat the password",
+        "cuenta.cambiar": "Change password",
+        "cuenta.no-coinciden": "⚠️ The passwords do not match.",
+        "cuenta.verificando": "🔄 Verifying your email…",
+        "cuenta.verificado": "✅ Email verified. You can close this page.",
+        "cuenta.ir-login": "Log in",
+
+        "torneos.titulo": "🏆 Tournaments",
+        "torneos.crear": "➕ New tournament",
+        "torneos.nombre": "Tournament name",
+        "torneos.eliminacion": "Single elimination",
+        "torneos.liga": "League (round robin)",
+        "torneos.ida-y-vuelta": "Home and away",
+        "torneos.elegir": "Participants (include yourself if you want to play):",
+        "torneos.otros": "Other IDs, comma separated",
+        "torneos.boton-crear": "🏆 Create tournament",
+        "torneos.recientes": "📋 Recent tournaments",
+        "torneos.vacio": "No tournaments yet.",
+        "torneos.error": "Could not load tournaments.",
+        "torneos.participantes": "{n} participants",
+        "torneos.en-curso": "⏳ Round {ronda} of {total}",
+        "torneos.campeon": "🏆 Champion: {nombre}",
+        "torneos.sin-campeon": "🏁 Finished without a champion",
+        "torneos.clasificacion": "📊 Standings",
+        "torneos.cuadro": "🗂️ Matches",
+        "torneos.volver": "⬅️ All tournaments",
+        "torneos.jugador": "Player",
+        "torneos.puntos": "Pts",
+        "torneos.ronda-alcanzada": "Round",
+        "torneos.ronda": "Round {ronda}",
+        "torneos.semifinal": "Semi-final",
+        "torneos.final": "Final",
+        "torneos.por-definir": "to be decided",
+        "torneos.bye": "advances without playing",
+        "torneos.jugando": "(match #{partida})",
+        "torneos.tu-partida": "⚽ You play against {rival}.",
+        "torneos.jugar": "▶️ Play",
+
+        "temporadas.titulo": "📈 Leaderboard",
+        "temporadas.temporada": "Season",
+        "temporadas.activa": "in progress",
+        "temporadas.hasta": "From {inicio} to {fin} · {jugadores} players",
+        "temporadas.cerrada": "Closed: from {inicio} to {fin} · {jugadores} players",
+        "temporadas.vacia": "Nobody has played this season yet.",
+        "temporadas.error": "Could not load the leaderboard.",
+        "temporadas.maximo": "Peak",
+        "temporadas.anterior": "⬅️ Previous",
+        "temporadas.siguiente": "Next ➡️",
+
+        "logros.desbloqueado": "Unlocked on {fecha}",
+        "logros.primera_victoria": "First win",
+        "logros.primera_victoria-desc": "Win a match.",
+        "logros.hat_trick": "Hat-trick",
+        "logros.hat_trick-desc": "Score 3 goals in a match.",
+        "logros.porteria_a_cero": "Clean sheet",
+        "logros.porteria_a_cero-desc": "Win without conceding.",
+        "logros.gol_de_poste": "Off the post",
+        "logros.gol_de_poste-desc": "Score after the ball bounces off a goal post.",
+        "logros.ganar_con": "Win with the {formacion}",
+        "logros.ganar_con-desc": "Win a match using the {formacion} formation.",
+        "logros.coleccionista": "Power-up collector",
+        "logros.coleccionista-desc": "Collect 10 power-ups.",
+
+        "formaciones.titulo": "🧩 My Formations",
+        "formaciones.ayuda": "Drag the 5 disks inside your half. Your goal is on the right; no disk may be inside the box or on top of another.",
+        "formaciones.nombre": "Formation name",
+        "formaciones.guardar": "💾 Save",
+        "formaciones.nueva": "➕ New",
+        "formaciones.guardadas": "Saved",
+        "formaciones.vacio": "You have not saved any formation yet.",
+        "formaciones.error-lista": "❌ Could not load formations",
+        "formaciones.editar": "✏️ Edit",
+        "formaciones.borrar": "🗑️ Delete",
+        "formaciones.confirmar-borrar": "Delete the formation \"{nombre}\"?",
+        "formaciones.sin-nombre": "⚠️ Give the formation a name",
+        "formaciones.guardada": "✅ Formation \"{nombre}\" saved",
+        "formaciones.valida": "✅ Valid formation",
+        "formaciones.cantidad": "A formation needs 5 disks (it has {n})",
+        "formaciones.coordenadas": "Disk {n}: invalid coordinates",
+        "formaciones.campo": "Disk {n}: must be fully inside your half",
+        "formaciones.fuera": "Disk {n}: is outside the pitch",
+        "formaciones.area": "Disk {n}: cannot be inside the box",
+        "formaciones.superpuestas": "Disks {a} and {b}: overlap",
+    },
+};
+
+export const IDIOMAS = { es: "Español", en: "English" };
+
+/* Idioma activo: preferencia guardada → navegador → español */
+export function idioma() {
+    const guardado = localStorage.getItem("rb_lang");
+    if (guardado && TEXTOS[guardado]) return guardado;
+
+    const navegador = (navigator.language || "").slice(0, 2).toLowerCase();
+    return TEXTOS[navegador] ? navegador : "es";
+}
+
+export function setIdioma(codigo) {
+    if (TEXTOS[codigo]) localStorage.setItem("rb_lang", codigo);
+}
+
+/* Texto traducido; cae al español y, si tampoco existe, a la clave */
+export function t(clave, vars = {}) {
+    const texto = TEXTOS[idioma()][clave] ?? TEXTOS.es[clave] ?? clave;
+    return texto.replace(/\{(\w+)\}/g, (m, nombre) => (nombre in vars ? vars[nombre] : m));
+}
+
+/* Traduce los elementos marcados con data-i18n / data-i18n-placeholder */
+export function traducirPagina(raiz = document) {
+    document.documentElement.lang = idioma();
+    raiz.querySelectorAll("[data-i18n]").forEach((el) => {
+        el.textContent = t(el.dataset.i18n);
+    });
+    raiz.querySelectorAll("[data-i18n-placeholder]").forEach((el) => {
+        el.placeholder = t(el.dataset.i18nPlaceholder);
+    });
+}
+
+document.addEventListener("DOMContentLoaded", () => traducirPagina());
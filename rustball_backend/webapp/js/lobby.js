@@ -1,5 +1,6 @@
-import { post, get } from "./api.js";
-import { entrarPartida } from "./utils.js";
+import { post, get, del } from "./api.js";
+import { entrarPartida, entrarPartidaLocal, entrarPartidaBot } from "./utils.js";
+import { IDIOMAS, idioma, setIdioma, t, traducirPagina } from "./i18n.js";
 
 document.addEventListener("DOMContentLoaded", () => {
     const $   = (id) => document.getElementById(id);
@@ -10,15 +11,70 @@ document.addEventListener("DOMContentLoaded", () => {
 
     $("lbl-user").textContent = `${user.nombre_usuario} (#${user.id_usuario})`;
 
-    $("btn-partida").addEventListener("click", async () => {
+    // 🌐 Idioma: se guarda en el perfil y en `rb_lang` (también lo lee el juego)
+    const selIdioma = $("sel-idioma");
+    for (const [codigo, nombre] of Object.entries(IDIOMAS)) {
+        selIdioma.add(new Option(nombre, codigo, false, codigo === idioma()));
+    }
+    selIdioma.addEventListener("change", async () => {
+        setIdioma(selIdioma.value);
+        traducirPagina();
+        cargarBandeja();
+        cargarPendientes();
+        cargarAmigos();
+        try {
+            await post("/usuario/idioma", { id_usuario: user.id_usuario, idioma: selIdioma.value });
+            localStorage.setItem("rb_user", JSON.stringify({ ...user, idioma: selIdioma.value }));
+            log(t("lobby.idioma-guardado"));
+        } catch (e) {
+            log(`❌ ${e.message}`);
+        }
+    });
+
+    $("btn-partida").addEventListener("click", () => {
         const rival = parseInt($("usuario-2").value.trim(), 10);
-        if (!rival) { log("⚠️ Ingresa el ID del rival."); return; }
+        if (!rival) { log(t("lobby.falta-rival")); return; }
+        retar(rival);
+    });
 
+    /* ⚔️ Manda un reto; el rival lo recibe al momento en su lobby.
+       Con plazo elegido, la partida será por correspondencia */
+    async function retar(rival) {
         try {
-            log("🔄 Creando / buscando partida…");
+            log(t("lobby.creando"));
+            const reto = await post("/retos", {
+                id_retador: user.id_usuario,
+                id_retado: rival,
+                plazo_turno_horas: parseInt($("plazo-turno").value, 10) || null,
+            });
+
+            // Los bots aceptan solos: se entra directo
+            if (reto.estado === "aceptado") {
+                entrarPartida(reto.id_partida, reto.id_retador, reto.id_retado, user.id_usuario);
+                return;
+            }
+            log(t("lobby.reto-enviado", { rival: reto.nombre_retado }));
+            cargarPendientes();
+        } catch (e) {
+            log(`❌ ${e.message}`);
+        }
+    }
+
+    $("btn-local")?.addEventListener("click", () => entrarPartidaLocal());
+
+    $("btn-bot")?.addEventListener("click", () => entrarPartidaBot($("bot-dificultad").value));
+
+    // 🤖 Reta a uno de los usuarios bot del servidor (partida online normal)
+    $("btn-bot-online")?.addEventListener("click", async () => {
+        try {
+            const bots = await get("/bots");
+            const bot  = bots.find(b => b.dificultad === $("bot-dificultad").value);
+            if (!bot) { log(t("lobby.sin-bot")); return; }
+
+            log(t("lobby.creando-bot"));
             const partida = await post("/partida", {
                 id_usuario_1: user.id_usuario,
-                id_usuario_2: rival,
+                id_usuario_2: bot.id_usuario,
             });
 
             entrarPartida(partida.id_partida, partida.id_usuario_1, partida.id_usuario_2, user.id_usuario);
@@ -35,46 +91,319 @@ document.addEventListener("DOMContentLoaded", () => {
         window.location.href = "/partidas.html";
     });
 
-    async function cargarPendientes() {
+    $("btn-formaciones")?.addEventListener("click", () => {
+        window.location.href = "/formaciones.html";
+    });
+
+    $("btn-buscar")?.addEventListener("click", buscarUsuarios);
+    $("buscar-usuario")?.addEventListener("keydown", (e) => { if (e.key === "Enter") buscarUsuarios(); });
+
+    $("btn-perfil")?.addEventListener("click", () => {
+        window.location.href = `/perfil.html?u=${user.id_usuario}`;
+    });
+
+    $("btn-torneos")?.addEventListener("click", () => {
+        window.location.href = "/torneos.html";
+    });
+
+    $("btn-clasificacion")?.addEventListener("click", () => {
+        window.location.href = "/temporadas.html";
+    });
+
+    /* ⏳ Partidas en las que me toca tirar o elegir formación, las que vencen antes primero */
+    async function cargarBandeja() {
         try {
-            const partidas = await get(`/pendientes/${user.id_usuario}`);
-            const mensajesDiv = $("mensajes");
-            mensajesDiv.innerHTML = "";
+            const partidas = await get(`/bandeja/${user.id_usuario}`);
+            const bandeja = $("bandeja");
+            bandeja.innerHTML = "";
 
             if (partidas.length === 0) {
-                mensajesDiv.innerText = "No tienes retos pendientes.";
+                bandeja.innerText = t("lobby.bandeja-vacia");
                 return;
             }
 
             partidas.forEach(p => {
-                const otro = (p.id_usuario_1 === user.id_usuario)
-                    ? p.id_usuario_2
-                    : p.id_usuario_1;
+                const texto = t(p.accion === "tirar" ? "lobby.bandeja-tirar" : "lobby.bandeja-formacion", {
+                    partida: p.id_partida,
+                    rival: enlacePerfil(p.id_rival, p.nombre_rival),
+                    marcador: `${p.gol_j1}–${p.gol_j2}`,
+                });
+                const plazo = p.fecha_limite
+                    ? ` — ${t("lobby.bandeja-plazo", { fecha: new Date(p.fecha_limite).toLocaleString(idioma()) })}`
+                    : "";
+                const div = fila(`${texto}${plazo} <button>${t("lobby.jugar")}</button>`);
+                div.querySelector("button").onclick = () =>
+                    entrarPartida(p.id_partida, p.id_jugador1, p.id_jugador2, user.id_usuario);
+                bandeja.appendChild(div);
+            });
+        } catch (e) {
+            $("bandeja").innerText = t("lobby.bandeja-error");
+            console.error(e);
+        }
+    }
 
-                const div = document.createElement("div");
-                div.innerHTML = `
-                    🎮 Jugador ${otro} te ha desafiado.
-                    <button>Aceptar</button>
-                `;
+    /* 📬 Retos recibidos y enviados */
+    async function cargarPendientes() {
+        try {
+            const retos = await get(`/retos/${user.id_usuario}`);
+            const mensajesDiv = $("mensajes");
+            mensajesDiv.innerHTML = "";
+
+            if (retos.recibidos.length + retos.enviados.length === 0) {
+                mensajesDiv.innerText = t("lobby.sin-retos");
+                return;
+            }
 
-                div.querySelector("button").onclick = async () => {
+            retos.recibidos.forEach(r => {
+                const div = fila(`
+                    ${t("lobby.reto", { rival: enlacePerfil(r.id_retador, r.nombre_retador) })}${modoReto(r)}
+                    <button data-accion="aceptar">${t("lobby.aceptar")}</button>
+                    <button data-accion="rechazar">${t("lobby.rechazar")}</button>
+                `);
+                div.querySelector('[data-accion="aceptar"]').onclick = async () => {
                     try {
-                        const res = await fetch(`/api/partida_detalle/${p.id_partida}`);
-                        const data = await res.json();
-                        entrarPartida(p.id_partida, data.id_usuario_1, data.id_usuario_2, user.id_usuario);
-                    } catch (err) {
-                        console.error("❌ Error al obtener detalles de la partida:", err);
-                        alert("No se pudo continuar la partida.");
+                        const reto = await post(`/retos/${r.id_reto}/aceptar`, { id_usuario: user.id_usuario });
+                        entrarPartida(reto.id_partida, reto.id_retador, reto.id_retado, user.id_usuario);
+                    } catch (e) {
+                        log(`❌ ${e.message}`);
+                        cargarPendientes();
                     }
                 };
+                div.querySelector('[data-accion="rechazar"]').onclick = () => responderReto(r.id_reto);
+                mensajesDiv.appendChild(div);
+            });
 
+            retos.enviados.forEach(r => {
+                const div = fila(`
+                    ${t("lobby.reto-esperando", { rival: enlacePerfil(r.id_retado, r.nombre_retado) })}${modoReto(r)}
+                    <button>${t("lobby.cancelar")}</button>
+                `);
+                div.querySelector("button").onclick = () => responderReto(r.id_reto);
                 mensajesDiv.appendChild(div);
             });
         } catch (e) {
-            $("mensajes").innerText = "Error al cargar retos.";
+            $("mensajes").innerText = t("lobby.error-retos");
             console.error(e);
         }
     }
 
+    /* Rechazar (si soy el retado) o retirar (si soy el retador) */
+    async function responderReto(idReto) {
+        try {
+            await post(`/retos/${idReto}/rechazar`, { id_usuario: user.id_usuario });
+        } catch (e) {
+            log(`❌ ${e.message}`);
+        }
+        cargarPendientes();
+    }
+
+    /* 🤝 Amigos y solicitudes */
+    async function cargarAmigos() {
+        try {
+            const { amigos, recibidas, enviadas } = await get(`/amigos/${user.id_usuario}`);
+
+            const solicitudes = $("solicitudes");
+            solicitudes.innerHTML = "";
+            recibidas.forEach(u => {
+                const div = fila(`
+                    ${t("amigos.solicitud", { nombre: enlacePerfil(u.id_usuario, u.nombre_usuario) })}
+                    <button data-accion="aceptar">${t("lobby.aceptar")}</button>
+                    <button data-accion="rechazar">${t("lobby.rechazar")}</button>
+                `);
+                div.querySelector('[data-accion="aceptar"]').onclick = () =>
+                    accionAmistad(() => post("/amigos/aceptar", { id_usuario: user.id_usuario, id_amigo: u.id_usuario }));
+                div.querySelector('[data-accion="rechazar"]').onclick = () =>
+                    accionAmistad(() => del(`/amigos/${user.id_usuario}/${u.id_usuario}`));
+                solicitudes.appendChild(div);
+            });
+            enviadas.forEach(u => {
+                const div = fila(`
+                    ${t("amigos.enviada", { nombre: enlacePerfil(u.id_usuario, u.nombre_usuario) })}
+                    <button>${t("lobby.cancelar")}</button>
+                `);
+                div.querySelector("button").onclick = () =>
+                    accionAmistad(() => del(`/amigos/${user.id_usuario}/${u.id_usuario}`));
+                solicitudes.appendChild(div);
+            });
+
+            const lista = $("lista-amigos");
+            lista.innerHTML = "";
+            if (amigos.length === 0) {
+                lista.innerText = t("amigos.vacio");
+                return;
+            }
+            amigos.forEach(u => {
+                const div = fila(`
+                    ${textoPresencia(u.presencia)} ${enlacePerfil(u.id_usuario, u.nombre_usuario)} (Elo ${u.elo})
+                    <button data-accion="retar">${t("amigos.retar")}</button>
+                    <button data-accion="quitar">${t("amigos.quitar")}</button>
+                `);
+                div.querySelector('[data-accion="retar"]').onclick = () => retar(u.id_usuario);
+                div.querySelector('[data-accion="quitar"]').onclick = () => {
+                    if (!confirm(t("amigos.confirmar-quitar", { nombre: u.nombre_usuario }))) return;
+                    accionAmistad(() => del(`/amigos/${user.id_usuario}/${u.id_usuario}`));
+                };
+                lista.appendChild(div);
+            });
+        } catch (e) {
+            $("lista-amigos").innerText = t("amigos.error");
+            console.error(e);
+        }
+    }
+
+    async function accionAmistad(peticion) {
+        try {
+            await peticion();
+        } catch (e) {
+            log(`❌ ${e.message}`);
+        }
+        cargarAmigos();
+    }
+
+    /* 🔍 Búsqueda por nombre de usuario */
+    async function buscarUsuarios() {
+        const texto = $("buscar-usuario").value.trim();
+        const resultados = $("resultados-busqueda");
+        resultados.innerHTML = "";
+        if (!texto) return;
+
+        try {
+            const usuarios = await get(`/usuarios/buscar?q=${encodeURIComponent(texto)}&u=${user.id_usuario}`);
+            if (usuarios.length === 0) {
+                resultados.innerText = t("amigos.sin-resultados");
+                return;
+            }
+            usuarios.forEach(u => {
+                const div = fila(`
+                    ${enlacePerfil(u.id_usuario, u.nombre_usuario)} (Elo ${u.elo})
+                    <button data-accion="agregar">${t("amigos.agregar")}</button>
+                    <button data-accion="retar">${t("amigos.retar")}</button>
+                `);
+                div.querySelector('[data-accion="agregar"]').onclick = async () => {
+                    await accionAmistad(() => post("/amigos/solicitud", { id_usuario: user.id_usuario, id_amigo: u.id_usuario }));
+                    log(t("amigos.solicitud-enviada", { nombre: u.nombre_usuario }));
+                };
+                div.querySelector('[data-accion="retar"]').onclick = () => retar(u.id_usuario);
+                resultados.appendChild(div);
+            });
+        } catch (e) {
+            log(`❌ ${e.message}`);
+        }
+    }
+
+    /* 🔔 Avisos del servidor: retos, amistades y presencia al momento */
+    let socketLobby = null;
+
+    function conectarLobby(intentos = 0) {
+        const proto = window.location.protocol === "https:" ? "wss://" : "ws://";
+        const socket = new WebSocket(`${proto}${window.location.host}/api/lobby/ws/${user.id_usuario}`);
+        socketLobby = socket;
+
+        socket.onopen = () => {
+            intentos = 0;
+            if (document.hidden) avisarVisibilidad();
+        };
+
+        socket.onmessage = (event) => {
+            let aviso;
+            try { aviso = JSON.parse(event.data); } catch { return; }
+
+            switch (aviso.tipo) {
+                case "reto":
+                    log(t("lobby.aviso-reto", { rival: aviso.reto.nombre_retador }));
+                    cargarPendientes();
+                    break;
+                case "reto_aceptado":
+                    log(t("lobby.aviso-aceptado", { rival: aviso.reto.nombre_retado }));
+                    cargarPendientes();
+                    cargarBandeja();
+                    break;
+                case "reto_rechazado":
+                    log(t("lobby.aviso-rechazado", { rival: aviso.reto.nombre_retado }));
+                    cargarPendientes();
+                    break;
+                case "reto_cancelado":
+                case "reto_expirado":
+                    cargarPendientes();
+                    break;
+                case "solicitud_amistad":
+                    log(t("amigos.aviso-solicitud", { nombre: aviso.nombre_usuario }));
+                    cargarAmigos();
+                    break;
+                case "amistad_aceptada":
+                    log(t("amigos.aviso-aceptada", { nombre: aviso.nombre_usuario }));
+                    cargarAmigos();
+                    break;
+                case "torneo_partida":
+                    log(t("lobby.aviso-torneo"));
+                    cargarBandeja();
+                    break;
+                case "torneo_terminado":
+                    log(t("lobby.aviso-torneo-fin"));
+                    break;
+                case "tu_turno":
+                    log(t("lobby.aviso-turno", { partida: aviso.id_partida }));
+                    cargarBandeja();
+                    break;
+                case "partida_vencida":
+                    log(t(avisoVencida(aviso.ganador, user.id_usuario), { partida: aviso.id_partida }));
+                    cargarBandeja();
+                    break;
+                case "logro":
+                    log(t("lobby.aviso-logro", { logro: t(`logros.${aviso.logro}`, { formacion: aviso.detalle }) }));
+                    break;
+                case "presencia":
+                case "amistad_eliminada":
+                    cargarAmigos();
+                    break;
+                default:
+                    cargarBandeja();
+                    cargarPendientes();
+                    cargarAmigos();
+            }
+        };
+
+        // Reintento con espera creciente (tope 30 s)
+        socket.onclose = () => {
+            setTimeout(() => conectarLobby(intentos + 1), Math.min(30, 2 ** intentos) * 1000);
+        };
+    }
+
+    /* 👀 Pestaña a la vista (activo) o en segundo plano (ausente) */
+    function avisarVisibilidad() {
+        if (socketLobby?.readyState !== WebSocket.OPEN) return;
+        socketLobby.send(JSON.stringify({ tipo: document.hidden ? "segundo_plano" : "actividad" }));
+    }
+    document.addEventListener("visibilitychange", avisarVisibilidad);
+
+    cargarBandeja();
     cargarPendientes();
+    cargarAmigos();
+    conectarLobby();
 });
+
+function textoPresencia(presencia) {
+    const estado = presencia?.estado ?? "desconectado";
+    return estado === "en_partida"
+        ? t("amigos.en_partida", { partida: presencia.id_partida })
+        : t(`amigos.${estado}`);
+}
+
+function modoReto(reto) {
+    return reto.plazo_turno_horas ? ` (${t("lobby.correspondencia", { horas: reto.plazo_turno_horas })})` : "";
+}
+
+function avisoVencida(ganador, miId) {
+    if (ganador === null) return "lobby.aviso-vencida-anulada";
+    return ganador === miId ? "lobby.aviso-vencida-ganada" : "lobby.aviso-vencida-perdida";
+}
+
+function fila(html) {
+    const div = document.createElement("div");
+    div.innerHTML = html;
+    return div;
+}
+
+function enlacePerfil(id, nombre) {
+    return `<a href="perfil.html?u=${id}">${nombre}</a>`;
+}
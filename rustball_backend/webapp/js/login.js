@@ -1,3 +1,5 @@
+import { idioma, setIdioma, t } from "./i18n.js";
+
 document.addEventListener("DOMContentLoaded", () => {
     const btnLogin = document.getElementById("btn-login");
 
@@ -6,28 +8,31 @@ document.addEventListener("DOMContentLoaded", () => {
         const contrasena = document.getElementById("login-contra").value.trim();
 
         if (!nombre || !contrasena) {
-            document.getElementById("resultado").textContent = "❌ Rellena todos los campos.";
+            document.getElementById("resultado").textContent = t("comun.campos");
             return;
         }
 
         try {
             const res = await fetch("https://rustball.lat/api/login", {
                 method: "POST",
-                headers: { "Content-Type": "application/json" },
+                headers: { "Content-Type": "application/json", "Accept-Language": idioma() },
                 body: JSON.stringify({ nombre_usuario: nombre, contrasena })
             });
 
-            const data = await res.json();
+            // El backend responde los errores como texto (ya traducido)
+            const texto = await res.text();
 
             if (res.ok) {
-                document.getElementById("resultado").textContent = "✅ Sesión iniciada correctamente.";
+                const data = JSON.parse(texto);
+                document.getElementById("resultado").textContent = t("login.ok");
                 localStorage.setItem("rb_user", JSON.stringify(data));
+                if (data.idioma) setIdioma(data.idioma);
                 window.location.href = "lobby.html";
             } else {
-                document.getElementById("resultado").textContent = `❌ ${data.error || "Credenciales incorrectas"}`;
+                document.getElementById("resultado").textContent = `❌ ${texto || t("login.error")}`;
             }
         } catch (err) {
-            document.getElementById("resultado").textContent = "⚠️ Error al conectar con el servidor.";
+            document.getElementById("resultado").textContent = t("comun.error-servidor");
         }
     });
 });
@@ -1,4 +1,5 @@
 import { entrarPartida } from "./utils.js";
+import { idioma, t } from "./i18n.js";
 
 document.addEventListener("DOMContentLoaded", async () => {
     const user = JSON.parse(localStorage.getItem("rb_user"));
@@ -11,7 +12,7 @@ document.addEventListener("DOMContentLoaded", async () => {
         const cont     = document.getElementById("lista-partidas");
 
         if (partidas.length === 0) {
-            cont.innerHTML = "<p>No tienes partidas registradas.</p>";
+            cont.innerHTML = `<p>${t("partidas.vacio")}</p>`;
             return;
         }
 
@@ -20,14 +21,17 @@ document.addEventListener("DOMContentLoaded", async () => {
             const div   = document.createElement("div");
             div.className = "partida";
             div.innerHTML = `
-                <p>Partida #${p.id_partida} vs Jugador #${rival}</p>
-                <button onclick="continuar(${p.id_partida})">Continuar</button>
+                <p>${t("partidas.fila", { partida: p.id_partida, rival })}</p>
+                <button onclick="continuar(${p.id_partida})">${t("partidas.continuar")}</button>
+                <button onclick="verEstadisticas(${p.id_partida}, this)">${t("partidas.estadisticas")}</button>
+                <button onclick="window.location.href='perfil.html?u=${rival}'">${t("partidas.perfil-rival")}</button>
+                <div class="estadisticas-partida" id="estadisticas-${p.id_partida}"></div>
             `;
             cont.appendChild(div);
         });
     } catch (err) {
         console.error(err);
-        alert("❌ Error al cargar partidas.");
+        alert(t("partidas.error"));
     }
 });
 
@@ -42,8 +46,50 @@ async function continuar(idPartida) {
         entrarPartida(idPartida, data.id_usuario_1, data.id_usuario_2, user.id_usuario);
     } catch (err) {
         console.error("❌ Error al obtener detalles de la partida:", err);
-        alert("No se pudo continuar la partida.");
+        alert(t("partidas.error-continuar"));
+    }
+}
+
+/* 📊 Tabla de eventos por jugador (GET /partida/:p/estadisticas) */
+async function verEstadisticas(idPartida, boton) {
+    const cont = document.getElementById(`estadisticas-${idPartida}`);
+    if (cont.innerHTML) {
+        cont.innerHTML = "";
+        return;
+    }
+
+    boton.disabled = true;
+    try {
+        const res = await fetch(`/api/partida/${idPartida}/estadisticas`, {
+            headers: { "Accept-Language": idioma() },
+        });
+        if (!res.ok) throw new Error(await res.text());
+        const { jugadores } = await res.json();
+
+        const filas = [
+            ["partidas.tiros", (j) => j.tiros],
+            ["partidas.potencia", (j) => (j.potencia_media == null ? "–" : `${Math.round(j.potencia_media * 100)}%`)],
+            ["partidas.contactos", (j) => j.contactos_pelota],
+            ["partidas.rebotes", (j) => j.rebotes],
+            ["partidas.powerups", (j) => `${j.powerups_recogidos} / ${j.powerups_usados}`],
+            ["partidas.zonas", (j) => j.zonas],
+            ["partidas.goles", (j) => j.goles],
+        ];
+        cont.innerHTML = `
+            <table>
+                <tr><th></th>${jugadores.map((j) => `<th>${j.nombre_usuario}</th>`).join("")}</tr>
+                ${filas.map(([clave, valor]) => `
+                    <tr><td>${t(clave)}</td>${jugadores.map((j) => `<td>${valor(j)}</td>`).join("")}</tr>
+                `).join("")}
+            </table>
+        `;
+    } catch (err) {
+        console.error(err);
+        alert(t("partidas.error-estadisticas"));
+    } finally {
+        boton.disabled = false;
     }
 }
 
 window.continuar = continuar;
+window.verEstadisticas = verEstadisticas;
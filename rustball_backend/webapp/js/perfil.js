@@ -0,0 +1,174 @@
+import { idioma, t } from "./i18n.js";
+
+/* perfil.html?u=ID[&rival=ID] — sin `u` se muestra el propio */
+document.addEventListener("DOMContentLoaded", async () => {
+    const $      = (id) => document.getElementById(id);
+    const params = new URLSearchParams(window.location.search);
+    const user   = JSON.parse(localStorage.getItem("rb_user") || "null");
+    const idUsuario = parseInt(params.get("u"), 10) || user?.id_usuario;
+
+    if (!idUsuario) {
+        alert(t("comun.sin-sesion"));
+        window.location.href = "login.html";
+        return;
+    }
+
+    // Si miro el perfil de otro, el cara a cara es contra mí
+    const rival = parseInt(params.get("rival"), 10)
+        || (user && user.id_usuario !== idUsuario ? user.id_usuario : null);
+    const query = rival ? `?rival=${rival}` : "";
+
+    try {
+        const res = await fetch(`/api/perfil/${idUsuario}${query}`, {
+            headers: { "Accept-Language": idioma() },
+        });
+        if (!res.ok) throw new Error(await res.text());
+        const p = await res.json();
+
+        document.title = `RustBall – ${p.nombre_usuario}`;
+        $("nombre").textContent = `👤 ${p.nombre_usuario} (#${p.id_usuario})`;
+        $("fecha_registro").textContent = new Date(p.fecha_registro).toLocaleDateString(idioma());
+        $("elo").textContent = p.elo;
+        $("jugadas").textContent = p.totales.jugadas;
+        $("balance").textContent = balance(p.totales);
+        $("goles").textContent = `${p.totales.goles_a_favor} / ${p.totales.goles_en_contra}`;
+        $("formacion_favorita").textContent = p.carrera.formacion_favorita ?? "–";
+        $("forma").innerHTML = p.forma.length
+            ? p.forma.map((r) => `<span class="forma forma-${r.resultado}">${t(`perfil.forma-${r.resultado}`)}</span>`).join(" ")
+            : "–";
+        $("ultimas").innerHTML = tabla(p.forma);
+
+        const resLogros = await fetch(`/api/logros/${idUsuario}`, {
+            headers: { "Accept-Language": idioma() },
+        });
+        if (resLogros.ok) $("logros").innerHTML = listaLogros(await resLogros.json());
+
+        const resTemporadas = await fetch(`/api/perfil/${idUsuario}/temporadas`, {
+            headers: { "Accept-Language": idioma() },
+        });
+        if (resTemporadas.ok) $("temporadas").innerHTML = tablaTemporadas(await resTemporadas.json());
+
+        if (user && user.id_usuario === idUsuario) await cargarAvisos(user.id_usuario);
+
+        if (p.cara_a_cara) {
+            const h = p.cara_a_cara;
+            $("titulo-cara-a-cara").textContent = t("perfil.cara-a-cara", { rival: h.nombre_rival });
+            $("h2h-balance").textContent = balance(h.totales);
+            $("h2h-goles").textContent = `${h.totales.goles_a_favor} / ${h.totales.goles_en_contra}`;
+            $("h2h-ultimas").innerHTML = tabla(h.ultimas);
+            $("cara-a-cara").hidden = false;
+        }
+    } catch (err) {
+        console.error(err);
+        alert(t("perfil.error"));
+    }
+});
+
+/* 🔔 Preferencias de avisos por correo/webhook (sólo las propias) */
+async function cargarAvisos(idUsuario) {
+    const $ = (id) => document.getElementById(id);
+    const casillas = {
+        por_correo: "aviso-correo",
+        reto: "aviso-reto",
+        turno: "aviso-turno",
+        partida_terminada: "aviso-partida-terminada",
+    };
+
+    const res = await fetch(`/api/notificaciones/${idUsuario}/preferencias`, {
+        headers: { "Accept-Language": idioma() },
+    });
+    if (!res.ok) return;
+    const prefs = await res.json();
+
+    for (const [campo, id] of Object.entries(casillas)) $(id).checked = prefs[campo];
+    $("aviso-webhook").value = prefs.webhook_url ?? "";
+    $("avisos").hidden = false;
+
+    // Sin verificar, los avisos no van por correo
+    const resCuenta = await fetch(`/api/cuenta/${idUsuario}`, {
+        headers: { "Accept-Language": idioma() },
+    });
+    if (resCuenta.ok) {
+        const cuenta = await resCuenta.json();
+        $("correo-estado").textContent = t(cuenta.correo_verificado ? "perfil.correo-verificado" : "perfil.correo-sin-verificar");
+        $("btn-verificacion").hidden = cuenta.correo_verificado;
+    }
+    $("btn-verificacion").onclick = async () => {
+        const resReenvio = await fetch("/api/cuenta/verificacion", {
+            method: "POST",
+            headers: { "Content-Type": "application/json", "Accept-Language": idioma() },
+            body: JSON.stringify({ id_usuario: idUsuario }),
+        });
+        $("correo-estado").textContent = resReenvio.ok
+            ? t("perfil.verificacion-enviada")
+            : `❌ ${await resReenvio.text()}`;
+    };
+
+    $("btn-avisos").onclick = async () => {
+        const body = { id_usuario: idUsuario, webhook_url: $("aviso-webhook").value.trim() || null };
+        for (const [campo, id] of Object.entries(casillas)) body[campo] = $(id).checked;
+
+        const resGuardar = await fetch("/api/notificaciones/preferencias", {
+            method: "POST",
+            headers: { "Content-Type": "application/json", "Accept-Language": idioma() },
+            body: JSON.stringify(body),
+        });
+        $("avisos-estado").textContent = resGuardar.ok
+            ? t("perfil.avisos-guardados")
+            : `❌ ${await resGuardar.text()}`;
+    };
+}
+
+function balance(totales) {
+    return `${totales.ganadas} / ${totales.empatadas} / ${totales.perdidas}`;
+}
+
+/* Partidas recientes, con enlace al perfil de cada rival */
+function tabla(partidas) {
+    if (partidas.length === 0) return `<p>${t("perfil.sin-partidas")}</p>`;
+
+    return `
+        <table>
+            <tr><th>#</th><th>${t("perfil.rival")}</th><th>${t("perfil.resultado")}</th><th>Elo</th></tr>
+            ${partidas.map((r) => `
+                <tr>
+                    <td>${r.id_partida}</td>
+                    <td><a href="perfil.html?u=${r.id_rival}">${r.nombre_rival}</a></td>
+                    <td>${t(`perfil.forma-${r.resultado}`)} ${r.goles_propios}–${r.goles_rival}</td>
+                    <td>${r.elo_cambio == null ? "–" : (r.elo_cambio > 0 ? `+${r.elo_cambio}` : r.elo_cambio)}</td>
+                </tr>
+            `).join("")}
+        </table>
+    `;
+}
+
+/* Desbloqueados primero (con su fecha); los pendientes, apagados */
+function listaLogros(logros) {
+    const orden = [...logros].sort((a, b) => (a.fecha_desbloqueo ? 0 : 1) - (b.fecha_desbloqueo ? 0 : 1));
+    return orden.map((l) => {
+        const nombre = t(`logros.${l.codigo}`, { formacion: l.detalle });
+        return l.fecha_desbloqueo
+            ? `<p title="${t("logros.desbloqueado", { fecha: new Date(l.fecha_desbloqueo).toLocaleDateString(idioma()) })}">🏅 <strong>${nombre}</strong> — ${t(`logros.${l.codigo}-desc`, { formacion: l.detalle })}</p>`
+            : `<p style="opacity: 0.5">🔒 ${nombre} — ${t(`logros.${l.codigo}-desc`, { formacion: l.detalle })}</p>`;
+    }).join("");
+}
+
+/* Puesto y rating de cada temporada jugada; la clasificación completa en temporadas.html */
+function tablaTemporadas(temporadas) {
+    if (temporadas.length === 0) return `<p>${t("perfil.sin-temporadas")}</p>`;
+
+    return `
+        <table>
+            <tr><th>${t("temporadas.temporada")}</th><th>#</th><th>Elo</th><th>${t("temporadas.maximo")}</th><th>${t("perfil.balance")}</th></tr>
+            ${temporadas.map((tp) => `
+                <tr>
+                    <td><a href="temporadas.html?t=${tp.id_temporada}">${tp.nombre}</a>${tp.estado === "activa" ? ` (${t("temporadas.activa")})` : ""}</td>
+                    <td>${tp.posicion}</td>
+                    <td>${tp.elo_inicial} → ${tp.elo}</td>
+                    <td>${tp.elo_maximo}</td>
+                    <td>${balance(tp)}</td>
+                </tr>
+            `).join("")}
+        </table>
+    `;
+}
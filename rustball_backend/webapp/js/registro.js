@@ -1,5 +1,6 @@
 /*  Módulo encargado del formulario de registro  */
 import { post } from "./api.js";
+import { t } from "./i18n.js";
 
 console.log("🔍 registro.js cargado — esperando al DOM…");
 
@@ -30,12 +31,12 @@ document.addEventListener("DOMContentLoaded", () => {
         const pass   = $("reg-contra").value.trim();
 
         if (!nombre || !correo || !pass) {
-            log("⚠️ Completa todos los campos.");
+            log(t("comun.campos"));
             return;
         }
 
         try {
-            log("🔄 Enviando datos…");
+            log(t("comun.enviando"));
 
             const user = await post("/registro", {
                 nombre_usuario: nombre,
@@ -46,11 +47,11 @@ document.addEventListener("DOMContentLoaded", () => {
             console.log("✅ Registro exitoso:", user);
             localStorage.setItem("rb_user", JSON.stringify(user));
 
-            log("✅ Registro exitoso. Redirigiendo…");
+            log(t("registro.ok"));
             setTimeout(() => (window.location.href = "lobby.html"), 800);
         } catch (e) {
             console.error("❌ Error de registro:", e);
-            log(`❌ ${e.message || "Error al registrar usuario"}`);
+            log(`❌ ${e.message || t("registro.error")}`);
         }
     });
 });
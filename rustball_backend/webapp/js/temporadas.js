@@ -0,0 +1,79 @@
+import { get } from "./api.js";
+import { idioma, t } from "./i18n.js";
+
+const POR_PAGINA = 50;
+
+/* temporadas.html[?t=ID] — clasificación de la temporada activa o de una pasada */
+document.addEventListener("DOMContentLoaded", async () => {
+    const $    = (id) => document.getElementById(id);
+    const user = JSON.parse(localStorage.getItem("rb_user") || "null");
+    const sel  = $("sel-temporada");
+    let desde  = 0;
+
+    try {
+        const temporadas = await get("/temporadas");
+        const pedida = parseInt(new URLSearchParams(window.location.search).get("t"), 10);
+        temporadas.forEach(tp => {
+            const texto = tp.estado === "activa" ? `${tp.nombre} (${t("temporadas.activa")})` : tp.nombre;
+            sel.add(new Option(texto, tp.id_temporada, false, tp.id_temporada === pedida));
+        });
+        if (temporadas.length === 0) {
+            $("clasificacion").innerText = t("temporadas.vacia");
+            return;
+        }
+    } catch (e) {
+        $("clasificacion").innerText = t("temporadas.error");
+        console.error(e);
+        return;
+    }
+
+    sel.addEventListener("change", () => { desde = 0; cargar(); });
+    $("btn-anterior").addEventListener("click", () => { desde = Math.max(0, desde - POR_PAGINA); cargar(); });
+    $("btn-siguiente").addEventListener("click", () => { desde += POR_PAGINA; cargar(); });
+
+    async function cargar() {
+        try {
+            const { temporada, filas } = await get(`/temporadas/${sel.value}/clasificacion?desde=${desde}&limite=${POR_PAGINA}`);
+            const fecha = (f) => new Date(f).toLocaleDateString(idioma());
+
+            $("resumen").textContent = t(temporada.estado === "activa" ? "temporadas.hasta" : "temporadas.cerrada", {
+                inicio: fecha(temporada.fecha_inicio),
+                fin: fecha(temporada.fecha_fin),
+                jugadores: temporada.jugadores,
+            });
+            $("btn-anterior").disabled = desde === 0;
+            $("btn-siguiente").disabled = desde + POR_PAGINA >= temporada.jugadores;
+
+            $("clasificacion").innerHTML = filas.length === 0
+                ? `<p>${t("temporadas.vacia")}</p>`
+                : `
+                <table>
+                    <tr>
+                        <th>#</th><th>${t("torneos.jugador")}</th><th>Elo</th><th>${t("temporadas.maximo")}</th>
+                        <th>PJ</th><th>G</th><th>E</th><th>P</th><th>GF</th><th>GC</th>
+                    </tr>
+                    ${filas.map(f => `
+                        <tr>
+                            <td>${f.posicion}</td>
+                            <td><a href="perfil.html?u=${f.id_usuario}">${f.id_usuario === user?.id_usuario
+                                ? `<strong>${f.nombre_usuario}</strong>` : f.nombre_usuario}</a></td>
+                            <td>${f.elo} (${diferencia(f.elo - f.elo_inicial)})</td>
+                            <td>${f.elo_maximo}</td>
+                            <td>${f.jugadas}</td><td>${f.ganadas}</td><td>${f.empatadas}</td><td>${f.perdidas}</td>
+                            <td>${f.goles_a_favor}</td><td>${f.goles_en_contra}</td>
+                        </tr>
+                    `).join("")}
+                </table>
+            `;
+        } catch (e) {
+            $("clasificacion").innerText = t("temporadas.error");
+            console.error(e);
+        }
+    }
+
+    cargar();
+});
+
+function diferencia(n) {
+    return n > 0 ? `+${n}` : `${n}`;
+}
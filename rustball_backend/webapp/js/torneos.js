@@ -0,0 +1,194 @@
+import { post, get } from "./api.js";
+import { entrarPartida } from "./utils.js";
+import { idioma, t } from "./i18n.js";
+
+/* torneos.html — lista y creación; torneos.html?t=ID — cuadro y clasificación */
+document.addEventListener("DOMContentLoaded", () => {
+    const $   = (id) => document.getElementById(id);
+    const log = (m)  => { $("resultado").textContent = m; };
+
+    const user = JSON.parse(localStorage.getItem("rb_user") || "null");
+    if (!user) {
+        alert(t("comun.sin-sesion"));
+        window.location.href = "login.html";
+        return;
+    }
+
+    const idTorneo = parseInt(new URLSearchParams(window.location.search).get("t"), 10);
+    if (idTorneo) {
+        $("vista-lista").hidden = true;
+        $("vista-detalle").hidden = false;
+        cargarTorneo(idTorneo);
+        return;
+    }
+
+    cargarCandidatos();
+    cargarLista();
+
+    $("formato").addEventListener("change", () => {
+        $("ida-y-vuelta").disabled = $("formato").value !== "liga";
+    });
+    $("ida-y-vuelta").disabled = true;
+
+    /* Candidatos: yo y mis amigos, marcados con casillas */
+    async function cargarCandidatos() {
+        const candidatos = [{ id_usuario: user.id_usuario, nombre_usuario: user.nombre_usuario }];
+        try {
+            const { amigos } = await get(`/amigos/${user.id_usuario}`);
+            candidatos.push(...amigos);
+        } catch (e) {
+            console.error(e);
+        }
+        $("candidatos").innerHTML = candidatos.map(u => `
+            <label><input type="checkbox" value="${u.id_usuario}" checked> ${u.nombre_usuario}</label>
+        `).join("");
+    }
+
+    $("btn-crear").addEventListener("click", async () => {
+        const marcados = [...$("candidatos").querySelectorAll("input:checked")].map(c => parseInt(c.value, 10));
+        const otros = $("otros").value.split(",").map(s => parseInt(s.trim(), 10)).filter(Boolean);
+        const participantes = [...new Set([...marcados, ...otros])];
+
+        try {
+            const torneo = await post("/torneos", {
+                nombre: $("nombre").value.trim(),
+                id_creador: user.id_usuario,
+                formato: $("formato").value,
+                ida_y_vuelta: $("ida-y-vuelta").checked,
+                participantes,
+            });
+            window.location.href = `torneos.html?t=${torneo.id_torneo}`;
+        } catch (e) {
+            log(`❌ ${e.message}`);
+        }
+    });
+
+    async function cargarLista() {
+        try {
+            const torneos = await get("/torneos");
+            $("lista").innerHTML = torneos.length === 0
+                ? t("torneos.vacio")
+                : torneos.map(tr => `
+                    <div>
+                        <a href="torneos.html?t=${tr.id_torneo}">${tr.nombre}</a>
+                        — ${t(`torneos.${tr.formato}`)}, ${t("torneos.participantes", { n: tr.participantes })},
+                        ${estadoTorneo(tr)}
+                    </div>
+                `).join("");
+        } catch (e) {
+            $("lista").innerText = t("torneos.error");
+            console.error(e);
+        }
+    }
+
+    async function cargarTorneo(id) {
+        try {
+            const tr = await get(`/torneos/${id}`);
+            const nombres = Object.fromEntries(tr.clasificacion.map(f => [f.id_usuario, f.nombre_usuario]));
+
+            document.title = `RustBall – ${tr.nombre}`;
+            $("titulo").textContent = `🏆 ${tr.nombre}`;
+            $("resumen").innerHTML = `
+                ${t(`torneos.${tr.formato}`)}${tr.ida_y_vuelta ? ` (${t("torneos.ida-y-vuelta")})` : ""}
+                — ${t("torneos.participantes", { n: tr.participantes })}
+                — ${new Date(tr.fecha_creacion).toLocaleDateString(idioma())}
+                — ${estadoTorneo(tr, nombres)}
+            `;
+
+            // ⚽ Mi partida pendiente en la ronda abierta
+            const mia = tr.encuentros.find(e =>
+                e.id_partida && e.estado_partida !== "finished"
+                && (e.id_jugador1 === user.id_usuario || e.id_jugador2 === user.id_usuario));
+            $("mi-partida").innerHTML = "";
+            if (mia) {
+                const rival = mia.id_jugador1 === user.id_usuario ? mia.nombre_jugador2 : mia.nombre_jugador1;
+                const div = document.createElement("div");
+                div.innerHTML = `${t("torneos.tu-partida", { rival })} <button>${t("torneos.jugar")}</button>`;
+                div.querySelector("button").onclick = () =>
+                    entrarPartida(mia.id_partida, mia.id_jugador1, mia.id_jugador2, user.id_usuario);
+                $("mi-partida").appendChild(div);
+            }
+
+            $("clasificacion").innerHTML = tablaClasificacion(tr);
+            $("cuadro").innerHTML = cuadro(tr);
+        } catch (e) {
+            log(`❌ ${e.message}`);
+            console.error(e);
+        }
+    }
+});
+
+function estadoTorneo(tr, nombres = {}) {
+    if (tr.estado === "terminado") {
+        // La final sin ganador (empate o anulada) deja el torneo sin campeón
+        if (tr.campeon == null) return t("torneos.sin-campeon");
+        return t("torneos.campeon", { nombre: nombres[tr.campeon] ?? `#${tr.campeon}` });
+    }
+    return t("torneos.en-curso", { ronda: tr.ronda_actual, total: tr.total_rondas });
+}
+
+function tablaClasificacion(tr) {
+    const liga = tr.formato === "liga";
+    const cabecera = liga
+        ? `<th>PJ</th><th>G</th><th>E</th><th>P</th><th>GF</th><th>GC</th><th>${t("torneos.puntos")}</th>`
+        : `<th>${t("torneos.ronda-alcanzada")}</th><th>G</th><th>GF</th><th>GC</th>`;
+
+    return `
+        <table>
+            <tr><th>#</th><th>${t("torneos.jugador")}</th>${cabecera}</tr>
+            ${tr.clasificacion.map((f, i) => `
+                <tr>
+                    <td>${i + 1}</td>
+                    <td><a href="perfil.html?u=${f.id_usuario}">${f.nombre_usuario}</a> (${f.semilla})${f.eliminado ? " ❌" : ""}</td>
+                    ${liga
+                        ? `<td>${f.jugadas}</td><td>${f.ganadas}</td><td>${f.empatadas}</td><td>${f.perdidas}</td>
+                           <td>${f.goles_a_favor}</td><td>${f.goles_en_contra}</td><td><strong>${f.puntos}</strong></td>`
+                        : `<td>${f.ronda_alcanzada}</td><td>${f.ganadas}</td>
+                           <td>${f.goles_a_favor}</td><td>${f.goles_en_contra}</td>`}
+                </tr>
+            `).join("")}
+        </table>
+    `;
+}
+
+/* Partidas agrupadas por ronda; en eliminación se ven también los cruces por definir */
+function cuadro(tr) {
+    const rondas = new Map();
+    tr.encuentros.forEach(e => {
+        if (!rondas.has(e.ronda)) rondas.set(e.ronda, []);
+        rondas.get(e.ronda).push(e);
+    });
+
+    return [...rondas].map(([ronda, encuentros]) => `
+        <h3>${nombreRonda(tr, ronda)}</h3>
+        ${encuentros.map(e => `<div>${encuentro(e)}</div>`).join("")}
+    `).join("");
+}
+
+function nombreRonda(tr, ronda) {
+    if (tr.formato === "eliminacion") {
+        const faltan = tr.total_rondas - ronda;
+        if (faltan === 0) return t("torneos.final");
+        if (faltan === 1) return t("torneos.semifinal");
+    }
+    return t("torneos.ronda", { ronda });
+}
+
+function encuentro(e) {
+    const jugador = (id, nombre) => {
+        if (!id) return `<em>${t("torneos.por-definir")}</em>`;
+        const texto = e.ganador === id ? `<strong>${nombre}</strong>` : nombre;
+        return `<a href="perfil.html?u=${id}">${texto}</a>`;
+    };
+
+    // Bye: un solo jugador que pasa sin jugar
+    if (!e.id_partida && e.ganador && (!e.id_jugador1 || !e.id_jugador2)) {
+        const id = e.id_jugador1 ?? e.id_jugador2;
+        return `${jugador(id, e.nombre_jugador1 ?? e.nombre_jugador2)} — ${t("torneos.bye")}`;
+    }
+
+    const marcador = e.estado_partida === "finished"
+        ? `${e.gol_j1}–${e.gol_j2}`
+        : e.id_partida ? t("torneos.jugando", { partida: e.id_partida }) : "vs";
+    return `${jugador(e.id_jugador1, e.nombre_jugador1)} ${marcador} ${jugador(e.id_jugador2, e.nombre_jugador2)}`;
+}
@@ -6,11 +6,14 @@
  * @param {number} id1 - ID del primer jugador
  * @param {number} id2 - ID del segundo jugador
  * @param {number} miId - ID del usuario actual (el que está entrando)
+ *
+ * Si la partida es clasificatoria lo dice el snapshot del servidor.
  */
 export function entrarPartida(idPartida, id1, id2, miId) {
     const left = Math.min(id1, id2);
     const right = Math.max(id1, id2);
 
+    localStorage.setItem("rb_modo", "online");
     localStorage.setItem("rb_pid", idPartida);
     localStorage.setItem("rb_uid", miId);
     localStorage.setItem("rb_id_left", left);
@@ -18,3 +21,22 @@ export function entrarPartida(idPartida, id1, id2, miId) {
 
     window.location.href = "/game/index.html";
 }
+
+/**
+ * Abre el juego en modo local (dos jugadores en la misma pantalla, sin backend).
+ */
+export function entrarPartidaLocal() {
+    localStorage.setItem("rb_modo", "local");
+    window.location.href = "/game/index.html";
+}
+
+/**
+ * Abre el juego contra la IA del cliente (sin backend).
+ *
+ * @param {"facil"|"medio"|"dificil"} dificultad
+ */
+export function entrarPartidaBot(dificultad = "medio") {
+    localStorage.setItem("rb_modo", "bot");
+    localStorage.setItem("rb_bot", dificultad);
+    window.location.href = "/game/index.html";
+}
@@ -0,0 +1,257 @@
+//! Rival controlado por la máquina: tablas de dificultad, puntuación de
+//! tiros y elección del tiro.  La usan el bot local del cliente
+//! (`GameMode::VsBot`) y los usuarios bot del servidor.
+//!
+//! Para cada ficha propia se prueba una rejilla de direcciones ×
+//! potencias con `sim::predict_shot`, se puntúa cada tiro según cuánto
+//! acerca la pelota al arco rival y cuánto riesgo deja frente al
+//! propio, y se dispara uno de los mejores.
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::field::{FIELD_W, SHOT_MAX_SPEED};
+use crate::math::V2;
+use crate::sim::{goal_side, predict_shot, Body, ShotPrediction};
+
+/// Rebotes contra paredes que simula el bot en cada tiro candidato.
+pub const MAX_BOUNCES: usize = 2;
+/// Puntuación de un gol (negativa si es en contra).
+pub const GOAL_SCORE: f32 = 1000.0;
+/// Distancia al propio arco a partir de la cual la pelota se considera peligrosa.
+pub const DANGER_RADIUS: f32 = 300.0;
+
+/* ───────── Dificultad ───────── */
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Medium,
+    Hard,
+}
+
+impl Difficulty {
+    /// Acepta los valores que guarda la web (`facil`, `medio`, `dificil`)
+    /// y sus equivalentes en inglés.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "facil" | "easy" => Some(Self::Easy),
+            "medio" | "medium" => Some(Self::Medium),
+            "dificil" | "hard" => Some(Self::Hard),
+            _ => None,
+        }
+    }
+
+    /// Nombre que usan la web y los usuarios bot (`bot_facil`, …).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Easy => "facil",
+            Self::Medium => "medio",
+            Self::Hard => "dificil",
+        }
+    }
+
+    pub fn direction_samples(&self) -> usize {
+        match self {
+            Self::Easy => 12,
+            Self::Medium => 24,
+            Self::Hard => 48,
+        }
+    }
+
+    pub fn power_samples(&self) -> &'static [f32] {
+        match self {
+            Self::Easy => &[0.6, 1.0],
+            Self::Medium => &[0.4, 0.7, 1.0],
+            Self::Hard => &[0.3, 0.5, 0.7, 0.85, 1.0],
+        }
+    }
+
+    /// Error máximo de puntería (radianes) aplicado al tiro elegido.
+    pub fn aim_noise(&self) -> f32 {
+        match self {
+            Self::Easy => 0.25,
+            Self::Medium => 0.08,
+            Self::Hard => 0.0,
+        }
+    }
+
+    /// Se elige al azar entre los `top_k` mejores tiros.
+    pub fn top_k(&self) -> usize {
+        match self {
+            Self::Easy => 4,
+            Self::Medium => 2,
+            Self::Hard => 1,
+        }
+    }
+}
+
+/* ───────── Planificación ───────── */
+
+/// Tiro elegido por el bot.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShotPlan {
+    /// Índice de la ficha disparada en `bodies`.
+    pub shooter: usize,
+    pub direction: V2,
+    pub power: f32,
+    pub score: f32,
+}
+
+impl ShotPlan {
+    pub fn velocity(&self) -> V2 {
+        self.direction * (self.power * SHOT_MAX_SPEED)
+    }
+}
+
+/// Todos los cuerpos menos `skip`, junto con su índice original.
+pub fn others_of(bodies: &[Body], skip: usize) -> (Vec<Body>, Vec<usize>) {
+    bodies.iter().enumerate().filter(|(i, _)| *i != skip).map(|(i, b)| (*b, i)).unzip()
+}
+
+/// Evalúa todos los tiros candidatos de `shooters` y devuelve el elegido.
+///
+/// * `shooters` – índices en `bodies` de las fichas del bot.
+/// * `bodies` – todas las fichas (propias y rivales) y la pelota.
+/// * `attacks_left` – `true` si el bot ataca el arco izquierdo.
+pub fn plan_shot(
+    shooters: &[usize],
+    bodies: &[Body],
+    attacks_left: bool,
+    difficulty: Difficulty,
+    rng: &mut impl Rng,
+) -> Option<ShotPlan> {
+    let ball = bodies.iter().find(|b| b.is_ball)?.pos;
+    let n_dirs = difficulty.direction_samples();
+    let mut candidates = Vec::new();
+
+    for &shooter in shooters {
+        let (others, _) = others_of(bodies, shooter);
+
+        for i in 0..n_dirs {
+            let direction = V2::from_angle(i as f32 / n_dirs as f32 * std::f32::consts::TAU);
+
+            for &power in difficulty.power_samples() {
+                let velocity = direction * (power * SHOT_MAX_SPEED);
+                let prediction = predict_shot(bodies[shooter], velocity, &others, MAX_BOUNCES);
+                let score = score_shot(&prediction, ball, attacks_left);
+                candidates.push(ShotPlan { shooter, direction, power, score });
+            }
+        }
+    }
+
+    candidates.sort_by(|a, b| b.score.total_cmp(&a.score));
+    candidates.truncate(difficulty.top_k());
+
+    let mut plan = *candidates.choose(rng)?;
+    let noise = difficulty.aim_noise();
+    if noise > 0.0 {
+        plan.direction = plan.direction.rotate(rng.gen_range(-noise..=noise));
+    }
+    Some(plan)
+}
+
+/// Puntuación de un tiro: avance de la pelota hacia el arco rival menos el
+/// riesgo de dejarla cerca del propio.
+pub fn score_shot(prediction: &ShotPrediction, ball: V2, attacks_left: bool) -> f32 {
+    let half_w = FIELD_W / 2.0;
+    let own_goal = V2::new(if attacks_left { half_w } else { -half_w }, 0.0);
+
+    match &prediction.contact {
+        Some(contact) if contact.target.is_ball => {
+            let end = contact.pushed_path.last().copied().unwrap_or(ball);
+
+            match goal_side(end) {
+                Some(left) if left == attacks_left => return GOAL_SCORE,
+                Some(_) => return -GOAL_SCORE,
+                None => {}
+            }
+
+            let progress = if attacks_left { ball.x - end.x } else { end.x - ball.x };
+            let centrality = -end.y.abs() * 0.2;
+            let danger = (DANGER_RADIUS - end.distance(own_goal)).max(0.0);
+
+            progress + centrality - danger * 0.5
+        }
+        // Chocar con otra ficha casi nunca ayuda
+        Some(_) => -20.0,
+        // Sin contacto: reposicionarse entre la pelota y el propio arco
+        None => {
+            let end = prediction.end().unwrap_or(ball);
+            let guard = own_goal.lerp(ball, 0.35);
+            -30.0 - end.distance(guard) * 0.1
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::mock::StepRng;
+
+    #[test]
+    fn difficulty_names_round_trip() {
+        for d in [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard] {
+            assert_eq!(Difficulty::parse(d.as_str()), Some(d));
+        }
+        assert_eq!(Difficulty::parse("hard"), Some(Difficulty::Hard));
+        assert_eq!(Difficulty::parse("imposible"), None);
+    }
+
+    #[test]
+    fn harder_bots_search_more_and_aim_better() {
+        let levels = [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard];
+        for pair in levels.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            assert!(a.direction_samples() < b.direction_samples());
+            assert!(a.power_samples().len() < b.power_samples().len());
+            assert!(a.aim_noise() > b.aim_noise());
+            assert!(a.top_k() > b.top_k());
+        }
+        assert_eq!(Difficulty::Hard.aim_noise(), 0.0);
+        assert_eq!(Difficulty::Hard.top_k(), 1);
+    }
+
+    #[test]
+    fn scoring_a_goal_beats_everything() {
+        // Pelota en la boca del arco izquierdo, la ficha justo detrás
+        let ball = V2::new(-FIELD_W / 2.0 + 60.0, 0.0);
+        let bodies = [Body::ball(ball), Body::disk(V2::new(ball.x + 120.0, 0.0))];
+        let velocity = V2::new(-SHOT_MAX_SPEED, 0.0);
+        let prediction = predict_shot(bodies[1], velocity, &bodies[..1], MAX_BOUNCES);
+
+        assert_eq!(score_shot(&prediction, ball, true), GOAL_SCORE);
+        assert_eq!(score_shot(&prediction, ball, false), -GOAL_SCORE);
+    }
+
+    #[test]
+    fn missing_everything_scores_below_pushing_the_ball_forward() {
+        let ball = V2::ZERO;
+        let shooter = Body::disk(V2::new(150.0, 0.0));
+        let others = [Body::ball(ball)];
+
+        let towards = predict_shot(shooter, V2::new(-400.0, 0.0), &others, MAX_BOUNCES);
+        let away = predict_shot(shooter, V2::new(0.0, 400.0), &others, MAX_BOUNCES);
+        assert!(score_shot(&towards, ball, true) > score_shot(&away, ball, true));
+    }
+
+    #[test]
+    fn hard_bot_takes_the_open_goal() {
+        let ball = V2::new(-FIELD_W / 2.0 + 100.0, 0.0);
+        let bodies = [
+            Body::ball(ball),
+            Body::disk(V2::new(ball.x + 150.0, 0.0)),
+            Body::disk(V2::new(300.0, 250.0)),
+        ];
+        // Difícil: sin ruido y sólo el mejor tiro, el rng no influye
+        let plan = plan_shot(&[1, 2], &bodies, true, Difficulty::Hard, &mut StepRng::new(0, 1)).unwrap();
+        assert_eq!(plan.shooter, 1);
+        assert_eq!(plan.score, GOAL_SCORE);
+    }
+
+    #[test]
+    fn no_shooters_no_plan() {
+        let bodies = [Body::ball(V2::ZERO)];
+        assert_eq!(plan_shot(&[], &bodies, true, Difficulty::Easy, &mut StepRng::new(0, 1)), None);
+    }
+}
@@ -0,0 +1,25 @@
+//! Medidas de la cancha y parámetros físicos (mismos valores que los
+//! spawns de Rapier del cliente).
+
+/// Ancho interior de la cancha en píxeles.
+pub const FIELD_W: f32 = 1100.0;
+/// Alto interior de la cancha en píxeles.
+pub const FIELD_H: f32 = 741.0;
+/// Grosor de las paredes laterales, superior e inferior.
+pub const WALL_THICKNESS: f32 = 10.0;
+/// Hueco de las paredes laterales donde están los arcos.
+pub const GOAL_GAP: f32 = 200.0;
+/// Restitución de las paredes (las esquinas usan un valor propio).
+pub const WALL_RESTITUTION: f32 = 1.0;
+
+/* ───────── Cuerpos ───────── */
+pub const DISK_RADIUS: f32 = 35.0;
+pub const BALL_RADIUS: f32 = 20.0;
+pub const DISK_RESTITUTION: f32 = 0.5;
+pub const BALL_RESTITUTION: f32 = 1.0;
+pub const LINEAR_DAMPING: f32 = 2.0;
+
+/// Paso de integración (60 Hz).
+pub const SIM_DT: f32 = 1.0 / 60.0;
+/// Velocidad de un disparo a potencia máxima.
+pub const SHOT_MAX_SPEED: f32 = 800.0;
@@ -0,0 +1,187 @@
+//! Geometría de las formaciones: las cuatro predefinidas, las reglas de
+//! las personalizadas y el ajuste de saque.
+//!
+//! Las posiciones van en coordenadas “canónicas” del lado derecho
+//! (x > 0, arco propio en x = +550); quien juega a la izquierda las
+//! refleja con `mirror`.
+
+use crate::field::{DISK_RADIUS, FIELD_H, FIELD_W};
+use crate::math::V2;
+
+pub const DISKS_PER_FORMATION: usize = 5;
+
+/// Área delante del arco propio donde no puede arrancar ninguna ficha:
+/// `x > FIELD_W / 2 - GOAL_AREA_DEPTH`, `|y| < GOAL_AREA_HALF_HEIGHT`.
+pub const GOAL_AREA_DEPTH: f32 = 100.0;
+pub const GOAL_AREA_HALF_HEIGHT: f32 = 150.0;
+
+/// Círculo central del saque: las fichas de quien no saca quedan fuera
+/// hasta el primer tiro de la ronda.
+pub const KICKOFF_RADIUS: f32 = 120.0;
+
+/// Formaciones predefinidas (mismo orden que `Formation::PRESETS` del cliente).
+pub const PRESETS: [(&str, [V2; DISKS_PER_FORMATION]); 4] = [
+    ("1-2-1-1", [V2::new(400.0, 0.0), V2::new(300.0, 100.0), V2::new(300.0, -100.0), V2::new(200.0, 0.0), V2::new(100.0, 0.0)]),
+    ("2-2-1", [V2::new(400.0, 100.0), V2::new(400.0, -100.0), V2::new(250.0, 100.0), V2::new(250.0, -100.0), V2::new(100.0, 0.0)]),
+    ("1-1-3", [V2::new(300.0, 150.0), V2::new(300.0, 0.0), V2::new(300.0, -150.0), V2::new(200.0, 0.0), V2::new(400.0, 0.0)]),
+    ("2-1-1-1", [V2::new(400.0, 100.0), V2::new(400.0, -100.0), V2::new(300.0, 0.0), V2::new(200.0, 0.0), V2::new(100.0, 0.0)]),
+];
+
+/// Posiciones canónicas de la predefinida `name`.
+pub fn preset(name: &str) -> Option<[V2; DISKS_PER_FORMATION]> {
+    PRESETS.iter().find(|(n, _)| *n == name).map(|(_, ps)| *ps)
+}
+
+/// Refleja una posición canónica si la formación juega a la izquierda.
+pub fn mirror(pos: V2, is_left: bool) -> V2 {
+    if is_left { V2::new(-pos.x, pos.y) } else { pos }
+}
+
+/// Si `pos` (ya reflejada) invade el círculo central se empuja hasta su
+/// borde, siempre hacia el campo propio.
+pub fn kickoff_position(pos: V2, is_left: bool) -> V2 {
+    let own_side = if is_left { -1.0 } else { 1.0 };
+    let min_dist = KICKOFF_RADIUS + DISK_RADIUS;
+    if pos.length() >= min_dist {
+        return pos;
+    }
+    let dir = if pos.x * own_side > 0.0 { pos.normalize_or_zero() } else { V2::new(own_side, 0.0) };
+    dir * min_dist
+}
+
+/// Motivo por el que se rechaza una formación personalizada.  Cada lado
+/// lo traduce con su propio catálogo (`key` + argumentos).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormationError {
+    /// Cantidad de fichas distinta de `DISKS_PER_FORMATION`.
+    Count(usize),
+    /// La ficha `n` (desde 1) tiene coordenadas no finitas.
+    Coordinates(usize),
+    /// La ficha `n` no queda entera en el campo propio.
+    OwnHalf(usize),
+    /// La ficha `n` se sale de la cancha.
+    OutOfField(usize),
+    /// La ficha `n` está dentro del área.
+    GoalArea(usize),
+    /// Las fichas `a` y `b` se superponen.
+    Overlap(usize, usize),
+}
+
+impl FormationError {
+    /// Clave de traducción (la misma en los `.ftl` del cliente y del servidor).
+    pub fn key(&self) -> &'static str {
+        match self {
+            Self::Count(_) => "formacion-cantidad",
+            Self::Coordinates(_) => "ficha-coordenadas",
+            Self::OwnHalf(_) => "ficha-campo",
+            Self::OutOfField(_) => "ficha-fuera",
+            Self::GoalArea(_) => "ficha-area",
+            Self::Overlap(..) => "fichas-superpuestas",
+        }
+    }
+}
+
+/// Reglas de una formación personalizada (coordenadas canónicas): 5
+/// fichas dentro de la cancha, en el campo propio, fuera del área y sin
+/// solaparse.
+pub fn validate_positions(positions: &[V2]) -> Result<(), FormationError> {
+    let (half_w, half_h) = (FIELD_W / 2.0, FIELD_H / 2.0);
+    let r = DISK_RADIUS;
+
+    if positions.len() != DISKS_PER_FORMATION {
+        return Err(FormationError::Count(positions.len()));
+    }
+
+    for (i, p) in positions.iter().enumerate() {
+        let n = i + 1;
+        if !p.x.is_finite() || !p.y.is_finite() {
+            return Err(FormationError::Coordinates(n));
+        }
+        if p.x - r < 0.0 {
+            return Err(FormationError::OwnHalf(n));
+        }
+        if p.x + r > half_w || p.y.abs() + r > half_h {
+            return Err(FormationError::OutOfField(n));
+        }
+        if p.x + r > half_w - GOAL_AREA_DEPTH && p.y.abs() - r < GOAL_AREA_HALF_HEIGHT {
+            return Err(FormationError::GoalArea(n));
+        }
+        if let Some(j) = positions[i + 1..].iter().position(|q| p.distance(*q) < 2.0 * r) {
+            return Err(FormationError::Overlap(n, n + j + 1));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with(i: usize, pos: V2) -> Vec<V2> {
+        let mut ps = PRESETS[0].1.to_vec();
+        ps[i] = pos;
+        ps
+    }
+
+    #[test]
+    fn presets_are_valid() {
+        for (name, positions) in PRESETS {
+            assert_eq!(validate_positions(&positions), Ok(()), "{name}");
+            assert_eq!(preset(name), Some(positions));
+        }
+        assert_eq!(preset("4-4-2"), None);
+    }
+
+    #[test]
+    fn each_rule_reports_its_disk() {
+        assert_eq!(validate_positions(&PRESETS[0].1[..4]), Err(FormationError::Count(4)));
+        assert_eq!(validate_positions(&with(1, V2::new(f32::NAN, 0.0))), Err(FormationError::Coordinates(2)));
+        assert_eq!(validate_positions(&with(2, V2::new(20.0, 250.0))), Err(FormationError::OwnHalf(3)));
+        assert_eq!(validate_positions(&with(3, V2::new(200.0, 350.0))), Err(FormationError::OutOfField(4)));
+        assert_eq!(validate_positions(&with(0, V2::new(450.0, 0.0))), Err(FormationError::GoalArea(1)));
+        assert_eq!(validate_positions(&with(4, V2::new(210.0, 10.0))), Err(FormationError::Overlap(4, 5)));
+    }
+
+    #[test]
+    fn keys_exist_in_every_locale_file() {
+        let locales = [
+            include_str!("../../RustBall/locales/es.ftl"),
+            include_str!("../../RustBall/locales/en.ftl"),
+            include_str!("../../rustball_backend/locales/es.ftl"),
+            include_str!("../../rustball_backend/locales/en.ftl"),
+        ];
+        let errors = [
+            FormationError::Count(0),
+            FormationError::Coordinates(1),
+            FormationError::OwnHalf(1),
+            FormationError::OutOfField(1),
+            FormationError::GoalArea(1),
+            FormationError::Overlap(1, 2),
+        ];
+        for ftl in locales {
+            for e in errors {
+                assert!(ftl.lines().any(|l| l.starts_with(&format!("{} =", e.key()))), "falta {}", e.key());
+            }
+        }
+    }
+
+    #[test]
+    fn mirror_flips_only_the_left_side() {
+        assert_eq!(mirror(V2::new(300.0, 10.0), true), V2::new(-300.0, 10.0));
+        assert_eq!(mirror(V2::new(300.0, 10.0), false), V2::new(300.0, 10.0));
+    }
+
+    #[test]
+    fn kickoff_pushes_disks_out_of_the_center_circle() {
+        let min_dist = KICKOFF_RADIUS + DISK_RADIUS;
+
+        // Propia: se empuja en su dirección
+        let p = kickoff_position(V2::new(100.0, 0.0), false);
+        assert!((p.x - min_dist).abs() < 1e-3 && p.y == 0.0);
+        // En el campo rival o en el centro: hacia el propio
+        assert_eq!(kickoff_position(V2::ZERO, true), V2::new(-min_dist, 0.0));
+        // Fuera del círculo no se toca
+        assert_eq!(kickoff_position(V2::new(-300.0, 10.0), true), V2::new(-300.0, 10.0));
+    }
+}
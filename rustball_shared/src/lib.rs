@@ -0,0 +1,23 @@
+//! rustball_shared/src/lib.rs
+//! --------------------------------------------------------------
+//! Código compartido entre el cliente y el servidor.
+//!
+//!   • `field`: medidas de la cancha y parámetros físicos.
+//!   • `math`: vector 2D mínimo (`V2`) para no depender de Bevy.
+//!   • `sim`: predicción simplificada de un disparo (la que dibuja
+//!     el cliente y la que usa la IA de ambos lados).
+//!   • `bot`: tablas de dificultad, puntuación y elección del tiro.
+//!   • `formation`: formaciones predefinidas, reglas de las
+//!     personalizadas y ajuste de saque.
+//!
+//! Si algo de aquí cambia, cambia a la vez para el bot local del
+//! cliente y para los bots del servidor.
+//! --------------------------------------------------------------
+
+pub mod bot;
+pub mod field;
+pub mod formation;
+pub mod math;
+pub mod sim;
+
+pub use math::V2;
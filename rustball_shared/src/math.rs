@@ -0,0 +1,100 @@
+//! Vector 2D mínimo: lo justo para la simulación sin arrastrar Bevy
+//! (el cliente convierte con `From<[f32; 2]>`).
+
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub};
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct V2 {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl V2 {
+    pub const ZERO: V2 = V2 { x: 0.0, y: 0.0 };
+
+    pub const fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    /// Vector unitario con ángulo `a` (radianes).
+    pub fn from_angle(a: f32) -> Self {
+        let (y, x) = a.sin_cos();
+        Self { x, y }
+    }
+
+    pub fn dot(self, o: V2) -> f32 {
+        self.x * o.x + self.y * o.y
+    }
+
+    pub fn length_squared(self) -> f32 {
+        self.dot(self)
+    }
+
+    pub fn length(self) -> f32 {
+        self.length_squared().sqrt()
+    }
+
+    pub fn distance(self, o: V2) -> f32 {
+        (self - o).length()
+    }
+
+    pub fn normalize_or_zero(self) -> V2 {
+        let l = self.length();
+        if l > 0.0 { self * (1.0 / l) } else { V2::ZERO }
+    }
+
+    /// Gira el vector `a` radianes.
+    pub fn rotate(self, a: f32) -> V2 {
+        let (s, c) = a.sin_cos();
+        V2 { x: self.x * c - self.y * s, y: self.x * s + self.y * c }
+    }
+
+    pub fn lerp(self, o: V2, t: f32) -> V2 {
+        self + (o - self) * t
+    }
+}
+
+impl Add for V2 {
+    type Output = V2;
+    fn add(self, o: V2) -> V2 {
+        V2 { x: self.x + o.x, y: self.y + o.y }
+    }
+}
+
+impl AddAssign for V2 {
+    fn add_assign(&mut self, o: V2) {
+        *self = *self + o;
+    }
+}
+
+impl Sub for V2 {
+    type Output = V2;
+    fn sub(self, o: V2) -> V2 {
+        V2 { x: self.x - o.x, y: self.y - o.y }
+    }
+}
+
+impl Mul<f32> for V2 {
+    type Output = V2;
+    fn mul(self, s: f32) -> V2 {
+        V2 { x: self.x * s, y: self.y * s }
+    }
+}
+
+impl MulAssign<f32> for V2 {
+    fn mul_assign(&mut self, s: f32) {
+        *self = *self * s;
+    }
+}
+
+impl From<[f32; 2]> for V2 {
+    fn from([x, y]: [f32; 2]) -> Self {
+        V2 { x, y }
+    }
+}
+
+impl From<V2> for [f32; 2] {
+    fn from(v: V2) -> Self {
+        [v.x, v.y]
+    }
+}
@@ -0,0 +1,262 @@
+//! Predicción simplificada de la trayectoria de un disparo.
+//!
+//! No es Rapier: integra la ficha con el mismo damping y las mismas
+//! restituciones que usan los spawns del cliente, rebota contra las
+//! paredes y se detiene en el primer contacto con otra ficha o con la
+//! pelota, calculando hacia dónde saldría empujado el cuerpo golpeado.
+//!
+//!   • Las esquinas rotadas y los postes de los arcos se ignoran.
+//!   • Las masas replican las de Rapier (densidad 1 + masa extra 1).
+
+use crate::field::*;
+use crate::math::V2;
+
+/// Velocidad por debajo de la cual el cuerpo se considera detenido
+/// (≈ √THRESHOLD de `check_turn_end`).
+pub const STOP_SPEED: f32 = 0.7;
+/// Límite duro de pasos por simulación (10 s a 60 Hz).
+pub const MAX_STEPS: usize = 600;
+
+/// Cuerpo circular participante en la simulación.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Body {
+    pub pos: V2,
+    pub radius: f32,
+    pub restitution: f32,
+    pub is_ball: bool,
+}
+
+impl Body {
+    pub fn disk(pos: V2) -> Self {
+        Self { pos, radius: DISK_RADIUS, restitution: DISK_RESTITUTION, is_ball: false }
+    }
+
+    pub fn ball(pos: V2) -> Self {
+        Self { pos, radius: BALL_RADIUS, restitution: BALL_RESTITUTION, is_ball: true }
+    }
+
+    /// Masa equivalente a la que calcula Rapier para el collider.
+    pub fn mass(&self) -> f32 {
+        std::f32::consts::PI * self.radius * self.radius + 1.0
+    }
+}
+
+/// Primer contacto del disparo con otro cuerpo.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ShotContact {
+    /// Posición de la ficha disparada en el momento del choque.
+    pub point: V2,
+    /// Índice del cuerpo golpeado en `others`.
+    pub index: usize,
+    pub target: Body,
+    /// Velocidad con la que sale despedido el cuerpo golpeado.
+    pub push_velocity: V2,
+    /// Recorrido estimado del cuerpo golpeado (sólo contra paredes).
+    pub pushed_path: Vec<V2>,
+}
+
+/// Resultado de `predict_shot`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ShotPrediction {
+    /// Origen, puntos de rebote y punto final de la ficha.
+    pub path: Vec<V2>,
+    pub bounces: Vec<V2>,
+    pub contact: Option<ShotContact>,
+}
+
+impl ShotPrediction {
+    /// Dónde queda la ficha disparada.
+    pub fn end(&self) -> Option<V2> {
+        self.path.last().copied()
+    }
+}
+
+/// Simula el disparo de `shooter` con `velocity` inicial.
+///
+/// La ficha rebota como máximo `max_bounces` veces contra las paredes; la
+/// simulación termina en el rebote siguiente, al detenerse, al entrar en un
+/// arco o al tocar cualquiera de `others`.
+pub fn predict_shot(shooter: Body, velocity: V2, others: &[Body], max_bounces: usize) -> ShotPrediction {
+    let roll = roll_body(shooter, velocity, others, max_bounces);
+
+    let contact = roll.contact.and_then(|(index, point, vel)| {
+        let target = others[index];
+        let normal = (target.pos - point).normalize_or_zero();
+        let vn = vel.dot(normal);
+        if vn <= 0.0 {
+            return None;
+        }
+
+        let e = (shooter.restitution + target.restitution) / 2.0;
+        let (m1, m2) = (shooter.mass(), target.mass());
+        let push_velocity = normal * (vn * (1.0 + e) * m1 / (m1 + m2));
+        let pushed_path = roll_body(target, push_velocity, &[], max_bounces).path;
+
+        Some(ShotContact { point, index, target, push_velocity, pushed_path })
+    });
+
+    ShotPrediction { path: roll.path, bounces: roll.bounces, contact }
+}
+
+/// Devuelve `Some(true)` si `pos` está dentro del arco izquierdo,
+/// `Some(false)` si está en el derecho.
+pub fn goal_side(pos: V2) -> Option<bool> {
+    if pos.y.abs() > GOAL_GAP / 2.0 || pos.x.abs() <= FIELD_W / 2.0 {
+        return None;
+    }
+    Some(pos.x < 0.0)
+}
+
+/* ───────── Integración interna ───────── */
+
+struct Roll {
+    path: Vec<V2>,
+    bounces: Vec<V2>,
+    /// (índice en `others`, posición al chocar, velocidad al chocar)
+    contact: Option<(usize, V2, V2)>,
+}
+
+fn roll_body(body: Body, velocity: V2, others: &[Body], max_bounces: usize) -> Roll {
+    let mut pos = body.pos;
+    let mut vel = velocity;
+    let mut path = vec![pos];
+    let mut bounces = Vec::new();
+
+    for _ in 0..MAX_STEPS {
+        // Rapier aplica el damping sobre la velocidad antes de integrar
+        vel *= 1.0 / (1.0 + SIM_DT * LINEAR_DAMPING);
+        if vel.length() < STOP_SPEED {
+            break;
+        }
+
+        let delta = vel * SIM_DT;
+
+        // 🎯 ¿Toca a otro cuerpo durante este paso?
+        let hit = others
+            .iter()
+            .enumerate()
+            .filter_map(|(i, o)| segment_circle_toi(pos, delta, o.pos, body.radius + o.radius).map(|t| (i, t)))
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+
+        if let Some((idx, t)) = hit {
+            pos += delta * t;
+            path.push(pos);
+            return Roll { path, bounces, contact: Some((idx, pos, vel)) };
+        }
+
+        pos += delta;
+
+        // 🧱 Rebotes contra las paredes
+        if bounce_on_walls(&mut pos, &mut vel, body.radius, body.restitution) {
+            path.push(pos);
+            bounces.push(pos);
+            if bounces.len() > max_bounces {
+                return Roll { path, bounces, contact: None };
+            }
+        }
+
+        // 🥅 Dentro de un arco: no seguimos
+        if goal_side(pos).is_some() {
+            break;
+        }
+    }
+
+    path.push(pos);
+    Roll { path, bounces, contact: None }
+}
+
+/// Menor `t ∈ [0, 1]` en el que `start + t·delta` queda a distancia `reach`
+/// de `center`.
+fn segment_circle_toi(start: V2, delta: V2, center: V2, reach: f32) -> Option<f32> {
+    let f = start - center;
+    let c = f.length_squared() - reach * reach;
+    if c <= 0.0 {
+        // ya en contacto: sólo cuenta si nos estamos acercando
+        return (f.dot(delta) < 0.0).then_some(0.0);
+    }
+
+    let a = delta.length_squared();
+    if a == 0.0 {
+        return None;
+    }
+    let b = 2.0 * f.dot(delta);
+    let disc = b * b - 4.0 * a * c;
+    if disc < 0.0 {
+        return None;
+    }
+
+    let t = (-b - disc.sqrt()) / (2.0 * a);
+    (0.0..=1.0).contains(&t).then_some(t)
+}
+
+/// Refleja posición y velocidad contra las paredes de `spawn_walls`.
+fn bounce_on_walls(pos: &mut V2, vel: &mut V2, radius: f32, restitution: f32) -> bool {
+    let (half_w, half_h) = (FIELD_W / 2.0, FIELD_H / 2.0);
+    let e = (restitution + WALL_RESTITUTION) / 2.0;
+    let mut bounced = false;
+
+    // Caras interiores de las paredes (ver posiciones en `spawn_walls`)
+    let max_x = half_w - WALL_THICKNESS / 2.0 - radius;
+    let max_y = half_h - WALL_THICKNESS / 2.0 - radius;
+    let min_y = -half_h + radius;
+    let in_goal_mouth = pos.y.abs() < GOAL_GAP / 2.0;
+
+    if !in_goal_mouth && pos.x.abs() > max_x {
+        let limit = max_x.copysign(pos.x);
+        pos.x = 2.0 * limit - pos.x;
+        vel.x = -vel.x * e;
+        bounced = true;
+    }
+    if pos.y > max_y {
+        pos.y = 2.0 * max_y - pos.y;
+        vel.y = -vel.y * e;
+        bounced = true;
+    } else if pos.y < min_y {
+        pos.y = 2.0 * min_y - pos.y;
+        vel.y = -vel.y * e;
+        bounced = true;
+    }
+
+    bounced
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn goal_side_only_inside_the_goal_mouth() {
+        assert_eq!(goal_side(V2::new(-FIELD_W / 2.0 - 1.0, 0.0)), Some(true));
+        assert_eq!(goal_side(V2::new(FIELD_W / 2.0 + 1.0, 50.0)), Some(false));
+        assert_eq!(goal_side(V2::new(FIELD_W / 2.0 + 1.0, GOAL_GAP)), None);
+        assert_eq!(goal_side(V2::ZERO), None);
+    }
+
+    #[test]
+    fn shot_stops_by_damping() {
+        let p = predict_shot(Body::disk(V2::ZERO), V2::new(0.0, 100.0), &[], 2);
+        let end = p.end().unwrap();
+        assert!(p.contact.is_none() && p.bounces.is_empty());
+        assert!(end.y > 0.0 && end.y < 100.0, "terminó en {end:?}");
+    }
+
+    #[test]
+    fn shot_bounces_off_the_top_wall() {
+        let p = predict_shot(Body::disk(V2::ZERO), V2::new(0.0, SHOT_MAX_SPEED), &[], 2);
+        let max_y = FIELD_H / 2.0 - WALL_THICKNESS / 2.0 - DISK_RADIUS;
+        assert_eq!(p.bounces.len(), 1);
+        assert!((p.bounces[0].y - max_y).abs() < SHOT_MAX_SPEED * SIM_DT);
+    }
+
+    #[test]
+    fn contact_pushes_the_ball_along_the_normal() {
+        let ball = Body::ball(V2::new(200.0, 0.0));
+        let p = predict_shot(Body::disk(V2::ZERO), V2::new(SHOT_MAX_SPEED, 0.0), &[ball], 2);
+        let contact = p.contact.expect("debería tocar la pelota");
+
+        assert_eq!(contact.index, 0);
+        assert!((contact.point.x - (200.0 - DISK_RADIUS - BALL_RADIUS)).abs() < 1e-3);
+        assert!(contact.push_velocity.x > 0.0 && contact.push_velocity.y.abs() < 1e-3);
+        assert!(contact.pushed_path.last().unwrap().x > ball.pos.x);
+    }
+}